@@ -68,3 +68,14 @@ Return the proper image name
 {{- $tag := default .Chart.AppVersion .Values.image.tag -}}
 {{- printf "%s:%s" .Values.image.repository $tag -}}
 {{- end }}
+
+{{/*
+Webhook service name and certificate secret
+*/}}
+{{- define "kafka-partition-remapper-operator.webhookServiceName" -}}
+{{- printf "%s-webhook" (include "kafka-partition-remapper-operator.fullname" .) | trunc 63 | trimSuffix "-" }}
+{{- end }}
+
+{{- define "kafka-partition-remapper-operator.webhookCertSecretName" -}}
+{{- default (printf "%s-webhook-tls" (include "kafka-partition-remapper-operator.fullname" .)) .Values.webhook.certSecretName }}
+{{- end }}
@@ -0,0 +1,196 @@
+//! Canary rollout of spec changes
+//!
+//! A spec change is first applied to a separate canary Deployment whose pods
+//! carry the remapper's labels, so the Service routes a share of the traffic
+//! to them. The stable Deployment is only updated once the canary has stayed
+//! healthy for the bake period.
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::apps::v1::Deployment;
+use std::time::Duration;
+
+use crate::adapters::deployment_builder;
+use crate::crd::{CanaryStatus, Condition, KafkaPartitionRemapper};
+
+/// Label marking canary pods and their Deployment
+pub const CANARY_LABEL: &str = "kafka.oso.sh/canary";
+
+/// Name of the canary Deployment for a remapper
+pub fn canary_name(name: &str) -> String {
+    format!("{}-canary", name)
+}
+
+/// Label selector matching a remapper's canary pods
+pub fn canary_label_selector(name: &str) -> String {
+    format!(
+        "{},{}=true",
+        deployment_builder::build_label_selector(name),
+        CANARY_LABEL
+    )
+}
+
+/// Build the canary Deployment running the new spec
+///
+/// The pods keep the stable labels so they receive traffic, and add the
+/// canary label so the two Deployments select disjoint pod sets.
+pub fn build_canary_deployment(
+    remapper: &KafkaPartitionRemapper,
+    config_map_name: &str,
+    config_hash: &str,
+    secrets_hash: Option<&str>,
+) -> Deployment {
+    let name = remapper.resource_name();
+    let mut deployment =
+        deployment_builder::build_deployment(remapper, config_map_name, config_hash, secrets_hash);
+    deployment.metadata.name = Some(canary_name(&name));
+
+    if let Some(ref mut spec) = deployment.spec {
+        spec.replicas = Some(canary_replicas(remapper));
+        spec.selector
+            .match_labels
+            .get_or_insert_with(Default::default)
+            .insert(CANARY_LABEL.to_string(), "true".to_string());
+        if let Some(ref mut metadata) = spec.template.metadata {
+            metadata
+                .labels
+                .get_or_insert_with(Default::default)
+                .insert(CANARY_LABEL.to_string(), "true".to_string());
+        }
+    }
+    deployment
+}
+
+/// Number of canary replicas requested by the rollout strategy
+pub fn canary_replicas(remapper: &KafkaPartitionRemapper) -> i32 {
+    remapper
+        .spec
+        .rollout_strategy
+        .as_ref()
+        .map(|r| r.canary_replicas)
+        .unwrap_or(1)
+}
+
+/// Bake period requested by the rollout strategy
+pub fn bake_time(remapper: &KafkaPartitionRemapper) -> Duration {
+    Duration::from_secs(
+        remapper
+            .spec
+            .rollout_strategy
+            .as_ref()
+            .map(|r| r.bake_time_seconds)
+            .unwrap_or_default(),
+    )
+}
+
+/// Judge the canary from its Deployment and the restarts of its pods
+///
+/// A canary that restarts, misses its progress deadline or turns unready
+/// during the bake period fails; a failed canary stays failed until the spec
+/// changes. Once ready for `bake_time` it passes.
+pub fn evaluate(
+    previous: Option<&CanaryStatus>,
+    config_hash: &str,
+    deployment: &Deployment,
+    restarts: i32,
+    bake_time: Duration,
+    now: DateTime<Utc>,
+) -> CanaryStatus {
+    let previous = previous.filter(|p| p.config_hash == config_hash);
+    if let Some(failed) = previous.filter(|p| p.state == "Failed") {
+        return failed.clone();
+    }
+    let healthy_since = previous.and_then(|p| p.healthy_since);
+
+    let status = |state: &str, healthy_since, message: String| CanaryStatus {
+        config_hash: config_hash.to_string(),
+        state: state.to_string(),
+        healthy_since,
+        message: Some(message),
+    };
+
+    if restarts > 0 {
+        return status(
+            "Failed",
+            healthy_since,
+            format!("Canary pods restarted {} times", restarts),
+        );
+    }
+    if progress_deadline_exceeded(deployment) {
+        return status(
+            "Failed",
+            healthy_since,
+            "Canary did not become ready within its progress deadline".to_string(),
+        );
+    }
+    if !deployment_builder::rolled_out(deployment) {
+        if healthy_since.is_some() {
+            return status(
+                "Failed",
+                healthy_since,
+                "Canary replicas became unready during the bake period".to_string(),
+            );
+        }
+        return status(
+            "Progressing",
+            None,
+            "Waiting for canary replicas to become ready".to_string(),
+        );
+    }
+
+    let since = healthy_since.unwrap_or(now);
+    let remaining = bake_remaining(since, bake_time, now);
+    if remaining.is_zero() {
+        status(
+            "Passed",
+            Some(since),
+            format!("Canary stayed healthy for {}s", bake_time.as_secs()),
+        )
+    } else {
+        status(
+            "Baking",
+            Some(since),
+            format!(
+                "Canary healthy, {}s of the {}s bake time remaining",
+                remaining.as_secs(),
+                bake_time.as_secs()
+            ),
+        )
+    }
+}
+
+/// Time left in the bake period for a canary healthy since `since`
+pub fn bake_remaining(since: DateTime<Utc>, bake_time: Duration, now: DateTime<Utc>) -> Duration {
+    let elapsed = (now - since).to_std().unwrap_or_default();
+    bake_time.saturating_sub(elapsed)
+}
+
+/// Build the CanaryRollout condition reported while a canary is active
+pub fn canary_condition(status: &CanaryStatus) -> Condition {
+    Condition {
+        type_: "CanaryRollout".to_string(),
+        status: if status.state == "Failed" {
+            "False"
+        } else {
+            "True"
+        }
+        .to_string(),
+        last_transition_time: Utc::now(),
+        observed_generation: None,
+        reason: Some(status.state.clone()),
+        message: status.message.clone(),
+    }
+}
+
+fn progress_deadline_exceeded(deployment: &Deployment) -> bool {
+    deployment
+        .status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|conditions| {
+            conditions.iter().any(|c| {
+                c.type_ == "Progressing"
+                    && c.status == "False"
+                    && c.reason.as_deref() == Some("ProgressDeadlineExceeded")
+            })
+        })
+}
@@ -0,0 +1,153 @@
+//! Certificate expiry tracking for mounted TLS secrets
+//!
+//! Renewed certificates already roll the pods through the secrets checksum;
+//! this module reads each certificate's notAfter so the status can warn
+//! before a renewal is missed.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use std::time::Duration;
+
+use crate::crd::{CertificateStatus, Condition};
+
+/// Certificates expiring within this window are reported as not fresh
+pub const RENEWAL_WINDOW: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Read the notAfter of the first certificate in a PEM bundle
+pub fn pem_not_after(pem: &[u8]) -> Option<DateTime<Utc>> {
+    let der = rustls_pemfile::certs(&mut &pem[..]).next()?.ok()?;
+    der_not_after(&der)
+}
+
+/// Read the notAfter of a DER encoded X.509 certificate
+pub fn der_not_after(der: &[u8]) -> Option<DateTime<Utc>> {
+    let (_, certificate, _) = read_tlv(der, 0x30)?;
+    let (_, tbs, _) = read_tlv(certificate, 0x30)?;
+
+    // Skip the optional [0] version, serial number, signature algorithm and
+    // issuer to reach the validity sequence
+    let mut rest = tbs;
+    if rest.first() == Some(&0xa0) {
+        rest = read_tlv(rest, 0xa0)?.2;
+    }
+    rest = read_tlv(rest, 0x02)?.2;
+    rest = read_tlv(rest, 0x30)?.2;
+    rest = read_tlv(rest, 0x30)?.2;
+    let (_, validity, _) = read_tlv(rest, 0x30)?;
+
+    let (_, _, after_not_before) = read_any_tlv(validity)?;
+    let (tag, not_after, _) = read_any_tlv(after_not_before)?;
+    parse_time(tag, not_after)
+}
+
+/// Read one DER element with the expected tag, returning the tag, contents
+/// and the remaining input
+fn read_tlv(input: &[u8], tag: u8) -> Option<(u8, &[u8], &[u8])> {
+    read_any_tlv(input).filter(|(t, _, _)| *t == tag)
+}
+
+fn read_any_tlv(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = input.split_first()?;
+    let (&first, rest) = rest.split_first()?;
+    let (len, rest) = if first < 0x80 {
+        (first as usize, rest)
+    } else {
+        let count = (first & 0x7f) as usize;
+        if count == 0 || count > 4 || rest.len() < count {
+            return None;
+        }
+        let len = rest[..count]
+            .iter()
+            .fold(0usize, |len, b| (len << 8) | *b as usize);
+        (len, &rest[count..])
+    };
+    (rest.len() >= len).then(|| (tag, &rest[..len], &rest[len..]))
+}
+
+/// Parse a UTCTime (0x17) or GeneralizedTime (0x18) value
+fn parse_time(tag: u8, value: &[u8]) -> Option<DateTime<Utc>> {
+    let value = std::str::from_utf8(value).ok()?;
+    let full = match tag {
+        // RFC 5280: two-digit years 50-99 are 19xx, 00-49 are 20xx
+        0x17 => {
+            let year: u32 = value.get(..2)?.parse().ok()?;
+            format!("{}{}", if year >= 50 { "19" } else { "20" }, value)
+        }
+        0x18 => value.to_string(),
+        _ => return None,
+    };
+    NaiveDateTime::parse_from_str(&full, "%Y%m%d%H%M%SZ")
+        .ok()
+        .map(|t| t.and_utc())
+}
+
+/// Build the CertificatesFresh condition from the certificates' expiry
+///
+/// False when a certificate has expired or expires within the renewal
+/// window; the message names the certificate expiring first.
+pub fn certificates_fresh_condition(
+    certificates: &[CertificateStatus],
+    now: DateTime<Utc>,
+) -> Condition {
+    let earliest = certificates.iter().min_by_key(|c| c.not_after);
+    let (status, reason, message) = match earliest {
+        None => (
+            "True",
+            "NoCertificates",
+            "No TLS certificates are mounted".to_string(),
+        ),
+        Some(c) if c.not_after <= now => (
+            "False",
+            "CertificateExpired",
+            format!(
+                "Certificate {}/{} expired at {}",
+                c.secret_name,
+                c.key,
+                c.not_after.to_rfc3339()
+            ),
+        ),
+        Some(c) if (c.not_after - now).to_std().unwrap_or_default() < RENEWAL_WINDOW => (
+            "False",
+            "CertificateExpiring",
+            format!(
+                "Certificate {}/{} expires at {}",
+                c.secret_name,
+                c.key,
+                c.not_after.to_rfc3339()
+            ),
+        ),
+        Some(c) => (
+            "True",
+            "CertificatesValid",
+            format!(
+                "Earliest expiry is {}/{} at {}",
+                c.secret_name,
+                c.key,
+                c.not_after.to_rfc3339()
+            ),
+        ),
+    };
+
+    Condition {
+        type_: "CertificatesFresh".to_string(),
+        status: status.to_string(),
+        last_transition_time: now,
+        observed_generation: None,
+        reason: Some(reason.to_string()),
+        message: Some(message),
+    }
+}
+
+/// Time until the freshness of the certificates next changes
+///
+/// Returns `None` when nothing will change, so no recheck is needed.
+pub fn recheck_after(certificates: &[CertificateStatus], now: DateTime<Utc>) -> Option<Duration> {
+    certificates
+        .iter()
+        .flat_map(|c| {
+            let window = chrono::Duration::from_std(RENEWAL_WINDOW).unwrap_or_default();
+            [c.not_after - window, c.not_after]
+        })
+        .filter(|t| *t > now)
+        .min()
+        .and_then(|t| (t - now).to_std().ok())
+}
@@ -0,0 +1,294 @@
+//! Kubernetes resource builders for the scheduled consistency audit
+//!
+//! The audit runs the operator image with `--audit` from a CronJob in the
+//! target namespace. Its ServiceAccount may only read the Kafka credential
+//! Secrets and patch the remapper's status, where the result is recorded.
+
+use k8s_openapi::api::batch::v1::{CronJob, CronJobSpec, JobSpec, JobTemplateSpec};
+use k8s_openapi::api::core::v1::{
+    Container, EnvVar, PodSpec, PodTemplateSpec, SecurityContext, ServiceAccount,
+};
+use k8s_openapi::api::rbac::v1::{PolicyRule, Role, RoleBinding, RoleRef, Subject};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::ResourceExt;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::adapters::deployment_builder::{
+    build_labels, build_owner_reference, owner_references, resource_annotations, resource_labels,
+};
+use crate::crd::{KafkaClusterSpec, KafkaPartitionRemapper, MappingSpec};
+use crate::{Error, Result};
+
+/// Environment variable carrying the serialized [`AuditTarget`]
+pub const AUDIT_TARGET_ENV: &str = "AUDIT_TARGET";
+
+/// Environment variable holding the operator's own image
+pub const OPERATOR_IMAGE_ENV: &str = "OPERATOR_IMAGE";
+
+/// What the audit job checks and where it reports
+///
+/// Connection settings are resolved by the operator, so the job needs no
+/// access to KafkaClusterRefs or topic override resources.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditTarget {
+    /// Namespace of the remapper whose status receives the result
+    pub namespace: String,
+    /// Name of the remapper
+    pub name: String,
+    /// Namespace holding the Kafka credential Secrets
+    pub secrets_namespace: String,
+    /// Resolved connection settings
+    pub kafka: KafkaClusterSpec,
+    /// Mapping including topic overrides
+    pub mapping: MappingSpec,
+    /// Topics that must exist
+    pub topics: Vec<String>,
+}
+
+/// Name shared by the audit CronJob, ServiceAccount, Roles and RoleBindings
+pub fn audit_name(resource_name: &str) -> String {
+    format!("{}-audit", resource_name)
+}
+
+/// Whether the remapper asks for a consistency audit
+pub fn audit_enabled(remapper: &KafkaPartitionRemapper) -> bool {
+    remapper.spec.audit.as_ref().is_some_and(|a| a.enabled)
+}
+
+/// Image running the audit: the configured one, else the operator's own
+pub fn audit_image(remapper: &KafkaPartitionRemapper) -> Option<String> {
+    remapper
+        .spec
+        .audit
+        .as_ref()
+        .and_then(|a| a.image.clone())
+        .or_else(|| std::env::var(OPERATOR_IMAGE_ENV).ok())
+        .filter(|i| !i.is_empty())
+}
+
+/// Audit target for a remapper with resolved connection settings
+pub fn build_audit_target(remapper: &KafkaPartitionRemapper) -> AuditTarget {
+    AuditTarget {
+        namespace: remapper.namespace().unwrap_or_default(),
+        name: remapper.name_any(),
+        secrets_namespace: remapper.target_namespace(),
+        kafka: remapper.spec.kafka.clone(),
+        mapping: remapper.spec.mapping.clone(),
+        topics: remapper
+            .spec
+            .audit
+            .as_ref()
+            .map(|a| a.topics.clone())
+            .unwrap_or_default(),
+    }
+}
+
+/// Secrets the audit reads to connect to Kafka
+fn credential_secrets(kafka: &KafkaClusterSpec) -> Vec<String> {
+    let tls = kafka
+        .tls_secret
+        .as_ref()
+        .filter(|_| kafka.security_protocol.uses_tls())
+        .map(|t| t.name.clone());
+    let sasl = kafka
+        .sasl_secret
+        .as_ref()
+        .filter(|_| kafka.security_protocol.uses_sasl())
+        .map(|s| s.name.clone());
+    let mut secrets: Vec<_> = tls
+        .into_iter()
+        .chain(sasl)
+        .filter(|n| !n.is_empty())
+        .collect();
+    secrets.dedup();
+    secrets
+}
+
+/// Build the ServiceAccount the audit job runs as
+pub fn build_audit_service_account(remapper: &KafkaPartitionRemapper) -> ServiceAccount {
+    ServiceAccount {
+        metadata: ObjectMeta {
+            name: Some(audit_name(&remapper.resource_name())),
+            namespace: Some(remapper.target_namespace()),
+            labels: Some(resource_labels(remapper)),
+            annotations: resource_annotations(remapper, &BTreeMap::new()),
+            owner_references: owner_references(remapper),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Build the Roles granting the audit job its access
+///
+/// Secrets are read in the target namespace and the status is patched in
+/// the remapper's namespace, so a cross-namespace remapper gets one Role in
+/// each. Every rule is limited to the named objects.
+pub fn build_audit_roles(remapper: &KafkaPartitionRemapper) -> Vec<Role> {
+    let secrets: Vec<_> = credential_secrets(&remapper.spec.kafka);
+    let secret_rules: Vec<_> = (!secrets.is_empty())
+        .then(|| PolicyRule {
+            api_groups: Some(vec![String::new()]),
+            resources: Some(vec!["secrets".to_string()]),
+            resource_names: Some(secrets),
+            verbs: vec!["get".to_string()],
+            ..Default::default()
+        })
+        .into_iter()
+        .collect();
+    let status_rule = PolicyRule {
+        api_groups: Some(vec!["kafka.oso.sh".to_string()]),
+        resources: Some(vec!["kafkapartitionremappers/status".to_string()]),
+        resource_names: Some(vec![remapper.name_any()]),
+        verbs: vec!["patch".to_string()],
+        ..Default::default()
+    };
+
+    if !remapper.deploys_cross_namespace() {
+        let mut rules = secret_rules;
+        rules.push(status_rule);
+        return vec![Role {
+            metadata: target_metadata(remapper),
+            rules: Some(rules),
+        }];
+    }
+    let mut roles = vec![Role {
+        metadata: remapper_metadata(remapper),
+        rules: Some(vec![status_rule]),
+    }];
+    if !secret_rules.is_empty() {
+        roles.push(Role {
+            metadata: target_metadata(remapper),
+            rules: Some(secret_rules),
+        });
+    }
+    roles
+}
+
+/// Build a RoleBinding for each audit Role
+pub fn build_audit_role_bindings(remapper: &KafkaPartitionRemapper) -> Vec<RoleBinding> {
+    let name = audit_name(&remapper.resource_name());
+    build_audit_roles(remapper)
+        .into_iter()
+        .map(|role| RoleBinding {
+            metadata: role.metadata,
+            role_ref: RoleRef {
+                api_group: "rbac.authorization.k8s.io".to_string(),
+                kind: "Role".to_string(),
+                name: name.clone(),
+            },
+            subjects: Some(vec![Subject {
+                kind: "ServiceAccount".to_string(),
+                name: name.clone(),
+                namespace: Some(remapper.target_namespace()),
+                ..Default::default()
+            }]),
+        })
+        .collect()
+}
+
+/// Build the audit CronJob
+///
+/// Fails when the audit has no image configured and the operator's own
+/// image is unknown.
+pub fn build_audit_cron_job(remapper: &KafkaPartitionRemapper) -> Result<Option<CronJob>> {
+    let Some(audit) = remapper.spec.audit.as_ref().filter(|a| a.enabled) else {
+        return Ok(None);
+    };
+    let image = audit_image(remapper).ok_or_else(|| {
+        Error::ConfigError(format!(
+            "audit.image is required when {} is not set on the operator",
+            OPERATOR_IMAGE_ENV
+        ))
+    })?;
+    let target = serde_json::to_string(&build_audit_target(remapper))
+        .map_err(|e| Error::ConfigError(format!("Failed to serialize audit target: {}", e)))?;
+    let name = audit_name(&remapper.resource_name());
+
+    let mut pod_labels = build_labels(&remapper.resource_name());
+    // A distinct name keeps audit pods out of the proxy Service selectors
+    pod_labels.insert(
+        "app.kubernetes.io/name".to_string(),
+        "kafka-partition-remapper-audit".to_string(),
+    );
+    pod_labels.insert(
+        "app.kubernetes.io/component".to_string(),
+        "audit".to_string(),
+    );
+
+    let container = Container {
+        name: "audit".to_string(),
+        image: Some(image),
+        args: Some(vec!["--audit".to_string()]),
+        env: Some(vec![EnvVar {
+            name: AUDIT_TARGET_ENV.to_string(),
+            value: Some(target),
+            ..Default::default()
+        }]),
+        security_context: Some(SecurityContext {
+            allow_privilege_escalation: Some(false),
+            read_only_root_filesystem: Some(true),
+            run_as_non_root: Some(true),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    Ok(Some(CronJob {
+        metadata: target_metadata(remapper),
+        spec: Some(CronJobSpec {
+            schedule: audit.schedule.clone(),
+            concurrency_policy: Some("Forbid".to_string()),
+            successful_jobs_history_limit: Some(1),
+            failed_jobs_history_limit: Some(1),
+            job_template: JobTemplateSpec {
+                metadata: None,
+                spec: Some(JobSpec {
+                    backoff_limit: Some(0),
+                    template: PodTemplateSpec {
+                        metadata: Some(ObjectMeta {
+                            labels: Some(pod_labels),
+                            ..Default::default()
+                        }),
+                        spec: Some(PodSpec {
+                            service_account_name: Some(name),
+                            restart_policy: Some("Never".to_string()),
+                            containers: vec![container],
+                            ..Default::default()
+                        }),
+                    },
+                    ..Default::default()
+                }),
+            },
+            ..Default::default()
+        }),
+        ..Default::default()
+    }))
+}
+
+/// Metadata of an audit resource in the target namespace
+fn target_metadata(remapper: &KafkaPartitionRemapper) -> ObjectMeta {
+    ObjectMeta {
+        name: Some(audit_name(&remapper.resource_name())),
+        namespace: Some(remapper.target_namespace()),
+        labels: Some(resource_labels(remapper)),
+        annotations: resource_annotations(remapper, &BTreeMap::new()),
+        owner_references: owner_references(remapper),
+        ..Default::default()
+    }
+}
+
+/// Metadata of an audit resource in the remapper's own namespace, which can
+/// always carry an owner reference
+fn remapper_metadata(remapper: &KafkaPartitionRemapper) -> ObjectMeta {
+    ObjectMeta {
+        name: Some(audit_name(&remapper.resource_name())),
+        namespace: remapper.namespace(),
+        labels: Some(build_labels(&remapper.resource_name())),
+        annotations: resource_annotations(remapper, &BTreeMap::new()),
+        owner_references: Some(vec![build_owner_reference(remapper)]),
+        ..Default::default()
+    }
+}
@@ -1,82 +1,285 @@
 //! Kubernetes Deployment builder for proxy pods
 
-use k8s_openapi::api::apps::v1::{Deployment, DeploymentSpec};
+use k8s_openapi::api::apps::v1::{
+    Deployment, DeploymentSpec, DeploymentStrategy, RollingUpdateDeployment,
+};
 use k8s_openapi::api::core::v1::{
-    ConfigMapVolumeSource, Container, ContainerPort, EnvVar, LocalObjectReference, PodSpec,
-    PodTemplateSpec, Probe, TCPSocketAction, Volume, VolumeMount,
+    Affinity, Capabilities, ConfigMapVolumeSource, Container, ContainerPort, EmptyDirVolumeSource,
+    EnvVar, ExecAction, HTTPGetAction, LocalObjectReference, PodAffinityTerm, PodAntiAffinity,
+    PodSecurityContext, PodSpec, PodTemplateSpec, Probe, ResourceRequirements, SeccompProfile,
+    SecurityContext, TCPSocketAction, Volume, VolumeMount, WeightedPodAffinityTerm,
 };
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta, OwnerReference};
 use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::ResourceExt;
 use std::collections::BTreeMap;
 
-use crate::crd::{KafkaPartitionRemapper, KafkaPartitionRemapperSpec};
+use crate::adapters::users;
+use crate::crd::{
+    KafkaPartitionRemapper, KafkaPartitionRemapperSpec, KerberosSpec, ListenerSpec, ProbeSpec,
+    ProbeType, WorkloadKind, OWNER_NAMESPACE_LABEL, OWNER_NAME_LABEL, RESTARTED_AT_ANNOTATION,
+};
+use crate::settings;
 
-const DEFAULT_IMAGE: &str = "ghcr.io/osodevops/kafka-partition-remapper";
-const DEFAULT_TAG: &str = "latest";
+/// Name of the proxy container in generated pods
+pub const PROXY_CONTAINER_NAME: &str = "proxy";
 
 /// Build a Deployment for the proxy
+///
+/// `secrets_hash` is a checksum of the referenced Secret contents; when it
+/// changes the pods are rolled so rotated credentials are picked up.
 pub fn build_deployment(
     remapper: &KafkaPartitionRemapper,
     config_map_name: &str,
     config_hash: &str,
+    secrets_hash: Option<&str>,
 ) -> Deployment {
-    let name = remapper.metadata.name.clone().unwrap_or_default();
-    let namespace = remapper.metadata.namespace.clone().unwrap_or_default();
-    let spec = &remapper.spec;
+    let name = remapper.resource_name();
+    let namespace = remapper.target_namespace();
 
     let labels = build_labels(&name);
-    let mut pod_annotations = BTreeMap::new();
-    pod_annotations.insert("checksum/config".to_string(), config_hash.to_string());
-
-    // Merge user-provided pod template annotations
-    if let Some(ref pt) = spec.pod_template {
-        for (k, v) in &pt.annotations {
-            pod_annotations.insert(k.clone(), v.clone());
-        }
-    }
-
-    let replicas = if spec.suspend { 0 } else { spec.replicas };
+    let rollout = remapper.spec.rollout_strategy.as_ref();
 
     Deployment {
         metadata: ObjectMeta {
             name: Some(name.clone()),
             namespace: Some(namespace),
-            labels: Some(labels.clone()),
-            owner_references: Some(vec![build_owner_reference(remapper)]),
+            labels: Some(resource_labels(remapper)),
+            annotations: resource_annotations(remapper, &BTreeMap::new()),
+            owner_references: owner_references(remapper),
             ..Default::default()
         },
         spec: Some(DeploymentSpec {
-            replicas: Some(replicas),
+            replicas: desired_replicas(remapper),
             selector: LabelSelector {
-                match_labels: Some(labels.clone()),
+                match_labels: Some(labels),
                 ..Default::default()
             },
-            template: PodTemplateSpec {
-                metadata: Some(ObjectMeta {
-                    labels: Some(labels.clone()),
-                    annotations: Some(pod_annotations),
-                    ..Default::default()
-                }),
-                spec: Some(build_pod_spec(spec, config_map_name)),
-            },
+            template: build_pod_template(remapper, config_map_name, config_hash, secrets_hash),
+            strategy: build_strategy(&remapper.spec),
+            progress_deadline_seconds: rollout.and_then(|r| r.progress_deadline_seconds),
+            revision_history_limit: rollout.and_then(|r| r.revision_history_limit),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Rolling update parameters for the Deployment
+///
+/// Left unset unless maxSurge or maxUnavailable is configured so the
+/// Kubernetes defaults apply.
+fn build_strategy(spec: &KafkaPartitionRemapperSpec) -> Option<DeploymentStrategy> {
+    let rollout = spec.rollout_strategy.as_ref()?;
+    if rollout.max_surge.is_none() && rollout.max_unavailable.is_none() {
+        return None;
+    }
+    Some(DeploymentStrategy {
+        type_: Some("RollingUpdate".to_string()),
+        rolling_update: Some(RollingUpdateDeployment {
+            max_surge: rollout.max_surge.clone(),
+            max_unavailable: rollout.max_unavailable.clone(),
+        }),
+    })
+}
+
+/// Replica count to set on the workload
+///
+/// Left unset when an HPA owns it so the two don't fight. A suspended
+/// remapper resuming with autoscaling is restored through the scale
+/// subresource instead, see `remapper::replicas_to_restore`, and a workload
+/// handed over to an HPA keeps its count, see `remapper::handover_replicas`.
+pub fn desired_replicas(remapper: &KafkaPartitionRemapper) -> Option<i32> {
+    let spec = &remapper.spec;
+    if spec.suspend {
+        Some(0)
+    } else if spec.autoscaling_enabled() {
+        None
+    } else {
+        Some(spec.replicas)
+    }
+}
+
+/// Whether every replica of a Deployment runs its latest template and is ready
+pub fn rolled_out(deployment: &Deployment) -> bool {
+    let desired = deployment
+        .spec
+        .as_ref()
+        .and_then(|s| s.replicas)
+        .unwrap_or(1);
+    let Some(ref status) = deployment.status else {
+        return false;
+    };
+    status.observed_generation >= deployment.metadata.generation
+        && status.updated_replicas.unwrap_or(0) >= desired
+        && status.ready_replicas.unwrap_or(0) >= desired
+        && status.replicas.unwrap_or(0) == desired
+}
+
+/// Whether a Deployment is part-way through rolling out a new template
+///
+/// Reads the Progressing condition, which keeps a reason other than
+/// NewReplicaSetAvailable until the new ReplicaSet is fully available.
+pub fn rollout_in_progress(deployment: &Deployment) -> bool {
+    let Some(ref status) = deployment.status else {
+        return false;
+    };
+    status.observed_generation < deployment.metadata.generation
+        || status.conditions.iter().flatten().any(|c| {
+            c.type_ == "Progressing"
+                && c.status == "True"
+                && c.reason.as_deref() != Some("NewReplicaSetAvailable")
+        })
+}
+
+/// Build the pod template shared by all workload kinds
+pub fn build_pod_template(
+    remapper: &KafkaPartitionRemapper,
+    config_map_name: &str,
+    config_hash: &str,
+    secrets_hash: Option<&str>,
+) -> PodTemplateSpec {
+    let name = remapper.resource_name();
+    let spec = &remapper.spec;
+
+    let mut pod_annotations = spec.common_annotations.clone();
+    pod_annotations.insert("checksum/config".to_string(), config_hash.to_string());
+    if let Some(secrets_hash) = secrets_hash {
+        pod_annotations.insert("checksum/secrets".to_string(), secrets_hash.to_string());
+    }
+
+    // Merge user-provided pod template annotations and labels; the standard
+    // labels are applied last so the workload selector keeps matching
+    let mut pod_labels = spec.common_labels.clone();
+    if let Some(ref pt) = spec.pod_template {
+        for (k, v) in &pt.annotations {
+            pod_annotations.insert(k.clone(), v.clone());
+        }
+        pod_labels.extend(pt.labels.clone());
+    }
+    pod_labels.extend(build_labels(&name));
+
+    // A new restart request changes the template and so rolls the pods
+    if let Some(restarted_at) = remapper.restarted_at() {
+        pod_annotations.insert(
+            RESTARTED_AT_ANNOTATION.to_string(),
+            restarted_at.to_string(),
+        );
+    }
+
+    PodTemplateSpec {
+        metadata: Some(ObjectMeta {
+            labels: Some(pod_labels),
+            annotations: Some(pod_annotations),
             ..Default::default()
         }),
+        spec: Some(apply_spreading(
+            build_pod_spec(spec, config_map_name, &users::users_secret_name(remapper)),
+            spec,
+            &name,
+        )),
+    }
+}
+
+/// Node label replicas are spread across by the default anti-affinity
+pub const HOSTNAME_TOPOLOGY_KEY: &str = "kubernetes.io/hostname";
+
+/// Zone label replicas are spread across by the default anti-affinity
+pub const ZONE_TOPOLOGY_KEY: &str = "topology.kubernetes.io/zone";
+
+/// Apply affinity and topology spread constraints
+///
+/// A user-provided affinity is used as is. Otherwise, when more than one
+/// replica may run, replicas prefer distinct nodes and then distinct zones
+/// so a single node failure cannot take out every proxy. On the host network
+/// distinct nodes are required.
+fn apply_spreading(
+    mut pod_spec: PodSpec,
+    spec: &KafkaPartitionRemapperSpec,
+    name: &str,
+) -> PodSpec {
+    let selector = LabelSelector {
+        match_labels: Some(build_labels(name)),
         ..Default::default()
+    };
+    let pod_template = spec.pod_template.as_ref();
+
+    let affinity = pod_template.and_then(|pt| pt.affinity.clone());
+    let multiple_replicas = spec.replicas > 1 || spec.autoscaling_enabled();
+    let host_network = pod_template.is_some_and(|pt| pt.host_network);
+    pod_spec.affinity = match affinity {
+        Some(affinity) => Some(affinity),
+        // Replicas on the host network would bind the same node ports
+        None if multiple_replicas && host_network => Some(Affinity {
+            pod_anti_affinity: Some(PodAntiAffinity {
+                required_during_scheduling_ignored_during_execution: Some(vec![PodAffinityTerm {
+                    label_selector: Some(selector.clone()),
+                    topology_key: HOSTNAME_TOPOLOGY_KEY.to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        None if multiple_replicas => Some(Affinity {
+            pod_anti_affinity: Some(PodAntiAffinity {
+                preferred_during_scheduling_ignored_during_execution: Some(
+                    [(HOSTNAME_TOPOLOGY_KEY, 100), (ZONE_TOPOLOGY_KEY, 50)]
+                        .into_iter()
+                        .map(|(topology_key, weight)| WeightedPodAffinityTerm {
+                            weight,
+                            pod_affinity_term: PodAffinityTerm {
+                                label_selector: Some(selector.clone()),
+                                topology_key: topology_key.to_string(),
+                                ..Default::default()
+                            },
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        None => None,
+    };
+
+    let constraints = pod_template
+        .map(|pt| pt.topology_spread_constraints.clone())
+        .unwrap_or_default();
+    if !constraints.is_empty() {
+        pod_spec.topology_spread_constraints = Some(
+            constraints
+                .into_iter()
+                .map(|mut c| {
+                    c.label_selector.get_or_insert_with(|| selector.clone());
+                    c
+                })
+                .collect(),
+        );
     }
+
+    pod_spec
 }
 
-fn build_pod_spec(spec: &KafkaPartitionRemapperSpec, config_map_name: &str) -> PodSpec {
+fn build_pod_spec(
+    spec: &KafkaPartitionRemapperSpec,
+    config_map_name: &str,
+    users_secret_name: &str,
+) -> PodSpec {
+    let settings = settings::current();
+
     let image = spec
         .pod_template
         .as_ref()
         .and_then(|pt| pt.image.clone())
-        .unwrap_or_else(|| DEFAULT_IMAGE.to_string());
+        .unwrap_or_else(|| settings.default_image.clone());
 
     let tag = spec
         .pod_template
         .as_ref()
         .and_then(|pt| pt.image_tag.clone())
-        .unwrap_or_else(|| DEFAULT_TAG.to_string());
+        .unwrap_or_else(|| settings.default_image_tag.clone());
 
     let image_pull_policy = spec
         .pod_template
@@ -84,107 +287,92 @@ fn build_pod_spec(spec: &KafkaPartitionRemapperSpec, config_map_name: &str) -> P
         .and_then(|pt| pt.image_pull_policy.clone())
         .unwrap_or_else(|| "IfNotPresent".to_string());
 
+    let probes = spec.probes.as_ref();
     let mut container = Container {
-        name: "proxy".to_string(),
+        name: PROXY_CONTAINER_NAME.to_string(),
         image: Some(format!("{}:{}", image, tag)),
         image_pull_policy: Some(image_pull_policy),
-        args: Some(vec![
-            "--config".to_string(),
-            "/etc/kafka-proxy/config.yaml".to_string(),
-        ]),
-        ports: Some(vec![
-            ContainerPort {
-                name: Some("kafka".to_string()),
-                container_port: spec.listen.port,
-                protocol: Some("TCP".to_string()),
-                ..Default::default()
-            },
-            ContainerPort {
-                name: Some("metrics".to_string()),
-                container_port: spec.metrics.port,
-                protocol: Some("TCP".to_string()),
-                ..Default::default()
-            },
-        ]),
+        args: Some(vec!["--config".to_string(), config_path(spec)]),
+        ports: Some(
+            spec.listeners
+                .iter()
+                .map(|listener| ContainerPort {
+                    name: Some(listener.name.clone()),
+                    container_port: listener.port,
+                    protocol: Some("TCP".to_string()),
+                    ..Default::default()
+                })
+                .chain(spec.metrics.enabled.then(|| ContainerPort {
+                    name: Some("metrics".to_string()),
+                    container_port: spec.metrics.port,
+                    protocol: Some("TCP".to_string()),
+                    ..Default::default()
+                }))
+                .chain(
+                    schema_registry_forward_port(spec).map(|port| ContainerPort {
+                        name: Some(SCHEMA_REGISTRY_PORT_NAME.to_string()),
+                        container_port: port,
+                        protocol: Some("TCP".to_string()),
+                        ..Default::default()
+                    }),
+                )
+                .collect(),
+        ),
         volume_mounts: Some(vec![VolumeMount {
             name: "config".to_string(),
             mount_path: "/etc/kafka-proxy".to_string(),
             read_only: Some(true),
             ..Default::default()
         }]),
-        liveness_probe: Some(Probe {
-            tcp_socket: Some(TCPSocketAction {
-                port: IntOrString::String("kafka".to_string()),
-                ..Default::default()
-            }),
-            initial_delay_seconds: Some(10),
-            period_seconds: Some(10),
-            timeout_seconds: Some(5),
-            failure_threshold: Some(3),
-            ..Default::default()
-        }),
-        readiness_probe: Some(Probe {
-            tcp_socket: Some(TCPSocketAction {
-                port: IntOrString::String("kafka".to_string()),
-                ..Default::default()
-            }),
-            initial_delay_seconds: Some(5),
-            period_seconds: Some(5),
-            timeout_seconds: Some(3),
-            failure_threshold: Some(3),
-            ..Default::default()
-        }),
+        liveness_probe: Some(build_probe(
+            spec,
+            probes.and_then(|p| p.liveness.as_ref()),
+            LIVENESS_DEFAULTS,
+            HEALTH_PATH,
+        )),
+        readiness_probe: Some(build_probe(
+            spec,
+            probes.and_then(|p| p.readiness.as_ref()),
+            READINESS_DEFAULTS,
+            if spec.kafka_readiness_required() {
+                READY_PATH
+            } else {
+                HEALTH_PATH
+            },
+        )),
+        startup_probe: probes
+            .and_then(|p| p.startup.as_ref())
+            .map(|startup| build_probe(spec, Some(startup), STARTUP_DEFAULTS, HEALTH_PATH)),
         ..Default::default()
     };
 
-    // Add resource requirements if specified
-    if let Some(ref pt) = spec.pod_template {
-        if let Some(ref resources) = pt.resources {
-            container.resources = Some(k8s_openapi::api::core::v1::ResourceRequirements {
-                limits: if resources.limits.is_empty() {
-                    None
-                } else {
-                    Some(
-                        resources
-                            .limits
-                            .iter()
-                            .map(|(k, v)| {
-                                (
-                                    k.clone(),
-                                    k8s_openapi::apimachinery::pkg::api::resource::Quantity(
-                                        v.clone(),
-                                    ),
-                                )
-                            })
-                            .collect(),
-                    )
-                },
-                requests: if resources.requests.is_empty() {
-                    None
-                } else {
-                    Some(
-                        resources
-                            .requests
-                            .iter()
-                            .map(|(k, v)| {
-                                (
-                                    k.clone(),
-                                    k8s_openapi::apimachinery::pkg::api::resource::Quantity(
-                                        v.clone(),
-                                    ),
-                                )
-                            })
-                            .collect(),
-                    )
-                },
-                ..Default::default()
-            });
-        }
-    }
+    // Add resource requirements, falling back to recommended requests
+    container.resources = Some(build_resource_requirements(spec));
 
     // Add environment variables for SASL credentials if configured
     let mut env_vars = Vec::new();
-    if let Some(ref sasl) = spec.kafka.sasl_secret {
+
+    // StatefulSet pods select their own config file by pod name
+    if spec.workload == WorkloadKind::StatefulSet {
+        env_vars.push(EnvVar {
+            name: "POD_NAME".to_string(),
+            value_from: Some(k8s_openapi::api::core::v1::EnvVarSource {
+                field_ref: Some(k8s_openapi::api::core::v1::ObjectFieldSelector {
+                    field_path: "metadata.name".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+    // Credentials from a SecretProviderClass are read from files instead
+    if let Some(sasl) = spec
+        .kafka
+        .sasl_secret
+        .as_ref()
+        .filter(|s| s.secret_provider_class.is_none())
+    {
         env_vars.push(EnvVar {
             name: "KAFKA_USERNAME".to_string(),
             value_from: Some(k8s_openapi::api::core::v1::EnvVarSource {
@@ -210,6 +398,69 @@ fn build_pod_spec(spec: &KafkaPartitionRemapperSpec, config_map_name: &str) -> P
             ..Default::default()
         });
     }
+    for cluster in spec.upstream_clusters() {
+        let Some(sasl) = cluster
+            .sasl_secret
+            .as_ref()
+            .filter(|s| s.secret_provider_class.is_none())
+        else {
+            continue;
+        };
+        for (field, key) in [
+            ("USERNAME", &sasl.username_key),
+            ("PASSWORD", &sasl.password_key),
+        ] {
+            env_vars.push(EnvVar {
+                name: upstream_sasl_env(&cluster.name, field),
+                value_from: Some(k8s_openapi::api::core::v1::EnvVarSource {
+                    secret_key_ref: Some(k8s_openapi::api::core::v1::SecretKeySelector {
+                        name: sasl.name.clone(),
+                        key: key.clone(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        }
+    }
+    if let Some(auth) = spec
+        .schema_registry
+        .as_ref()
+        .and_then(|r| r.auth_secret.as_ref())
+    {
+        for (name, key) in [
+            ("SCHEMA_REGISTRY_USERNAME", &auth.username_key),
+            ("SCHEMA_REGISTRY_PASSWORD", &auth.password_key),
+        ] {
+            env_vars.push(EnvVar {
+                name: name.to_string(),
+                value_from: Some(k8s_openapi::api::core::v1::EnvVarSource {
+                    secret_key_ref: Some(k8s_openapi::api::core::v1::SecretKeySelector {
+                        name: auth.name.clone(),
+                        key: key.clone(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        }
+    }
+    if let Some(ref kerberos) = spec.kafka.kerberos {
+        env_vars.push(EnvVar {
+            name: "KRB5_CONFIG".to_string(),
+            value: Some(krb5_config_path(kerberos)),
+            ..Default::default()
+        });
+    }
+    // User-provided variables follow the operator's own
+    if let Some(ref pt) = spec.pod_template {
+        env_vars.extend(pt.env.iter().cloned());
+        if !pt.env_from.is_empty() {
+            container.env_from = Some(pt.env_from.clone());
+        }
+    }
     if !env_vars.is_empty() {
         container.env = Some(env_vars);
     }
@@ -226,26 +477,277 @@ fn build_pod_spec(spec: &KafkaPartitionRemapperSpec, config_map_name: &str) -> P
     // Add TLS volume mounts if configured
     let mut volume_mounts = container.volume_mounts.take().unwrap_or_default();
     if let Some(ref tls) = spec.kafka.tls_secret {
-        volumes.push(Volume {
+        volumes.push(kafka_credentials_volume(
+            "kafka-tls",
+            &tls.name,
+            tls.secret_provider_class.as_deref(),
+        ));
+        volume_mounts.push(VolumeMount {
             name: "kafka-tls".to_string(),
+            mount_path: "/etc/kafka-proxy/tls/kafka".to_string(),
+            read_only: Some(true),
+            ..Default::default()
+        });
+    }
+    if let Some(ref sasl) = spec.kafka.sasl_secret {
+        if let Some(ref provider_class) = sasl.secret_provider_class {
+            volumes.push(kafka_credentials_volume(
+                "kafka-sasl",
+                &sasl.name,
+                Some(provider_class),
+            ));
+            volume_mounts.push(VolumeMount {
+                name: "kafka-sasl".to_string(),
+                mount_path: KAFKA_SASL_DIR.to_string(),
+                read_only: Some(true),
+                ..Default::default()
+            });
+        }
+    }
+
+    // Failover and routed clusters mount their credentials under their name
+    for cluster in spec.upstream_clusters() {
+        let mut credential_mounts = Vec::new();
+        if let Some(ref tls) = cluster.tls_secret {
+            credential_mounts.push((
+                "tls",
+                &tls.name,
+                tls.secret_provider_class.as_deref(),
+                upstream_tls_dir(&cluster.name),
+            ));
+        }
+        if let Some(ref sasl) = cluster.sasl_secret {
+            if let Some(ref provider_class) = sasl.secret_provider_class {
+                credential_mounts.push((
+                    "sasl",
+                    &sasl.name,
+                    Some(provider_class.as_str()),
+                    upstream_sasl_dir(&cluster.name),
+                ));
+            }
+        }
+        for (kind, secret_name, provider_class, mount_path) in credential_mounts {
+            let volume_name = format!("upstream-{}-{}", cluster.name, kind);
+            volumes.push(kafka_credentials_volume(
+                &volume_name,
+                secret_name,
+                provider_class,
+            ));
+            volume_mounts.push(VolumeMount {
+                name: volume_name,
+                mount_path,
+                read_only: Some(true),
+                ..Default::default()
+            });
+        }
+    }
+
+    if let Some(tls) = spec
+        .schema_registry
+        .as_ref()
+        .and_then(|r| r.tls_secret.as_ref())
+    {
+        volumes.push(kafka_credentials_volume(
+            "schema-registry-tls",
+            &tls.name,
+            tls.secret_provider_class.as_deref(),
+        ));
+        volume_mounts.push(VolumeMount {
+            name: "schema-registry-tls".to_string(),
+            mount_path: SCHEMA_REGISTRY_TLS_DIR.to_string(),
+            read_only: Some(true),
+            ..Default::default()
+        });
+    }
+
+    // Mount the Kerberos keytab and krb5.conf for GSSAPI
+    if let Some(ref kerberos) = spec.kafka.kerberos {
+        volumes.push(Volume {
+            name: "kafka-keytab".to_string(),
             secret: Some(k8s_openapi::api::core::v1::SecretVolumeSource {
-                secret_name: Some(tls.name.clone()),
+                secret_name: Some(kerberos.keytab_secret.name.clone()),
+                items: Some(vec![k8s_openapi::api::core::v1::KeyToPath {
+                    key: kerberos.keytab_secret.key.clone(),
+                    path: kerberos.keytab_secret.key.clone(),
+                    ..Default::default()
+                }]),
                 ..Default::default()
             }),
             ..Default::default()
         });
         volume_mounts.push(VolumeMount {
-            name: "kafka-tls".to_string(),
-            mount_path: "/etc/kafka-proxy/tls/kafka".to_string(),
+            name: "kafka-keytab".to_string(),
+            mount_path: KERBEROS_KEYTAB_DIR.to_string(),
+            read_only: Some(true),
+            ..Default::default()
+        });
+        volumes.push(Volume {
+            name: "krb5-config".to_string(),
+            config_map: Some(ConfigMapVolumeSource {
+                name: kerberos.krb5_config_map.name.clone(),
+                items: Some(vec![k8s_openapi::api::core::v1::KeyToPath {
+                    key: kerberos.krb5_config_map.key.clone(),
+                    path: kerberos.krb5_config_map.key.clone(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        volume_mounts.push(VolumeMount {
+            name: "krb5-config".to_string(),
+            mount_path: KRB5_CONFIG_DIR.to_string(),
+            read_only: Some(true),
+            ..Default::default()
+        });
+    }
+
+    // Mount each listener's server certificate, client CA and SASL users
+    for listener in &spec.listeners {
+        let Some(ref security) = listener.security else {
+            continue;
+        };
+        // TLS volumes project only the referenced keys; SASL users are one
+        // key per username, so that Secret is mounted whole
+        let mut secret_mounts = Vec::new();
+        if let Some(ref tls) = security.tls {
+            let cert = &tls.certificate_secret;
+            secret_mounts.push((
+                "tls",
+                cert.name.clone(),
+                Some(vec![cert.cert_key.clone(), cert.key_key.clone()]),
+                listener_tls_dir(&listener.name),
+            ));
+            if let Some(ref ca) = tls.client_ca_secret {
+                secret_mounts.push((
+                    "ca",
+                    ca.name.clone(),
+                    Some(vec![ca.key.clone()]),
+                    listener_client_ca_dir(&listener.name),
+                ));
+            }
+        }
+        if let Some(ref sasl) = security.sasl {
+            secret_mounts.push((
+                "sasl",
+                sasl.credentials_secret.name.clone(),
+                None,
+                listener_sasl_dir(&listener.name),
+            ));
+        }
+        for (kind, secret_name, keys, mount_path) in secret_mounts {
+            let volume_name = format!("listener-{}-{}", listener.name, kind);
+            volumes.push(Volume {
+                name: volume_name.clone(),
+                secret: Some(k8s_openapi::api::core::v1::SecretVolumeSource {
+                    secret_name: Some(secret_name),
+                    items: keys.map(|keys| {
+                        keys.into_iter()
+                            .map(|key| k8s_openapi::api::core::v1::KeyToPath {
+                                path: key.clone(),
+                                key,
+                                ..Default::default()
+                            })
+                            .collect()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+            volume_mounts.push(VolumeMount {
+                name: volume_name,
+                mount_path,
+                read_only: Some(true),
+                ..Default::default()
+            });
+        }
+    }
+
+    // Generated users are shared by all SASL listeners; with rotation the
+    // passwords in their overlap window are mounted alongside
+    let mut user_mounts = Vec::new();
+    if !spec.users.is_empty() {
+        user_mounts.push((
+            "generated-users",
+            users_secret_name.to_string(),
+            users::GENERATED_USERS_DIR,
+        ));
+    }
+    if spec.user_rotation_enabled() {
+        user_mounts.push((
+            "generated-users-previous",
+            format!("{}-previous", users_secret_name),
+            users::PREVIOUS_USERS_DIR,
+        ));
+    }
+    for (volume_name, secret_name, mount_path) in user_mounts {
+        volumes.push(Volume {
+            name: volume_name.to_string(),
+            secret: Some(k8s_openapi::api::core::v1::SecretVolumeSource {
+                secret_name: Some(secret_name),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        volume_mounts.push(VolumeMount {
+            name: volume_name.to_string(),
+            mount_path: mount_path.to_string(),
             read_only: Some(true),
             ..Default::default()
         });
     }
+
+    // Audit files are written to their own volume for collection
+    if spec
+        .audit_logging
+        .as_ref()
+        .is_some_and(|a| a.writes_files())
+    {
+        volumes.push(Volume {
+            name: "audit-log".to_string(),
+            empty_dir: Some(EmptyDirVolumeSource::default()),
+            ..Default::default()
+        });
+        volume_mounts.push(VolumeMount {
+            name: "audit-log".to_string(),
+            mount_path: AUDIT_LOG_DIR.to_string(),
+            ..Default::default()
+        });
+    }
+
+    // Writable scratch space, since the root filesystem is read-only
+    volumes.push(Volume {
+        name: "tmp".to_string(),
+        empty_dir: Some(EmptyDirVolumeSource::default()),
+        ..Default::default()
+    });
+    volume_mounts.push(VolumeMount {
+        name: "tmp".to_string(),
+        mount_path: "/tmp".to_string(),
+        ..Default::default()
+    });
+
+    if let Some(ref pt) = spec.pod_template {
+        volumes.extend(pt.extra_volumes.iter().cloned());
+        volume_mounts.extend(pt.extra_volume_mounts.iter().cloned());
+    }
     container.volume_mounts = Some(volume_mounts);
+    container.security_context = Some(
+        spec.pod_template
+            .as_ref()
+            .and_then(|pt| pt.container_security_context.clone())
+            .unwrap_or_else(restricted_container_security_context),
+    );
 
     let mut pod_spec = PodSpec {
         containers: vec![container],
         volumes: Some(volumes),
+        security_context: Some(
+            spec.pod_template
+                .as_ref()
+                .and_then(|pt| pt.security_context.clone())
+                .unwrap_or_else(restricted_pod_security_context),
+        ),
         ..Default::default()
     };
 
@@ -274,6 +776,22 @@ fn build_pod_spec(spec: &KafkaPartitionRemapperSpec, config_map_name: &str) -> P
             pod_spec.service_account_name = Some(sa.clone());
         }
 
+        if pt.host_network {
+            pod_spec.host_network = Some(true);
+        }
+        pod_spec.dns_policy = pt.dns_policy.clone().or_else(|| {
+            pt.host_network
+                .then(|| "ClusterFirstWithHostNet".to_string())
+        });
+        pod_spec.dns_config = pt
+            .dns_config
+            .clone()
+            .and_then(|c| serde_json::from_value(c).ok());
+
+        pod_spec.priority_class_name = pt.priority_class_name.clone();
+        pod_spec.runtime_class_name = pt.runtime_class_name.clone();
+        pod_spec.scheduler_name = pt.scheduler_name.clone();
+
         if !pt.image_pull_secrets.is_empty() {
             pod_spec.image_pull_secrets = Some(
                 pt.image_pull_secrets
@@ -282,12 +800,429 @@ fn build_pod_spec(spec: &KafkaPartitionRemapperSpec, config_map_name: &str) -> P
                     .collect(),
             );
         }
+
+        if !pt.init_containers.is_empty() {
+            pod_spec.init_containers = Some(pt.init_containers.clone());
+        }
+
+        pod_spec.containers.extend(pt.sidecars.iter().cloned());
     }
 
     pod_spec
 }
 
-fn build_labels(name: &str) -> BTreeMap<String, String> {
+/// User and group the proxy runs as unless the pod template overrides it
+pub const NON_ROOT_ID: i64 = 65532;
+
+/// Pod security context meeting the restricted Pod Security Standard
+pub fn restricted_pod_security_context() -> PodSecurityContext {
+    PodSecurityContext {
+        run_as_non_root: Some(true),
+        run_as_user: Some(NON_ROOT_ID),
+        run_as_group: Some(NON_ROOT_ID),
+        fs_group: Some(NON_ROOT_ID),
+        seccomp_profile: Some(SeccompProfile {
+            type_: "RuntimeDefault".to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Proxy container security context meeting the restricted Pod Security Standard
+pub fn restricted_container_security_context() -> SecurityContext {
+    SecurityContext {
+        allow_privilege_escalation: Some(false),
+        read_only_root_filesystem: Some(true),
+        run_as_non_root: Some(true),
+        capabilities: Some(Capabilities {
+            drop: Some(vec!["ALL".to_string()]),
+            ..Default::default()
+        }),
+        seccomp_profile: Some(SeccompProfile {
+            type_: "RuntimeDefault".to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Path of the proxy's health endpoint on the metrics port
+pub const HEALTH_PATH: &str = "/health";
+
+/// Path of the proxy endpoint that fails while brokers are unreachable
+pub const READY_PATH: &str = "/ready";
+
+/// Timing defaults of a probe kind
+#[derive(Clone, Copy)]
+struct ProbeDefaults {
+    initial_delay_seconds: i32,
+    period_seconds: i32,
+    timeout_seconds: i32,
+    failure_threshold: i32,
+}
+
+const LIVENESS_DEFAULTS: ProbeDefaults = ProbeDefaults {
+    initial_delay_seconds: 10,
+    period_seconds: 10,
+    timeout_seconds: 5,
+    failure_threshold: 3,
+};
+
+const READINESS_DEFAULTS: ProbeDefaults = ProbeDefaults {
+    initial_delay_seconds: 5,
+    period_seconds: 5,
+    timeout_seconds: 3,
+    failure_threshold: 3,
+};
+
+/// Startup probes allow up to 150 seconds before liveness takes over
+const STARTUP_DEFAULTS: ProbeDefaults = ProbeDefaults {
+    initial_delay_seconds: 0,
+    period_seconds: 5,
+    timeout_seconds: 3,
+    failure_threshold: 30,
+};
+
+/// Build a probe for the proxy container
+///
+/// Without an override `default_path` is checked over HTTP on the metrics
+/// port, falling back to a TCP check of the primary listener when metrics
+/// are disabled.
+fn build_probe(
+    spec: &KafkaPartitionRemapperSpec,
+    probe: Option<&ProbeSpec>,
+    defaults: ProbeDefaults,
+    default_path: &str,
+) -> Probe {
+    let default_type = if spec.metrics.enabled {
+        ProbeType::Http
+    } else {
+        ProbeType::Tcp
+    };
+    let type_ = probe.and_then(|p| p.type_).unwrap_or(default_type);
+    let port = probe.and_then(|p| p.port.clone());
+
+    let mut result = Probe {
+        initial_delay_seconds: Some(
+            probe
+                .and_then(|p| p.initial_delay_seconds)
+                .unwrap_or(defaults.initial_delay_seconds),
+        ),
+        period_seconds: Some(
+            probe
+                .and_then(|p| p.period_seconds)
+                .unwrap_or(defaults.period_seconds),
+        ),
+        timeout_seconds: Some(
+            probe
+                .and_then(|p| p.timeout_seconds)
+                .unwrap_or(defaults.timeout_seconds),
+        ),
+        failure_threshold: Some(
+            probe
+                .and_then(|p| p.failure_threshold)
+                .unwrap_or(defaults.failure_threshold),
+        ),
+        success_threshold: probe.and_then(|p| p.success_threshold),
+        ..Default::default()
+    };
+    match type_ {
+        ProbeType::Tcp => {
+            result.tcp_socket = Some(TCPSocketAction {
+                port: port.unwrap_or_else(|| IntOrString::String(spec.primary_listener().name)),
+                ..Default::default()
+            });
+        }
+        ProbeType::Http => {
+            result.http_get = Some(HTTPGetAction {
+                path: Some(
+                    probe
+                        .and_then(|p| p.path.clone())
+                        .unwrap_or_else(|| default_path.to_string()),
+                ),
+                port: port.unwrap_or_else(|| IntOrString::String("metrics".to_string())),
+                ..Default::default()
+            });
+        }
+        ProbeType::Exec => {
+            result.exec = Some(ExecAction {
+                command: probe.map(|p| p.command.clone()),
+            });
+        }
+    }
+    result
+}
+
+/// CSI driver serving SecretProviderClass volumes
+pub const SECRETS_STORE_CSI_DRIVER: &str = "secrets-store.csi.k8s.io";
+
+/// Directory holding broker SASL credentials from a SecretProviderClass
+pub const KAFKA_SASL_DIR: &str = "/etc/kafka-proxy/broker-sasl";
+
+/// Volume for broker credentials, from a Secret or a SecretProviderClass
+fn kafka_credentials_volume(
+    volume_name: &str,
+    secret_name: &str,
+    provider_class: Option<&str>,
+) -> Volume {
+    match provider_class {
+        Some(provider_class) => Volume {
+            name: volume_name.to_string(),
+            csi: Some(k8s_openapi::api::core::v1::CSIVolumeSource {
+                driver: SECRETS_STORE_CSI_DRIVER.to_string(),
+                read_only: Some(true),
+                volume_attributes: Some(BTreeMap::from([(
+                    "secretProviderClass".to_string(),
+                    provider_class.to_string(),
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        None => Volume {
+            name: volume_name.to_string(),
+            secret: Some(k8s_openapi::api::core::v1::SecretVolumeSource {
+                secret_name: Some(secret_name.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    }
+}
+
+/// Directory holding the Kerberos keytab for broker connections
+pub const KERBEROS_KEYTAB_DIR: &str = "/etc/kafka-proxy/kerberos/keytab";
+
+/// Directory holding krb5.conf for broker connections
+pub const KRB5_CONFIG_DIR: &str = "/etc/kafka-proxy/kerberos/krb5";
+
+/// Path of the mounted Kerberos keytab
+pub fn kerberos_keytab_path(kerberos: &KerberosSpec) -> String {
+    format!("{}/{}", KERBEROS_KEYTAB_DIR, kerberos.keytab_secret.key)
+}
+
+/// Path of the mounted krb5.conf
+pub fn krb5_config_path(kerberos: &KerberosSpec) -> String {
+    format!("{}/{}", KRB5_CONFIG_DIR, kerberos.krb5_config_map.key)
+}
+
+/// Directory audit log files are written to, on the `audit-log` volume
+pub const AUDIT_LOG_DIR: &str = "/var/log/kafka-proxy";
+
+/// Directory holding the Schema Registry TLS material
+pub const SCHEMA_REGISTRY_TLS_DIR: &str = "/etc/kafka-proxy/tls/schema-registry";
+
+/// Name of the container and Service port forwarding Schema Registry traffic
+pub const SCHEMA_REGISTRY_PORT_NAME: &str = "schema-registry";
+
+/// Port the proxy forwards Schema Registry traffic on, if enabled
+pub fn schema_registry_forward_port(spec: &KafkaPartitionRemapperSpec) -> Option<i32> {
+    spec.schema_registry.as_ref()?.forward_port
+}
+
+/// Directory holding an upstream cluster's broker TLS material
+pub fn upstream_tls_dir(cluster: &str) -> String {
+    format!("/etc/kafka-proxy/upstream/{}/tls", cluster)
+}
+
+/// Directory holding an upstream cluster's SASL credentials from a
+/// SecretProviderClass
+pub fn upstream_sasl_dir(cluster: &str) -> String {
+    format!("/etc/kafka-proxy/upstream/{}/sasl", cluster)
+}
+
+/// Variable holding an upstream cluster's SASL `USERNAME` or `PASSWORD`
+pub fn upstream_sasl_env(cluster: &str, field: &str) -> String {
+    format!(
+        "KAFKA_UPSTREAM_{}_{}",
+        cluster.to_ascii_uppercase().replace('-', "_"),
+        field
+    )
+}
+
+/// Directory holding a listener's server certificate and key
+pub fn listener_tls_dir(listener: &str) -> String {
+    format!("/etc/kafka-proxy/tls/listeners/{}", listener)
+}
+
+/// Directory holding the CA used to verify a listener's client certificates
+pub fn listener_client_ca_dir(listener: &str) -> String {
+    format!("/etc/kafka-proxy/tls/listeners/{}-ca", listener)
+}
+
+/// Directory holding a listener's SASL users, one file per username
+pub fn listener_sasl_dir(listener: &str) -> String {
+    format!("/etc/kafka-proxy/sasl/{}", listener)
+}
+
+/// Path of the proxy config file inside the container
+fn config_path(spec: &KafkaPartitionRemapperSpec) -> String {
+    match spec.workload {
+        WorkloadKind::Deployment => "/etc/kafka-proxy/config.yaml".to_string(),
+        WorkloadKind::StatefulSet => "/etc/kafka-proxy/$(POD_NAME).yaml".to_string(),
+    }
+}
+
+/// Connection ceiling of listeners without maxConnections and no memory limit
+pub const DEFAULT_MAX_CONNECTIONS: u32 = 1000;
+
+/// Memory budgeted per client connection unless tuning overrides it
+pub const DEFAULT_CONNECTION_MEMORY_BYTES: u64 = 1 << 20;
+
+/// Connection ceiling rendered for a listener
+///
+/// An explicit maxConnections wins. Otherwise three quarters of the proxy
+/// container's memory limit, less the connections of listeners with an
+/// explicit ceiling, is shared between the remaining listeners; the last
+/// quarter is left for the proxy itself.
+pub fn max_connections(spec: &KafkaPartitionRemapperSpec, listener: &ListenerSpec) -> u32 {
+    if let Some(max) = listener.max_connections {
+        return max;
+    }
+    let Some(limit) = memory_limit_bytes(spec) else {
+        return DEFAULT_MAX_CONNECTIONS;
+    };
+
+    let per_connection = spec
+        .tuning
+        .as_ref()
+        .and_then(|t| t.connection_memory_bytes)
+        .unwrap_or(DEFAULT_CONNECTION_MEMORY_BYTES)
+        .max(1);
+    let budget = limit / 4 * 3 / per_connection;
+    let explicit: u64 = spec
+        .listeners
+        .iter()
+        .filter_map(|l| l.max_connections)
+        .map(u64::from)
+        .sum();
+    let derived = spec
+        .listeners
+        .iter()
+        .filter(|l| l.max_connections.is_none())
+        .count() as u64;
+    let share = budget.saturating_sub(explicit) / derived.max(1);
+    share.clamp(1, u64::from(u32::MAX)) as u32
+}
+
+/// Memory limit of the proxy container in bytes, including the operator's
+/// default limits
+pub fn memory_limit_bytes(spec: &KafkaPartitionRemapperSpec) -> Option<u64> {
+    let limits = build_resource_requirements(spec).limits?;
+    parse_memory_quantity(&limits.get("memory")?.0)
+}
+
+/// Bytes in a Kubernetes memory quantity such as `512Mi` or `1.5G`
+pub fn parse_memory_quantity(value: &str) -> Option<u64> {
+    let bytes = parse_quantity(value)?;
+    (bytes >= 0.0).then(|| bytes.ceil() as u64)
+}
+
+/// Value of a Kubernetes quantity such as `500m`, `2`, `512Mi` or `1e3`
+///
+/// Follows the API server's grammar: an optionally signed decimal number
+/// followed by a binary SI suffix (`Ki`..`Ei`), a decimal SI suffix
+/// (`n`, `u`, `m`, `k`, `M`..`E`) or a decimal exponent. Suffixes are
+/// case-sensitive, so `100mi` and `2Gb` are rejected.
+pub fn parse_quantity(value: &str) -> Option<f64> {
+    let (sign, unsigned) = match value.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let split = unsigned
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(unsigned.len());
+    let (number, suffix) = unsigned.split_at(split);
+    if number.is_empty() || number == "." || number.matches('.').count() > 1 {
+        return None;
+    }
+    let multiplier = match suffix {
+        "" => 1.0,
+        "n" => 1e-9,
+        "u" => 1e-6,
+        "m" => 1e-3,
+        "k" => 1e3,
+        "M" => 1e6,
+        "G" => 1e9,
+        "T" => 1e12,
+        "P" => 1e15,
+        "E" => 1e18,
+        "Ki" => 1024f64,
+        "Mi" => 1024f64.powi(2),
+        "Gi" => 1024f64.powi(3),
+        "Ti" => 1024f64.powi(4),
+        "Pi" => 1024f64.powi(5),
+        "Ei" => 1024f64.powi(6),
+        _ => {
+            let exponent = suffix.strip_prefix(['e', 'E'])?;
+            let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+            if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            10f64.powi(exponent.parse().ok()?)
+        }
+    };
+    let number: f64 = number.parse().ok()?;
+    Some(sign * number * multiplier)
+}
+
+fn build_resource_requirements(spec: &KafkaPartitionRemapperSpec) -> ResourceRequirements {
+    let resources = spec
+        .pod_template
+        .as_ref()
+        .and_then(|pt| pt.resources.as_ref());
+
+    let to_quantities = |values: &BTreeMap<String, String>| -> BTreeMap<String, Quantity> {
+        values
+            .iter()
+            .map(|(k, v)| (k.clone(), Quantity(v.clone())))
+            .collect()
+    };
+
+    let defaults = settings::current().default_resources.clone();
+
+    let mut limits = resources
+        .map(|r| to_quantities(&r.limits))
+        .unwrap_or_default();
+    for (resource, default) in defaults.limits {
+        limits.entry(resource).or_insert_with(|| Quantity(default));
+    }
+
+    let mut requests = resources
+        .map(|r| to_quantities(&r.requests))
+        .unwrap_or_default();
+    for (resource, default) in defaults.requests {
+        requests
+            .entry(resource)
+            .or_insert_with(|| Quantity(default));
+    }
+
+    ResourceRequirements {
+        limits: Some(limits).filter(|l| !l.is_empty()),
+        requests: Some(requests).filter(|r| !r.is_empty()),
+        ..Default::default()
+    }
+}
+
+/// Default resource requests for the proxy container
+///
+/// Taken from the operator settings so they can be tuned per installation.
+pub fn default_resource_requests() -> BTreeMap<String, String> {
+    settings::current().default_resources.requests.clone()
+}
+
+/// Build the label selector string matching the proxy pods
+pub fn build_label_selector(name: &str) -> String {
+    build_labels(name)
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Build the standard labels for resources owned by a remapper
+pub fn build_labels(name: &str) -> BTreeMap<String, String> {
     let mut labels = BTreeMap::new();
     labels.insert(
         "app.kubernetes.io/name".to_string(),
@@ -301,7 +1236,54 @@ fn build_labels(name: &str) -> BTreeMap<String, String> {
     labels
 }
 
-fn build_owner_reference(remapper: &KafkaPartitionRemapper) -> OwnerReference {
+/// Labels for a child resource of the remapper
+///
+/// The common labels with the standard labels on top, so selectors built
+/// from [`build_labels`] keep matching. Children in another namespace also
+/// carry the owner labels in place of an owner reference.
+pub fn resource_labels(remapper: &KafkaPartitionRemapper) -> BTreeMap<String, String> {
+    let mut labels = remapper.spec.common_labels.clone();
+    labels.extend(build_labels(&remapper.resource_name()));
+    if remapper.deploys_cross_namespace() {
+        labels.extend(owner_labels(remapper));
+    }
+    labels
+}
+
+/// Labels identifying the remapper that owns a child in another namespace
+pub fn owner_labels(remapper: &KafkaPartitionRemapper) -> BTreeMap<String, String> {
+    BTreeMap::from([
+        (OWNER_NAME_LABEL.to_string(), remapper.name_any()),
+        (
+            OWNER_NAMESPACE_LABEL.to_string(),
+            remapper.namespace().unwrap_or_default(),
+        ),
+    ])
+}
+
+/// Annotations for a child resource of the remapper
+///
+/// The common annotations overlaid with the resource's own. Returns `None`
+/// when there are none.
+pub fn resource_annotations(
+    remapper: &KafkaPartitionRemapper,
+    annotations: &BTreeMap<String, String>,
+) -> Option<BTreeMap<String, String>> {
+    let mut merged = remapper.spec.common_annotations.clone();
+    merged.extend(annotations.clone());
+    (!merged.is_empty()).then_some(merged)
+}
+
+/// Owner references for a child resource of the remapper
+///
+/// Owner references cannot cross namespaces, so children in another
+/// namespace get none and rely on [`owner_labels`] instead.
+pub fn owner_references(remapper: &KafkaPartitionRemapper) -> Option<Vec<OwnerReference>> {
+    (!remapper.deploys_cross_namespace()).then(|| vec![build_owner_reference(remapper)])
+}
+
+/// Build the controller owner reference pointing at the remapper
+pub fn build_owner_reference(remapper: &KafkaPartitionRemapper) -> OwnerReference {
     OwnerReference {
         api_version: "kafka.oso.sh/v1alpha1".to_string(),
         kind: "KafkaPartitionRemapper".to_string(),
@@ -0,0 +1,116 @@
+//! Kubernetes HorizontalPodAutoscaler builder for the proxy workload
+
+use k8s_openapi::api::autoscaling::v2::{
+    CrossVersionObjectReference, HorizontalPodAutoscaler, HorizontalPodAutoscalerSpec,
+    MetricIdentifier, MetricSpec, MetricTarget, PodsMetricSource, ResourceMetricSource,
+};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use std::collections::BTreeMap;
+
+use crate::adapters::deployment_builder::{
+    owner_references, resource_annotations, resource_labels,
+};
+use crate::crd::{AutoscalingSpec, KafkaPartitionRemapper, WorkloadKind};
+
+const DEFAULT_TARGET_CPU_UTILIZATION: i32 = 80;
+
+/// Build a HorizontalPodAutoscaler for the proxy workload
+///
+/// Returns `None` when autoscaling is not enabled, the remapper is suspended,
+/// or KEDA manages the autoscaler.
+pub fn build_horizontal_pod_autoscaler(
+    remapper: &KafkaPartitionRemapper,
+) -> Option<HorizontalPodAutoscaler> {
+    if !remapper.spec.autoscaling_enabled() || remapper.spec.keda_enabled() {
+        return None;
+    }
+    let autoscaling = remapper.spec.autoscaling.as_ref()?;
+
+    let name = remapper.resource_name();
+    let namespace = remapper.target_namespace();
+
+    Some(HorizontalPodAutoscaler {
+        metadata: ObjectMeta {
+            name: Some(name.clone()),
+            namespace: Some(namespace),
+            labels: Some(resource_labels(remapper)),
+            annotations: resource_annotations(remapper, &BTreeMap::new()),
+            owner_references: owner_references(remapper),
+            ..Default::default()
+        },
+        spec: Some(HorizontalPodAutoscalerSpec {
+            scale_target_ref: CrossVersionObjectReference {
+                api_version: Some("apps/v1".to_string()),
+                kind: match remapper.spec.workload {
+                    WorkloadKind::Deployment => "Deployment".to_string(),
+                    WorkloadKind::StatefulSet => "StatefulSet".to_string(),
+                },
+                name,
+            },
+            min_replicas: Some(autoscaling.min_replicas.unwrap_or(1)),
+            max_replicas: autoscaling.max_replicas,
+            metrics: Some(build_metrics(autoscaling)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+fn build_metrics(autoscaling: &AutoscalingSpec) -> Vec<MetricSpec> {
+    let mut metrics = Vec::new();
+
+    let cpu_target = match (
+        autoscaling.target_cpu_utilization_percentage,
+        autoscaling.target_memory_utilization_percentage,
+        autoscaling.custom_metrics.is_empty(),
+    ) {
+        (Some(cpu), _, _) => Some(cpu),
+        // Fall back to CPU-based scaling when nothing else is configured
+        (None, None, true) => Some(DEFAULT_TARGET_CPU_UTILIZATION),
+        _ => None,
+    };
+
+    if let Some(cpu) = cpu_target {
+        metrics.push(resource_metric("cpu", cpu));
+    }
+
+    if let Some(memory) = autoscaling.target_memory_utilization_percentage {
+        metrics.push(resource_metric("memory", memory));
+    }
+
+    for custom in &autoscaling.custom_metrics {
+        metrics.push(MetricSpec {
+            type_: "Pods".to_string(),
+            pods: Some(PodsMetricSource {
+                metric: MetricIdentifier {
+                    name: custom.name.clone(),
+                    ..Default::default()
+                },
+                target: MetricTarget {
+                    type_: "AverageValue".to_string(),
+                    average_value: Some(Quantity(custom.target_average_value.clone())),
+                    ..Default::default()
+                },
+            }),
+            ..Default::default()
+        });
+    }
+
+    metrics
+}
+
+fn resource_metric(resource: &str, utilization: i32) -> MetricSpec {
+    MetricSpec {
+        type_: "Resource".to_string(),
+        resource: Some(ResourceMetricSource {
+            name: resource.to_string(),
+            target: MetricTarget {
+                type_: "Utilization".to_string(),
+                average_utilization: Some(utilization),
+                ..Default::default()
+            },
+        }),
+        ..Default::default()
+    }
+}
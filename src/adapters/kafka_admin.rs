@@ -0,0 +1,714 @@
+//! Minimal Kafka admin client for verifying topic metadata
+//!
+//! Speaks just enough of the Kafka wire protocol (Metadata v1,
+//! FindCoordinator v0 and DescribeGroups v0, plus SaslHandshake/
+//! SaslAuthenticate for SASL/PLAIN) to read partition counts and consumer
+//! group membership from the configured bootstrap servers.
+
+use k8s_openapi::api::core::v1::Secret;
+use kube::Client;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_rustls::rustls::client::danger::{
+    HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier,
+};
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use tokio_rustls::rustls::{self, ClientConfig, DigitallySignedStruct, RootCertStore};
+use tokio_rustls::TlsConnector;
+
+use crate::adapters::secrets;
+use crate::crd::{reachable_broker_address, KafkaClusterSpec, MappingSpec, SaslMechanism};
+use crate::{Error, Result};
+
+const CLIENT_ID: &str = "kafka-partition-remapper-operator";
+
+const API_KEY_METADATA: i16 = 3;
+const API_KEY_FIND_COORDINATOR: i16 = 10;
+const API_KEY_DESCRIBE_GROUPS: i16 = 15;
+const API_KEY_SASL_HANDSHAKE: i16 = 17;
+const API_KEY_SASL_AUTHENTICATE: i16 = 36;
+
+/// Largest response frame accepted from a broker
+const MAX_FRAME_SIZE: usize = 64 * 1024 * 1024;
+
+/// Resolved connection settings for talking to the Kafka cluster
+#[derive(Clone, Debug)]
+pub struct KafkaConnection {
+    /// Bootstrap servers as `host:port`
+    pub bootstrap_servers: Vec<String>,
+    /// Timeout for establishing and authenticating a connection
+    pub connection_timeout: Duration,
+    /// Timeout for a single request round trip
+    pub request_timeout: Duration,
+    /// TLS material, when the protocol uses SSL
+    pub tls: Option<TlsMaterial>,
+    /// SASL credentials, when the protocol uses SASL
+    pub sasl: Option<SaslCredentials>,
+    /// Reachable addresses of brokers by advertised address
+    pub broker_address_map: BTreeMap<String, String>,
+    /// Host replacing the advertised host of unmapped brokers
+    pub broker_address_host: Option<String>,
+}
+
+/// PEM-encoded TLS material read from the TLS Secret
+#[derive(Clone, Debug, Default)]
+pub struct TlsMaterial {
+    /// CA certificate(s) used to verify the brokers
+    pub ca_pem: Option<Vec<u8>>,
+    /// Client certificate for mTLS
+    pub cert_pem: Option<Vec<u8>>,
+    /// Client private key for mTLS
+    pub key_pem: Option<Vec<u8>>,
+    /// Skip broker certificate verification
+    pub insecure_skip_verify: bool,
+}
+
+/// SASL credentials read from the SASL Secret
+#[derive(Clone)]
+pub struct SaslCredentials {
+    /// SASL mechanism (only PLAIN is supported)
+    pub mechanism: SaslMechanism,
+    /// Username
+    pub username: String,
+    /// Password
+    pub password: String,
+}
+
+impl std::fmt::Debug for SaslCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SaslCredentials")
+            .field("mechanism", &self.mechanism)
+            .field("username", &self.username)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Partition metadata for a single topic
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TopicMetadata {
+    /// Topic name
+    pub name: String,
+    /// Number of partitions
+    pub partitions: usize,
+    /// Whether the topic is a Kafka internal topic
+    pub internal: bool,
+}
+
+/// Membership of a consumer group as reported by its coordinator
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupDescription {
+    /// Consumer group id
+    pub group_id: String,
+    /// Group state (Stable, Empty, Dead, PreparingRebalance, ...)
+    pub state: String,
+    /// Hosts the group members connect from, without the leading `/`
+    pub member_hosts: Vec<String>,
+}
+
+/// A topic whose partition count differs from the mapping
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartitionMismatch {
+    /// Topic name
+    pub topic: String,
+    /// Physical partitions expected by the mapping
+    pub expected: u32,
+    /// Partitions reported by the cluster
+    pub actual: usize,
+}
+
+impl std::fmt::Display for PartitionMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} has {} partitions, expected {}",
+            self.topic, self.actual, self.expected
+        )
+    }
+}
+
+/// Resolve connection settings, reading TLS and SASL material from Secrets
+pub async fn load_connection(
+    client: &Client,
+    namespace: &str,
+    kafka: &KafkaClusterSpec,
+) -> Result<KafkaConnection> {
+    let uses_tls = kafka.security_protocol.uses_tls();
+    let uses_sasl = kafka.security_protocol.uses_sasl();
+
+    // Material behind a SecretProviderClass only exists inside proxy pods
+    let provider_class = kafka
+        .tls_secret
+        .as_ref()
+        .filter(|_| uses_tls)
+        .and_then(|t| t.secret_provider_class.as_ref())
+        .or(kafka
+            .sasl_secret
+            .as_ref()
+            .filter(|_| uses_sasl)
+            .and_then(|s| s.secret_provider_class.as_ref()));
+    if let Some(provider_class) = provider_class {
+        return Err(Error::KafkaError(format!(
+            "Credentials from SecretProviderClass {} cannot be read by the operator",
+            provider_class
+        )));
+    }
+
+    let tls = match (&kafka.tls_secret, uses_tls) {
+        (Some(tls_ref), true) => {
+            let secret = secrets::get_secret(client, namespace, &tls_ref.name).await?;
+            Some(TlsMaterial {
+                ca_pem: secret_bytes(&secret, &tls_ref.ca_key),
+                cert_pem: tls_ref
+                    .cert_key
+                    .as_ref()
+                    .and_then(|k| secret_bytes(&secret, k)),
+                key_pem: tls_ref
+                    .key_key
+                    .as_ref()
+                    .and_then(|k| secret_bytes(&secret, k)),
+                insecure_skip_verify: tls_ref.insecure_skip_verify,
+            })
+        }
+        (None, true) => Some(TlsMaterial::default()),
+        _ => None,
+    };
+
+    let sasl = match (&kafka.sasl_secret, uses_sasl) {
+        (Some(sasl_ref), true) => {
+            let secret = secrets::get_secret(client, namespace, &sasl_ref.name).await?;
+            Some(SaslCredentials {
+                mechanism: sasl_ref.mechanism,
+                username: secrets::get_secret_key(&secret, &sasl_ref.username_key)?,
+                password: secrets::get_secret_key(&secret, &sasl_ref.password_key)?,
+            })
+        }
+        _ => None,
+    };
+
+    Ok(KafkaConnection {
+        bootstrap_servers: kafka.bootstrap_servers.clone(),
+        connection_timeout: Duration::from_millis(kafka.connection_timeout_ms),
+        request_timeout: Duration::from_millis(kafka.request_timeout_ms),
+        tls,
+        sasl,
+        broker_address_map: kafka.broker_address_map.clone(),
+        broker_address_host: kafka.broker_address_host(),
+    })
+}
+
+fn secret_bytes(secret: &Secret, key: &str) -> Option<Vec<u8>> {
+    secret
+        .data
+        .as_ref()
+        .and_then(|d| d.get(key))
+        .map(|v| v.0.clone())
+}
+
+/// Fetch partition metadata for all topics in the cluster
+///
+/// Bootstrap servers are tried in order until one answers.
+pub async fn fetch_topic_metadata(connection: &KafkaConnection) -> Result<Vec<TopicMetadata>> {
+    let response = request_any(connection, &encode_metadata_request(1)).await?;
+    decode_metadata_response(&response)
+}
+
+/// Describe consumer groups through their group coordinators
+pub async fn describe_consumer_groups(
+    connection: &KafkaConnection,
+    groups: &[String],
+) -> Result<Vec<GroupDescription>> {
+    let mut descriptions = Vec::with_capacity(groups.len());
+    for group in groups {
+        let response = request_any(connection, &encode_find_coordinator_request(1, group)).await?;
+        let coordinator = reachable_broker_address(
+            &connection.broker_address_map,
+            connection.broker_address_host.as_deref(),
+            &decode_find_coordinator_response(&response)?,
+        );
+
+        let response = request_server(
+            connection,
+            &coordinator,
+            &encode_describe_groups_request(2, std::slice::from_ref(group)),
+        )
+        .await?;
+        descriptions.extend(decode_describe_groups_response(&response)?);
+    }
+    Ok(descriptions)
+}
+
+/// Send a request to the first bootstrap server that answers
+async fn request_any(connection: &KafkaConnection, request: &[u8]) -> Result<Vec<u8>> {
+    if let Some(ref sasl) = connection.sasl {
+        if sasl.mechanism != SaslMechanism::Plain {
+            return Err(Error::KafkaError(format!(
+                "SASL mechanism {} is not supported for admin requests",
+                sasl.mechanism.as_str()
+            )));
+        }
+    }
+
+    let mut last_error = None;
+    for server in &connection.bootstrap_servers {
+        match request_server(connection, server, request).await {
+            Ok(response) => return Ok(response),
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    Err(last_error
+        .unwrap_or_else(|| Error::KafkaError("no bootstrap servers configured".to_string())))
+}
+
+async fn request_server(
+    connection: &KafkaConnection,
+    server: &str,
+    request: &[u8],
+) -> Result<Vec<u8>> {
+    let mut stream = timeout(connection.connection_timeout, connect(connection, server))
+        .await
+        .map_err(|_| Error::KafkaError(format!("Timed out connecting to {}", server)))??;
+
+    timeout(connection.request_timeout, round_trip(&mut stream, request))
+        .await
+        .map_err(|_| Error::KafkaError(format!("Timed out waiting for {}", server)))?
+}
+
+trait BrokerStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> BrokerStream for T {}
+
+async fn connect(connection: &KafkaConnection, server: &str) -> Result<Box<dyn BrokerStream>> {
+    let tcp = TcpStream::connect(server)
+        .await
+        .map_err(|e| Error::KafkaError(format!("Failed to connect to {}: {}", server, e)))?;
+
+    let mut stream: Box<dyn BrokerStream> = match connection.tls {
+        Some(ref tls) => {
+            let host = server.rsplit_once(':').map(|(h, _)| h).unwrap_or(server);
+            let server_name = ServerName::try_from(host.to_string())
+                .map_err(|e| Error::KafkaError(format!("Invalid server name {}: {}", host, e)))?;
+            let connector = TlsConnector::from(Arc::new(build_tls_config(tls)?));
+            let tls_stream = connector
+                .connect(server_name, tcp)
+                .await
+                .map_err(|e| Error::KafkaError(format!("TLS handshake with {}: {}", server, e)))?;
+            Box::new(tls_stream)
+        }
+        None => Box::new(tcp),
+    };
+
+    if let Some(ref sasl) = connection.sasl {
+        authenticate_plain(&mut stream, sasl).await?;
+    }
+
+    Ok(stream)
+}
+
+async fn authenticate_plain(
+    stream: &mut Box<dyn BrokerStream>,
+    sasl: &SaslCredentials,
+) -> Result<()> {
+    let mut body = Vec::new();
+    put_string(&mut body, "PLAIN");
+    let response = round_trip(stream, &encode_request(API_KEY_SASL_HANDSHAKE, 1, 2, &body)).await?;
+    let mut reader = Reader::new(&response);
+    reader.i32()?; // correlation id
+    let error_code = reader.i16()?;
+    if error_code != 0 {
+        return Err(Error::KafkaError(format!(
+            "SASL handshake rejected with error code {}",
+            error_code
+        )));
+    }
+
+    let token = format!("\0{}\0{}", sasl.username, sasl.password);
+    let mut body = Vec::new();
+    body.extend_from_slice(&(token.len() as i32).to_be_bytes());
+    body.extend_from_slice(token.as_bytes());
+    let response = round_trip(
+        stream,
+        &encode_request(API_KEY_SASL_AUTHENTICATE, 0, 3, &body),
+    )
+    .await?;
+    let mut reader = Reader::new(&response);
+    reader.i32()?; // correlation id
+    let error_code = reader.i16()?;
+    if error_code != 0 {
+        let message = reader.nullable_string()?.unwrap_or_default();
+        return Err(Error::KafkaError(format!(
+            "SASL authentication failed ({}): {}",
+            error_code, message
+        )));
+    }
+
+    Ok(())
+}
+
+fn build_tls_config(tls: &TlsMaterial) -> Result<ClientConfig> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let builder = ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| Error::KafkaError(format!("Invalid TLS configuration: {}", e)))?;
+
+    let builder = if tls.insecure_skip_verify {
+        builder
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(NoVerification(provider)))
+    } else {
+        let mut roots = RootCertStore::empty();
+        let ca_pem = tls
+            .ca_pem
+            .as_ref()
+            .ok_or_else(|| Error::SecretError("TLS secret has no CA certificate".to_string()))?;
+        for cert in rustls_pemfile::certs(&mut ca_pem.as_slice()) {
+            let cert =
+                cert.map_err(|e| Error::SecretError(format!("Invalid CA certificate: {}", e)))?;
+            roots
+                .add(cert)
+                .map_err(|e| Error::SecretError(format!("Invalid CA certificate: {}", e)))?;
+        }
+        builder.with_root_certificates(roots)
+    };
+
+    match (&tls.cert_pem, &tls.key_pem) {
+        (Some(cert_pem), Some(key_pem)) => {
+            let certs: Vec<CertificateDer<'static>> =
+                rustls_pemfile::certs(&mut cert_pem.as_slice())
+                    .collect::<std::result::Result<_, _>>()
+                    .map_err(|e| {
+                        Error::SecretError(format!("Invalid client certificate: {}", e))
+                    })?;
+            let key: PrivateKeyDer<'static> = rustls_pemfile::private_key(&mut key_pem.as_slice())
+                .map_err(|e| Error::SecretError(format!("Invalid client key: {}", e)))?
+                .ok_or_else(|| Error::SecretError("No client key found".to_string()))?;
+            builder
+                .with_client_auth_cert(certs, key)
+                .map_err(|e| Error::SecretError(format!("Invalid client certificate: {}", e)))
+        }
+        _ => Ok(builder.with_no_client_auth()),
+    }
+}
+
+/// Certificate verifier used when `insecureSkipVerify` is set
+#[derive(Debug)]
+struct NoVerification(Arc<rustls::crypto::CryptoProvider>);
+
+impl ServerCertVerifier for NoVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+async fn round_trip(stream: &mut Box<dyn BrokerStream>, request: &[u8]) -> Result<Vec<u8>> {
+    let io_error = |e: std::io::Error| Error::KafkaError(format!("Broker I/O error: {}", e));
+
+    stream.write_all(request).await.map_err(io_error)?;
+    stream.flush().await.map_err(io_error)?;
+
+    let mut size = [0u8; 4];
+    stream.read_exact(&mut size).await.map_err(io_error)?;
+    let size = i32::from_be_bytes(size);
+    if size < 0 || size as usize > MAX_FRAME_SIZE {
+        return Err(Error::KafkaError(format!(
+            "Invalid response frame size {}",
+            size
+        )));
+    }
+
+    let mut response = vec![0u8; size as usize];
+    stream.read_exact(&mut response).await.map_err(io_error)?;
+    Ok(response)
+}
+
+fn encode_request(api_key: i16, api_version: i16, correlation_id: i32, body: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(body.len() + 32);
+    payload.extend_from_slice(&api_key.to_be_bytes());
+    payload.extend_from_slice(&api_version.to_be_bytes());
+    payload.extend_from_slice(&correlation_id.to_be_bytes());
+    put_string(&mut payload, CLIENT_ID);
+    payload.extend_from_slice(body);
+
+    let mut frame = Vec::with_capacity(payload.len() + 4);
+    frame.extend_from_slice(&(payload.len() as i32).to_be_bytes());
+    frame.extend_from_slice(&payload);
+    frame
+}
+
+fn put_string(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(&(value.len() as i16).to_be_bytes());
+    buf.extend_from_slice(value.as_bytes());
+}
+
+/// Encode a framed Metadata v1 request for all topics
+pub fn encode_metadata_request(correlation_id: i32) -> Vec<u8> {
+    // A null topic array requests metadata for every topic
+    encode_request(API_KEY_METADATA, 1, correlation_id, &(-1i32).to_be_bytes())
+}
+
+/// Decode a Metadata v1 response (without the size prefix)
+pub fn decode_metadata_response(response: &[u8]) -> Result<Vec<TopicMetadata>> {
+    let mut reader = Reader::new(response);
+    reader.i32()?; // correlation id
+
+    let brokers = reader.array_len()?;
+    for _ in 0..brokers {
+        reader.i32()?; // node id
+        reader.string()?; // host
+        reader.i32()?; // port
+        reader.nullable_string()?; // rack
+    }
+    reader.i32()?; // controller id
+
+    let topic_count = reader.array_len()?;
+    let mut topics = Vec::with_capacity(topic_count);
+    for _ in 0..topic_count {
+        let error_code = reader.i16()?;
+        let name = reader.string()?;
+        let internal = reader.i8()? != 0;
+
+        let partitions = reader.array_len()?;
+        for _ in 0..partitions {
+            reader.i16()?; // error code
+            reader.i32()?; // partition index
+            reader.i32()?; // leader id
+            let replicas = reader.array_len()?;
+            reader.skip(replicas * 4)?;
+            let isr = reader.array_len()?;
+            reader.skip(isr * 4)?;
+        }
+
+        if error_code == 0 {
+            topics.push(TopicMetadata {
+                name,
+                partitions,
+                internal,
+            });
+        }
+    }
+
+    Ok(topics)
+}
+
+/// Encode a framed FindCoordinator v0 request for a consumer group
+pub fn encode_find_coordinator_request(correlation_id: i32, group: &str) -> Vec<u8> {
+    let mut body = Vec::new();
+    put_string(&mut body, group);
+    encode_request(API_KEY_FIND_COORDINATOR, 0, correlation_id, &body)
+}
+
+/// Decode a FindCoordinator v0 response into the coordinator's `host:port`
+pub fn decode_find_coordinator_response(response: &[u8]) -> Result<String> {
+    let mut reader = Reader::new(response);
+    reader.i32()?; // correlation id
+    let error_code = reader.i16()?;
+    if error_code != 0 {
+        return Err(Error::KafkaError(format!(
+            "FindCoordinator failed with error code {}",
+            error_code
+        )));
+    }
+    reader.i32()?; // node id
+    let host = reader.string()?;
+    let port = reader.i32()?;
+    Ok(format!("{}:{}", host, port))
+}
+
+/// Encode a framed DescribeGroups v0 request
+pub fn encode_describe_groups_request(correlation_id: i32, groups: &[String]) -> Vec<u8> {
+    let mut body = Vec::new();
+    body.extend_from_slice(&(groups.len() as i32).to_be_bytes());
+    for group in groups {
+        put_string(&mut body, group);
+    }
+    encode_request(API_KEY_DESCRIBE_GROUPS, 0, correlation_id, &body)
+}
+
+/// Decode a DescribeGroups v0 response (without the size prefix)
+pub fn decode_describe_groups_response(response: &[u8]) -> Result<Vec<GroupDescription>> {
+    let mut reader = Reader::new(response);
+    reader.i32()?; // correlation id
+
+    let group_count = reader.array_len()?;
+    let mut groups = Vec::with_capacity(group_count);
+    for _ in 0..group_count {
+        let error_code = reader.i16()?;
+        let group_id = reader.string()?;
+        let state = reader.string()?;
+        reader.string()?; // protocol type
+        reader.string()?; // protocol
+
+        let members = reader.array_len()?;
+        let mut member_hosts = Vec::with_capacity(members);
+        for _ in 0..members {
+            reader.string()?; // member id
+            reader.string()?; // client id
+            let host = reader.string()?;
+            reader.bytes()?; // member metadata
+            reader.bytes()?; // member assignment
+            member_hosts.push(host.trim_start_matches('/').to_string());
+        }
+
+        if error_code != 0 {
+            return Err(Error::KafkaError(format!(
+                "DescribeGroups for {} failed with error code {}",
+                group_id, error_code
+            )));
+        }
+        groups.push(GroupDescription {
+            group_id,
+            state,
+            member_hosts,
+        });
+    }
+
+    Ok(groups)
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.buf.len())
+            .ok_or_else(|| Error::KafkaError("Truncated broker response".to_string()))?;
+        let slice = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn skip(&mut self, n: usize) -> Result<()> {
+        self.take(n).map(|_| ())
+    }
+
+    fn i8(&mut self) -> Result<i8> {
+        Ok(self.take(1)?[0] as i8)
+    }
+
+    fn i16(&mut self) -> Result<i16> {
+        Ok(i16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        Ok(i32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn array_len(&mut self) -> Result<usize> {
+        Ok(self.i32()?.max(0) as usize)
+    }
+
+    fn nullable_string(&mut self) -> Result<Option<String>> {
+        let len = self.i16()?;
+        if len < 0 {
+            return Ok(None);
+        }
+        let bytes = self.take(len as usize)?;
+        String::from_utf8(bytes.to_vec())
+            .map(Some)
+            .map_err(|e| Error::KafkaError(format!("Invalid string in broker response: {}", e)))
+    }
+
+    fn string(&mut self) -> Result<String> {
+        Ok(self.nullable_string()?.unwrap_or_default())
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        let len = self.i32()?.max(0) as usize;
+        self.take(len)
+    }
+}
+
+/// Compare cluster partition counts against the mapping
+///
+/// Every non-internal topic is checked against its per-topic override (exact
+/// name or regex pattern) or the global `physicalPartitions`.
+pub fn find_partition_mismatches(
+    mapping: &MappingSpec,
+    topics: &[TopicMetadata],
+) -> Vec<PartitionMismatch> {
+    let overrides: Vec<_> = mapping
+        .topics
+        .iter()
+        .map(|o| {
+            let pattern = Regex::new(&format!("^(?:{})$", o.topic)).ok();
+            (o, pattern)
+        })
+        .collect();
+
+    let mut mismatches: Vec<_> = topics
+        .iter()
+        .filter(|t| !t.internal)
+        .filter_map(|topic| {
+            let expected = overrides
+                .iter()
+                .find(|(o, pattern)| {
+                    o.topic == topic.name
+                        || pattern.as_ref().is_some_and(|p| p.is_match(&topic.name))
+                })
+                .and_then(|(o, _)| o.physical_partitions)
+                .unwrap_or(mapping.physical_partitions);
+
+            (topic.partitions != expected as usize).then(|| PartitionMismatch {
+                topic: topic.name.clone(),
+                expected,
+                actual: topic.partitions,
+            })
+        })
+        .collect();
+
+    mismatches.sort_by(|a, b| a.topic.cmp(&b.topic));
+    mismatches
+}
@@ -0,0 +1,104 @@
+//! KEDA ScaledObject builder for the proxy workload
+//!
+//! ScaledObject is not part of the Kubernetes API, so it is built as a
+//! dynamic object. KEDA creates and drives its own HorizontalPodAutoscaler
+//! from the Prometheus triggers, so no custom metrics API adapter is needed.
+
+use kube::api::{ApiResource, DynamicObject, GroupVersionKind};
+use kube::core::ObjectMeta;
+
+use crate::adapters::deployment_builder;
+use crate::crd::{KafkaPartitionRemapper, KedaSpec, WorkloadKind};
+
+/// API group serving KEDA ScaledObjects
+pub const KEDA_API_GROUP: &str = "keda.sh";
+
+/// API resource for ScaledObject
+pub fn scaled_object_resource() -> ApiResource {
+    ApiResource::from_gvk(&GroupVersionKind::gvk(
+        KEDA_API_GROUP,
+        "v1alpha1",
+        "ScaledObject",
+    ))
+}
+
+/// Build a ScaledObject scaling the proxy workload on its Prometheus metrics
+///
+/// Returns `None` when KEDA autoscaling is not enabled or the remapper is
+/// suspended.
+pub fn build_scaled_object(remapper: &KafkaPartitionRemapper) -> Option<DynamicObject> {
+    if !remapper.spec.keda_enabled() {
+        return None;
+    }
+    let autoscaling = remapper.spec.autoscaling.as_ref()?;
+    let keda = autoscaling.keda.as_ref()?;
+
+    let name = remapper.resource_name();
+    let namespace = remapper.target_namespace();
+
+    let mut spec = serde_json::json!({
+        "scaleTargetRef": {
+            "apiVersion": "apps/v1",
+            "kind": match remapper.spec.workload {
+                WorkloadKind::Deployment => "Deployment",
+                WorkloadKind::StatefulSet => "StatefulSet",
+            },
+            "name": name,
+        },
+        "minReplicaCount": autoscaling.min_replicas.unwrap_or(1),
+        "maxReplicaCount": autoscaling.max_replicas,
+        "triggers": build_triggers(keda, &namespace, &name),
+    });
+    if let Some(interval) = keda.polling_interval_seconds {
+        spec["pollingInterval"] = serde_json::json!(interval);
+    }
+    if let Some(cooldown) = keda.cooldown_period_seconds {
+        spec["cooldownPeriod"] = serde_json::json!(cooldown);
+    }
+
+    let mut scaled_object = DynamicObject::new(&name, &scaled_object_resource()).within(&namespace);
+    scaled_object.metadata = ObjectMeta {
+        labels: Some(deployment_builder::resource_labels(remapper)),
+        annotations: deployment_builder::resource_annotations(remapper, &Default::default()),
+        owner_references: deployment_builder::owner_references(remapper),
+        ..scaled_object.metadata
+    };
+    scaled_object.data = serde_json::json!({ "spec": spec });
+    Some(scaled_object)
+}
+
+/// Prometheus triggers for the configured per-pod targets
+///
+/// KEDA divides the query result by the threshold to get the replica
+/// count, so each query sums the metric over all proxy pods.
+fn build_triggers(keda: &KedaSpec, namespace: &str, name: &str) -> Vec<serde_json::Value> {
+    let selector = format!("namespace=\"{}\",pod=~\"{}-.*\"", namespace, name);
+
+    let connections = keda.target_connections_per_pod.map(|target| {
+        prometheus_trigger(
+            keda,
+            format!("sum({}{{{}}})", keda.connections_metric, selector),
+            target,
+        )
+    });
+    let throughput = keda.target_bytes_per_second_per_pod.map(|target| {
+        prometheus_trigger(
+            keda,
+            format!("sum(rate({}{{{}}}[1m]))", keda.throughput_metric, selector),
+            target,
+        )
+    });
+
+    connections.into_iter().chain(throughput).collect()
+}
+
+fn prometheus_trigger(keda: &KedaSpec, query: String, threshold: i64) -> serde_json::Value {
+    serde_json::json!({
+        "type": "prometheus",
+        "metadata": {
+            "serverAddress": keda.server_address,
+            "query": query,
+            "threshold": threshold.to_string(),
+        }
+    })
+}
@@ -1,6 +1,18 @@
 //! Adapters for configuration transformation and Kubernetes resource building
 
+pub mod canary;
+pub mod certificates;
+pub mod consistency_audit_builder;
 pub mod deployment_builder;
+pub mod horizontal_pod_autoscaler_builder;
+pub mod kafka_admin;
+pub mod keda_scaled_object_builder;
+pub mod pod_disruption_budget_builder;
+pub mod proxy_reload;
 pub mod remapper_config;
 pub mod secrets;
 pub mod service_builder;
+pub mod sharding;
+pub mod stateful_set_builder;
+pub mod tcp_route_builder;
+pub mod users;
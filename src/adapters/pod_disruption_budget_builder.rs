@@ -0,0 +1,56 @@
+//! Kubernetes PodDisruptionBudget builder for proxy pods
+
+use k8s_openapi::api::policy::v1::{PodDisruptionBudget, PodDisruptionBudgetSpec};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use std::collections::BTreeMap;
+
+use crate::adapters::deployment_builder::{
+    build_labels, owner_references, resource_annotations, resource_labels,
+};
+use crate::crd::KafkaPartitionRemapper;
+
+/// Build a PodDisruptionBudget for the proxy
+///
+/// Returns `None` when no budget is configured or it is disabled.
+pub fn build_pod_disruption_budget(
+    remapper: &KafkaPartitionRemapper,
+) -> Option<PodDisruptionBudget> {
+    let pdb = remapper.spec.pod_disruption_budget.as_ref()?;
+    if !pdb.enabled {
+        return None;
+    }
+
+    let name = remapper.resource_name();
+    let namespace = remapper.target_namespace();
+
+    let labels = build_labels(&name);
+
+    // Default to allowing a single pod disruption at a time
+    let max_unavailable = if pdb.min_available.is_none() && pdb.max_unavailable.is_none() {
+        Some(IntOrString::Int(1))
+    } else {
+        pdb.max_unavailable.clone()
+    };
+
+    Some(PodDisruptionBudget {
+        metadata: ObjectMeta {
+            name: Some(name.clone()),
+            namespace: Some(namespace),
+            labels: Some(resource_labels(remapper)),
+            annotations: resource_annotations(remapper, &BTreeMap::new()),
+            owner_references: owner_references(remapper),
+            ..Default::default()
+        },
+        spec: Some(PodDisruptionBudgetSpec {
+            selector: Some(LabelSelector {
+                match_labels: Some(labels),
+                ..Default::default()
+            }),
+            min_available: pdb.min_available.clone(),
+            max_unavailable,
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
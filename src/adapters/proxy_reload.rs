@@ -0,0 +1,87 @@
+//! Hot reload of proxy configuration
+//!
+//! The proxy accepts a replacement configuration with `POST /-/reload` on its
+//! metrics port and applies it without dropping client connections. The
+//! rendered config is sent in the request body, so a reload does not depend
+//! on the kubelet having refreshed the mounted ConfigMap yet.
+
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+use crate::crd::{KafkaPartitionRemapperSpec, WorkloadKind};
+use crate::{Error, Result};
+
+/// Path of the proxy's reload endpoint
+pub const RELOAD_PATH: &str = "/-/reload";
+
+/// Pod annotation recording the config hash a pod last reloaded
+pub const RELOADED_CONFIG_ANNOTATION: &str = "kafka.oso.sh/reloaded-config";
+
+const RELOAD_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// ConfigMap key holding the config a given pod runs with
+pub fn pod_config_key(spec: &KafkaPartitionRemapperSpec, pod_name: &str) -> String {
+    match spec.workload {
+        WorkloadKind::Deployment => "config.yaml".to_string(),
+        WorkloadKind::StatefulSet => format!("{}.yaml", pod_name),
+    }
+}
+
+/// Encode an HTTP/1.1 reload request carrying the new config
+pub fn encode_reload_request(host: &str, config: &str) -> Vec<u8> {
+    let mut request = format!(
+        "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/yaml\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        RELOAD_PATH,
+        host,
+        config.len()
+    )
+    .into_bytes();
+    request.extend_from_slice(config.as_bytes());
+    request
+}
+
+/// Parse the status code from an HTTP/1.x response
+pub fn parse_status_code(response: &[u8]) -> Result<u16> {
+    let status_line = response
+        .split(|b| *b == b'\n')
+        .next()
+        .map(|line| String::from_utf8_lossy(line).trim_end().to_string())
+        .unwrap_or_default();
+
+    let mut parts = status_line.split_whitespace();
+    match (parts.next(), parts.next().and_then(|c| c.parse().ok())) {
+        (Some(version), Some(code)) if version.starts_with("HTTP/1.") => Ok(code),
+        _ => Err(Error::ReloadError(format!(
+            "Malformed reload response: {:?}",
+            status_line
+        ))),
+    }
+}
+
+/// Send the config to the proxy at `address` (`host:port`) and wait for it
+/// to be accepted
+pub async fn reload(address: &str, config: &str) -> Result<()> {
+    let host = address.rsplit_once(':').map(|(h, _)| h).unwrap_or(address);
+    let request = encode_reload_request(host, config);
+
+    let response = timeout(RELOAD_TIMEOUT, async {
+        let mut stream = TcpStream::connect(address).await?;
+        stream.write_all(&request).await?;
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).await?;
+        Ok::<_, std::io::Error>(response)
+    })
+    .await
+    .map_err(|_| Error::ReloadError(format!("Timed out reloading {}", address)))?
+    .map_err(|e| Error::ReloadError(format!("Failed to reload {}: {}", address, e)))?;
+
+    match parse_status_code(&response)? {
+        code if (200..300).contains(&code) => Ok(()),
+        code => Err(Error::ReloadError(format!(
+            "Reload of {} rejected with HTTP {}",
+            address, code
+        ))),
+    }
+}
@@ -1,33 +1,79 @@
 //! CRD spec to proxy YAML configuration transformation
 
-use crate::crd::KafkaPartitionRemapperSpec;
+use crate::adapters::deployment_builder;
+use crate::adapters::sharding::ShardRange;
+use crate::adapters::users;
+use crate::crd::{
+    AuditDestinationType, KafkaPartitionRemapperSpec, ListenerSpec, QuotaLimits, TlsSecretRef,
+    UpstreamClusterSpec,
+};
 use crate::Result;
 
 /// Build the proxy YAML configuration from CRD spec
+///
+/// `advertised_addresses` holds one address per entry of `spec.listeners`.
 pub fn build_proxy_config(
     spec: &KafkaPartitionRemapperSpec,
-    advertised_address: &str,
+    advertised_addresses: &[String],
 ) -> Result<String> {
-    // Build the YAML configuration that the proxy expects
-    let mut config = serde_yaml::Mapping::new();
+    serialize_config(build_config_mapping(spec, advertised_addresses))
+}
 
-    // Listen configuration
-    let mut listen = serde_yaml::Mapping::new();
-    listen.insert(
-        serde_yaml::Value::String("address".to_string()),
-        serde_yaml::Value::String(format!("0.0.0.0:{}", spec.listen.port)),
+/// Build the proxy YAML configuration for a single shard
+///
+/// The proxy only serves virtual partitions within the shard's range.
+pub fn build_shard_proxy_config(
+    spec: &KafkaPartitionRemapperSpec,
+    advertised_addresses: &[String],
+    shard: &ShardRange,
+) -> Result<String> {
+    let mut config = build_config_mapping(spec, advertised_addresses);
+
+    let mut range = serde_yaml::Mapping::new();
+    range.insert(
+        serde_yaml::Value::String("shard".to_string()),
+        serde_yaml::Value::Number(shard.index.into()),
     );
-    listen.insert(
-        serde_yaml::Value::String("advertised_address".to_string()),
-        serde_yaml::Value::String(advertised_address.to_string()),
+    range.insert(
+        serde_yaml::Value::String("start".to_string()),
+        serde_yaml::Value::Number(shard.start.into()),
     );
-    listen.insert(
-        serde_yaml::Value::String("max_connections".to_string()),
-        serde_yaml::Value::Number(spec.listen.max_connections.into()),
+    range.insert(
+        serde_yaml::Value::String("end".to_string()),
+        serde_yaml::Value::Number(shard.end.into()),
     );
+
+    if let Some(serde_yaml::Value::Mapping(mapping)) =
+        config.get_mut(serde_yaml::Value::String("mapping".to_string()))
+    {
+        mapping.insert(
+            serde_yaml::Value::String("partition_range".to_string()),
+            serde_yaml::Value::Mapping(range),
+        );
+    }
+
+    serialize_config(config)
+}
+
+fn build_config_mapping(
+    spec: &KafkaPartitionRemapperSpec,
+    advertised_addresses: &[String],
+) -> serde_yaml::Mapping {
+    // Build the YAML configuration that the proxy expects
+    let mut config = serde_yaml::Mapping::new();
+
+    // Listener configuration
+    let listeners = spec
+        .listeners
+        .iter()
+        .zip(advertised_addresses)
+        .map(|(listener, advertised_address)| {
+            serde_yaml::Value::Mapping(build_listener(spec, listener, advertised_address))
+        })
+        .collect();
     config.insert(
-        serde_yaml::Value::String("listen".to_string()),
-        serde_yaml::Value::Mapping(listen),
+        serde_yaml::Value::String("listeners".to_string()),
+        serde_yaml::Value::Sequence(listeners),
     );
 
     // Kafka configuration
@@ -56,13 +102,357 @@ pub fn build_proxy_config(
     );
     kafka.insert(
         serde_yaml::Value::String("security_protocol".to_string()),
-        serde_yaml::Value::String(spec.kafka.security_protocol.clone()),
+        serde_yaml::Value::String(spec.kafka.security_protocol.as_str().to_string()),
     );
+    // Brokers advertising addresses the proxy cannot reach are dialed elsewhere
+    if !spec.kafka.broker_address_map.is_empty() {
+        let map = spec
+            .kafka
+            .broker_address_map
+            .iter()
+            .map(|(advertised, reachable)| {
+                (
+                    serde_yaml::Value::String(advertised.clone()),
+                    serde_yaml::Value::String(reachable.clone()),
+                )
+            })
+            .collect();
+        kafka.insert(
+            serde_yaml::Value::String("broker_address_map".to_string()),
+            serde_yaml::Value::Mapping(map),
+        );
+    }
+    if let Some(host) = spec.kafka.broker_address_host() {
+        kafka.insert(
+            serde_yaml::Value::String("broker_address_host".to_string()),
+            serde_yaml::Value::String(host),
+        );
+    }
+    // Protocol version ceilings
+    if let Some(version) = spec.kafka.max_api_version {
+        kafka.insert(
+            serde_yaml::Value::String("max_api_version".to_string()),
+            serde_yaml::Value::Number(version.into()),
+        );
+    }
+    if !spec.kafka.api_versions.is_empty() {
+        let versions = spec
+            .kafka
+            .api_versions
+            .iter()
+            .map(|(api, version)| {
+                (
+                    serde_yaml::Value::String(api.clone()),
+                    serde_yaml::Value::Number((*version).into()),
+                )
+            })
+            .collect();
+        kafka.insert(
+            serde_yaml::Value::String("api_versions".to_string()),
+            serde_yaml::Value::Mapping(versions),
+        );
+    }
+    // Credentials from a SecretProviderClass are mounted as files rather
+    // than passed in the environment
+    if let Some(ref sasl) = spec.kafka.sasl_secret {
+        if sasl.secret_provider_class.is_some() {
+            let mut sasl_config = serde_yaml::Mapping::new();
+            sasl_config.insert(
+                serde_yaml::Value::String("mechanism".to_string()),
+                serde_yaml::Value::String(sasl.mechanism.as_str().to_string()),
+            );
+            sasl_config.insert(
+                serde_yaml::Value::String("username_file".to_string()),
+                serde_yaml::Value::String(format!(
+                    "{}/{}",
+                    deployment_builder::KAFKA_SASL_DIR,
+                    sasl.username_key
+                )),
+            );
+            sasl_config.insert(
+                serde_yaml::Value::String("password_file".to_string()),
+                serde_yaml::Value::String(format!(
+                    "{}/{}",
+                    deployment_builder::KAFKA_SASL_DIR,
+                    sasl.password_key
+                )),
+            );
+            kafka.insert(
+                serde_yaml::Value::String("sasl".to_string()),
+                serde_yaml::Value::Mapping(sasl_config),
+            );
+        }
+    }
+    // The keytab and krb5.conf are mounted by the pod template
+    if let Some(ref kerberos) = spec.kafka.kerberos {
+        let mut gssapi = serde_yaml::Mapping::new();
+        gssapi.insert(
+            serde_yaml::Value::String("principal".to_string()),
+            serde_yaml::Value::String(kerberos.principal.clone()),
+        );
+        gssapi.insert(
+            serde_yaml::Value::String("service_name".to_string()),
+            serde_yaml::Value::String(kerberos.service_name.clone()),
+        );
+        gssapi.insert(
+            serde_yaml::Value::String("keytab_file".to_string()),
+            serde_yaml::Value::String(deployment_builder::kerberos_keytab_path(kerberos)),
+        );
+        gssapi.insert(
+            serde_yaml::Value::String("krb5_config_file".to_string()),
+            serde_yaml::Value::String(deployment_builder::krb5_config_path(kerberos)),
+        );
+
+        let mut sasl = serde_yaml::Mapping::new();
+        sasl.insert(
+            serde_yaml::Value::String("mechanism".to_string()),
+            serde_yaml::Value::String("GSSAPI".to_string()),
+        );
+        sasl.insert(
+            serde_yaml::Value::String("kerberos".to_string()),
+            serde_yaml::Value::Mapping(gssapi),
+        );
+        kafka.insert(
+            serde_yaml::Value::String("sasl".to_string()),
+            serde_yaml::Value::Mapping(sasl),
+        );
+    }
+    // Standby clusters are tried in order while the primary is unreachable
+    if !spec.kafka.failover_clusters.is_empty() {
+        let clusters = spec
+            .kafka
+            .failover_clusters
+            .iter()
+            .map(|cluster| serde_yaml::Value::Mapping(build_upstream_cluster(cluster)))
+            .collect();
+        kafka.insert(
+            serde_yaml::Value::String("failover_clusters".to_string()),
+            serde_yaml::Value::Sequence(clusters),
+        );
+    }
     config.insert(
         serde_yaml::Value::String("kafka".to_string()),
         serde_yaml::Value::Mapping(kafka),
     );
 
+    // Topic routing to other clusters, first matching route wins
+    if let Some(ref routing) = spec.routing {
+        let mut routing_config = serde_yaml::Mapping::new();
+        routing_config.insert(
+            serde_yaml::Value::String("clusters".to_string()),
+            serde_yaml::Value::Sequence(
+                routing
+                    .clusters
+                    .iter()
+                    .map(|cluster| serde_yaml::Value::Mapping(build_upstream_cluster(cluster)))
+                    .collect(),
+            ),
+        );
+        let routes = routing
+            .routes
+            .iter()
+            .map(|route| {
+                let mut entry = serde_yaml::Mapping::new();
+                if let Some(ref prefix) = route.prefix {
+                    entry.insert(
+                        serde_yaml::Value::String("prefix".to_string()),
+                        serde_yaml::Value::String(prefix.clone()),
+                    );
+                }
+                if let Some(ref pattern) = route.pattern {
+                    entry.insert(
+                        serde_yaml::Value::String("pattern".to_string()),
+                        serde_yaml::Value::String(format!("^(?:{})$", pattern)),
+                    );
+                }
+                entry.insert(
+                    serde_yaml::Value::String("cluster".to_string()),
+                    serde_yaml::Value::String(route.cluster.clone()),
+                );
+                serde_yaml::Value::Mapping(entry)
+            })
+            .collect();
+        routing_config.insert(
+            serde_yaml::Value::String("routes".to_string()),
+            serde_yaml::Value::Sequence(routes),
+        );
+        config.insert(
+            serde_yaml::Value::String("routing".to_string()),
+            serde_yaml::Value::Mapping(routing_config),
+        );
+    }
+
+    // Topic allow and deny lists
+    if let Some(ref access) = spec.access {
+        let mut access_config = serde_yaml::Mapping::new();
+        for (key, topics) in [
+            ("topic_allow_list", &access.topic_allow_list),
+            ("topic_deny_list", &access.topic_deny_list),
+        ] {
+            if !topics.is_empty() {
+                access_config.insert(
+                    serde_yaml::Value::String(key.to_string()),
+                    serde_yaml::Value::Sequence(
+                        topics
+                            .iter()
+                            .map(|t| serde_yaml::Value::String(t.clone()))
+                            .collect(),
+                    ),
+                );
+            }
+        }
+        config.insert(
+            serde_yaml::Value::String("access".to_string()),
+            serde_yaml::Value::Mapping(access_config),
+        );
+    }
+
+    // Client quotas
+    if let Some(ref quotas) = spec.quotas {
+        let mut quotas_config = serde_yaml::Mapping::new();
+        if let Some(ref default) = quotas.default {
+            quotas_config.insert(
+                serde_yaml::Value::String("default".to_string()),
+                serde_yaml::Value::Mapping(build_quota_limits(default)),
+            );
+        }
+        if !quotas.clients.is_empty() {
+            let clients = quotas
+                .clients
+                .iter()
+                .map(|client| {
+                    let mut entry = serde_yaml::Mapping::new();
+                    if let Some(ref client_id) = client.client_id {
+                        entry.insert(
+                            serde_yaml::Value::String("client_id".to_string()),
+                            serde_yaml::Value::String(client_id.clone()),
+                        );
+                    }
+                    if let Some(ref user) = client.user {
+                        entry.insert(
+                            serde_yaml::Value::String("user".to_string()),
+                            serde_yaml::Value::String(user.clone()),
+                        );
+                    }
+                    entry.extend(build_quota_limits(&client.limits));
+                    serde_yaml::Value::Mapping(entry)
+                })
+                .collect();
+            quotas_config.insert(
+                serde_yaml::Value::String("clients".to_string()),
+                serde_yaml::Value::Sequence(clients),
+            );
+        }
+        config.insert(
+            serde_yaml::Value::String("quotas".to_string()),
+            serde_yaml::Value::Mapping(quotas_config),
+        );
+    }
+
+    // Consumer group coordination
+    if let Some(ref groups) = spec.consumer_groups {
+        let mut groups_config = serde_yaml::Mapping::new();
+        if let Some(ref prefix) = groups.group_id_prefix {
+            groups_config.insert(
+                serde_yaml::Value::String("group_id_prefix".to_string()),
+                serde_yaml::Value::String(prefix.clone()),
+            );
+        }
+        if !groups.passthrough_protocol_types.is_empty() {
+            groups_config.insert(
+                serde_yaml::Value::String("passthrough_protocol_types".to_string()),
+                serde_yaml::Value::Sequence(
+                    groups
+                        .passthrough_protocol_types
+                        .iter()
+                        .map(|t| serde_yaml::Value::String(t.clone()))
+                        .collect(),
+                ),
+            );
+        }
+        for (key, value) in [
+            ("min_session_timeout_ms", groups.min_session_timeout_ms),
+            ("max_session_timeout_ms", groups.max_session_timeout_ms),
+        ] {
+            if let Some(value) = value {
+                groups_config.insert(
+                    serde_yaml::Value::String(key.to_string()),
+                    serde_yaml::Value::Number(value.into()),
+                );
+            }
+        }
+        config.insert(
+            serde_yaml::Value::String("consumer_groups".to_string()),
+            serde_yaml::Value::Mapping(groups_config),
+        );
+    }
+
+    // Performance tuning
+    if let Some(ref tuning) = spec.tuning {
+        let tuning_config = tuning
+            .values()
+            .into_iter()
+            .map(|(key, value)| {
+                (
+                    serde_yaml::Value::String(key.to_string()),
+                    serde_yaml::Value::Number(value.into()),
+                )
+            })
+            .collect();
+        config.insert(
+            serde_yaml::Value::String("tuning".to_string()),
+            serde_yaml::Value::Mapping(tuning_config),
+        );
+    }
+
+    if let Some(ref registry) = spec.schema_registry {
+        let mut registry_config = serde_yaml::Mapping::new();
+        registry_config.insert(
+            serde_yaml::Value::String("url".to_string()),
+            serde_yaml::Value::String(registry.url.clone()),
+        );
+        if let Some(ref advertised_url) = registry.advertised_url {
+            registry_config.insert(
+                serde_yaml::Value::String("advertised_url".to_string()),
+                serde_yaml::Value::String(advertised_url.clone()),
+            );
+        }
+        if let Some(ref tls) = registry.tls_secret {
+            registry_config.insert(
+                serde_yaml::Value::String("tls".to_string()),
+                serde_yaml::Value::Mapping(build_tls_files(
+                    tls,
+                    deployment_builder::SCHEMA_REGISTRY_TLS_DIR,
+                )),
+            );
+        }
+        if registry.auth_secret.is_some() {
+            let mut auth_config = serde_yaml::Mapping::new();
+            auth_config.insert(
+                serde_yaml::Value::String("username_env".to_string()),
+                serde_yaml::Value::String("SCHEMA_REGISTRY_USERNAME".to_string()),
+            );
+            auth_config.insert(
+                serde_yaml::Value::String("password_env".to_string()),
+                serde_yaml::Value::String("SCHEMA_REGISTRY_PASSWORD".to_string()),
+            );
+            registry_config.insert(
+                serde_yaml::Value::String("basic_auth".to_string()),
+                serde_yaml::Value::Mapping(auth_config),
+            );
+        }
+        if let Some(port) = registry.forward_port {
+            registry_config.insert(
+                serde_yaml::Value::String("listen_address".to_string()),
+                serde_yaml::Value::String(format!("0.0.0.0:{}", port)),
+            );
+        }
+        config.insert(
+            serde_yaml::Value::String("schema_registry".to_string()),
+            serde_yaml::Value::Mapping(registry_config),
+        );
+    }
+
     // Mapping configuration
     let mut mapping = serde_yaml::Mapping::new();
     mapping.insert(
@@ -111,6 +501,70 @@ pub fn build_proxy_config(
             serde_yaml::Value::Mapping(topics),
         );
     }
+    if !spec.mapping.topic_renames.is_empty() {
+        let renames = spec
+            .mapping
+            .topic_renames
+            .iter()
+            .map(|rename| {
+                let mut entry = serde_yaml::Mapping::new();
+                if let Some(ref prefix) = rename.prefix {
+                    entry.insert(
+                        serde_yaml::Value::String("prefix".to_string()),
+                        serde_yaml::Value::String(prefix.clone()),
+                    );
+                }
+                if let Some(ref pattern) = rename.pattern {
+                    entry.insert(
+                        serde_yaml::Value::String("pattern".to_string()),
+                        serde_yaml::Value::String(format!("^(?:{})$", pattern)),
+                    );
+                }
+                entry.insert(
+                    serde_yaml::Value::String("replacement".to_string()),
+                    serde_yaml::Value::String(rename.replacement.clone()),
+                );
+                serde_yaml::Value::Mapping(entry)
+            })
+            .collect();
+        mapping.insert(
+            serde_yaml::Value::String("topic_renames".to_string()),
+            serde_yaml::Value::Sequence(renames),
+        );
+    }
+    if let Some(ref injection) = spec.mapping.header_injection {
+        let mut injection_config = serde_yaml::Mapping::new();
+        if let Some(ref header) = injection.virtual_partition_header {
+            injection_config.insert(
+                serde_yaml::Value::String("virtual_partition_header".to_string()),
+                serde_yaml::Value::String(header.clone()),
+            );
+        }
+        if !injection.headers.is_empty() {
+            let headers = injection
+                .headers
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        serde_yaml::Value::String(name.clone()),
+                        serde_yaml::Value::String(value.clone()),
+                    )
+                })
+                .collect();
+            injection_config.insert(
+                serde_yaml::Value::String("headers".to_string()),
+                serde_yaml::Value::Mapping(headers),
+            );
+        }
+        injection_config.insert(
+            serde_yaml::Value::String("overwrite".to_string()),
+            serde_yaml::Value::Bool(injection.overwrite),
+        );
+        mapping.insert(
+            serde_yaml::Value::String("header_injection".to_string()),
+            serde_yaml::Value::Mapping(injection_config),
+        );
+    }
     config.insert(
         serde_yaml::Value::String("mapping".to_string()),
         serde_yaml::Value::Mapping(mapping),
@@ -131,11 +585,82 @@ pub fn build_proxy_config(
         serde_yaml::Value::Mapping(metrics),
     );
 
+    // Requests rejected during maintenance
+    if let Some(mode) = spec.maintenance_mode {
+        let mut maintenance = serde_yaml::Mapping::new();
+        maintenance.insert(
+            serde_yaml::Value::String("mode".to_string()),
+            serde_yaml::Value::String(mode.config_value().to_string()),
+        );
+        config.insert(
+            serde_yaml::Value::String("maintenance".to_string()),
+            serde_yaml::Value::Mapping(maintenance),
+        );
+    }
+
+    // Audit logging
+    if let Some(audit) = spec.audit_logging.as_ref().filter(|a| a.enabled) {
+        let mut audit_config = serde_yaml::Mapping::new();
+        audit_config.insert(
+            serde_yaml::Value::String("sample_rate".to_string()),
+            serde_yaml::Value::Number(audit.sample_rate.into()),
+        );
+        audit_config.insert(
+            serde_yaml::Value::String("include_client_id".to_string()),
+            serde_yaml::Value::Bool(audit.include_client_id),
+        );
+        audit_config.insert(
+            serde_yaml::Value::String("include_principal".to_string()),
+            serde_yaml::Value::Bool(audit.include_principal),
+        );
+        let destinations = audit
+            .destinations
+            .iter()
+            .map(|destination| {
+                let mut entry = serde_yaml::Mapping::new();
+                let type_ = match destination.type_ {
+                    AuditDestinationType::Stdout => "stdout",
+                    AuditDestinationType::File => "file",
+                    AuditDestinationType::Syslog => "syslog",
+                };
+                entry.insert(
+                    serde_yaml::Value::String("type".to_string()),
+                    serde_yaml::Value::String(type_.to_string()),
+                );
+                if destination.type_ == AuditDestinationType::File {
+                    entry.insert(
+                        serde_yaml::Value::String("path".to_string()),
+                        serde_yaml::Value::String(format!(
+                            "{}/{}",
+                            deployment_builder::AUDIT_LOG_DIR,
+                            destination.file_name.as_deref().unwrap_or("audit.log")
+                        )),
+                    );
+                }
+                if let Some(ref address) = destination.address {
+                    entry.insert(
+                        serde_yaml::Value::String("address".to_string()),
+                        serde_yaml::Value::String(address.clone()),
+                    );
+                }
+                serde_yaml::Value::Mapping(entry)
+            })
+            .collect();
+        audit_config.insert(
+            serde_yaml::Value::String("destinations".to_string()),
+            serde_yaml::Value::Sequence(destinations),
+        );
+        config.insert(
+            serde_yaml::Value::String("audit".to_string()),
+            serde_yaml::Value::Mapping(audit_config),
+        );
+    }
+
     // Logging configuration
     let mut logging = serde_yaml::Mapping::new();
     logging.insert(
         serde_yaml::Value::String("level".to_string()),
-        serde_yaml::Value::String(spec.logging.level.clone()),
+        serde_yaml::Value::String(spec.logging.level.as_str().to_string()),
     );
     logging.insert(
         serde_yaml::Value::String("json".to_string()),
@@ -146,6 +671,231 @@ pub fn build_proxy_config(
         serde_yaml::Value::Mapping(logging),
     );
 
+    config
+}
+
+fn build_listener(
+    spec: &KafkaPartitionRemapperSpec,
+    listener: &ListenerSpec,
+    advertised_address: &str,
+) -> serde_yaml::Mapping {
+    let mut entry = serde_yaml::Mapping::new();
+    entry.insert(
+        serde_yaml::Value::String("name".to_string()),
+        serde_yaml::Value::String(listener.name.clone()),
+    );
+    entry.insert(
+        serde_yaml::Value::String("address".to_string()),
+        serde_yaml::Value::String(format!("0.0.0.0:{}", listener.port)),
+    );
+    entry.insert(
+        serde_yaml::Value::String("advertised_address".to_string()),
+        serde_yaml::Value::String(advertised_address.to_string()),
+    );
+    entry.insert(
+        serde_yaml::Value::String("max_connections".to_string()),
+        serde_yaml::Value::Number(deployment_builder::max_connections(spec, listener).into()),
+    );
+
+    let Some(ref security) = listener.security else {
+        entry.insert(
+            serde_yaml::Value::String("security_protocol".to_string()),
+            serde_yaml::Value::String("PLAINTEXT".to_string()),
+        );
+        return entry;
+    };
+    entry.insert(
+        serde_yaml::Value::String("security_protocol".to_string()),
+        serde_yaml::Value::String(security.protocol.as_str().to_string()),
+    );
+
+    // Secrets are mounted per listener by the pod template
+    if let Some(ref tls) = security.tls {
+        let dir = deployment_builder::listener_tls_dir(&listener.name);
+        let mut tls_config = serde_yaml::Mapping::new();
+        tls_config.insert(
+            serde_yaml::Value::String("cert_file".to_string()),
+            serde_yaml::Value::String(format!("{}/{}", dir, tls.certificate_secret.cert_key)),
+        );
+        tls_config.insert(
+            serde_yaml::Value::String("key_file".to_string()),
+            serde_yaml::Value::String(format!("{}/{}", dir, tls.certificate_secret.key_key)),
+        );
+        if let Some(ref ca) = tls.client_ca_secret {
+            tls_config.insert(
+                serde_yaml::Value::String("ca_file".to_string()),
+                serde_yaml::Value::String(format!(
+                    "{}/{}",
+                    deployment_builder::listener_client_ca_dir(&listener.name),
+                    ca.key
+                )),
+            );
+        }
+        tls_config.insert(
+            serde_yaml::Value::String("require_client_cert".to_string()),
+            serde_yaml::Value::Bool(tls.require_client_cert),
+        );
+        entry.insert(
+            serde_yaml::Value::String("tls".to_string()),
+            serde_yaml::Value::Mapping(tls_config),
+        );
+    }
+    if let Some(ref sasl) = security.sasl {
+        let mut sasl_config = serde_yaml::Mapping::new();
+        sasl_config.insert(
+            serde_yaml::Value::String("mechanisms".to_string()),
+            serde_yaml::Value::Sequence(
+                sasl.enabled_mechanisms
+                    .iter()
+                    .map(|m| serde_yaml::Value::String(m.as_str().to_string()))
+                    .collect(),
+            ),
+        );
+        sasl_config.insert(
+            serde_yaml::Value::String("credentials_dir".to_string()),
+            serde_yaml::Value::String(deployment_builder::listener_sasl_dir(&listener.name)),
+        );
+        // Generated users are accepted on every SASL listener, with a
+        // rotated-out password valid until its overlap window ends
+        if !spec.users.is_empty() {
+            sasl_config.insert(
+                serde_yaml::Value::String("generated_credentials_dir".to_string()),
+                serde_yaml::Value::String(users::GENERATED_USERS_DIR.to_string()),
+            );
+        }
+        if spec.user_rotation_enabled() {
+            sasl_config.insert(
+                serde_yaml::Value::String("previous_credentials_dir".to_string()),
+                serde_yaml::Value::String(users::PREVIOUS_USERS_DIR.to_string()),
+            );
+        }
+        entry.insert(
+            serde_yaml::Value::String("sasl".to_string()),
+            serde_yaml::Value::Mapping(sasl_config),
+        );
+    }
+
+    entry
+}
+
+/// Limits that are set, leaving unset ones unlimited
+fn build_quota_limits(limits: &QuotaLimits) -> serde_yaml::Mapping {
+    let mut entry = serde_yaml::Mapping::new();
+    let values = [
+        ("ingress_bytes_per_second", limits.ingress_bytes_per_second),
+        ("egress_bytes_per_second", limits.egress_bytes_per_second),
+        (
+            "connections_per_second",
+            limits.connections_per_second.map(u64::from),
+        ),
+    ];
+    for (key, value) in values {
+        if let Some(value) = value {
+            entry.insert(
+                serde_yaml::Value::String(key.to_string()),
+                serde_yaml::Value::Number(value.into()),
+            );
+        }
+    }
+    entry
+}
+
+/// Connection settings for a failover or routed cluster
+/// TLS file paths for material mounted under `dir`
+fn build_tls_files(tls: &TlsSecretRef, dir: &str) -> serde_yaml::Mapping {
+    let mut tls_config = serde_yaml::Mapping::new();
+    tls_config.insert(
+        serde_yaml::Value::String("ca_file".to_string()),
+        serde_yaml::Value::String(format!("{}/{}", dir, tls.ca_key)),
+    );
+    if let Some(ref cert_key) = tls.cert_key {
+        tls_config.insert(
+            serde_yaml::Value::String("cert_file".to_string()),
+            serde_yaml::Value::String(format!("{}/{}", dir, cert_key)),
+        );
+    }
+    if let Some(ref key_key) = tls.key_key {
+        tls_config.insert(
+            serde_yaml::Value::String("key_file".to_string()),
+            serde_yaml::Value::String(format!("{}/{}", dir, key_key)),
+        );
+    }
+    tls_config.insert(
+        serde_yaml::Value::String("insecure_skip_verify".to_string()),
+        serde_yaml::Value::Bool(tls.insecure_skip_verify),
+    );
+    tls_config
+}
+
+fn build_upstream_cluster(cluster: &UpstreamClusterSpec) -> serde_yaml::Mapping {
+    let mut entry = serde_yaml::Mapping::new();
+    entry.insert(
+        serde_yaml::Value::String("name".to_string()),
+        serde_yaml::Value::String(cluster.name.clone()),
+    );
+    entry.insert(
+        serde_yaml::Value::String("bootstrap_servers".to_string()),
+        serde_yaml::Value::Sequence(
+            cluster
+                .bootstrap_servers
+                .iter()
+                .map(|s| serde_yaml::Value::String(s.clone()))
+                .collect(),
+        ),
+    );
+    entry.insert(
+        serde_yaml::Value::String("security_protocol".to_string()),
+        serde_yaml::Value::String(cluster.security_protocol.as_str().to_string()),
+    );
+
+    // Credentials are mounted under the cluster's name by the pod template
+    if let Some(ref tls) = cluster.tls_secret {
+        let dir = deployment_builder::upstream_tls_dir(&cluster.name);
+        entry.insert(
+            serde_yaml::Value::String("tls".to_string()),
+            serde_yaml::Value::Mapping(build_tls_files(tls, &dir)),
+        );
+    }
+    if let Some(ref sasl) = cluster.sasl_secret {
+        let mut sasl_config = serde_yaml::Mapping::new();
+        sasl_config.insert(
+            serde_yaml::Value::String("mechanism".to_string()),
+            serde_yaml::Value::String(sasl.mechanism.as_str().to_string()),
+        );
+        let credentials = if sasl.secret_provider_class.is_some() {
+            let dir = deployment_builder::upstream_sasl_dir(&cluster.name);
+            [
+                ("username_file", format!("{}/{}", dir, sasl.username_key)),
+                ("password_file", format!("{}/{}", dir, sasl.password_key)),
+            ]
+        } else {
+            [
+                (
+                    "username_env",
+                    deployment_builder::upstream_sasl_env(&cluster.name, "USERNAME"),
+                ),
+                (
+                    "password_env",
+                    deployment_builder::upstream_sasl_env(&cluster.name, "PASSWORD"),
+                ),
+            ]
+        };
+        for (key, value) in credentials {
+            sasl_config.insert(
+                serde_yaml::Value::String(key.to_string()),
+                serde_yaml::Value::String(value),
+            );
+        }
+        entry.insert(
+            serde_yaml::Value::String("sasl".to_string()),
+            serde_yaml::Value::Mapping(sasl_config),
+        );
+    }
+
+    entry
+}
+
+fn serialize_config(config: serde_yaml::Mapping) -> Result<String> {
     serde_yaml::to_string(&serde_yaml::Value::Mapping(config))
         .map_err(|e| crate::Error::ConfigError(format!("Failed to serialize config: {}", e)))
 }
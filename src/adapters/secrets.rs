@@ -1,17 +1,118 @@
 //! Kubernetes secret fetching utilities
+//!
+//! Fetched secrets are cached for a short TTL, so many remappers referencing
+//! the same TLS or SASL secrets do not each read them from the API server.
+//! Entries are dropped early once a watch sees a newer resourceVersion.
 
 use k8s_openapi::api::core::v1::Secret;
-use kube::{Api, Client};
+use kube::{Api, Client, ResourceExt};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use crate::{Error, Result};
 
+/// How long a fetched secret is served from the cache
+pub const CACHE_TTL: Duration = Duration::from_secs(30);
+
+lazy_static::lazy_static! {
+    static ref CACHE: SecretCache = SecretCache::new(CACHE_TTL);
+}
+
+/// Secrets by namespace and name, each with the time it was fetched
+#[derive(Debug)]
+pub struct SecretCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<(String, String), (Secret, Instant)>>,
+}
+
+impl SecretCache {
+    /// Create an empty cache serving entries for `ttl`
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Cached secret, if fetched within the TTL
+    pub fn get(&self, namespace: &str, name: &str) -> Option<Secret> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let key = (namespace.to_string(), name.to_string());
+        match entries.get(&key) {
+            Some((secret, fetched)) if fetched.elapsed() < self.ttl => Some(secret.clone()),
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Cache a secret just fetched from `namespace`
+    pub fn insert(&self, namespace: &str, secret: &Secret) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.insert(
+            (namespace.to_string(), secret.name_any()),
+            (secret.clone(), Instant::now()),
+        );
+    }
+
+    /// Drop the cached copy of a secret seen by a watch if it is outdated
+    pub fn observe(&self, secret: &Secret) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let key = (secret.namespace().unwrap_or_default(), secret.name_any());
+        if entries
+            .get(&key)
+            .is_some_and(|(cached, _)| cached.resource_version() != secret.resource_version())
+        {
+            entries.remove(&key);
+        }
+    }
+
+    /// Drop the cached copy of a secret
+    pub fn invalidate(&self, namespace: &str, name: &str) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.remove(&(namespace.to_string(), name.to_string()));
+    }
+}
+
+/// Drop the cached copy of a secret seen by a watch if it is outdated
+pub fn observe(secret: &Secret) {
+    CACHE.observe(secret);
+}
+
+/// Drop the cached copy of a secret after the operator changed it
+pub fn invalidate(namespace: &str, name: &str) {
+    CACHE.invalidate(namespace, name);
+}
+
 /// Fetch a secret by name from the given namespace
 pub async fn get_secret(client: &Client, namespace: &str, name: &str) -> Result<Secret> {
+    get_secret_opt(client, namespace, name)
+        .await?
+        .ok_or_else(|| Error::SecretError(format!("Secret {} not found", name)))
+}
+
+/// Fetch a secret by name, returning `None` if it does not exist
+pub async fn get_secret_opt(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+) -> Result<Option<Secret>> {
+    if let Some(secret) = CACHE.get(namespace, name) {
+        return Ok(Some(secret));
+    }
+
     let secrets: Api<Secret> = Api::namespaced(client.clone(), namespace);
-    secrets
-        .get(name)
+    let secret = secrets
+        .get_opt(name)
         .await
-        .map_err(|e| Error::KubeError(format!("Failed to get secret {}: {}", name, e)))
+        .map_err(|e| Error::kube(format!("Failed to get secret {}", name), e))?;
+    if let Some(ref secret) = secret {
+        CACHE.insert(namespace, secret);
+    }
+    Ok(secret)
 }
 
 /// Get a specific key from a secret
@@ -28,3 +129,17 @@ pub fn get_secret_key(secret: &Secret, key: &str) -> Result<String> {
     String::from_utf8(value.0.clone())
         .map_err(|e| Error::SecretError(format!("Invalid UTF-8 in secret key '{}': {}", key, e)))
 }
+
+/// Keys that are absent or empty in a secret, in the order given
+pub fn missing_keys(secret: &Secret, keys: &[String]) -> Vec<String> {
+    keys.iter()
+        .filter(|key| {
+            secret
+                .data
+                .as_ref()
+                .and_then(|data| data.get(*key))
+                .is_none_or(|value| value.0.is_empty())
+        })
+        .cloned()
+        .collect()
+}
@@ -1,34 +1,60 @@
 //! Kubernetes Service builder for proxy access
 
-use k8s_openapi::api::core::v1::{Service, ServicePort, ServiceSpec};
-use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+use k8s_openapi::api::core::v1::{
+    ClientIPConfig, Service, ServicePort, ServiceSpec, SessionAffinityConfig,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
 use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use std::collections::BTreeMap;
 
-use crate::crd::{KafkaPartitionRemapper, KafkaPartitionRemapperSpec};
+use crate::adapters::deployment_builder::{self, build_labels, owner_references};
+use crate::adapters::sharding;
+use crate::crd::{
+    safe_resource_name, KafkaPartitionRemapper, KafkaPartitionRemapperSpec, ServiceType,
+};
+
+/// Annotation ExternalDNS reads the published hostname from
+pub const EXTERNAL_DNS_HOSTNAME_ANNOTATION: &str = "external-dns.alpha.kubernetes.io/hostname";
 
 /// Build a Service for the proxy
 pub fn build_service(remapper: &KafkaPartitionRemapper) -> Service {
-    let name = remapper.metadata.name.clone().unwrap_or_default();
-    let namespace = remapper.metadata.namespace.clone().unwrap_or_default();
+    let name = remapper.resource_name();
+    let namespace = remapper.target_namespace();
     let spec = &remapper.spec;
 
-    let labels = build_labels(&name);
+    // During a remap task cutover the Service routes to the new generation
+    let selector = remapper
+        .serving_generation()
+        .map(|g| build_labels(&safe_resource_name(g)))
+        .unwrap_or_else(|| build_labels(&name));
+
+    let mut annotations = spec.service.annotations.clone();
+    if let Some(ref hostname) = spec.service.external_hostname {
+        annotations.insert(
+            EXTERNAL_DNS_HOSTNAME_ANNOTATION.to_string(),
+            hostname.clone(),
+        );
+    }
+
+    // Fixed node ports are only set here; shard Services would collide
+    let mut service_spec = build_service_spec(spec, &selector);
+    if spec.service.type_ != ServiceType::ClusterIP {
+        for port in service_spec.ports.iter_mut().flatten() {
+            let name = port.name.as_deref().unwrap_or_default();
+            port.node_port = spec.service.node_ports.get(name).copied();
+        }
+    }
 
     Service {
         metadata: ObjectMeta {
             name: Some(name.clone()),
             namespace: Some(namespace),
-            labels: Some(labels.clone()),
-            annotations: if spec.service.annotations.is_empty() {
-                None
-            } else {
-                Some(spec.service.annotations.clone())
-            },
-            owner_references: Some(vec![build_owner_reference(remapper)]),
+            labels: Some(deployment_builder::resource_labels(remapper)),
+            annotations: deployment_builder::resource_annotations(remapper, &annotations),
+            owner_references: owner_references(remapper),
             ..Default::default()
         },
-        spec: Some(build_service_spec(spec, &labels)),
+        spec: Some(service_spec),
         ..Default::default()
     }
 }
@@ -37,72 +63,339 @@ fn build_service_spec(
     spec: &KafkaPartitionRemapperSpec,
     selector: &BTreeMap<String, String>,
 ) -> ServiceSpec {
+    let service = &spec.service;
     let mut service_spec = ServiceSpec {
-        type_: Some(spec.service.type_.clone()),
+        type_: Some(service.type_.as_str().to_string()),
         selector: Some(selector.clone()),
-        ports: Some(vec![
-            ServicePort {
-                name: Some("kafka".to_string()),
-                port: spec.listen.port,
-                target_port: Some(IntOrString::String("kafka".to_string())),
-                protocol: Some("TCP".to_string()),
-                ..Default::default()
-            },
-            ServicePort {
-                name: Some("metrics".to_string()),
-                port: spec.metrics.port,
-                target_port: Some(IntOrString::String("metrics".to_string())),
-                protocol: Some("TCP".to_string()),
-                ..Default::default()
-            },
-        ]),
+        ports: Some(
+            listener_ports(spec)
+                .chain(spec.metrics.on_client_service().then(|| metrics_port(spec)))
+                .chain(schema_registry_port(spec))
+                .collect(),
+        ),
+        ip_families: ip_families(spec),
+        ip_family_policy: service.ip_family_policy.clone(),
+        internal_traffic_policy: service.internal_traffic_policy.clone(),
+        session_affinity: service.session_affinity.clone(),
+        session_affinity_config: service.session_affinity_timeout_seconds.map(|timeout| {
+            SessionAffinityConfig {
+                client_ip: Some(ClientIPConfig {
+                    timeout_seconds: Some(timeout),
+                }),
+            }
+        }),
         ..Default::default()
     };
 
-    if let Some(ref lb_ip) = spec.service.load_balancer_ip {
+    if let Some(ref lb_ip) = service.load_balancer_ip {
         service_spec.load_balancer_ip = Some(lb_ip.clone());
     }
 
-    if let Some(ref policy) = spec.service.external_traffic_policy {
-        service_spec.external_traffic_policy = Some(policy.clone());
+    if let Some(ref policy) = service.external_traffic_policy {
+        service_spec.external_traffic_policy = Some(policy.as_str().to_string());
+    }
+
+    if service.type_ == ServiceType::LoadBalancer {
+        service_spec.load_balancer_class = service.load_balancer_class.clone();
+        service_spec.load_balancer_source_ranges =
+            (!service.load_balancer_source_ranges.is_empty())
+                .then(|| service.load_balancer_source_ranges.clone());
+        service_spec.allocate_load_balancer_node_ports = service.allocate_load_balancer_node_ports;
     }
 
     service_spec
 }
 
-fn build_labels(name: &str) -> BTreeMap<String, String> {
-    let mut labels = BTreeMap::new();
-    labels.insert(
-        "app.kubernetes.io/name".to_string(),
-        "kafka-partition-remapper".to_string(),
-    );
-    labels.insert("app.kubernetes.io/instance".to_string(), name.to_string());
-    labels.insert(
-        "app.kubernetes.io/managed-by".to_string(),
-        "kafka-partition-remapper-operator".to_string(),
-    );
-    labels
+/// IP families for the proxy Services, left unset for the cluster default
+fn ip_families(spec: &KafkaPartitionRemapperSpec) -> Option<Vec<String>> {
+    (!spec.service.ip_families.is_empty()).then(|| spec.service.ip_families.clone())
+}
+
+/// Name of the external Service
+pub fn external_service_name(name: &str) -> String {
+    format!("{}-external", name)
+}
+
+/// Build the external Service exposing the selected listeners
+///
+/// Returns `None` when no external Service is configured. Only listener
+/// ports are exposed; metrics stay on the internal Service.
+pub fn build_external_service(remapper: &KafkaPartitionRemapper) -> Option<Service> {
+    let spec = &remapper.spec;
+    let external = spec.external_service.as_ref()?;
+    let listeners = spec.external_listeners();
+    if listeners.is_empty() {
+        return None;
+    }
+
+    let name = remapper.resource_name();
+    let namespace = remapper.target_namespace();
+    let selector = remapper
+        .serving_generation()
+        .map(|g| build_labels(&safe_resource_name(g)))
+        .unwrap_or_else(|| build_labels(&name));
+
+    Some(Service {
+        metadata: ObjectMeta {
+            name: Some(external_service_name(&name)),
+            namespace: Some(namespace),
+            labels: Some(deployment_builder::resource_labels(remapper)),
+            annotations: deployment_builder::resource_annotations(remapper, &external.annotations),
+            owner_references: owner_references(remapper),
+            ..Default::default()
+        },
+        spec: Some(ServiceSpec {
+            type_: Some(external.type_.as_str().to_string()),
+            selector: Some(selector),
+            ports: Some(
+                listeners
+                    .into_iter()
+                    .map(|listener| ServicePort {
+                        name: Some(listener.name.clone()),
+                        port: listener.port,
+                        target_port: Some(IntOrString::String(listener.name.clone())),
+                        protocol: Some("TCP".to_string()),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            load_balancer_ip: external.load_balancer_ip.clone(),
+            external_traffic_policy: external
+                .external_traffic_policy
+                .map(|p| p.as_str().to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+/// Service port for the metrics endpoint
+fn metrics_port(spec: &KafkaPartitionRemapperSpec) -> ServicePort {
+    ServicePort {
+        name: Some("metrics".to_string()),
+        port: spec.metrics.port,
+        target_port: Some(IntOrString::String("metrics".to_string())),
+        protocol: Some("TCP".to_string()),
+        ..Default::default()
+    }
+}
+
+/// Service port forwarding Schema Registry traffic, if enabled
+fn schema_registry_port(spec: &KafkaPartitionRemapperSpec) -> Option<ServicePort> {
+    let port = deployment_builder::schema_registry_forward_port(spec)?;
+    Some(ServicePort {
+        name: Some(deployment_builder::SCHEMA_REGISTRY_PORT_NAME.to_string()),
+        port,
+        target_port: Some(IntOrString::String(
+            deployment_builder::SCHEMA_REGISTRY_PORT_NAME.to_string(),
+        )),
+        protocol: Some("TCP".to_string()),
+        ..Default::default()
+    })
+}
+
+/// Name of the dedicated metrics Service
+pub fn metrics_service_name(name: &str) -> String {
+    format!("{}-metrics", name)
+}
+
+/// Build the dedicated Service exposing only the metrics port
+///
+/// Returns `None` unless `metrics.service` is enabled. The Headless type
+/// gives each pod its own scrape address.
+pub fn build_metrics_service(remapper: &KafkaPartitionRemapper) -> Option<Service> {
+    let spec = &remapper.spec;
+    let metrics_service = spec.metrics.dedicated_service()?;
+
+    let name = remapper.resource_name();
+    let headless = metrics_service.type_ == "Headless";
+
+    Some(Service {
+        metadata: ObjectMeta {
+            name: Some(metrics_service_name(&name)),
+            namespace: Some(remapper.target_namespace()),
+            labels: Some(deployment_builder::resource_labels(remapper)),
+            annotations: deployment_builder::resource_annotations(
+                remapper,
+                &metrics_service.annotations,
+            ),
+            owner_references: owner_references(remapper),
+            ..Default::default()
+        },
+        spec: Some(ServiceSpec {
+            type_: Some(
+                if headless {
+                    "ClusterIP"
+                } else {
+                    &metrics_service.type_
+                }
+                .to_string(),
+            ),
+            cluster_ip: headless.then(|| "None".to_string()),
+            selector: Some(build_labels(&name)),
+            ports: Some(vec![metrics_port(spec)]),
+            ip_families: ip_families(spec),
+            ip_family_policy: spec.service.ip_family_policy.clone(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+/// One Service port per listener, targeting the named container port
+fn listener_ports(spec: &KafkaPartitionRemapperSpec) -> impl Iterator<Item = ServicePort> + '_ {
+    spec.listeners.iter().map(|listener| ServicePort {
+        name: Some(listener.name.clone()),
+        port: listener.port,
+        target_port: Some(IntOrString::String(listener.name.clone())),
+        protocol: Some("TCP".to_string()),
+        ..Default::default()
+    })
+}
+
+/// Address a listener advertises when none is set explicitly
+///
+/// The ExternalDNS hostname when configured, otherwise the in-cluster
+/// Service address.
+pub fn default_advertised_address(
+    name: &str,
+    namespace: &str,
+    spec: &KafkaPartitionRemapperSpec,
+    port: i32,
+) -> String {
+    match spec.service.external_hostname {
+        Some(ref hostname) => format!("{}:{}", hostname, port),
+        None => cluster_dns_address(name, namespace, port),
+    }
+}
+
+/// Build the in-cluster DNS address for a Service port
+pub fn cluster_dns_address(name: &str, namespace: &str, port: i32) -> String {
+    format!("{}.{}.svc.cluster.local:{}", name, namespace, port)
+}
+
+/// Name of the headless Service used in StatefulSet mode
+pub fn headless_service_name(name: &str) -> String {
+    format!("{}-headless", name)
+}
+
+/// Build the stable DNS address of a StatefulSet pod
+pub fn pod_dns_address(name: &str, ordinal: i32, namespace: &str, port: i32) -> String {
+    format!(
+        "{}-{}.{}.{}.svc.cluster.local:{}",
+        name,
+        ordinal,
+        headless_service_name(name),
+        namespace,
+        port
+    )
 }
 
-fn build_owner_reference(remapper: &KafkaPartitionRemapper) -> OwnerReference {
-    OwnerReference {
-        api_version: "kafka.oso.sh/v1alpha1".to_string(),
-        kind: "KafkaPartitionRemapper".to_string(),
-        name: remapper.metadata.name.clone().unwrap_or_default(),
-        uid: remapper.metadata.uid.clone().unwrap_or_default(),
-        controller: Some(true),
-        block_owner_deletion: Some(true),
+/// Build a headless Service giving StatefulSet pods stable DNS names
+pub fn build_headless_service(remapper: &KafkaPartitionRemapper) -> Service {
+    let name = remapper.resource_name();
+    let namespace = remapper.target_namespace();
+    let spec = &remapper.spec;
+
+    let labels = build_labels(&name);
+
+    Service {
+        metadata: ObjectMeta {
+            name: Some(headless_service_name(&name)),
+            namespace: Some(namespace),
+            labels: Some(deployment_builder::resource_labels(remapper)),
+            annotations: deployment_builder::resource_annotations(remapper, &BTreeMap::new()),
+            owner_references: owner_references(remapper),
+            ..Default::default()
+        },
+        spec: Some(ServiceSpec {
+            cluster_ip: Some("None".to_string()),
+            publish_not_ready_addresses: Some(true),
+            selector: Some(labels),
+            ports: Some(listener_ports(spec).collect()),
+            // Pod DNS records follow the Service's IP families
+            ip_families: ip_families(spec),
+            ip_family_policy: spec.service.ip_family_policy.clone(),
+            ..Default::default()
+        }),
+        ..Default::default()
     }
 }
 
+/// Build the routing Service for one shard
+///
+/// Selects only the StatefulSet pod serving the shard.
+pub fn build_shard_service(remapper: &KafkaPartitionRemapper, index: i32) -> Service {
+    let name = remapper.resource_name();
+    let namespace = remapper.target_namespace();
+    let spec = &remapper.spec;
+
+    let mut labels = deployment_builder::resource_labels(remapper);
+    labels.insert(sharding::SHARD_LABEL.to_string(), index.to_string());
+
+    let mut selector = build_labels(&name);
+    selector.insert(
+        "statefulset.kubernetes.io/pod-name".to_string(),
+        format!("{}-{}", name, index),
+    );
+
+    Service {
+        metadata: ObjectMeta {
+            name: Some(sharding::shard_service_name(&name, index)),
+            namespace: Some(namespace),
+            labels: Some(labels),
+            annotations: deployment_builder::resource_annotations(
+                remapper,
+                &spec.service.annotations,
+            ),
+            owner_references: owner_references(remapper),
+            ..Default::default()
+        },
+        spec: Some(build_service_spec(spec, &selector)),
+        ..Default::default()
+    }
+}
+
+/// Whether a LoadBalancer Service is still waiting for an ingress address
+pub fn load_balancer_pending(service: &Service) -> bool {
+    let is_load_balancer = service
+        .spec
+        .as_ref()
+        .and_then(|s| s.type_.as_deref())
+        .is_some_and(|t| t == "LoadBalancer");
+    let assigned = service
+        .status
+        .as_ref()
+        .and_then(|s| s.load_balancer.as_ref())
+        .and_then(|lb| lb.ingress.as_ref())
+        .and_then(|ingress| ingress.first())
+        .is_some_and(|i| i.ip.is_some() || i.hostname.is_some());
+    is_load_balancer && !assigned
+}
+
 /// Get the service endpoint for advertised address
 pub fn get_service_endpoint(
     service: &Service,
     spec: &KafkaPartitionRemapperSpec,
 ) -> Option<String> {
+    let port = spec.primary_listener().port;
+    match spec.service.external_hostname {
+        Some(ref hostname) => Some(format!("{}:{}", hostname, port)),
+        None => endpoint_for_port(service, port),
+    }
+}
+
+/// Get the endpoint of the external Service's first exposed listener
+pub fn get_external_endpoint(
+    service: &Service,
+    spec: &KafkaPartitionRemapperSpec,
+) -> Option<String> {
+    endpoint_for_port(service, spec.external_listeners().first()?.port)
+}
+
+fn endpoint_for_port(service: &Service, port: i32) -> Option<String> {
     let name = service.metadata.name.as_ref()?;
     let namespace = service.metadata.namespace.as_ref()?;
-    let port = spec.listen.port;
 
     let service_spec = service.spec.as_ref()?;
     let service_type = service_spec.type_.as_deref().unwrap_or("ClusterIP");
@@ -125,15 +418,15 @@ pub fn get_service_endpoint(
                 }
             }
             // Fallback to cluster DNS if LB not ready
-            Some(format!("{}.{}.svc.cluster.local:{}", name, namespace, port))
+            Some(cluster_dns_address(name, namespace, port))
         }
         "NodePort" => {
             // For NodePort, return cluster DNS as we don't know node IPs
-            Some(format!("{}.{}.svc.cluster.local:{}", name, namespace, port))
+            Some(cluster_dns_address(name, namespace, port))
         }
         _ => {
             // ClusterIP - use internal DNS
-            Some(format!("{}.{}.svc.cluster.local:{}", name, namespace, port))
+            Some(cluster_dns_address(name, namespace, port))
         }
     }
 }
@@ -0,0 +1,58 @@
+//! Virtual partition range assignment for sharded proxies
+
+use crate::crd::KafkaPartitionRemapperSpec;
+
+/// Label carrying the shard index on per-shard resources
+pub const SHARD_LABEL: &str = "kafka.oso.sh/shard";
+
+/// Contiguous range of virtual partitions served by one shard
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShardRange {
+    /// Shard index (matches the StatefulSet pod ordinal)
+    pub index: i32,
+    /// First virtual partition (inclusive)
+    pub start: u32,
+    /// Last virtual partition (exclusive)
+    pub end: u32,
+}
+
+/// Split `virtual_partitions` into `shards` contiguous, disjoint ranges
+///
+/// Any remainder is spread over the leading shards so range sizes differ by
+/// at most one.
+pub fn shard_ranges(virtual_partitions: u32, shards: i32) -> Vec<ShardRange> {
+    if shards <= 0 {
+        return Vec::new();
+    }
+
+    let count = shards as u32;
+    let base = virtual_partitions / count;
+    let remainder = virtual_partitions % count;
+
+    let mut start = 0;
+    (0..count)
+        .map(|i| {
+            let size = base + u32::from(i < remainder);
+            let range = ShardRange {
+                index: i as i32,
+                start,
+                end: start + size,
+            };
+            start += size;
+            range
+        })
+        .collect()
+}
+
+/// Shard ranges for the spec, empty unless sharding is enabled
+pub fn shard_ranges_for(spec: &KafkaPartitionRemapperSpec) -> Vec<ShardRange> {
+    if !spec.sharding_enabled() {
+        return Vec::new();
+    }
+    shard_ranges(spec.mapping.virtual_partitions, spec.replicas)
+}
+
+/// Name of the routing Service for a shard
+pub fn shard_service_name(name: &str, index: i32) -> String {
+    format!("{}-shard-{}", name, index)
+}
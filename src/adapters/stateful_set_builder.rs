@@ -0,0 +1,81 @@
+//! Kubernetes StatefulSet builder for proxy pods
+
+use k8s_openapi::api::apps::v1::{StatefulSet, StatefulSetSpec};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+
+use crate::adapters::deployment_builder::{
+    build_labels, build_pod_template, desired_replicas, owner_references, resource_annotations,
+    resource_labels,
+};
+use crate::adapters::service_builder::headless_service_name;
+use crate::crd::KafkaPartitionRemapper;
+
+/// Build a StatefulSet for the proxy
+///
+/// Pods are governed by the headless Service so each one gets a stable DNS
+/// name matching the advertised address rendered into its config file.
+pub fn build_stateful_set(
+    remapper: &KafkaPartitionRemapper,
+    config_map_name: &str,
+    config_hash: &str,
+    secrets_hash: Option<&str>,
+) -> StatefulSet {
+    let name = remapper.resource_name();
+    let namespace = remapper.target_namespace();
+
+    let labels = build_labels(&name);
+
+    StatefulSet {
+        metadata: ObjectMeta {
+            name: Some(name.clone()),
+            namespace: Some(namespace),
+            labels: Some(resource_labels(remapper)),
+            annotations: resource_annotations(remapper, &Default::default()),
+            owner_references: owner_references(remapper),
+            ..Default::default()
+        },
+        spec: Some(StatefulSetSpec {
+            replicas: desired_replicas(remapper),
+            service_name: headless_service_name(&name),
+            // Proxy pods don't depend on each other, so start them together
+            pod_management_policy: Some("Parallel".to_string()),
+            selector: LabelSelector {
+                match_labels: Some(labels),
+                ..Default::default()
+            },
+            template: build_pod_template(remapper, config_map_name, config_hash, secrets_hash),
+            revision_history_limit: remapper
+                .spec
+                .rollout_strategy
+                .as_ref()
+                .and_then(|r| r.revision_history_limit),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Whether every replica of a StatefulSet runs its latest revision and is ready
+pub fn rolled_out(stateful_set: &StatefulSet) -> bool {
+    let desired = stateful_set
+        .spec
+        .as_ref()
+        .and_then(|s| s.replicas)
+        .unwrap_or(1);
+    let Some(ref status) = stateful_set.status else {
+        return false;
+    };
+    status.observed_generation >= stateful_set.metadata.generation
+        && status.updated_replicas.unwrap_or(0) >= desired
+        && status.ready_replicas.unwrap_or(0) >= desired
+        && status.current_revision == status.update_revision
+}
+
+/// Whether a StatefulSet is part-way through rolling out a new revision
+pub fn rollout_in_progress(stateful_set: &StatefulSet) -> bool {
+    let Some(ref status) = stateful_set.status else {
+        return false;
+    };
+    status.observed_generation < stateful_set.metadata.generation
+        || (status.update_revision.is_some() && status.current_revision != status.update_revision)
+}
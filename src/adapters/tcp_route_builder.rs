@@ -0,0 +1,70 @@
+//! Gateway API TCPRoute builder for proxy access
+//!
+//! TCPRoute is not part of the Kubernetes API, so the route is built as a
+//! dynamic object against the experimental Gateway API group.
+
+use kube::api::{ApiResource, DynamicObject, GroupVersionKind};
+use kube::core::ObjectMeta;
+
+use crate::adapters::deployment_builder;
+use crate::crd::KafkaPartitionRemapper;
+
+/// Gateway API group serving TCPRoute
+pub const GATEWAY_API_GROUP: &str = "gateway.networking.k8s.io";
+
+/// API resource for TCPRoute
+pub fn tcp_route_resource() -> ApiResource {
+    ApiResource::from_gvk(&GroupVersionKind::gvk(
+        GATEWAY_API_GROUP,
+        "v1alpha2",
+        "TCPRoute",
+    ))
+}
+
+/// Build a TCPRoute sending the Gateway's traffic to the proxy Service
+///
+/// Returns `None` when no Gateway is configured, it is disabled, or the
+/// routed listener does not exist.
+pub fn build_tcp_route(remapper: &KafkaPartitionRemapper) -> Option<DynamicObject> {
+    let spec = &remapper.spec;
+    let gateway = spec.gateway.as_ref().filter(|g| g.enabled)?;
+    let listener = match gateway.listener {
+        Some(ref name) => spec.listeners.iter().find(|l| &l.name == name)?.clone(),
+        None => spec.primary_listener(),
+    };
+
+    let name = remapper.resource_name();
+    let namespace = remapper.target_namespace();
+
+    let mut parent_ref = serde_json::json!({
+        "group": GATEWAY_API_GROUP,
+        "kind": "Gateway",
+        "name": gateway.parent_ref.name,
+    });
+    if let Some(ref ns) = gateway.parent_ref.namespace {
+        parent_ref["namespace"] = serde_json::json!(ns);
+    }
+    if let Some(ref section) = gateway.parent_ref.section_name {
+        parent_ref["sectionName"] = serde_json::json!(section);
+    }
+    if let Some(port) = gateway.parent_ref.port {
+        parent_ref["port"] = serde_json::json!(port);
+    }
+
+    let mut route = DynamicObject::new(&name, &tcp_route_resource()).within(&namespace);
+    route.metadata = ObjectMeta {
+        labels: Some(deployment_builder::resource_labels(remapper)),
+        annotations: deployment_builder::resource_annotations(remapper, &Default::default()),
+        owner_references: deployment_builder::owner_references(remapper),
+        ..route.metadata
+    };
+    route.data = serde_json::json!({
+        "spec": {
+            "parentRefs": [parent_ref],
+            "rules": [{
+                "backendRefs": [{ "name": name, "port": listener.port }]
+            }]
+        }
+    });
+    Some(route)
+}
@@ -0,0 +1,303 @@
+//! Generated SCRAM users for client authentication
+//!
+//! Every entry of `spec.users` gets a random password stored in an owned
+//! `<name>-user-<user>` Secret that client teams mount directly. The proxy
+//! reads all generated users from a single `<name>-users` Secret holding one
+//! key per username, the same layout as a listener's credentials Secret.
+//!
+//! Users with a rotation schedule get a new password every interval. The
+//! replaced password moves to `<name>-users-previous` and stays accepted for
+//! the overlap window, after which it is retired.
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::Secret;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use k8s_openapi::ByteString;
+use std::collections::BTreeMap;
+
+use crate::adapters::deployment_builder;
+use crate::crd::{
+    CredentialRotationSpec, KafkaPartitionRemapper, ProxyUserSpec, SaslMechanism, UserStatus,
+};
+use crate::{Error, Result};
+
+/// Label naming the user a per-user Secret belongs to
+pub const USER_LABEL: &str = "kafka.oso.sh/user";
+
+/// Annotation overriding the Secret generated users are read from
+///
+/// Set on remap task generations so they accept the remapper's users.
+pub const USERS_SECRET_ANNOTATION: &str = "kafka.oso.sh/users-secret";
+
+/// Annotation recording when a user's current password was issued
+pub const ROTATED_AT_ANNOTATION: &str = "kafka.oso.sh/rotated-at";
+
+/// Annotation recording when a user's previous password is retired
+pub const PREVIOUS_EXPIRES_AT_ANNOTATION: &str = "kafka.oso.sh/previous-password-expires-at";
+
+/// Directory holding the generated users, one file per username
+pub const GENERATED_USERS_DIR: &str = "/etc/kafka-proxy/sasl/generated";
+
+/// Directory holding passwords still accepted during a rotation overlap
+pub const PREVIOUS_USERS_DIR: &str = "/etc/kafka-proxy/sasl/generated-previous";
+
+/// Mechanisms generated credentials can be issued for
+pub const USER_MECHANISMS: [SaslMechanism; 2] =
+    [SaslMechanism::ScramSha256, SaslMechanism::ScramSha512];
+
+const PASSWORD_BYTES: usize = 24;
+
+/// Name of the Secret holding one generated user's credentials
+pub fn user_secret_name(name: &str, user: &str) -> String {
+    format!("{}-user-{}", name, user)
+}
+
+/// Name of the Secret the proxy reads generated users from
+pub fn users_secret_name(remapper: &KafkaPartitionRemapper) -> String {
+    remapper
+        .metadata
+        .annotations
+        .as_ref()
+        .and_then(|a| a.get(USERS_SECRET_ANNOTATION))
+        .cloned()
+        .unwrap_or_else(|| format!("{}-users", remapper.resource_name()))
+}
+
+/// Name of the Secret holding passwords accepted during a rotation overlap
+pub fn previous_users_secret_name(remapper: &KafkaPartitionRemapper) -> String {
+    format!("{}-previous", users_secret_name(remapper))
+}
+
+/// Credentials held in a user's Secret
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserCredentials {
+    /// Current password
+    pub password: String,
+    /// Time the current password was issued
+    pub rotated_at: DateTime<Utc>,
+    /// Replaced password still accepted until `previous_expires_at`
+    pub previous_password: Option<String>,
+    /// Time the previous password is retired
+    pub previous_expires_at: Option<DateTime<Utc>>,
+}
+
+impl UserCredentials {
+    /// Freshly generated credentials issued at `now`
+    pub fn generate(now: DateTime<Utc>) -> Result<Self> {
+        Ok(Self {
+            password: generate_password()?,
+            rotated_at: now,
+            previous_password: None,
+            previous_expires_at: None,
+        })
+    }
+
+    /// Read the credentials from a user's Secret
+    ///
+    /// Secrets without a rotation timestamp count from their creation.
+    pub fn from_secret(secret: &Secret) -> Option<Self> {
+        let data = secret.data.as_ref()?;
+        let read = |key: &str| {
+            data.get(key)
+                .and_then(|v| String::from_utf8(v.0.clone()).ok())
+                .filter(|v| !v.is_empty())
+        };
+        let annotation = |key: &str| {
+            secret
+                .metadata
+                .annotations
+                .as_ref()
+                .and_then(|a| a.get(key))
+                .and_then(|v| DateTime::parse_from_rfc3339(v).ok())
+                .map(|t| t.with_timezone(&Utc))
+        };
+
+        let rotated_at = annotation(ROTATED_AT_ANNOTATION)
+            .or_else(|| secret.metadata.creation_timestamp.as_ref().map(|t| t.0))?;
+        let previous_expires_at = annotation(PREVIOUS_EXPIRES_AT_ANNOTATION);
+        Some(Self {
+            password: read("password")?,
+            rotated_at,
+            previous_password: read("previous-password").filter(|_| previous_expires_at.is_some()),
+            previous_expires_at,
+        })
+    }
+
+    /// Time the password is next due for rotation
+    pub fn next_rotation_at(
+        &self,
+        rotation: Option<&CredentialRotationSpec>,
+    ) -> Option<DateTime<Utc>> {
+        rotation.map(|r| self.rotated_at + chrono::Duration::days(r.interval_days.into()))
+    }
+}
+
+/// Advance a user's credentials to `now`
+///
+/// Generates a password for new users, rotates it once the interval has
+/// passed and retires the previous password when its overlap ends or the
+/// schedule is removed. Returns the credentials and whether they rotated.
+pub fn next_credentials(
+    existing: Option<UserCredentials>,
+    rotation: Option<&CredentialRotationSpec>,
+    now: DateTime<Utc>,
+) -> Result<(UserCredentials, bool)> {
+    let Some(mut credentials) = existing else {
+        return Ok((UserCredentials::generate(now)?, false));
+    };
+
+    let expired = credentials.previous_expires_at.is_some_and(|t| t <= now);
+    if expired || rotation.is_none() {
+        credentials.previous_password = None;
+        credentials.previous_expires_at = None;
+    }
+
+    let Some(rotation) = rotation else {
+        return Ok((credentials, false));
+    };
+    if credentials
+        .next_rotation_at(Some(rotation))
+        .is_some_and(|t| t > now)
+    {
+        return Ok((credentials, false));
+    }
+
+    let overlap = chrono::Duration::hours(rotation.overlap_hours.into());
+    let (previous_password, previous_expires_at) = if overlap.is_zero() {
+        (None, None)
+    } else {
+        (Some(credentials.password), Some(now + overlap))
+    };
+    Ok((
+        UserCredentials {
+            previous_password,
+            previous_expires_at,
+            ..UserCredentials::generate(now)?
+        },
+        true,
+    ))
+}
+
+/// Report a user's credential state
+pub fn user_status(
+    remapper: &KafkaPartitionRemapper,
+    user: &ProxyUserSpec,
+    credentials: &UserCredentials,
+) -> UserStatus {
+    UserStatus {
+        name: user.name.clone(),
+        secret_name: user_secret_name(&remapper.resource_name(), &user.name),
+        rotated_at: credentials.rotated_at,
+        next_rotation_at: credentials.next_rotation_at(user.rotation.as_ref()),
+        previous_password_expires_at: credentials.previous_expires_at,
+    }
+}
+
+/// Time until the next rotation or retirement of a previous password
+///
+/// Returns `None` when no user has anything scheduled.
+pub fn recheck_after(users: &[UserStatus], now: DateTime<Utc>) -> Option<std::time::Duration> {
+    users
+        .iter()
+        .flat_map(|u| [u.next_rotation_at, u.previous_password_expires_at])
+        .flatten()
+        .map(|t| t.max(now))
+        .min()
+        .and_then(|t| (t - now).to_std().ok())
+}
+
+/// Generate a random password of 48 hex characters
+pub fn generate_password() -> Result<String> {
+    let mut bytes = [0u8; PASSWORD_BYTES];
+    getrandom::getrandom(&mut bytes)
+        .map_err(|e| Error::SecretError(format!("Failed to generate password: {}", e)))?;
+    Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// Build the Secret handed to clients for one generated user
+///
+/// Besides the raw credentials it carries a ready-made `sasl.jaas.config`
+/// for Java clients. During a rotation overlap the replaced password is
+/// kept under `previous-password`.
+pub fn build_user_secret(
+    remapper: &KafkaPartitionRemapper,
+    user: &ProxyUserSpec,
+    credentials: &UserCredentials,
+) -> Secret {
+    let password = credentials.password.as_str();
+    let name = remapper.resource_name();
+    let mut labels = deployment_builder::resource_labels(remapper);
+    labels.insert(USER_LABEL.to_string(), user.name.clone());
+
+    let jaas_config = format!(
+        "org.apache.kafka.common.security.scram.ScramLoginModule required username=\"{}\" password=\"{}\";",
+        user.name, password
+    );
+    let data = [
+        ("username", Some(user.name.as_str())),
+        ("password", Some(password)),
+        ("mechanism", Some(user.mechanism.as_str())),
+        ("sasl.jaas.config", Some(jaas_config.as_str())),
+        (
+            "previous-password",
+            credentials.previous_password.as_deref(),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(k, v)| Some((k.to_string(), ByteString(v?.as_bytes().to_vec()))))
+    .collect();
+
+    let mut annotations = BTreeMap::from([(
+        ROTATED_AT_ANNOTATION.to_string(),
+        credentials.rotated_at.to_rfc3339(),
+    )]);
+    if let Some(expires_at) = credentials.previous_expires_at {
+        annotations.insert(
+            PREVIOUS_EXPIRES_AT_ANNOTATION.to_string(),
+            expires_at.to_rfc3339(),
+        );
+    }
+
+    Secret {
+        metadata: ObjectMeta {
+            name: Some(user_secret_name(&name, &user.name)),
+            namespace: Some(remapper.target_namespace()),
+            labels: Some(labels),
+            annotations: deployment_builder::resource_annotations(remapper, &annotations),
+            owner_references: deployment_builder::owner_references(remapper),
+            ..Default::default()
+        },
+        data: Some(data),
+        type_: Some("Opaque".to_string()),
+        ..Default::default()
+    }
+}
+
+/// Build a Secret mounted into the proxy, keyed by username
+///
+/// `secret_name` is either [`users_secret_name`] for current passwords or
+/// [`previous_users_secret_name`] for passwords in their overlap window.
+pub fn build_users_secret(
+    remapper: &KafkaPartitionRemapper,
+    secret_name: &str,
+    passwords: &BTreeMap<String, String>,
+) -> Secret {
+    Secret {
+        metadata: ObjectMeta {
+            name: Some(secret_name.to_string()),
+            namespace: Some(remapper.target_namespace()),
+            labels: Some(deployment_builder::resource_labels(remapper)),
+            annotations: deployment_builder::resource_annotations(remapper, &BTreeMap::new()),
+            owner_references: deployment_builder::owner_references(remapper),
+            ..Default::default()
+        },
+        data: Some(
+            passwords
+                .iter()
+                .map(|(user, password)| (user.clone(), ByteString(password.as_bytes().to_vec())))
+                .collect(),
+        ),
+        type_: Some("Opaque".to_string()),
+        ..Default::default()
+    }
+}
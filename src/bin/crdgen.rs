@@ -4,12 +4,128 @@
 //! defined by the kafka-partition-remapper-operator.
 //!
 //! Usage: cargo run --bin crdgen > deploy/crds/all.yaml
+//!
+//! `--json` prints a JSON List instead, and `--split <dir>` writes one file
+//! per CRD named after it:
+//! cargo run --bin crdgen -- crds [--json] [--split <dir>]
+//!
+//! Webhook configurations can be generated with:
+//! cargo run --bin crdgen -- webhooks [--namespace <ns>] [--service <name>]
+//!
+//! The full install bundle (CRDs, RBAC, operator Deployment and, with
+//! `--webhooks`, the webhook Service and configurations) with:
+//! cargo run --bin crdgen -- bundle [--namespace <ns>] [--image <image>] [--webhooks]
+//!
+//! An OLM bundle for OperatorHub is written to a directory with:
+//! cargo run --bin crdgen -- olm [--output <dir>] [--image <image>] [--channel <channel>]
+
+use kafka_partition_remapper_operator::bundle::{generate_bundle, BundleOptions};
+use kafka_partition_remapper_operator::crd::{crds, generate_crds, generate_crds_json};
+use kafka_partition_remapper_operator::olm::{generate_olm_bundle, DEFAULT_CHANNEL};
+use kafka_partition_remapper_operator::webhooks::configuration::generate_webhook_configurations;
 
-use kafka_partition_remapper_operator::crd::generate_crds;
+const DEFAULT_NAMESPACE: &str = "kafka-partition-remapper-system";
+const DEFAULT_SERVICE: &str = "kafka-partition-remapper-operator-webhook";
 
 fn main() {
-    for crd in generate_crds() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let manifests = match args.first().map(String::as_str) {
+        Some("webhooks") => {
+            let namespace = flag_value(&args, "--namespace").unwrap_or(DEFAULT_NAMESPACE);
+            let service = flag_value(&args, "--service").unwrap_or(DEFAULT_SERVICE);
+            generate_webhook_configurations(service, namespace)
+        }
+        Some("bundle") => {
+            let defaults = BundleOptions::default();
+            let options = BundleOptions {
+                namespace: flag_value(&args, "--namespace")
+                    .map_or(defaults.namespace, str::to_string),
+                image: flag_value(&args, "--image").map_or(defaults.image, str::to_string),
+                webhooks: args.iter().any(|a| a == "--webhooks"),
+            };
+            generate_bundle(&options).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            })
+        }
+        Some("olm") => {
+            let image =
+                flag_value(&args, "--image").map_or(BundleOptions::default().image, str::to_string);
+            let channel = flag_value(&args, "--channel").unwrap_or(DEFAULT_CHANNEL);
+            let output = std::path::Path::new(flag_value(&args, "--output").unwrap_or("bundle"));
+            let files = generate_olm_bundle(&image, channel).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            });
+            for (path, content) in files {
+                let path = output.join(path);
+                let written = path
+                    .parent()
+                    .map_or(Ok(()), std::fs::create_dir_all)
+                    .and_then(|_| std::fs::write(&path, content));
+                if let Err(e) = written {
+                    eprintln!("Failed to write {}: {}", path.display(), e);
+                    std::process::exit(1);
+                }
+                eprintln!("Wrote {}", path.display());
+            }
+            return;
+        }
+        None | Some("crds") | Some("--json") | Some("--split") => {
+            let json = args.iter().any(|a| a == "--json");
+            if let Some(dir) = flag_value(&args, "--split") {
+                split_crds(std::path::Path::new(dir), json);
+                return;
+            }
+            if json {
+                let list = serde_json::json!({
+                    "apiVersion": "v1",
+                    "kind": "List",
+                    "items": crds(),
+                });
+                println!("{}", serde_json::to_string_pretty(&list).unwrap());
+                return;
+            }
+            generate_crds()
+        }
+        Some(other) => {
+            eprintln!(
+                "Unknown command '{}', expected 'crds', 'webhooks', 'bundle' or 'olm'",
+                other
+            );
+            std::process::exit(1);
+        }
+    };
+
+    for manifest in manifests {
         println!("---");
-        print!("{}", crd);
+        print!("{}", manifest);
+    }
+}
+
+/// Write each CRD to `<dir>/<crd name>.yaml`, or `.json`
+fn split_crds(dir: &std::path::Path, json: bool) {
+    let (manifests, extension) = if json {
+        (generate_crds_json(), "json")
+    } else {
+        (generate_crds(), "yaml")
+    };
+    for (crd, manifest) in crds().iter().zip(manifests) {
+        let name = crd.metadata.name.as_deref().unwrap_or_default();
+        let path = dir.join(format!("{}.{}", name, extension));
+        let written = std::fs::create_dir_all(dir).and_then(|_| std::fs::write(&path, manifest));
+        if let Err(e) = written {
+            eprintln!("Failed to write {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+        eprintln!("Wrote {}", path.display());
     }
 }
+
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str)
+}
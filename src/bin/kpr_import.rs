@@ -0,0 +1,62 @@
+//! Proxy configuration importer
+//!
+//! Reads the `config.yaml` of a hand-managed proxy and prints an equivalent
+//! KafkaPartitionRemapper manifest, so the proxy can be moved onto the
+//! operator. Settings that must be configured by hand are reported on
+//! standard error.
+//!
+//! Usage: kpr-import [--name <name>] [--namespace <ns>] [FILE]   (`-` or no
+//! file reads standard input)
+
+use kafka_partition_remapper_operator::import::import_proxy_config;
+
+const DEFAULT_NAME: &str = "kafka-partition-remapper";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let name = flag_value(&args, "--name").unwrap_or(DEFAULT_NAME);
+    let namespace = flag_value(&args, "--namespace");
+    let path = positional(&args).unwrap_or("-");
+
+    let contents = if path == "-" {
+        std::io::read_to_string(std::io::stdin())
+    } else {
+        std::fs::read_to_string(path)
+    };
+    let contents = contents.unwrap_or_else(|e| {
+        eprintln!("Failed to read {}: {}", path, e);
+        std::process::exit(1);
+    });
+
+    let import = import_proxy_config(&contents, name, namespace).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+    for warning in &import.warnings {
+        eprintln!("warning: {}", warning);
+    }
+    print!(
+        "{}",
+        serde_yaml::to_string(&import.manifest).expect("manifest serializes")
+    );
+}
+
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str)
+}
+
+/// First argument that is neither a flag nor a flag's value
+fn positional(args: &[String]) -> Option<&str> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg.starts_with("--") {
+            args.next();
+        } else {
+            return Some(arg);
+        }
+    }
+    None
+}
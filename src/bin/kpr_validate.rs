@@ -0,0 +1,86 @@
+//! Offline KafkaPartitionRemapper validation
+//!
+//! Runs the operator's validation against manifests without a cluster and
+//! prints one JSON result per remapper, so pipelines can gate merges on it.
+//! Documents of other kinds are skipped. Exits non-zero when any remapper is
+//! invalid or a file cannot be read.
+//!
+//! Usage: kpr-validate [FILE...]   (no files or `-` reads standard input)
+
+use kafka_partition_remapper_operator::crd::KafkaPartitionRemapper;
+use kafka_partition_remapper_operator::reconcilers::remapper;
+use serde::Deserialize;
+use serde_json::json;
+
+fn main() {
+    let mut paths: Vec<String> = std::env::args().skip(1).collect();
+    if paths.is_empty() {
+        paths.push("-".to_string());
+    }
+
+    let mut results = Vec::new();
+    for path in &paths {
+        let contents = if path == "-" {
+            std::io::read_to_string(std::io::stdin())
+        } else {
+            std::fs::read_to_string(path)
+        };
+        match contents {
+            Ok(contents) => results.extend(validate_documents(path, &contents)),
+            Err(e) => results.push(json!({
+                "file": path,
+                "valid": false,
+                "error": format!("Failed to read file: {}", e),
+            })),
+        }
+    }
+
+    let valid = results.iter().all(|r| r["valid"] == true);
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&json!({ "valid": valid, "results": results }))
+            .expect("results serialize")
+    );
+    if !valid {
+        std::process::exit(1);
+    }
+}
+
+/// Validate every KafkaPartitionRemapper document in a YAML stream
+fn validate_documents(file: &str, contents: &str) -> Vec<serde_json::Value> {
+    let mut results = Vec::new();
+    for (index, document) in serde_yaml::Deserializer::from_str(contents).enumerate() {
+        let value = match serde_yaml::Value::deserialize(document) {
+            Ok(value) => value,
+            Err(e) => {
+                results.push(json!({
+                    "file": file,
+                    "document": index,
+                    "valid": false,
+                    "error": format!("Invalid YAML: {}", e),
+                }));
+                // The stream cannot be resumed after a syntax error
+                break;
+            }
+        };
+        if value["kind"].as_str() != Some("KafkaPartitionRemapper") {
+            continue;
+        }
+
+        let name = value["metadata"]["name"].as_str().map(str::to_string);
+        let namespace = value["metadata"]["namespace"].as_str().map(str::to_string);
+        let error = match serde_yaml::from_value::<KafkaPartitionRemapper>(value) {
+            Ok(remapper) => remapper::validate(&remapper).err().map(|e| e.to_string()),
+            Err(e) => Some(format!("Invalid spec: {}", e)),
+        };
+        results.push(json!({
+            "file": file,
+            "document": index,
+            "name": name,
+            "namespace": namespace,
+            "valid": error.is_none(),
+            "error": error,
+        }));
+    }
+    results
+}
@@ -0,0 +1,318 @@
+//! kubectl plugin for KafkaPartitionRemapper status and diagnostics
+//!
+//! Installed on the PATH as `kubectl-kpr`, it is invoked as `kubectl kpr`.
+//!
+//! Usage: kubectl kpr <command> <name> [-n <namespace>]
+//!
+//! Commands:
+//!   status       Phase, replicas, conditions and proxy pod health
+//!   topics       Effective partition mapping and topic overrides
+//!   endpoints    Client, external, metrics and shard endpoints
+//!   config show  Proxy configuration rendered into the ConfigMap
+//!   restart      Roll the proxy pods by stamping the restartedAt annotation
+//!   simulate     Physical partition and offset of a virtual position:
+//!                simulate <name> <topic> <partition> <offset>, or with
+//!                `-f <manifest>` in place of the name to work offline
+
+use anyhow::{bail, Context as _};
+use k8s_openapi::api::core::v1::{ConfigMap, Pod};
+use kube::api::{ListParams, Patch, PatchParams};
+use kube::{Api, Client};
+
+use kafka_partition_remapper_operator::crd::{
+    KafkaPartitionRemapper, MappingSpec, RESTARTED_AT_ANNOTATION,
+};
+use kafka_partition_remapper_operator::mapping;
+
+const USAGE: &str =
+    "Usage: kubectl kpr <status|topics|endpoints|config show|restart> <name> [-n <namespace>]
+       kubectl kpr simulate <name|-f manifest> <topic> <partition> <offset> [-n <namespace>]";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let namespace = take_flag(&mut args, &["-n", "--namespace"]);
+    let manifest = take_flag(&mut args, &["-f", "--filename"]);
+
+    // Simulation from a manifest needs no cluster access
+    if let (Some(path), [simulate, position @ ..]) = (&manifest, args.as_slice()) {
+        if simulate != "simulate" {
+            bail!(USAGE);
+        }
+        let manifest = if path == "-" {
+            std::io::read_to_string(std::io::stdin())?
+        } else {
+            std::fs::read_to_string(path)?
+        };
+        let remapper: KafkaPartitionRemapper = serde_yaml::from_str(&manifest)?;
+        return simulate_position(&remapper.spec.mapping, position);
+    }
+
+    let (command, name) = match args.as_slice() {
+        [simulate, name, ..] if simulate == "simulate" => ("simulate", name.clone()),
+        [command, name] if command != "config" => (command.as_str(), name.clone()),
+        [config, show, name] if config == "config" && show == "show" => {
+            ("config show", name.clone())
+        }
+        _ => bail!(USAGE),
+    };
+
+    let client = Client::try_default().await?;
+    let namespace = namespace.unwrap_or_else(|| client.default_namespace().to_string());
+    let remappers: Api<KafkaPartitionRemapper> = Api::namespaced(client.clone(), &namespace);
+    let remapper = remappers
+        .get(&name)
+        .await
+        .with_context(|| format!("KafkaPartitionRemapper {}/{} not found", namespace, name))?;
+
+    match command {
+        "status" => status(&client, &remapper).await,
+        "topics" => {
+            topics(&remapper);
+            Ok(())
+        }
+        "endpoints" => {
+            endpoints(&remapper);
+            Ok(())
+        }
+        "config show" => config_show(&client, &remapper).await,
+        "restart" => restart(&remappers, &name).await,
+        "simulate" => simulate_position(applied_mapping(&remapper), &args[2..]),
+        other => bail!("Unknown command '{}'\n{}", other, USAGE),
+    }
+}
+
+/// Request a rolling restart of the proxy pods
+async fn restart(remappers: &Api<KafkaPartitionRemapper>, name: &str) -> anyhow::Result<()> {
+    let restarted_at = chrono::Utc::now().to_rfc3339();
+    let patch = serde_json::json!({
+        "metadata": { "annotations": { RESTARTED_AT_ANNOTATION: restarted_at } }
+    });
+    remappers
+        .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .with_context(|| format!("Failed to restart {}", name))?;
+    println!(
+        "KafkaPartitionRemapper {} restarted at {}",
+        name, restarted_at
+    );
+    Ok(())
+}
+
+/// Remove a flag and its value from the arguments
+fn take_flag(args: &mut Vec<String>, flags: &[&str]) -> Option<String> {
+    let i = args.iter().position(|a| flags.contains(&a.as_str()))?;
+    args.remove(i);
+    (i < args.len()).then(|| args.remove(i))
+}
+
+fn or_dash<T: ToString>(value: Option<T>) -> String {
+    value
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+async fn status(client: &Client, remapper: &KafkaPartitionRemapper) -> anyhow::Result<()> {
+    let status = remapper.status.clone().unwrap_or_default();
+    println!("Phase:      {}", or_dash(status.phase.as_deref()));
+    println!("Message:    {}", or_dash(status.message.as_deref()));
+    println!(
+        "Replicas:   {} ready / {} updated / {} desired",
+        status.ready_replicas.unwrap_or(0),
+        status.updated_replicas.unwrap_or(0),
+        remapper.spec.replicas
+    );
+    let workload = status
+        .stateful_set_name
+        .as_ref()
+        .map(|n| format!("StatefulSet/{}", n))
+        .or_else(|| {
+            status
+                .deployment_name
+                .as_ref()
+                .map(|n| format!("Deployment/{}", n))
+        });
+    println!("Workload:   {}", or_dash(workload));
+    println!(
+        "Generation: {} observed of {}",
+        or_dash(status.observed_generation),
+        or_dash(remapper.metadata.generation)
+    );
+
+    println!();
+    println!(
+        "{:<24} {:<8} {:<28} MESSAGE",
+        "CONDITION", "STATUS", "REASON"
+    );
+    for condition in &status.conditions {
+        println!(
+            "{:<24} {:<8} {:<28} {}",
+            condition.type_,
+            condition.status,
+            or_dash(condition.reason.as_deref()),
+            or_dash(condition.message.as_deref())
+        );
+    }
+
+    // Proxy pods live in the target namespace under the recorded selector
+    let Some(selector) = status.selector else {
+        return Ok(());
+    };
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &remapper.target_namespace());
+    let pods = pods.list(&ListParams::default().labels(&selector)).await?;
+    println!();
+    println!("{:<40} {:<10} {:<6} RESTARTS", "POD", "PHASE", "READY");
+    for pod in pods {
+        let pod_status = pod.status.unwrap_or_default();
+        let containers = pod_status.container_statuses.unwrap_or_default();
+        let ready = containers.iter().filter(|c| c.ready).count();
+        let restarts: i32 = containers.iter().map(|c| c.restart_count).sum();
+        println!(
+            "{:<40} {:<10} {:<6} {}",
+            or_dash(pod.metadata.name),
+            or_dash(pod_status.phase),
+            format!("{}/{}", ready, containers.len()),
+            restarts
+        );
+    }
+    Ok(())
+}
+
+/// Mapping the proxy runs with
+///
+/// Topic override resources are only merged into the applied mapping.
+fn applied_mapping(remapper: &KafkaPartitionRemapper) -> &MappingSpec {
+    remapper
+        .status
+        .as_ref()
+        .and_then(|s| s.applied_mapping.as_ref())
+        .unwrap_or(&remapper.spec.mapping)
+}
+
+fn topics(remapper: &KafkaPartitionRemapper) {
+    let status = remapper.status.as_ref();
+    let mapping = applied_mapping(remapper);
+
+    println!(
+        "Default: {} virtual -> {} physical partitions, offset range {}",
+        mapping.virtual_partitions, mapping.physical_partitions, mapping.offset_range
+    );
+    if let Some(condition) =
+        status.and_then(|s| s.conditions.iter().find(|c| c.type_ == "TopicsConsistent"))
+    {
+        println!(
+            "Topics consistent: {} ({})",
+            condition.status,
+            or_dash(condition.message.as_deref())
+        );
+    }
+    if mapping.topics.is_empty() {
+        return;
+    }
+
+    println!();
+    println!(
+        "{:<40} {:<8} {:<8} OFFSET RANGE",
+        "TOPIC", "VIRTUAL", "PHYSICAL"
+    );
+    for topic in &mapping.topics {
+        println!(
+            "{:<40} {:<8} {:<8} {}",
+            topic.topic,
+            topic
+                .virtual_partitions
+                .unwrap_or(mapping.virtual_partitions),
+            topic
+                .physical_partitions
+                .unwrap_or(mapping.physical_partitions),
+            topic.offset_range.unwrap_or(mapping.offset_range)
+        );
+    }
+}
+
+fn endpoints(remapper: &KafkaPartitionRemapper) {
+    let status = remapper.status.clone().unwrap_or_default();
+    println!("Service:  {}", or_dash(status.service_endpoint));
+    println!("External: {}", or_dash(status.external_endpoint));
+    println!("Metrics:  {}", or_dash(status.metrics_endpoint));
+
+    println!();
+    println!("{:<20} {:<6} ADVERTISED", "LISTENER", "PORT");
+    for listener in &remapper.spec.listeners {
+        println!(
+            "{:<20} {:<6} {}",
+            listener.name,
+            listener.port,
+            or_dash(listener.advertised_address.as_deref())
+        );
+    }
+
+    if !status.shards.is_empty() {
+        println!();
+        println!("{:<6} {:<40} PARTITIONS", "SHARD", "SERVICE");
+        for shard in &status.shards {
+            println!(
+                "{:<6} {:<40} {}-{}",
+                shard.index,
+                shard.service_name,
+                shard.virtual_partition_start,
+                shard.virtual_partition_end
+            );
+        }
+    }
+}
+
+async fn config_show(client: &Client, remapper: &KafkaPartitionRemapper) -> anyhow::Result<()> {
+    let Some(name) = remapper
+        .status
+        .as_ref()
+        .and_then(|s| s.config_map_name.clone())
+    else {
+        bail!("The remapper has not rendered a ConfigMap yet");
+    };
+    let config_maps: Api<ConfigMap> = Api::namespaced(client.clone(), &remapper.target_namespace());
+    let config_map = config_maps.get(&name).await?;
+
+    for (key, value) in config_map.data.unwrap_or_default() {
+        println!("# {}", key);
+        print!("{}", value);
+        if !value.ends_with('\n') {
+            println!();
+        }
+    }
+    Ok(())
+}
+
+/// Print where a `<topic> <partition> <offset>` position lands upstream
+fn simulate_position(spec: &MappingSpec, position: &[String]) -> anyhow::Result<()> {
+    let [topic, partition, offset] = position else {
+        bail!(USAGE);
+    };
+    let partition = partition
+        .parse()
+        .with_context(|| format!("Invalid partition '{}'", partition))?;
+    let offset = offset
+        .parse()
+        .with_context(|| format!("Invalid offset '{}'", offset))?;
+    let simulation = mapping::simulate(spec, topic, partition, offset)?;
+
+    println!(
+        "Mapping:  {} virtual -> {} physical partitions, offset range {}{}",
+        simulation.virtual_partitions,
+        simulation.physical_partitions,
+        simulation.offset_range,
+        simulation
+            .matched_override
+            .map(|t| format!(" (override '{}')", t))
+            .unwrap_or_default()
+    );
+    println!(
+        "Virtual:  {} partition {} offset {}",
+        simulation.topic, simulation.virtual_partition, simulation.virtual_offset
+    );
+    println!(
+        "Physical: {} partition {} offset {}",
+        simulation.physical_topic, simulation.physical_partition, simulation.physical_offset
+    );
+    Ok(())
+}
@@ -0,0 +1,405 @@
+//! Install bundle manifests
+//!
+//! Renders everything needed to run the operator: CRDs, the operator
+//! Namespace, ServiceAccount, ClusterRole and binding, Deployment and
+//! metrics Service, and optionally the admission webhook Service and
+//! configurations. [`operator_rules`] is the single list of permissions the
+//! controllers use, and the Helm chart's ClusterRole is checked against it.
+
+use k8s_openapi::api::apps::v1::{Deployment, DeploymentSpec};
+use k8s_openapi::api::core::v1::{
+    Capabilities, Container, ContainerPort, EmptyDirVolumeSource, EnvVar, EnvVarSource,
+    HTTPGetAction, Namespace, ObjectFieldSelector, PodSecurityContext, PodSpec, PodTemplateSpec,
+    Probe, ResourceRequirements, SecretVolumeSource, SecurityContext, Service, ServiceAccount,
+    ServicePort, ServiceSpec, Volume, VolumeMount,
+};
+use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding, PolicyRule, RoleRef, Subject};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::adapters::consistency_audit_builder::OPERATOR_IMAGE_ENV;
+use crate::crd::generate_crds;
+use crate::webhooks::configuration::{
+    build_mutating_webhook_configuration, build_validating_webhook_configuration,
+};
+use crate::webhooks::server::{DEFAULT_CERT_DIR, DEFAULT_WEBHOOK_PORT};
+use crate::{Error, Result};
+
+/// Name of the operator's own resources
+pub const OPERATOR_NAME: &str = "kafka-partition-remapper-operator";
+
+/// Namespace the bundle installs into unless told otherwise
+pub const DEFAULT_NAMESPACE: &str = "kafka-partition-remapper-system";
+
+/// Port of the metrics and health endpoints
+pub const METRICS_PORT: i32 = 8080;
+
+/// What the install bundle contains
+#[derive(Clone, Debug)]
+pub struct BundleOptions {
+    /// Namespace of the operator
+    pub namespace: String,
+    /// Operator image
+    pub image: String,
+    /// Include the admission webhooks; their TLS Secret must be provisioned
+    /// separately, for example by cert-manager
+    pub webhooks: bool,
+}
+
+impl Default for BundleOptions {
+    fn default() -> Self {
+        Self {
+            namespace: DEFAULT_NAMESPACE.to_string(),
+            image: format!(
+                "ghcr.io/osodevops/kafka-partition-remapper-operator:{}",
+                env!("CARGO_PKG_VERSION")
+            ),
+            webhooks: false,
+        }
+    }
+}
+
+/// Permissions the controllers need, and nothing more
+///
+/// Children are written with server-side apply, which needs `create` as well
+/// as `patch`, and removed with `delete`; no code path uses `update` outside
+/// of the finalizers subresource.
+pub fn operator_rules() -> Vec<PolicyRule> {
+    const MANAGE: &[&str] = &["get", "list", "watch", "create", "patch", "delete"];
+    const READ: &[&str] = &["get", "list", "watch"];
+
+    let rule = |groups: &[&str], resources: &[&str], verbs: &[&str]| PolicyRule {
+        api_groups: Some(groups.iter().map(|g| g.to_string()).collect()),
+        resources: Some(resources.iter().map(|r| r.to_string()).collect()),
+        verbs: verbs.iter().map(|v| v.to_string()).collect(),
+        ..Default::default()
+    };
+
+    vec![
+        // Kafka credentials, generated user credentials and proxy config
+        rule(&[""], &["secrets", "configmaps", "services"], MANAGE),
+        // Remap task and canary tracking, and hot reload markers
+        rule(&[""], &["pods"], &["get", "list", "watch", "patch"]),
+        // Consistency audit identity, applied and cleaned up but not watched
+        rule(
+            &[""],
+            &["serviceaccounts"],
+            &["get", "list", "create", "patch", "delete"],
+        ),
+        rule(
+            &["rbac.authorization.k8s.io"],
+            &["roles", "rolebindings"],
+            &["get", "list", "create", "patch", "delete"],
+        ),
+        rule(&["", "events.k8s.io"], &["events"], &["create", "patch"]),
+        rule(&["apps"], &["deployments", "statefulsets"], MANAGE),
+        // Replicas restored on resume go through the scale subresource
+        rule(
+            &["apps"],
+            &["deployments/scale", "statefulsets/scale"],
+            &["patch"],
+        ),
+        rule(&["policy"], &["poddisruptionbudgets"], MANAGE),
+        rule(&["gateway.networking.k8s.io"], &["tcproutes"], MANAGE),
+        rule(&["autoscaling"], &["horizontalpodautoscalers"], MANAGE),
+        rule(&["keda.sh"], &["scaledobjects"], MANAGE),
+        rule(&["batch"], &["cronjobs"], MANAGE),
+        rule(
+            &["kafka.oso.sh"],
+            &["kafkapartitionremappers", "kafkapartitionremaptasks"],
+            &["get", "list", "watch", "patch"],
+        ),
+        rule(
+            &["kafka.oso.sh"],
+            &[
+                "kafkapartitionremappers/status",
+                "kafkapartitionremaptasks/status",
+            ],
+            &["get", "patch"],
+        ),
+        rule(
+            &["kafka.oso.sh"],
+            &[
+                "kafkapartitionremappers/finalizers",
+                "kafkapartitionremaptasks/finalizers",
+            ],
+            &["update"],
+        ),
+        rule(
+            &["kafka.oso.sh"],
+            &["kafkaclusterrefs", "kafkaremappertopicoverrides"],
+            READ,
+        ),
+    ]
+}
+
+fn labels() -> BTreeMap<String, String> {
+    BTreeMap::from([
+        (
+            "app.kubernetes.io/name".to_string(),
+            OPERATOR_NAME.to_string(),
+        ),
+        (
+            "app.kubernetes.io/component".to_string(),
+            "controller".to_string(),
+        ),
+    ])
+}
+
+fn metadata(name: &str, namespace: Option<&str>) -> ObjectMeta {
+    ObjectMeta {
+        name: Some(name.to_string()),
+        namespace: namespace.map(str::to_string),
+        labels: Some(labels()),
+        ..Default::default()
+    }
+}
+
+fn webhook_service_name() -> String {
+    format!("{}-webhook", OPERATOR_NAME)
+}
+
+/// Build the ClusterRole granting [`operator_rules`]
+pub fn build_cluster_role() -> ClusterRole {
+    ClusterRole {
+        metadata: metadata(OPERATOR_NAME, None),
+        rules: Some(operator_rules()),
+        ..Default::default()
+    }
+}
+
+/// Build the binding of the ClusterRole to the operator ServiceAccount
+pub fn build_cluster_role_binding(options: &BundleOptions) -> ClusterRoleBinding {
+    ClusterRoleBinding {
+        metadata: metadata(OPERATOR_NAME, None),
+        role_ref: RoleRef {
+            api_group: "rbac.authorization.k8s.io".to_string(),
+            kind: "ClusterRole".to_string(),
+            name: OPERATOR_NAME.to_string(),
+        },
+        subjects: Some(vec![Subject {
+            kind: "ServiceAccount".to_string(),
+            name: OPERATOR_NAME.to_string(),
+            namespace: Some(options.namespace.clone()),
+            ..Default::default()
+        }]),
+    }
+}
+
+/// Build the operator Deployment
+pub fn build_operator_deployment(options: &BundleOptions) -> Deployment {
+    let field_env = |name: &str, path: &str| EnvVar {
+        name: name.to_string(),
+        value_from: Some(EnvVarSource {
+            field_ref: Some(ObjectFieldSelector {
+                field_path: path.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let value_env = |name: &str, value: &str| EnvVar {
+        name: name.to_string(),
+        value: Some(value.to_string()),
+        ..Default::default()
+    };
+    let probe = |path: &str, initial_delay: i32, period: i32| Probe {
+        http_get: Some(HTTPGetAction {
+            path: Some(path.to_string()),
+            port: IntOrString::String("metrics".to_string()),
+            ..Default::default()
+        }),
+        initial_delay_seconds: Some(initial_delay),
+        period_seconds: Some(period),
+        timeout_seconds: Some(5),
+        failure_threshold: Some(3),
+        ..Default::default()
+    };
+
+    let mut env = vec![
+        value_env("RUST_LOG", "info"),
+        field_env("OPERATOR_NAMESPACE", "metadata.namespace"),
+        field_env("POD_NAME", "metadata.name"),
+        value_env(OPERATOR_IMAGE_ENV, &options.image),
+    ];
+    let mut ports = vec![ContainerPort {
+        name: Some("metrics".to_string()),
+        container_port: METRICS_PORT,
+        protocol: Some("TCP".to_string()),
+        ..Default::default()
+    }];
+    let mut volume_mounts = vec![VolumeMount {
+        name: "tmp".to_string(),
+        mount_path: "/tmp".to_string(),
+        ..Default::default()
+    }];
+    let mut volumes = vec![Volume {
+        name: "tmp".to_string(),
+        empty_dir: Some(EmptyDirVolumeSource::default()),
+        ..Default::default()
+    }];
+    if options.webhooks {
+        env.push(value_env("WEBHOOK_ENABLED", "true"));
+        env.push(value_env("WEBHOOK_PORT", &DEFAULT_WEBHOOK_PORT.to_string()));
+        env.push(value_env("WEBHOOK_CERT_DIR", DEFAULT_CERT_DIR));
+        ports.push(ContainerPort {
+            name: Some("webhook".to_string()),
+            container_port: i32::from(DEFAULT_WEBHOOK_PORT),
+            protocol: Some("TCP".to_string()),
+            ..Default::default()
+        });
+        volume_mounts.push(VolumeMount {
+            name: "webhook-certs".to_string(),
+            mount_path: DEFAULT_CERT_DIR.to_string(),
+            read_only: Some(true),
+            ..Default::default()
+        });
+        volumes.push(Volume {
+            name: "webhook-certs".to_string(),
+            secret: Some(SecretVolumeSource {
+                secret_name: Some(format!("{}-cert", webhook_service_name())),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+
+    let quantities = |cpu: &str, memory: &str| {
+        BTreeMap::from([
+            ("cpu".to_string(), Quantity(cpu.to_string())),
+            ("memory".to_string(), Quantity(memory.to_string())),
+        ])
+    };
+
+    Deployment {
+        metadata: metadata(OPERATOR_NAME, Some(&options.namespace)),
+        spec: Some(DeploymentSpec {
+            replicas: Some(1),
+            selector: LabelSelector {
+                match_labels: Some(labels()),
+                ..Default::default()
+            },
+            template: PodTemplateSpec {
+                metadata: Some(ObjectMeta {
+                    labels: Some(labels()),
+                    ..Default::default()
+                }),
+                spec: Some(PodSpec {
+                    service_account_name: Some(OPERATOR_NAME.to_string()),
+                    security_context: Some(PodSecurityContext {
+                        run_as_non_root: Some(true),
+                        run_as_user: Some(1000),
+                        fs_group: Some(1000),
+                        ..Default::default()
+                    }),
+                    containers: vec![Container {
+                        name: "operator".to_string(),
+                        image: Some(options.image.clone()),
+                        ports: Some(ports),
+                        env: Some(env),
+                        resources: Some(ResourceRequirements {
+                            requests: Some(quantities("100m", "128Mi")),
+                            limits: Some(quantities("500m", "512Mi")),
+                            ..Default::default()
+                        }),
+                        liveness_probe: Some(probe("/healthz", 10, 30)),
+                        readiness_probe: Some(probe("/readyz", 5, 10)),
+                        security_context: Some(SecurityContext {
+                            allow_privilege_escalation: Some(false),
+                            read_only_root_filesystem: Some(true),
+                            capabilities: Some(Capabilities {
+                                drop: Some(vec!["ALL".to_string()]),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        }),
+                        volume_mounts: Some(volume_mounts),
+                        ..Default::default()
+                    }],
+                    volumes: Some(volumes),
+                    termination_grace_period_seconds: Some(30),
+                    ..Default::default()
+                }),
+            },
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn build_service(name: &str, options: &BundleOptions, port: i32, target: &str) -> Service {
+    Service {
+        metadata: metadata(name, Some(&options.namespace)),
+        spec: Some(ServiceSpec {
+            selector: Some(labels()),
+            ports: Some(vec![ServicePort {
+                name: Some(target.to_string()),
+                port,
+                target_port: Some(IntOrString::String(target.to_string())),
+                protocol: Some("TCP".to_string()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Render the install bundle as YAML documents, CRDs first
+pub fn generate_bundle(options: &BundleOptions) -> Result<Vec<String>> {
+    fn to_yaml<T: Serialize>(kind: &str, value: &T) -> Result<String> {
+        serde_yaml::to_string(value)
+            .map_err(|e| Error::ConfigError(format!("Failed to render {}: {}", kind, e)))
+    }
+
+    let mut manifests = generate_crds();
+    manifests.push(to_yaml(
+        "Namespace",
+        &Namespace {
+            metadata: metadata(&options.namespace, None),
+            ..Default::default()
+        },
+    )?);
+    manifests.push(to_yaml(
+        "ServiceAccount",
+        &ServiceAccount {
+            metadata: metadata(OPERATOR_NAME, Some(&options.namespace)),
+            ..Default::default()
+        },
+    )?);
+    manifests.push(to_yaml("ClusterRole", &build_cluster_role())?);
+    manifests.push(to_yaml(
+        "ClusterRoleBinding",
+        &build_cluster_role_binding(options),
+    )?);
+    manifests.push(to_yaml("Deployment", &build_operator_deployment(options))?);
+    manifests.push(to_yaml(
+        "Service",
+        &build_service(
+            &format!("{}-metrics", OPERATOR_NAME),
+            options,
+            METRICS_PORT,
+            "metrics",
+        ),
+    )?);
+    if options.webhooks {
+        let service = webhook_service_name();
+        manifests.push(to_yaml(
+            "Service",
+            &build_service(&service, options, 443, "webhook"),
+        )?);
+        manifests.push(to_yaml(
+            "MutatingWebhookConfiguration",
+            &build_mutating_webhook_configuration(&service, &options.namespace, 443),
+        )?);
+        manifests.push(to_yaml(
+            "ValidatingWebhookConfiguration",
+            &build_validating_webhook_configuration(&service, &options.namespace, 443),
+        )?);
+    }
+    Ok(manifests)
+}
@@ -1,19 +1,304 @@
 //! Controller implementations for watching and reconciling resources
 
+pub mod remap_task_controller;
 pub mod remapper_controller;
 
-use kube::Client;
-use std::sync::Arc;
+use futures::Stream;
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use k8s_openapi::api::core::v1::Service;
+use k8s_openapi::NamespaceResourceScope;
+use kube::api::DynamicObject;
+use kube::runtime::events::{Recorder, Reporter};
+use kube::runtime::reflector::{ObjectRef, Store};
+use kube::runtime::{controller, watcher};
+use kube::{Api, Client, Resource};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+/// Label selector matching child resources created by this operator
+pub(crate) const MANAGED_BY_SELECTOR: &str =
+    "app.kubernetes.io/managed-by=kafka-partition-remapper-operator";
+
+/// Controller name reported on published Events
+const REPORTING_CONTROLLER: &str = "kafka-partition-remapper-operator";
+
+/// Restricts which KafkaPartitionRemappers a controller watches
+///
+/// Lets several operator installs share a cluster without fighting over
+/// the same resources.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WatchScope {
+    /// Namespaces to watch (empty means all namespaces)
+    pub namespaces: Vec<String>,
+    /// Label selector remappers must match
+    pub label_selector: Option<String>,
+}
+
+impl WatchScope {
+    /// Build a scope from a comma-separated namespace list and a selector
+    pub fn new(namespaces: &str, label_selector: Option<&str>) -> Self {
+        Self {
+            namespaces: namespaces
+                .split(',')
+                .map(str::trim)
+                .filter(|n| !n.is_empty())
+                .map(String::from)
+                .collect(),
+            label_selector: label_selector
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(String::from),
+        }
+    }
+
+    /// Whether the scope covers every namespace
+    pub fn is_cluster_wide(&self) -> bool {
+        self.namespaces.is_empty()
+    }
+
+    /// Controllers each kind runs: one per watched namespace, or a single
+    /// cluster-wide one
+    pub fn controller_count(&self) -> usize {
+        self.namespaces.len().max(1)
+    }
+}
+
+/// Throughput limits of the controllers and their Kubernetes client
+///
+/// Large fleets raise these to reconcile faster, or lower them to spare the
+/// API server.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ControllerTuning {
+    /// Reconciles run at once by each controller (0 means unlimited)
+    pub max_concurrent_reconciles: u16,
+    /// Sustained Kubernetes API requests per second (0 disables the limit)
+    pub client_qps: f64,
+    /// Requests that may be sent at once before `client_qps` applies
+    pub client_burst: u32,
+    /// Objects fetched per page when a watch lists (`None` lists in one call)
+    pub watch_page_size: Option<u32>,
+    /// Interval at which every watched object is reconciled again
+    pub resync_interval: Option<Duration>,
+}
+
+impl Default for ControllerTuning {
+    fn default() -> Self {
+        Self {
+            max_concurrent_reconciles: 10,
+            client_qps: 20.0,
+            client_burst: 30,
+            watch_page_size: Some(500),
+            resync_interval: None,
+        }
+    }
+}
+
+impl ControllerTuning {
+    /// Read tuning from variables looked up by `var`, keeping the default
+    /// of any that is unset or invalid
+    ///
+    /// Reads `MAX_CONCURRENT_RECONCILES`, `KUBE_CLIENT_QPS`,
+    /// `KUBE_CLIENT_BURST`, `WATCH_PAGE_SIZE` (0 lists in one call) and
+    /// `WATCH_RESYNC_SECS` (0 disables resyncs).
+    pub fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        fn parse<T: std::str::FromStr>(value: Option<String>) -> Option<T> {
+            value.and_then(|v| v.trim().parse().ok())
+        }
+
+        let defaults = Self::default();
+        Self {
+            max_concurrent_reconciles: parse(var("MAX_CONCURRENT_RECONCILES"))
+                .unwrap_or(defaults.max_concurrent_reconciles),
+            client_qps: parse(var("KUBE_CLIENT_QPS"))
+                .filter(|qps: &f64| qps.is_finite() && *qps >= 0.0)
+                .unwrap_or(defaults.client_qps),
+            client_burst: parse(var("KUBE_CLIENT_BURST")).unwrap_or(defaults.client_burst),
+            watch_page_size: parse(var("WATCH_PAGE_SIZE"))
+                .map(|size: u32| Some(size).filter(|s| *s > 0))
+                .unwrap_or(defaults.watch_page_size),
+            resync_interval: parse(var("WATCH_RESYNC_SECS"))
+                .map(|secs: u64| Some(Duration::from_secs(secs)).filter(|d| !d.is_zero()))
+                .unwrap_or(defaults.resync_interval),
+        }
+    }
+
+    /// Client rate limit as a number of requests per period
+    ///
+    /// `client_burst` requests may be sent at once, refilled at
+    /// `client_qps`. `None` when the client is not rate limited.
+    pub fn client_rate_limit(&self) -> Option<(u64, Duration)> {
+        if self.client_qps <= 0.0 {
+            return None;
+        }
+        let burst = self.client_burst.max(1);
+        Some((
+            u64::from(burst),
+            Duration::from_secs_f64(f64::from(burst) / self.client_qps),
+        ))
+    }
+
+    /// Controller settings limiting concurrent reconciles
+    pub fn controller_config(&self) -> controller::Config {
+        controller::Config::default().concurrency(self.max_concurrent_reconciles)
+    }
+
+    /// Watcher settings using the configured list page size
+    pub fn watcher_config(&self) -> watcher::Config {
+        let config = watcher::Config::default();
+        match self.watch_page_size {
+            Some(size) => config.page_size(size),
+            None => watcher::Config {
+                page_size: None,
+                ..config
+            },
+        }
+    }
+
+    /// Stream ticking once per resync interval, to reconcile every object
+    pub(crate) fn resync(&self) -> Option<impl Stream<Item = ()> + Send + Sync + 'static> {
+        let interval = self.resync_interval?;
+        Some(futures::stream::unfold((), move |()| async move {
+            tokio::time::sleep(interval).await;
+            Some(((), ()))
+        }))
+    }
+}
+
+/// Consecutive reconcile failures of each object
+///
+/// Drives the backoff of `error_policy`. An object's count is cleared by its
+/// next successful reconcile.
+#[derive(Debug, Default)]
+pub struct Retries {
+    failures: Mutex<HashMap<ObjectRef<DynamicObject>, u32>>,
+}
+
+impl Retries {
+    /// Count a failed reconcile, returning the consecutive failures so far
+    pub fn record_failure<K: Resource<DynamicType = ()>>(&self, resource: &K) -> u32 {
+        let mut failures = self.failures.lock().unwrap_or_else(|e| e.into_inner());
+        let count = failures.entry(Self::key(resource)).or_default();
+        *count += 1;
+        *count
+    }
+
+    /// Consecutive failed reconciles of a resource
+    pub fn failures<K: Resource<DynamicType = ()>>(&self, resource: &K) -> u32 {
+        let failures = self.failures.lock().unwrap_or_else(|e| e.into_inner());
+        failures.get(&Self::key(resource)).copied().unwrap_or(0)
+    }
+
+    /// Forget the failures of a resource after it reconciled
+    pub fn reset<K: Resource<DynamicType = ()>>(&self, resource: &K) {
+        let mut failures = self.failures.lock().unwrap_or_else(|e| e.into_inner());
+        failures.remove(&Self::key(resource));
+    }
+
+    fn key<K: Resource<DynamicType = ()>>(resource: &K) -> ObjectRef<DynamicObject> {
+        ObjectRef::from_obj(resource).erase()
+    }
+}
+
+/// Reflector stores of one child resource kind, one per watched namespace
+///
+/// Filled by the controllers' watches of owned children, so status can be
+/// computed without a GET per child on every reconcile.
+pub struct ChildStore<K: Resource<DynamicType = ()> + Clone + 'static> {
+    stores: RwLock<Vec<Store<K>>>,
+}
+
+impl<K: Resource<DynamicType = ()> + Clone + 'static> Default for ChildStore<K> {
+    fn default() -> Self {
+        Self {
+            stores: RwLock::new(Vec::new()),
+        }
+    }
+}
+
+impl<K: Resource<DynamicType = ()> + Clone + 'static> ChildStore<K> {
+    /// Register the store of a controller's watch
+    pub fn add(&self, store: Store<K>) {
+        self.stores
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(store);
+    }
+
+    /// Cached child, if any store has seen it
+    pub fn get(&self, namespace: &str, name: &str) -> Option<Arc<K>> {
+        let key = ObjectRef::new(name).within(namespace);
+        self.stores
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .find_map(|store| store.get(&key))
+    }
+}
+
+/// Cached child resources read back when computing remapper status
+#[derive(Default)]
+pub struct ChildStores {
+    /// Proxy Deployments
+    pub deployments: ChildStore<Deployment>,
+    /// Proxy StatefulSets
+    pub stateful_sets: ChildStore<StatefulSet>,
+    /// Client-facing Services
+    pub services: ChildStore<Service>,
+}
+
+/// Random factor in `[0, 1)` for spreading out retries
+pub(crate) fn jitter() -> f64 {
+    let mut bytes = [0u8; 8];
+    if getrandom::getrandom(&mut bytes).is_err() {
+        return 0.5;
+    }
+    (u64::from_le_bytes(bytes) >> 11) as f64 / (1u64 << 53) as f64
+}
 
 /// Shared context for controllers
 pub struct Context {
     /// Kubernetes client
     pub client: Client,
+    /// Identity used when publishing Events
+    pub reporter: Reporter,
+    /// Consecutive failures of reconciled objects
+    pub retries: Retries,
+    /// Child resources cached by the controllers' watches
+    pub children: ChildStores,
 }
 
 impl Context {
     /// Create a new context
     pub fn new(client: Client) -> Arc<Self> {
-        Arc::new(Self { client })
+        Arc::new(Self {
+            client,
+            reporter: Reporter {
+                controller: REPORTING_CONTROLLER.to_string(),
+                instance: std::env::var("POD_NAME").ok(),
+            },
+            retries: Retries::default(),
+            children: ChildStores::default(),
+        })
+    }
+
+    /// Create an Event recorder for a resource
+    pub fn recorder<K: Resource<DynamicType = ()>>(&self, resource: &K) -> Recorder {
+        Recorder::new(
+            self.client.clone(),
+            self.reporter.clone(),
+            resource.object_ref(&()),
+        )
+    }
+}
+
+/// Api for a namespaced resource, limited to `namespace` when given
+pub(crate) fn scoped_api<K>(ctx: &Context, namespace: Option<&str>) -> Api<K>
+where
+    K: kube::Resource<Scope = NamespaceResourceScope, DynamicType = ()>,
+{
+    match namespace {
+        Some(ns) => Api::namespaced(ctx.client.clone(), ns),
+        None => Api::all(ctx.client.clone()),
     }
 }
@@ -0,0 +1,375 @@
+//! Controller for KafkaPartitionRemapTask resources
+
+use futures::StreamExt;
+use k8s_openapi::api::apps::v1::Deployment;
+use kube::{
+    runtime::{
+        controller::{Action, Controller},
+        events::{Event, EventType},
+        finalizer::{finalizer, Event as FinalizerEvent},
+        reflector::ObjectRef,
+    },
+    Api, ResourceExt,
+};
+use std::sync::Arc;
+use tracing::{error, info, instrument, warn};
+
+use crate::controllers::{
+    jitter, scoped_api, Context, ControllerTuning, WatchScope, MANAGED_BY_SELECTOR,
+};
+use crate::crd::{KafkaPartitionRemapTask, KafkaPartitionRemapper};
+use crate::metrics::{self, health, RECONCILE_DURATION, RECONCILIATIONS, RECONCILIATION_ERRORS};
+use crate::reconcilers::{remap_task, remapper};
+use crate::settings;
+use crate::Error;
+
+/// Finalizer name for cleanup
+pub const FINALIZER: &str = "kafka.oso.sh/remap-task-finalizer";
+
+/// Run the remap task controller
+pub async fn run(ctx: Arc<Context>, scope: WatchScope, tuning: ControllerTuning) {
+    info!("Starting KafkaPartitionRemapTask controller");
+
+    let namespaces = if scope.is_cluster_wide() {
+        vec![None]
+    } else {
+        scope.namespaces.iter().cloned().map(Some).collect()
+    };
+    futures::future::join_all(
+        namespaces
+            .into_iter()
+            .map(|namespace| run_controller(ctx.clone(), namespace, &tuning)),
+    )
+    .await;
+
+    info!("KafkaPartitionRemapTask controller stopped");
+}
+
+/// Run a controller over one namespace, or all namespaces when `None`
+async fn run_controller(ctx: Arc<Context>, namespace: Option<String>, tuning: &ControllerTuning) {
+    let mut controller = Controller::new(
+        scoped_api::<KafkaPartitionRemapTask>(&ctx, namespace.as_deref()),
+        tuning.watcher_config().any_semantic(),
+    )
+    .with_config(tuning.controller_config());
+    if let Some(resync) = tuning.resync() {
+        controller = controller.reconcile_all_on(resync);
+    }
+    let store = controller.store();
+
+    // Report ready once the tasks have been listed
+    let task_store = store.clone();
+    tokio::spawn(async move {
+        if task_store.wait_until_ready().await.is_ok() {
+            health::watch_ready();
+        }
+    });
+
+    // Watch generation Deployments for rollout progress and remappers so
+    // their rollout of the target mapping advances the cutover
+    controller
+        .owns(
+            scoped_api::<Deployment>(&ctx, namespace.as_deref()),
+            tuning.watcher_config().labels(MANAGED_BY_SELECTOR),
+        )
+        .watches(
+            scoped_api::<KafkaPartitionRemapper>(&ctx, namespace.as_deref()),
+            tuning.watcher_config(),
+            move |remapper| {
+                let name = remapper.name_any();
+                let namespace = remapper.namespace();
+                store
+                    .state()
+                    .into_iter()
+                    .filter(|t| t.namespace() == namespace && t.spec.remapper_ref == name)
+                    .map(|t| ObjectRef::from_obj(&*t))
+                    .collect::<Vec<_>>()
+            },
+        )
+        .shutdown_on_signal()
+        .run(reconcile, error_policy, ctx)
+        .for_each(|res| async move {
+            match res {
+                Ok(o) => info!("Reconciled {:?}", o),
+                Err(e) => error!("Reconcile failed: {:?}", e),
+            }
+        })
+        .await;
+}
+
+/// Reconcile a KafkaPartitionRemapTask resource
+#[instrument(skip(task, ctx), fields(name = %task.name_any(), namespace = task.namespace().unwrap_or_default()))]
+async fn reconcile(task: Arc<KafkaPartitionRemapTask>, ctx: Arc<Context>) -> Result<Action, Error> {
+    let start = std::time::Instant::now();
+    let ns = task.namespace().unwrap_or_default();
+    let name = task.name_any();
+
+    RECONCILIATIONS
+        .with_label_values(&["KafkaPartitionRemapTask"])
+        .inc();
+
+    let tasks: Api<KafkaPartitionRemapTask> = Api::namespaced(ctx.client.clone(), &ns);
+
+    let result = finalizer(&tasks, FINALIZER, task.clone(), |event| async {
+        match event {
+            FinalizerEvent::Apply(task) => apply(&task, &ctx).await,
+            FinalizerEvent::Cleanup(task) => cleanup(&task, &ctx).await,
+        }
+    })
+    .await;
+
+    let duration = start.elapsed().as_secs_f64();
+    RECONCILE_DURATION
+        .with_label_values(&["KafkaPartitionRemapTask"])
+        .observe(duration);
+
+    let result = result.map_err(Error::from);
+    if let Err(ref e) = result {
+        RECONCILIATION_ERRORS
+            .with_label_values(&[
+                "KafkaPartitionRemapTask",
+                e.metric_label(),
+                &e.is_retryable().to_string(),
+            ])
+            .inc();
+        error!("Failed to reconcile {}/{}: {:?}", ns, name, e);
+        ctx.retries.record_failure(task.as_ref());
+    } else {
+        ctx.retries.reset(task.as_ref());
+        health::record_reconcile();
+    }
+
+    result
+}
+
+/// Advance the task through its phases
+async fn apply(task: &KafkaPartitionRemapTask, ctx: &Context) -> Result<Action, Error> {
+    let ns = task.namespace().unwrap_or_default();
+    let phase = task
+        .status
+        .as_ref()
+        .and_then(|s| s.phase.clone())
+        .unwrap_or_else(|| "Pending".to_string());
+
+    if matches!(phase.as_str(), "Completed" | "Failed") {
+        return Ok(Action::await_change());
+    }
+
+    let remappers: Api<KafkaPartitionRemapper> = Api::namespaced(ctx.client.clone(), &ns);
+    let Some(remapper) = remappers
+        .get_opt(&task.spec.remapper_ref)
+        .await
+        .map_err(|e| Error::kube("Failed to get remapper", e))?
+    else {
+        let message = format!(
+            "KafkaPartitionRemapper {} not found",
+            task.spec.remapper_ref
+        );
+        return fail(task, ctx, &phase, message).await;
+    };
+    let remapper = remapper::resolve_cluster_ref(&remapper, &ctx.client).await?;
+    let generation = remap_task::build_generation(&remapper, task);
+    let generation_name = generation.name_any();
+    let requeue = settings::current().requeue.clone();
+
+    let (next_phase, message, consumer_groups, retry) = match phase.as_str() {
+        "Pending" | "Provisioning" => {
+            if phase == "Pending" {
+                if let Err(e) = remap_task::validate(&remapper, task) {
+                    return fail(task, ctx, &phase, e.to_string()).await;
+                }
+            }
+            if remap_task::reconcile_generation(&generation, task, &ctx.client, &ns).await? {
+                (
+                    "Migrating",
+                    format!("Proxy generation {} is ready", generation_name),
+                    None,
+                    requeue.progressing(),
+                )
+            } else {
+                (
+                    "Provisioning",
+                    format!(
+                        "Waiting for proxy generation {} to roll out",
+                        generation_name
+                    ),
+                    None,
+                    requeue.progressing(),
+                )
+            }
+        }
+        "Migrating" | "AwaitingCutover" => {
+            remap_task::reconcile_generation(&generation, task, &ctx.client, &ns).await?;
+            match remap_task::consumer_group_progress(&remapper, task, &ctx.client, &ns).await {
+                Err(Error::KafkaError(e)) => (
+                    phase.as_str(),
+                    format!("Waiting for Kafka: {}", e),
+                    None,
+                    requeue.kafka_unreachable(),
+                ),
+                Err(e) => return Err(e),
+                Ok(progress) => {
+                    let migrated = progress.iter().filter(|p| p.migrated).count();
+                    if migrated < progress.len() {
+                        (
+                            "Migrating",
+                            format!(
+                                "{}/{} consumer groups migrated to {}",
+                                migrated,
+                                progress.len(),
+                                generation_name
+                            ),
+                            Some(progress),
+                            requeue.progressing(),
+                        )
+                    } else if task.spec.auto_cutover {
+                        (
+                            "CuttingOver",
+                            "All consumer groups migrated, cutting over".to_string(),
+                            Some(progress),
+                            requeue.progressing(),
+                        )
+                    } else {
+                        (
+                            "AwaitingCutover",
+                            "All consumer groups migrated; set autoCutover to cut over".to_string(),
+                            Some(progress),
+                            requeue.progressing(),
+                        )
+                    }
+                }
+            }
+        }
+        "CuttingOver" => {
+            remap_task::reconcile_generation(&generation, task, &ctx.client, &ns).await?;
+            let cut_over = remapper.serving_generation() == Some(generation_name.as_str());
+            if !cut_over {
+                remap_task::begin_cutover(task, &ctx.client, &ns).await?;
+            }
+
+            if cut_over && remap_task::remapper_converged(&remapper, task, &ctx.client, &ns).await?
+            {
+                remap_task::finish_cutover(task, &ctx.client, &ns).await?;
+                (
+                    "Completed",
+                    format!(
+                        "KafkaPartitionRemapper {} now serves the target mapping",
+                        task.spec.remapper_ref
+                    ),
+                    None,
+                    requeue.progressing(),
+                )
+            } else {
+                (
+                    "CuttingOver",
+                    format!(
+                        "Waiting for KafkaPartitionRemapper {} to roll out the target mapping",
+                        task.spec.remapper_ref
+                    ),
+                    None,
+                    requeue.progressing(),
+                )
+            }
+        }
+        other => {
+            let message = format!("Unknown phase {}", other);
+            return fail(task, ctx, &phase, message).await;
+        }
+    };
+
+    let status = remap_task::next_status(task, next_phase, message.clone(), consumer_groups);
+    remap_task::update_status(task, &ctx.client, &ns, status).await?;
+
+    if next_phase != phase {
+        info!(
+            "KafkaPartitionRemapTask {}/{} moved to {}",
+            ns,
+            task.name_any(),
+            next_phase
+        );
+        publish_event(task, ctx, EventType::Normal, next_phase, message).await;
+    }
+
+    match next_phase {
+        "Completed" => Ok(Action::await_change()),
+        reason => Ok(metrics::requeue("KafkaPartitionRemapTask", reason, retry)),
+    }
+}
+
+/// Mark the task Failed without retrying
+async fn fail(
+    task: &KafkaPartitionRemapTask,
+    ctx: &Context,
+    phase: &str,
+    message: String,
+) -> Result<Action, Error> {
+    let ns = task.namespace().unwrap_or_default();
+    warn!(
+        "KafkaPartitionRemapTask {}/{} failed in phase {}: {}",
+        ns,
+        task.name_any(),
+        phase,
+        message
+    );
+
+    let status = remap_task::next_status(task, "Failed", message.clone(), None);
+    remap_task::update_status(task, &ctx.client, &ns, status).await?;
+    publish_event(task, ctx, EventType::Warning, "Failed", message).await;
+
+    Ok(Action::await_change())
+}
+
+/// Publish an Event for a task, logging rather than failing on error
+async fn publish_event(
+    task: &KafkaPartitionRemapTask,
+    ctx: &Context,
+    type_: EventType,
+    reason: &str,
+    note: String,
+) {
+    let event = Event {
+        type_,
+        reason: reason.to_string(),
+        note: Some(note),
+        action: "Remap".to_string(),
+        secondary: None,
+    };
+    if let Err(e) = ctx.recorder(task).publish(event).await {
+        warn!("Failed to publish event: {}", e);
+    }
+}
+
+/// Release the remapper when a task is deleted
+///
+/// Generation resources are garbage collected through their owner
+/// references; only the cutover annotations on the remapper need removing.
+async fn cleanup(task: &KafkaPartitionRemapTask, ctx: &Context) -> Result<Action, Error> {
+    let ns = task.namespace().unwrap_or_default();
+
+    info!(
+        "Cleaning up KafkaPartitionRemapTask {}/{}",
+        ns,
+        task.name_any()
+    );
+
+    remap_task::release_remapper(task, &ctx.client, &ns).await?;
+
+    Ok(Action::await_change())
+}
+
+/// Error policy for the controller
+fn error_policy(task: Arc<KafkaPartitionRemapTask>, err: &Error, ctx: Arc<Context>) -> Action {
+    let ns = task.namespace().unwrap_or_default();
+    let name = task.name_any();
+
+    error!("Reconciliation error for {}/{}: {:?}", ns, name, err);
+
+    let requeue = &settings::current().requeue;
+    let base = err.retry_after(requeue);
+    let failures = ctx.retries.failures(task.as_ref()).max(1);
+    metrics::requeue(
+        "KafkaPartitionRemapTask",
+        "error",
+        requeue.backoff(base, failures, jitter()),
+    )
+}
@@ -1,35 +1,245 @@
 //! Controller for KafkaPartitionRemapper resources
 
+use chrono::Utc;
 use futures::StreamExt;
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler;
+use k8s_openapi::api::batch::v1::CronJob;
+use k8s_openapi::api::core::v1::{ConfigMap, Secret, Service};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
 use kube::{
     runtime::{
         controller::{Action, Controller},
-        finalizer::{finalizer, Event},
-        watcher::Config,
+        events::{Event, EventType},
+        finalizer::{finalizer, Event as FinalizerEvent},
+        reflector::{self, reflector, ObjectRef, Store},
+        watcher::watcher,
+        WatchStreamExt,
     },
     Api, ResourceExt,
 };
 use std::sync::Arc;
-use std::time::Duration;
-use tracing::{error, info, instrument};
+use tracing::{debug, error, info, instrument, warn};
 
-use crate::controllers::Context;
-use crate::crd::KafkaPartitionRemapper;
-use crate::metrics::prometheus::{RECONCILE_DURATION, RECONCILIATIONS, RECONCILIATION_ERRORS};
+use crate::adapters::{canary, certificates, secrets, users};
+use crate::controllers::{
+    jitter, scoped_api, Context, ControllerTuning, WatchScope, MANAGED_BY_SELECTOR,
+};
+use crate::crd::{
+    CanaryStatus, Condition, KafkaClusterRef, KafkaPartitionRemapper, KafkaPartitionRemapperStatus,
+    KafkaRemapperTopicOverride, WorkloadKind, OWNER_NAMESPACE_LABEL, OWNER_NAME_LABEL,
+};
+use crate::metrics::{self, health, RECONCILE_DURATION, RECONCILIATIONS, RECONCILIATION_ERRORS};
 use crate::reconcilers::remapper;
+use crate::settings;
 use crate::Error;
 
 /// Finalizer name for cleanup
 pub const FINALIZER: &str = "kafka.oso.sh/remapper-finalizer";
 
 /// Run the remapper controller
-pub async fn run(ctx: Arc<Context>) {
+///
+/// One controller is started per watched namespace, or a single
+/// cluster-wide controller when the scope does not restrict namespaces.
+pub async fn run(ctx: Arc<Context>, scope: WatchScope, tuning: ControllerTuning) {
+    let client = ctx.client.clone();
+
+    info!(
+        "Starting KafkaPartitionRemapper controller with scope {:?}",
+        scope
+    );
+
+    // Cache KafkaClusterRefs so Secret events can be traced through them
+    let (cluster_refs, cluster_refs_writer) = reflector::store::<KafkaClusterRef>();
+    let cluster_refs_reflector = reflector(
+        cluster_refs_writer,
+        watcher(
+            Api::<KafkaClusterRef>::all(client.clone()),
+            tuning.watcher_config(),
+        )
+        .default_backoff(),
+    )
+    .for_each(|_| futures::future::ready(()));
+
+    let namespaces = if scope.is_cluster_wide() {
+        vec![None]
+    } else {
+        scope.namespaces.iter().cloned().map(Some).collect()
+    };
+    let controllers = futures::future::join_all(namespaces.into_iter().map(|namespace| {
+        run_controller(
+            ctx.clone(),
+            namespace,
+            scope.label_selector.clone(),
+            cluster_refs.clone(),
+            &tuning,
+        )
+    }));
+
+    futures::future::select(Box::pin(controllers), Box::pin(cluster_refs_reflector)).await;
+
+    info!("KafkaPartitionRemapper controller stopped");
+}
+
+/// Run a controller over one namespace, or all namespaces when `None`
+async fn run_controller(
+    ctx: Arc<Context>,
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    cluster_refs: Store<KafkaClusterRef>,
+    tuning: &ControllerTuning,
+) {
     let client = ctx.client.clone();
-    let remappers: Api<KafkaPartitionRemapper> = Api::all(client.clone());
+    let owned = || tuning.watcher_config().labels(MANAGED_BY_SELECTOR);
+    let labelled = || tuning.watcher_config().labels(OWNER_NAMESPACE_LABEL);
 
-    info!("Starting KafkaPartitionRemapper controller");
+    let mut remapper_config = tuning.watcher_config().any_semantic();
+    if let Some(ref selector) = label_selector {
+        remapper_config = remapper_config.labels(selector);
+    }
 
-    Controller::new(remappers, Config::default().any_semantic())
+    let mut controller = Controller::new(
+        scoped_api::<KafkaPartitionRemapper>(&ctx, namespace.as_deref()),
+        remapper_config,
+    )
+    .with_config(tuning.controller_config());
+    if let Some(resync) = tuning.resync() {
+        controller = controller.reconcile_all_on(resync);
+    }
+    let store = controller.store();
+    let cluster_ref_store = store.clone();
+
+    // Owned workloads and Services are cached for status computation
+    let (deployment_store, deployments) = reflector::store();
+    let (stateful_set_store, stateful_sets) = reflector::store();
+    let (service_store, services) = reflector::store();
+    ctx.children.deployments.add(deployment_store.clone());
+    ctx.children.stateful_sets.add(stateful_set_store.clone());
+    ctx.children.services.add(service_store.clone());
+
+    // Report ready once the remappers and cached children have been listed
+    let remapper_store = store.clone();
+    tokio::spawn(async move {
+        let listed = futures::try_join!(
+            remapper_store.wait_until_ready(),
+            deployment_store.wait_until_ready(),
+            stateful_set_store.wait_until_ready(),
+            service_store.wait_until_ready(),
+        );
+        if listed.is_ok() {
+            health::watch_ready();
+        }
+    });
+
+    // Watch owned child resources, including those in another namespace
+    // tracked by owner labels, so drift or deletion triggers a reconcile
+    // and load balancer addresses are reported as soon as they are assigned,
+    // referenced and generated Secrets so credential rotation is picked up, and
+    // KafkaClusterRefs and topic overrides so their changes propagate
+    controller
+        .owns_stream(
+            watcher(
+                scoped_api::<Deployment>(&ctx, namespace.as_deref()),
+                owned(),
+            )
+            .default_backoff()
+            .reflect(deployments)
+            .touched_objects(),
+        )
+        .owns_stream(
+            watcher(
+                scoped_api::<StatefulSet>(&ctx, namespace.as_deref()),
+                owned(),
+            )
+            .default_backoff()
+            .reflect(stateful_sets)
+            .touched_objects(),
+        )
+        .owns_stream(
+            watcher(scoped_api::<Service>(&ctx, namespace.as_deref()), owned())
+                .default_backoff()
+                .reflect(services)
+                .touched_objects(),
+        )
+        .owns(scoped_api::<ConfigMap>(&ctx, namespace.as_deref()), owned())
+        .owns(
+            scoped_api::<PodDisruptionBudget>(&ctx, namespace.as_deref()),
+            owned(),
+        )
+        .owns(
+            scoped_api::<HorizontalPodAutoscaler>(&ctx, namespace.as_deref()),
+            owned(),
+        )
+        .owns(scoped_api::<CronJob>(&ctx, namespace.as_deref()), owned())
+        .watches(
+            scoped_api::<Deployment>(&ctx, namespace.as_deref()),
+            labelled(),
+            labelled_owner,
+        )
+        .watches(
+            scoped_api::<StatefulSet>(&ctx, namespace.as_deref()),
+            labelled(),
+            labelled_owner,
+        )
+        .watches(
+            scoped_api::<Service>(&ctx, namespace.as_deref()),
+            labelled(),
+            labelled_owner,
+        )
+        .watches(
+            scoped_api::<ConfigMap>(&ctx, namespace.as_deref()),
+            labelled(),
+            labelled_owner,
+        )
+        .watches(
+            scoped_api::<Secret>(&ctx, namespace.as_deref()),
+            tuning.watcher_config(),
+            move |secret| {
+                // Drop cached copies older than the watched Secret
+                secrets::observe(&secret);
+                let name = secret.name_any();
+                let namespace = secret.namespace();
+                store
+                    .state()
+                    .into_iter()
+                    .filter(|r| {
+                        Some(r.target_namespace()) == namespace
+                            && (r.spec.referenced_secrets().contains(&name)
+                                || remapper::is_owned_by(r, &secret.metadata)
+                                || r.spec.kafka.cluster_ref.as_ref().is_some_and(|c| {
+                                    cluster_refs.get(&ObjectRef::new(c)).is_some_and(|c| {
+                                        c.spec.referenced_secrets().contains(&name)
+                                    })
+                                }))
+                    })
+                    .map(|r| ObjectRef::from_obj(&*r))
+                    .collect::<Vec<_>>()
+            },
+        )
+        .watches(
+            Api::<KafkaClusterRef>::all(client.clone()),
+            tuning.watcher_config(),
+            move |cluster_ref| {
+                let name = cluster_ref.name_any();
+                cluster_ref_store
+                    .state()
+                    .into_iter()
+                    .filter(|r| r.spec.kafka.cluster_ref.as_ref() == Some(&name))
+                    .map(|r| ObjectRef::from_obj(&*r))
+                    .collect::<Vec<_>>()
+            },
+        )
+        .watches(
+            scoped_api::<KafkaRemapperTopicOverride>(&ctx, namespace.as_deref()),
+            tuning.watcher_config(),
+            |topic_override| {
+                let namespace = topic_override.namespace().unwrap_or_default();
+                Some(
+                    ObjectRef::<KafkaPartitionRemapper>::new(&topic_override.spec.remapper_ref)
+                        .within(&namespace),
+                )
+            },
+        )
         .shutdown_on_signal()
         .run(reconcile, error_policy, ctx)
         .for_each(|res| async move {
@@ -39,8 +249,14 @@ pub async fn run(ctx: Arc<Context>) {
             }
         })
         .await;
+}
 
-    info!("KafkaPartitionRemapper controller stopped");
+/// Remapper owning a child in another namespace, read from its owner labels
+fn labelled_owner<K: ResourceExt>(child: K) -> Option<ObjectRef<KafkaPartitionRemapper>> {
+    let labels = child.labels();
+    let name = labels.get(OWNER_NAME_LABEL)?;
+    let namespace = labels.get(OWNER_NAMESPACE_LABEL)?;
+    Some(ObjectRef::new(name).within(namespace))
 }
 
 /// Reconcile a KafkaPartitionRemapper resource
@@ -59,10 +275,20 @@ async fn reconcile(
 
     let remappers: Api<KafkaPartitionRemapper> = Api::namespaced(ctx.client.clone(), &ns);
 
-    let result = finalizer(&remappers, FINALIZER, remapper, |event| async {
+    let result = finalizer(&remappers, FINALIZER, remapper.clone(), |event| async {
         match event {
-            Event::Apply(remapper) => apply(&remapper, &ctx).await,
-            Event::Cleanup(remapper) => cleanup(&remapper, &ctx).await,
+            FinalizerEvent::Apply(remapper) => match apply(&remapper, &ctx).await {
+                Err(Error::FieldConflict(message)) => {
+                    report_field_conflict(&remapper, &ctx, &message).await;
+                    Err(Error::FieldConflict(message))
+                }
+                Err(e @ (Error::ValidationError(_) | Error::ConfigError(_))) => {
+                    report_invalid_spec(&remapper, &ctx, &e.to_string()).await;
+                    Err(e)
+                }
+                result => result,
+            },
+            FinalizerEvent::Cleanup(remapper) => cleanup(&remapper, &ctx).await,
         }
     })
     .await;
@@ -72,20 +298,33 @@ async fn reconcile(
         .with_label_values(&["KafkaPartitionRemapper"])
         .observe(duration);
 
-    match &result {
-        Ok(_) => info!(
-            "Successfully reconciled {}/{} in {:.2}s",
-            ns, name, duration
-        ),
+    match result {
+        Ok(action) => {
+            ctx.retries.reset(remapper.as_ref());
+            health::record_reconcile();
+            info!(
+                "Successfully reconciled {}/{} in {:.2}s",
+                ns, name, duration
+            );
+            Ok(action)
+        }
         Err(e) => {
+            let e = Error::from(e);
             RECONCILIATION_ERRORS
-                .with_label_values(&["KafkaPartitionRemapper"])
+                .with_label_values(&[
+                    "KafkaPartitionRemapper",
+                    e.metric_label(),
+                    &e.is_retryable().to_string(),
+                ])
                 .inc();
             error!("Failed to reconcile {}/{}: {:?}", ns, name, e);
+            let failures = ctx.retries.record_failure(remapper.as_ref());
+            if settings::current().requeue.budget_exhausted(failures) {
+                report_retries_exhausted(&remapper, &ctx, failures, e.cause()).await;
+            }
+            Err(e)
         }
     }
-
-    Ok(result?)
 }
 
 /// Apply changes for a KafkaPartitionRemapper
@@ -93,62 +332,569 @@ async fn apply(remapper: &KafkaPartitionRemapper, ctx: &Context) -> Result<Actio
     let ns = remapper.namespace().unwrap_or_default();
     let name = remapper.name_any();
 
+    if !settings::current().watches_namespace(&ns) {
+        debug!(
+            "Skipping KafkaPartitionRemapper {}/{}: namespace not watched",
+            ns, name
+        );
+        return Ok(Action::await_change());
+    }
+
+    // A paused remapper keeps its child resources untouched
+    if remapper.is_paused() {
+        let was_paused =
+            remapper.status.as_ref().and_then(|s| s.phase.as_deref()) == Some("Paused");
+        remapper::update_paused_status(remapper, &ctx.client, &ns).await?;
+        if !was_paused {
+            publish_event(
+                remapper,
+                ctx,
+                Event {
+                    type_: EventType::Normal,
+                    reason: "Paused".to_string(),
+                    note: Some("Reconciliation paused by annotation".to_string()),
+                    action: "Reconcile".to_string(),
+                    secondary: None,
+                },
+            )
+            .await;
+        }
+        return Ok(Action::await_change());
+    }
+
     info!("Applying KafkaPartitionRemapper {}/{}", ns, name);
 
+    // Resolve shared connection settings from a KafkaClusterRef
+    let resolved = remapper::resolve_cluster_ref(remapper, &ctx.client).await?;
+
+    // Aggregate topic overrides managed as separate resources
+    let resolved = remapper::aggregate_topic_overrides(&resolved, &ctx.client, &ns).await?;
+    let remapper = &resolved;
+    let resource_name = remapper.resource_name();
+
     // Validate the spec
     remapper::validate(remapper)?;
 
-    // Reconcile ConfigMap
-    let config_map_name = remapper::reconcile_config_map(remapper, &ctx.client, &ns).await?;
+    // Guard against mapping changes that break live offset translation
+    if let Some(applied) = remapper
+        .status
+        .as_ref()
+        .and_then(|s| s.applied_mapping.as_ref())
+    {
+        remapper::validate_mapping_change(remapper, applied)?;
+    }
+
+    // Child resources live in the target namespace, which may differ from
+    // the remapper's own
+    let ns = remapper.target_namespace();
+    if !settings::current().watches_namespace(&ns) {
+        return Err(Error::ConfigError(format!(
+            "targetNamespace {} is not watched by the operator",
+            ns
+        )));
+    }
+
+    // Refuse to roll pods onto missing or incomplete credentials
+    let secrets_valid = remapper::check_secrets(remapper, &ctx.client, &ns).await?;
+    if secrets_valid.status == "False" {
+        let status = remapper::update_status(
+            remapper,
+            &ctx.client,
+            &ctx.children,
+            &ns,
+            &remapper::config_map_name(&resource_name),
+            &resource_name,
+            &resource_name,
+            vec![secrets_valid],
+            remapper.status.as_ref().and_then(|s| s.canary.clone()),
+        )
+        .await?;
+        publish_condition_warning(remapper, ctx, &status, "SecretsValid", "SecretsInvalid").await;
+        return Ok(metrics::requeue(
+            "KafkaPartitionRemapper",
+            "secrets_invalid",
+            settings::current().requeue.invalid_spec(),
+        ));
+    }
+
+    // Probe the Kafka cluster before touching the workload
+    let probe = remapper::probe_kafka(remapper, &ctx.client, &ns).await;
+    let kafka_reachable = remapper::kafka_reachable_condition(&probe);
+    let topics_consistent = remapper::topics_consistent_condition(&remapper.spec.mapping, &probe);
+    publish_reachability_event(remapper, ctx, &kafka_reachable).await;
+
+    if kafka_reachable.status == "False" {
+        remapper::update_status(
+            remapper,
+            &ctx.client,
+            &ctx.children,
+            &ns,
+            &remapper::config_map_name(&resource_name),
+            &resource_name,
+            &resource_name,
+            vec![secrets_valid, kafka_reachable],
+            remapper.status.as_ref().and_then(|s| s.canary.clone()),
+        )
+        .await?;
+        return Ok(metrics::requeue(
+            "KafkaPartitionRemapper",
+            "kafka_unreachable",
+            settings::current().requeue.kafka_unreachable(),
+        ));
+    }
+
+    // Generate or rotate credentials for listed users before pods mount them
+    let rotated = remapper::reconcile_users(remapper, &ctx.client, &ns).await?;
+    if !rotated.is_empty() {
+        let event = Event {
+            type_: EventType::Normal,
+            reason: "CredentialsRotated".to_string(),
+            note: Some(format!(
+                "Rotated passwords of users: {}",
+                rotated.join(", ")
+            )),
+            action: "Reconcile".to_string(),
+            secondary: None,
+        };
+        publish_event(remapper, ctx, event).await;
+    }
+
+    // Roll spec changes out to canary replicas first when requested
+    let canary = remapper::reconcile_canary(remapper, &ctx.client, &ns).await?;
+    publish_canary_event(remapper, ctx, canary.as_ref()).await;
+
+    // The stable ConfigMap and workload keep the previous spec until the
+    // canary passes
+    let holding = canary.as_ref().is_some_and(|c| c.state != "Passed");
+    let (config_map_name, workload_name) = if holding {
+        (remapper::config_map_name(&resource_name), resource_name)
+    } else {
+        // Reconcile ConfigMap
+        let config_map_name = metrics::time_apply(
+            "configmap",
+            remapper::reconcile_config_map(remapper, &ctx.client, &ns),
+        )
+        .await?;
+
+        // Reconcile Deployment or StatefulSet
+        let workload_kind = match remapper.spec.workload {
+            WorkloadKind::Deployment => "deployment",
+            WorkloadKind::StatefulSet => "statefulset",
+        };
+        let workload_name = metrics::time_apply(
+            workload_kind,
+            remapper::reconcile_workload(remapper, &ctx.client, &ns, &config_map_name),
+        )
+        .await?;
+        (config_map_name, workload_name)
+    };
 
-    // Reconcile Deployment
-    let deployment_name =
-        remapper::reconcile_deployment(remapper, &ctx.client, &ns, &config_map_name).await?;
+    // Push config-only changes to running pods with the Hot reload policy
+    let reload_pending = if holding {
+        0
+    } else {
+        remapper::reload_config(remapper, &ctx.client, &ns).await?
+    };
 
     // Reconcile Service
-    let service_name = remapper::reconcile_service(remapper, &ctx.client, &ns).await?;
+    let service_name = metrics::time_apply(
+        "service",
+        remapper::reconcile_service(remapper, &ctx.client, &ns),
+    )
+    .await?;
+
+    // Reconcile the external Service
+    metrics::time_apply(
+        "external_service",
+        remapper::reconcile_external_service(remapper, &ctx.client, &ns),
+    )
+    .await?;
+
+    // Reconcile the dedicated metrics Service
+    remapper::reconcile_metrics_service(remapper, &ctx.client, &ns).await?;
+
+    // Reconcile the Gateway API TCPRoute
+    remapper::reconcile_tcp_route(remapper, &ctx.client, &ns).await?;
+
+    // Reconcile per-shard routing Services
+    remapper::reconcile_shard_services(remapper, &ctx.client, &ns).await?;
+
+    // Reconcile PodDisruptionBudget
+    remapper::reconcile_pod_disruption_budget(remapper, &ctx.client, &ns).await?;
+
+    // Reconcile HorizontalPodAutoscaler
+    remapper::reconcile_horizontal_pod_autoscaler(remapper, &ctx.client, &ns).await?;
+
+    // Reconcile the KEDA ScaledObject, after our HPA is gone so KEDA's can replace it
+    remapper::reconcile_keda_scaled_object(remapper, &ctx.client, &ns).await?;
+
+    // Reconcile the consistency audit CronJob
+    remapper::reconcile_consistency_audit(remapper, &ctx.client, &ns).await?;
+
+    // Remove children generated under a previous resource name
+    remapper::prune_renamed_children(remapper, &ctx.client, &ns).await?;
 
     // Update status
-    remapper::update_status(
+    let mut conditions = vec![
+        secrets_valid,
+        kafka_reachable,
+        topics_consistent,
+        remapper::offset_headroom_condition(&remapper.spec.mapping),
+    ];
+    conditions.extend(canary.as_ref().map(canary::canary_condition));
+    let status = remapper::update_status(
         remapper,
         &ctx.client,
+        &ctx.children,
         &ns,
         &config_map_name,
-        &deployment_name,
+        &workload_name,
         &service_name,
+        conditions,
+        canary.clone(),
     )
     .await?;
+    publish_condition_warning(
+        remapper,
+        ctx,
+        &status,
+        "WorkloadHealthy",
+        "WorkloadUnhealthy",
+    )
+    .await;
+    publish_condition_warning(
+        remapper,
+        ctx,
+        &status,
+        "CertificatesFresh",
+        "CertificateExpiring",
+    )
+    .await;
+    publish_condition_warning(
+        remapper,
+        ctx,
+        &status,
+        "OffsetRangeHeadroom",
+        "OffsetHeadroomLow",
+    )
+    .await;
+
+    // Come back when the bake period ends rather than waiting a full interval
+    let progressing = settings::current().requeue.progressing();
+    match canary {
+        Some(ref c) if c.state == "Progressing" => {
+            return Ok(metrics::requeue(
+                "KafkaPartitionRemapper",
+                "canary_progressing",
+                progressing,
+            ))
+        }
+        Some(ref c) if c.state == "Baking" => {
+            let remaining = c
+                .healthy_since
+                .map(|since| canary::bake_remaining(since, canary::bake_time(remapper), Utc::now()))
+                .unwrap_or(progressing);
+            return Ok(metrics::requeue(
+                "KafkaPartitionRemapper",
+                "canary_baking",
+                remaining.min(progressing),
+            ));
+        }
+        _ => {}
+    }
+    if reload_pending > 0 {
+        return Ok(metrics::requeue(
+            "KafkaPartitionRemapper",
+            "reload_pending",
+            progressing,
+        ));
+    }
 
-    // Requeue after 30 seconds to check deployment status
-    Ok(Action::requeue(Duration::from_secs(30)))
+    // The Service watch reports the load balancer address as soon as it is
+    // assigned; poll as a fallback, backing off while the provider is slow
+    if let Some(pending) = status
+        .conditions
+        .iter()
+        .find(|c| c.type_ == "LoadBalancerReady" && c.status == "False")
+    {
+        return Ok(metrics::requeue(
+            "KafkaPartitionRemapper",
+            "load_balancer_pending",
+            remapper::load_balancer_requeue(pending.last_transition_time, Utc::now(), progressing),
+        ));
+    }
+
+    // Owned resource watches drive steady-state reconciles; only poll while
+    // the rollout is still converging, or to notice certificates ageing and
+    // user passwords falling due for rotation
+    match status.phase.as_deref().unwrap_or_default() {
+        "Running" | "Suspended" => Ok(
+            match [
+                certificates::recheck_after(&status.certificates, Utc::now()),
+                users::recheck_after(&status.users, Utc::now()),
+            ]
+            .into_iter()
+            .flatten()
+            .min()
+            {
+                Some(after) => metrics::requeue("KafkaPartitionRemapper", "recheck", after),
+                None => Action::await_change(),
+            },
+        ),
+        _ => Ok(metrics::requeue(
+            "KafkaPartitionRemapper",
+            "progressing",
+            settings::current().requeue.progressing(),
+        )),
+    }
+}
+
+/// Publish an Event when Kafka reachability changes
+///
+/// Only transitions are reported so an outage doesn't flood the Event log.
+async fn publish_reachability_event(
+    remapper: &KafkaPartitionRemapper,
+    ctx: &Context,
+    condition: &Condition,
+) {
+    let previous = remapper
+        .status
+        .as_ref()
+        .and_then(|s| s.conditions.iter().find(|c| c.type_ == condition.type_))
+        .map(|c| c.status.as_str());
+
+    let event = match (previous, condition.status.as_str()) {
+        (Some("False"), "False") => return,
+        (_, "False") => Event {
+            type_: EventType::Warning,
+            reason: "KafkaUnreachable".to_string(),
+            note: condition.message.clone(),
+            action: "Probe".to_string(),
+            secondary: None,
+        },
+        (Some("False"), "True") => Event {
+            type_: EventType::Normal,
+            reason: "KafkaReachable".to_string(),
+            note: condition.message.clone(),
+            action: "Probe".to_string(),
+            secondary: None,
+        },
+        _ => return,
+    };
+
+    publish_event(remapper, ctx, event).await;
+}
+
+/// Publish a Warning Event when a status condition turns False
+///
+/// Each new failure reason is reported once.
+async fn publish_condition_warning(
+    remapper: &KafkaPartitionRemapper,
+    ctx: &Context,
+    status: &KafkaPartitionRemapperStatus,
+    condition_type: &str,
+    event_reason: &str,
+) {
+    let find = |conditions: &[Condition]| {
+        conditions
+            .iter()
+            .find(|c| c.type_ == condition_type)
+            .cloned()
+    };
+    let previous = remapper.status.as_ref().and_then(|s| find(&s.conditions));
+    let Some(current) = find(&status.conditions) else {
+        return;
+    };
+
+    if current.status != "False"
+        || previous.is_some_and(|p| p.status == "False" && p.reason == current.reason)
+    {
+        return;
+    }
+
+    let event = Event {
+        type_: EventType::Warning,
+        reason: event_reason.to_string(),
+        note: current.message,
+        action: "Reconcile".to_string(),
+        secondary: None,
+    };
+    publish_event(remapper, ctx, event).await;
+}
+
+/// Surface a rejected spec as a Failed status and Warning Event
+///
+/// As with field conflicts, the Event is only published when the reason
+/// changes.
+async fn report_invalid_spec(remapper: &KafkaPartitionRemapper, ctx: &Context, message: &str) {
+    let ns = remapper.namespace().unwrap_or_default();
+    let previous = remapper
+        .status
+        .as_ref()
+        .and_then(|s| {
+            s.conditions
+                .iter()
+                .find(|c| c.type_ == "ConfigValid" && c.status == "False")
+        })
+        .and_then(|c| c.message.clone());
+
+    if let Err(e) = remapper::update_invalid_spec_status(remapper, &ctx.client, &ns, message).await
+    {
+        warn!("Failed to record invalid spec: {}", e);
+    }
+
+    if previous.as_deref() != Some(message) {
+        let event = Event {
+            type_: EventType::Warning,
+            reason: "InvalidSpec".to_string(),
+            note: Some(message.to_string()),
+            action: "Reconcile".to_string(),
+            secondary: None,
+        };
+        publish_event(remapper, ctx, event).await;
+    }
+}
+
+/// Mark a remapper Stalled once its failures exceed the retry budget
+///
+/// Rejected specs and field conflicts already report themselves as Stalled.
+/// The Warning Event is only published when the budget is first exceeded.
+async fn report_retries_exhausted(
+    remapper: &KafkaPartitionRemapper,
+    ctx: &Context,
+    failures: u32,
+    err: &Error,
+) {
+    if matches!(
+        err,
+        Error::ValidationError(_) | Error::ConfigError(_) | Error::FieldConflict(_)
+    ) {
+        return;
+    }
+    let ns = remapper.namespace().unwrap_or_default();
+    let message = err.to_string();
+
+    if let Err(e) =
+        remapper::update_retries_exhausted_status(remapper, &ctx.client, &ns, failures, &message)
+            .await
+    {
+        warn!("Failed to record exhausted retries: {}", e);
+    }
+
+    if !settings::current().requeue.budget_exhausted(failures - 1) {
+        let event = Event {
+            type_: EventType::Warning,
+            reason: "RetriesExhausted".to_string(),
+            note: Some(format!(
+                "Reconcile failed {} times in a row: {}",
+                failures, message
+            )),
+            action: "Reconcile".to_string(),
+            secondary: None,
+        };
+        publish_event(remapper, ctx, event).await;
+    }
+}
+
+/// Surface a server-side apply conflict as a condition and Warning Event
+///
+/// The Event is only published when the conflict is new, so a conflict
+/// persisting across requeues does not flood the remapper's Events.
+async fn report_field_conflict(remapper: &KafkaPartitionRemapper, ctx: &Context, message: &str) {
+    let ns = remapper.namespace().unwrap_or_default();
+    let previous = remapper
+        .status
+        .as_ref()
+        .and_then(|s| s.conditions.iter().find(|c| c.type_ == "FieldConflict"))
+        .and_then(|c| c.message.clone());
+
+    if let Err(e) =
+        remapper::update_field_conflict_status(remapper, &ctx.client, &ns, message).await
+    {
+        warn!("Failed to record field conflict: {}", e);
+    }
+
+    if previous.as_deref() != Some(message) {
+        let event = Event {
+            type_: EventType::Warning,
+            reason: "FieldConflict".to_string(),
+            note: Some(message.to_string()),
+            action: "Reconcile".to_string(),
+            secondary: None,
+        };
+        publish_event(remapper, ctx, event).await;
+    }
+}
+
+/// Publish an Event when a canary passes or fails
+async fn publish_canary_event(
+    remapper: &KafkaPartitionRemapper,
+    ctx: &Context,
+    canary: Option<&CanaryStatus>,
+) {
+    let Some(canary) = canary else {
+        return;
+    };
+    let previous = remapper.status.as_ref().and_then(|s| s.canary.as_ref());
+    if previous.is_some_and(|p| p.config_hash == canary.config_hash && p.state == canary.state) {
+        return;
+    }
+
+    let (type_, reason) = match canary.state.as_str() {
+        "Passed" => (EventType::Normal, "CanaryPromoted"),
+        "Failed" => (EventType::Warning, "CanaryFailed"),
+        _ => return,
+    };
+    publish_event(
+        remapper,
+        ctx,
+        Event {
+            type_,
+            reason: reason.to_string(),
+            note: canary.message.clone(),
+            action: "Rollout".to_string(),
+            secondary: None,
+        },
+    )
+    .await;
+}
+
+/// Publish an Event for a remapper, logging rather than failing on error
+async fn publish_event(remapper: &KafkaPartitionRemapper, ctx: &Context, event: Event) {
+    if let Err(e) = ctx.recorder(remapper).publish(event).await {
+        warn!("Failed to publish event: {}", e);
+    }
 }
 
 /// Cleanup resources when a KafkaPartitionRemapper is deleted
-async fn cleanup(remapper: &KafkaPartitionRemapper, _ctx: &Context) -> Result<Action, Error> {
+async fn cleanup(remapper: &KafkaPartitionRemapper, ctx: &Context) -> Result<Action, Error> {
     let ns = remapper.namespace().unwrap_or_default();
     let name = remapper.name_any();
 
-    info!("Cleaning up KafkaPartitionRemapper {}/{}", ns, name);
+    info!(
+        "Cleaning up KafkaPartitionRemapper {}/{} with deletion policy {:?}",
+        ns, name, remapper.spec.deletion_policy
+    );
 
-    // Resources are cleaned up automatically via owner references
-    // Just log and return
+    remapper::cleanup_children(remapper, &ctx.client, &remapper.target_namespace()).await?;
 
     Ok(Action::await_change())
 }
 
 /// Error policy for the controller
-fn error_policy(remapper: Arc<KafkaPartitionRemapper>, err: &Error, _ctx: Arc<Context>) -> Action {
+fn error_policy(remapper: Arc<KafkaPartitionRemapper>, err: &Error, ctx: Arc<Context>) -> Action {
     let ns = remapper.namespace().unwrap_or_default();
     let name = remapper.name_any();
 
     error!("Reconciliation error for {}/{}: {:?}", ns, name, err);
 
-    // Requeue with exponential backoff based on error type
-    match err {
-        Error::KubeError(_) => Action::requeue(Duration::from_secs(30)),
-        Error::ConfigError(_) | Error::ValidationError(_) => {
-            Action::requeue(Duration::from_secs(300))
-        }
-        _ => Action::requeue(Duration::from_secs(60)),
-    }
+    // Back off per object from the interval for the kind of error
+    let requeue = &settings::current().requeue;
+    let base = err.retry_after(requeue);
+    let failures = ctx.retries.failures(remapper.as_ref()).max(1);
+    metrics::requeue(
+        "KafkaPartitionRemapper",
+        "error",
+        requeue.backoff(base, failures, jitter()),
+    )
 }
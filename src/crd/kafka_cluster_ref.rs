@@ -0,0 +1,82 @@
+//! KafkaClusterRef Custom Resource Definition
+
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::kafka_partition_remapper::{
+    KafkaClusterSpec, KerberosSpec, SaslSecretRef, SecurityProtocol, TlsSecretRef,
+};
+
+/// Shared Kafka connection definition referenced by remappers
+///
+/// Secret references are resolved in the namespace of each referencing
+/// KafkaPartitionRemapper, since proxy pods can only mount local Secrets.
+#[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "kafka.oso.sh",
+    version = "v1alpha1",
+    kind = "KafkaClusterRef",
+    plural = "kafkaclusterrefs",
+    singular = "kafkaclusterref",
+    shortname = "kcr",
+    printcolumn = r#"{"name": "Protocol", "type": "string", "jsonPath": ".spec.securityProtocol"}"#,
+    printcolumn = r#"{"name": "Age", "type": "date", "jsonPath": ".metadata.creationTimestamp"}"#,
+    category = "kafka"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaClusterRefSpec {
+    /// Bootstrap servers
+    pub bootstrap_servers: Vec<String>,
+
+    /// Security protocol
+    #[serde(default)]
+    pub security_protocol: SecurityProtocol,
+
+    /// TLS configuration for broker connections
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_secret: Option<TlsSecretRef>,
+
+    /// SASL configuration for broker connections
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sasl_secret: Option<SaslSecretRef>,
+
+    /// Kerberos (SASL GSSAPI) authentication for broker connections
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kerberos: Option<KerberosSpec>,
+}
+
+impl KafkaClusterRefSpec {
+    /// Fill the connection fields of a remapper's Kafka spec from this reference
+    ///
+    /// Timeouts stay as configured on the remapper; `clusterRef` is cleared
+    /// so the result validates like an inline definition.
+    pub fn resolve_into(&self, kafka: &mut KafkaClusterSpec) {
+        kafka.bootstrap_servers = self.bootstrap_servers.clone();
+        kafka.security_protocol = self.security_protocol;
+        kafka.tls_secret = self.tls_secret.clone();
+        kafka.sasl_secret = self.sasl_secret.clone();
+        kafka.kerberos = self.kerberos.clone();
+        kafka.cluster_ref = None;
+    }
+
+    /// Names of the Secrets referenced by this connection definition
+    pub fn referenced_secrets(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .tls_secret
+            .iter()
+            .filter(|t| t.secret_provider_class.is_none())
+            .map(|t| t.name.clone())
+            .chain(
+                self.sasl_secret
+                    .iter()
+                    .filter(|s| s.secret_provider_class.is_none())
+                    .map(|s| s.name.clone()),
+            )
+            .chain(self.kerberos.iter().map(|k| k.keytab_secret.name.clone()))
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+}
@@ -0,0 +1,98 @@
+//! KafkaPartitionRemapTask Custom Resource Definition
+
+use chrono::{DateTime, Utc};
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::kafka_partition_remapper::MappingSpec;
+
+/// Safe migration of a KafkaPartitionRemapper to a new partition mapping
+///
+/// A second proxy generation is started with the target mapping, consumer
+/// groups are tracked until they only connect through it, and then the
+/// remapper's Service is cut over and the remapper itself adopts the mapping.
+#[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "kafka.oso.sh",
+    version = "v1alpha1",
+    kind = "KafkaPartitionRemapTask",
+    plural = "kafkapartitionremaptasks",
+    singular = "kafkapartitionremaptask",
+    shortname = "kprt",
+    namespaced,
+    status = "KafkaPartitionRemapTaskStatus",
+    printcolumn = r#"{"name": "Remapper", "type": "string", "jsonPath": ".spec.remapperRef"}"#,
+    printcolumn = r#"{"name": "Phase", "type": "string", "jsonPath": ".status.phase"}"#,
+    printcolumn = r#"{"name": "Generation", "type": "string", "jsonPath": ".status.generationName"}"#,
+    printcolumn = r#"{"name": "Age", "type": "date", "jsonPath": ".metadata.creationTimestamp"}"#,
+    category = "kafka"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaPartitionRemapTaskSpec {
+    /// Name of the KafkaPartitionRemapper in the same namespace
+    pub remapper_ref: String,
+
+    /// Mapping served by the new proxy generation
+    pub target_mapping: MappingSpec,
+
+    /// Consumer groups that must move to the new generation before cutover
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consumer_groups: Vec<String>,
+
+    /// Cut the Service over as soon as every consumer group has migrated
+    /// When false, the task waits in AwaitingCutover until this is set
+    #[serde(default = "default_true")]
+    pub auto_cutover: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// KafkaPartitionRemapTask status
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaPartitionRemapTaskStatus {
+    /// Current phase (Pending, Provisioning, Migrating, AwaitingCutover,
+    /// CuttingOver, Completed, Failed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+
+    /// Human-readable message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// Name of the proxy generation serving the target mapping
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation_name: Option<String>,
+
+    /// Migration progress per consumer group
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consumer_groups: Vec<ConsumerGroupProgress>,
+
+    /// When the task started
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<Utc>>,
+
+    /// When the task completed or failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_time: Option<DateTime<Utc>>,
+}
+
+/// Migration progress of one consumer group
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsumerGroupProgress {
+    /// Consumer group id
+    pub group: String,
+
+    /// Group state reported by the coordinator
+    pub state: String,
+
+    /// Members still connected through the previous generation
+    pub remaining_members: u32,
+
+    /// Whether the group now only consumes through the new generation
+    pub migrated: bool,
+}
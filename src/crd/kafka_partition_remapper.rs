@@ -1,9 +1,15 @@
 //! KafkaPartitionRemapper Custom Resource Definition
 
 use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::{
+    Affinity, Container, EnvFromSource, EnvVar, PodSecurityContext, SecurityContext,
+    TopologySpreadConstraint, Volume, VolumeMount,
+};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use kube::CustomResource;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::BTreeMap;
 
 /// KafkaPartitionRemapper resource specification
@@ -17,12 +23,18 @@ use std::collections::BTreeMap;
     shortname = "kpr",
     namespaced,
     status = "KafkaPartitionRemapperStatus",
+    scale = r#"{"specReplicasPath": ".spec.replicas", "statusReplicasPath": ".status.replicas", "labelSelectorPath": ".status.selector"}"#,
     printcolumn = r#"{"name": "Phase", "type": "string", "jsonPath": ".status.phase"}"#,
     printcolumn = r#"{"name": "Ready", "type": "string", "jsonPath": ".status.readyReplicas"}"#,
     printcolumn = r#"{"name": "Replicas", "type": "integer", "jsonPath": ".spec.replicas"}"#,
+    printcolumn = r#"{"name": "Up-to-date", "type": "integer", "jsonPath": ".status.updatedReplicas"}"#,
+    printcolumn = r#"{"name": "Available", "type": "integer", "jsonPath": ".status.availableReplicas"}"#,
     printcolumn = r#"{"name": "Endpoint", "type": "string", "jsonPath": ".status.serviceEndpoint"}"#,
-    printcolumn = r#"{"name": "Ratio", "type": "string", "jsonPath": ".status.compressionRatio"}"#,
-    printcolumn = r#"{"name": "Age", "type": "date", "jsonPath": ".metadata.creationTimestamp"}"#
+    printcolumn = r#"{"name": "Ratio", "type": "string", "jsonPath": ".status.ratio"}"#,
+    printcolumn = r#"{"name": "Suspend", "type": "boolean", "jsonPath": ".spec.suspend"}"#,
+    printcolumn = r#"{"name": "Age", "type": "date", "jsonPath": ".metadata.creationTimestamp"}"#,
+    category = "all",
+    category = "kafka"
 )]
 #[serde(rename_all = "camelCase")]
 pub struct KafkaPartitionRemapperSpec {
@@ -30,12 +42,51 @@ pub struct KafkaPartitionRemapperSpec {
     #[serde(default = "default_replicas")]
     pub replicas: i32,
 
-    /// TCP listener configuration for client connections
-    pub listen: ListenSpec,
+    /// Workload kind used to run the proxy pods
+    /// StatefulSet mode gives every pod a stable advertised address
+    #[serde(default)]
+    pub workload: WorkloadKind,
+
+    /// TCP listeners for client connections
+    /// The first listener is the primary one reported as the service endpoint
+    #[serde(default = "default_listeners")]
+    pub listeners: Vec<ListenerSpec>,
+
+    /// Client users whose SCRAM credentials the operator generates
+    /// Each user gets an owned `<name>-user-<user>` Secret with its password,
+    /// and SASL listeners accept all generated users
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<ProxyUserSpec>,
 
     /// Kafka cluster connection configuration
     pub kafka: KafkaClusterSpec,
 
+    /// Topics served by other Kafka clusters, routed by name through the
+    /// same virtual endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing: Option<RoutingSpec>,
+
+    /// Topics clients may access through the proxy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access: Option<AccessSpec>,
+
+    /// Throughput and connection limits per client id or user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quotas: Option<QuotasSpec>,
+
+    /// How the proxy handles consumer group coordination
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer_groups: Option<ConsumerGroupsSpec>,
+
+    /// Proxy runtime and socket tuning for throughput-sensitive deployments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tuning: Option<TuningSpec>,
+
+    /// Schema Registry the proxy forwards registry traffic to and rewrites
+    /// client references for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistrySpec>,
+
     /// Partition remapping configuration
     pub mapping: MappingSpec,
 
@@ -47,27 +98,529 @@ pub struct KafkaPartitionRemapperSpec {
     #[serde(default)]
     pub logging: LoggingSpec,
 
+    /// Audit records of client requests, tracing who produced and consumed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audit_logging: Option<AuditLoggingSpec>,
+
     /// Kubernetes Service configuration
     #[serde(default)]
     pub service: ServiceSpec,
 
+    /// Additional Service exposing the proxy outside the cluster
+    /// Created alongside the internal Service as `<name>-external`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_service: Option<ExternalServiceSpec>,
+
+    /// Gateway API integration routing a listener through a TCPRoute
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<GatewaySpec>,
+
     /// Pod template customizations
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pod_template: Option<PodTemplateSpec>,
 
+    /// Labels added to every child resource and the proxy pods
+    /// The operator's own app.kubernetes.io labels cannot be overridden
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub common_labels: BTreeMap<String, String>,
+
+    /// Annotations added to every child resource and the proxy pods
+    /// Resource-specific annotations take precedence
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub common_annotations: BTreeMap<String, String>,
+
+    /// Liveness, readiness and startup probe overrides for the proxy container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub probes: Option<ProbesSpec>,
+
+    /// Readiness gating for the proxy pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readiness: Option<ReadinessSpec>,
+
+    /// PodDisruptionBudget configuration for proxy replicas
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pod_disruption_budget: Option<PodDisruptionBudgetSpec>,
+
+    /// HorizontalPodAutoscaler configuration
+    /// When enabled, the operator no longer manages the Deployment replica count
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autoscaling: Option<AutoscalingSpec>,
+
+    /// Partition-range sharding across replicas
+    /// Requires the StatefulSet workload; each replica serves one shard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sharding: Option<ShardingSpec>,
+
+    /// Scheduled consistency audit of the upstream cluster against the
+    /// mapping, reported in `status.audit`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audit: Option<ConsistencyAuditSpec>,
+
+    /// How spec changes are rolled out to the proxy pods
+    /// Canary requires the Deployment workload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rollout_strategy: Option<RolloutStrategySpec>,
+
+    /// How config-only changes reach running proxies
+    /// Hot applies log level, topic override and maintenance mode changes
+    /// without restarting pods
+    #[serde(default)]
+    pub reload_policy: ReloadPolicy,
+
+    /// What happens to child resources when the remapper is deleted
+    #[serde(default)]
+    pub deletion_policy: DeletionPolicy,
+
     /// Suspend proxy (scale to 0)
     #[serde(default)]
     pub suspend: bool,
+
+    /// Freeze client traffic while keeping the endpoint up: ReadOnly rejects
+    /// produce requests and Blocked rejects every request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintenance_mode: Option<MaintenanceMode>,
+
+    /// Suffix appended to the remapper name in child resource names,
+    /// giving `<name>-<nameOverride>`
+    /// Cannot be changed once set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_override: Option<String>,
+
+    /// Base name for child resources, replacing the remapper name entirely
+    /// Takes precedence over nameOverride and cannot be changed once set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fullname_override: Option<String>,
+
+    /// Namespace the child resources are deployed into, defaulting to the
+    /// remapper's own namespace
+    /// Children in another namespace are tracked by owner labels and removed
+    /// by the finalizer, as owner references cannot cross namespaces.
+    /// Referenced Secrets must exist in this namespace. Cannot be changed once
+    /// set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_namespace: Option<String>,
 }
 
 fn default_replicas() -> i32 {
     1
 }
 
+/// Annotation that freezes all child-resource mutation when set to "true"
+pub const PAUSED_ANNOTATION: &str = "kafka.oso.sh/paused";
+
+/// Annotation allowing the remapper to take over unowned, pre-existing children
+pub const ADOPT_ANNOTATION: &str = "kafka.oso.sh/adopt";
+
+/// Annotation allowing mapping changes that break existing offset translation
+pub const ALLOW_BREAKING_CHANGE_ANNOTATION: &str = "kafka.oso.sh/allow-breaking-change";
+
+/// Annotation whose value is copied onto the proxy pod template, so changing
+/// it restarts the proxies like `kubectl rollout restart`
+pub const RESTARTED_AT_ANNOTATION: &str = "kafka.oso.sh/restartedAt";
+
+/// Annotation naming a KafkaPartitionRemapTask generation the Service routes to
+pub const SERVING_GENERATION_ANNOTATION: &str = "kafka.oso.sh/serving-generation";
+
+/// Label naming the remapper that owns a child in another namespace
+pub const OWNER_NAME_LABEL: &str = "kafka.oso.sh/owner-name";
+
+/// Label naming the namespace of the remapper that owns a child in another
+/// namespace
+pub const OWNER_NAMESPACE_LABEL: &str = "kafka.oso.sh/owner-namespace";
+
+/// Longest base name for child resources
+///
+/// Leaves room for the longest suffix the operator appends
+/// (`-users-previous`) and for the revision hash Kubernetes appends to
+/// StatefulSet pod labels, keeping names and label values within 63
+/// characters.
+pub const MAX_RESOURCE_NAME_LEN: usize = 40;
+
+/// Shorten a child resource base name to [`MAX_RESOURCE_NAME_LEN`]
+///
+/// Longer names keep their head and end in a hash of the full name, so
+/// distinct long names stay distinct.
+pub fn safe_resource_name(name: &str) -> String {
+    if name.len() <= MAX_RESOURCE_NAME_LEN {
+        return name.to_string();
+    }
+    let digest = Sha256::digest(name.as_bytes());
+    let hash: String = digest
+        .iter()
+        .take(4)
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    let head = name[..MAX_RESOURCE_NAME_LEN - hash.len() - 1].trim_end_matches(['-', '.']);
+    format!("{}-{}", head, hash)
+}
+
+impl KafkaPartitionRemapper {
+    /// Whether reconciliation is paused via the `kafka.oso.sh/paused` annotation
+    pub fn is_paused(&self) -> bool {
+        self.annotation_is_true(PAUSED_ANNOTATION)
+    }
+
+    /// Whether breaking mapping changes are allowed via the
+    /// `kafka.oso.sh/allow-breaking-change` annotation
+    pub fn breaking_change_allowed(&self) -> bool {
+        self.annotation_is_true(ALLOW_BREAKING_CHANGE_ANNOTATION)
+    }
+
+    /// Whether adoption of pre-existing children is allowed via the
+    /// `kafka.oso.sh/adopt` annotation
+    pub fn adoption_requested(&self) -> bool {
+        self.annotation_is_true(ADOPT_ANNOTATION)
+    }
+
+    /// Restart requested via the `kafka.oso.sh/restartedAt` annotation
+    pub fn restarted_at(&self) -> Option<&str> {
+        self.metadata
+            .annotations
+            .as_ref()?
+            .get(RESTARTED_AT_ANNOTATION)
+            .map(String::as_str)
+            .filter(|v| !v.is_empty())
+    }
+
+    /// Base name of the remapper's child resources
+    ///
+    /// The fullnameOverride, the name with the nameOverride suffix, or the
+    /// remapper name, shortened by [`safe_resource_name`].
+    pub fn resource_name(&self) -> String {
+        let name = self.metadata.name.as_deref().unwrap_or_default();
+        let base = match (&self.spec.fullname_override, &self.spec.name_override) {
+            (Some(fullname), _) => fullname.clone(),
+            (None, Some(suffix)) => format!("{}-{}", name, suffix),
+            (None, None) => name.to_string(),
+        };
+        safe_resource_name(&base)
+    }
+
+    /// Namespace the child resources are deployed into
+    pub fn target_namespace(&self) -> String {
+        self.spec
+            .target_namespace
+            .clone()
+            .or_else(|| self.metadata.namespace.clone())
+            .unwrap_or_default()
+    }
+
+    /// Whether the child resources live outside the remapper's namespace
+    pub fn deploys_cross_namespace(&self) -> bool {
+        self.spec
+            .target_namespace
+            .as_ref()
+            .is_some_and(|ns| self.metadata.namespace.as_ref() != Some(ns))
+    }
+
+    /// Proxy generation the Service routes to during a remap task cutover
+    pub fn serving_generation(&self) -> Option<&str> {
+        self.metadata
+            .annotations
+            .as_ref()
+            .and_then(|a| a.get(SERVING_GENERATION_ANNOTATION))
+            .map(String::as_str)
+            .filter(|g| !g.is_empty())
+    }
+
+    fn annotation_is_true(&self, key: &str) -> bool {
+        self.metadata
+            .annotations
+            .as_ref()
+            .and_then(|a| a.get(key))
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"))
+    }
+}
+
+/// Workload kind used to run the proxy pods
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub enum WorkloadKind {
+    /// Interchangeable pods behind a single Service address
+    #[default]
+    Deployment,
+    /// Pods with stable identities and per-pod advertised addresses
+    StatefulSet,
+}
+
+/// What happens to child resources when the remapper is deleted
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub enum DeletionPolicy {
+    /// Delete the proxy workload, Services and configuration
+    #[default]
+    Delete,
+    /// Keep child resources running, detached from the remapper
+    Retain,
+}
+
+impl KafkaPartitionRemapperSpec {
+    /// Upper bound on the number of proxy pods that may run at once
+    pub fn max_replica_count(&self) -> i32 {
+        match self.autoscaling {
+            Some(ref a) if self.autoscaling_enabled() => a.max_replicas.max(self.replicas),
+            _ => self.replicas,
+        }
+    }
+
+    /// Whether each replica serves a disjoint range of virtual partitions
+    pub fn sharding_enabled(&self) -> bool {
+        self.sharding.as_ref().is_some_and(|s| s.enabled)
+    }
+
+    /// Whether spec changes are first rolled out to canary replicas
+    pub fn canary_enabled(&self) -> bool {
+        self.rollout_strategy
+            .as_ref()
+            .is_some_and(|r| r.type_ == RolloutStrategyType::Canary)
+    }
+
+    /// Whether replica count is delegated to a HorizontalPodAutoscaler
+    pub fn autoscaling_enabled(&self) -> bool {
+        !self.suspend && self.autoscaling.as_ref().is_some_and(|a| a.enabled)
+    }
+
+    /// Whether autoscaling is driven by a KEDA ScaledObject instead of our HPA
+    pub fn keda_enabled(&self) -> bool {
+        self.autoscaling_enabled()
+            && self
+                .autoscaling
+                .as_ref()
+                .and_then(|a| a.keda.as_ref())
+                .is_some_and(|k| k.enabled)
+    }
+
+    /// Whether readiness is gated on broker connectivity
+    pub fn kafka_readiness_required(&self) -> bool {
+        self.readiness.as_ref().is_some_and(|r| r.require_kafka)
+    }
+
+    /// Whether any generated user has a password rotation schedule
+    pub fn user_rotation_enabled(&self) -> bool {
+        self.users.iter().any(|u| u.rotation.is_some())
+    }
+
+    /// Prefix the proxy adds to group ids on the upstream cluster
+    pub fn group_id_prefix(&self) -> &str {
+        self.consumer_groups
+            .as_ref()
+            .and_then(|g| g.group_id_prefix.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Clusters besides the primary one: failover clusters, then routed ones
+    pub fn upstream_clusters(&self) -> impl Iterator<Item = &UpstreamClusterSpec> {
+        self.kafka
+            .failover_clusters
+            .iter()
+            .chain(self.routing.iter().flat_map(|r| r.clusters.iter()))
+    }
+
+    /// Primary listener, used for probes and the reported service endpoint
+    pub fn primary_listener(&self) -> ListenerSpec {
+        self.listeners.first().cloned().unwrap_or_default()
+    }
+
+    /// Certificates mounted from Secrets, as (secret name, key) pairs
+    pub fn referenced_certificates(&self) -> Vec<(String, String)> {
+        let mut certificates = Vec::new();
+
+        if let Some(tls) = self
+            .kafka
+            .tls_secret
+            .as_ref()
+            .filter(|t| t.secret_provider_class.is_none())
+        {
+            certificates.push((tls.name.clone(), tls.ca_key.clone()));
+            if let Some(ref cert_key) = tls.cert_key {
+                certificates.push((tls.name.clone(), cert_key.clone()));
+            }
+        }
+        for tls in self
+            .upstream_clusters()
+            .filter_map(|c| c.tls_secret.as_ref())
+            .filter(|t| t.secret_provider_class.is_none())
+        {
+            certificates.push((tls.name.clone(), tls.ca_key.clone()));
+            if let Some(ref cert_key) = tls.cert_key {
+                certificates.push((tls.name.clone(), cert_key.clone()));
+            }
+        }
+        if let Some(tls) = self
+            .schema_registry
+            .as_ref()
+            .and_then(|r| r.tls_secret.as_ref())
+            .filter(|t| t.secret_provider_class.is_none())
+        {
+            certificates.push((tls.name.clone(), tls.ca_key.clone()));
+            if let Some(ref cert_key) = tls.cert_key {
+                certificates.push((tls.name.clone(), cert_key.clone()));
+            }
+        }
+        for tls in self
+            .listeners
+            .iter()
+            .filter_map(|l| l.security.as_ref()?.tls.as_ref())
+        {
+            certificates.push((
+                tls.certificate_secret.name.clone(),
+                tls.certificate_secret.cert_key.clone(),
+            ));
+            if let Some(ref ca) = tls.client_ca_secret {
+                certificates.push((ca.name.clone(), ca.key.clone()));
+            }
+        }
+
+        certificates.sort();
+        certificates.dedup();
+        certificates
+    }
+
+    /// Listeners exposed by the external Service, or none when it is disabled
+    pub fn external_listeners(&self) -> Vec<&ListenerSpec> {
+        match self.external_service {
+            Some(ref external) if external.enabled => self
+                .listeners
+                .iter()
+                .filter(|l| external.listeners.is_empty() || external.listeners.contains(&l.name))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Keys each referenced Secret must contain, by Secret name
+    ///
+    /// Listener SASL credentials hold one key per username, so only their
+    /// Secret's existence is required.
+    pub fn required_secret_keys(&self) -> BTreeMap<String, Vec<String>> {
+        let mut required: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let mut require = |name: &str, keys: Vec<&str>| {
+            let entry = required.entry(name.to_string()).or_default();
+            entry.extend(keys.into_iter().map(str::to_string));
+            entry.sort();
+            entry.dedup();
+        };
+
+        let broker_credentials = std::iter::once((&self.kafka.tls_secret, &self.kafka.sasl_secret))
+            .chain(
+                self.upstream_clusters()
+                    .map(|c| (&c.tls_secret, &c.sasl_secret)),
+            );
+        for (tls_secret, sasl_secret) in broker_credentials {
+            if let Some(tls) = tls_secret {
+                if tls.secret_provider_class.is_none() {
+                    let keys = std::iter::once(tls.ca_key.as_str())
+                        .chain(tls.cert_key.as_deref())
+                        .chain(tls.key_key.as_deref())
+                        .collect();
+                    require(&tls.name, keys);
+                }
+            }
+            if let Some(sasl) = sasl_secret {
+                if sasl.secret_provider_class.is_none() {
+                    require(&sasl.name, vec![&sasl.username_key, &sasl.password_key]);
+                }
+            }
+        }
+        if let Some(ref kerberos) = self.kafka.kerberos {
+            require(
+                &kerberos.keytab_secret.name,
+                vec![&kerberos.keytab_secret.key],
+            );
+        }
+        if let Some(ref registry) = self.schema_registry {
+            if let Some(tls) = registry
+                .tls_secret
+                .as_ref()
+                .filter(|t| t.secret_provider_class.is_none())
+            {
+                let keys = std::iter::once(tls.ca_key.as_str())
+                    .chain(tls.cert_key.as_deref())
+                    .chain(tls.key_key.as_deref())
+                    .collect();
+                require(&tls.name, keys);
+            }
+            if let Some(ref auth) = registry.auth_secret {
+                require(&auth.name, vec![&auth.username_key, &auth.password_key]);
+            }
+        }
+        for security in self.listeners.iter().filter_map(|l| l.security.as_ref()) {
+            if let Some(ref tls) = security.tls {
+                let cert = &tls.certificate_secret;
+                require(&cert.name, vec![&cert.cert_key, &cert.key_key]);
+                if let Some(ref ca) = tls.client_ca_secret {
+                    require(&ca.name, vec![&ca.key]);
+                }
+            }
+            if let Some(ref sasl) = security.sasl {
+                require(&sasl.credentials_secret.name, vec![]);
+            }
+        }
+
+        required
+    }
+
+    /// Names of all Secrets referenced by this spec, sorted and deduplicated
+    pub fn referenced_secrets(&self) -> Vec<String> {
+        let mut names = Vec::new();
+
+        let broker_credentials = std::iter::once((&self.kafka.tls_secret, &self.kafka.sasl_secret))
+            .chain(
+                self.upstream_clusters()
+                    .map(|c| (&c.tls_secret, &c.sasl_secret)),
+            );
+        for (tls_secret, sasl_secret) in broker_credentials {
+            if let Some(tls) = tls_secret {
+                if tls.secret_provider_class.is_none() {
+                    names.push(tls.name.clone());
+                }
+            }
+            if let Some(sasl) = sasl_secret {
+                if sasl.secret_provider_class.is_none() {
+                    names.push(sasl.name.clone());
+                }
+            }
+        }
+        if let Some(ref kerberos) = self.kafka.kerberos {
+            names.push(kerberos.keytab_secret.name.clone());
+        }
+        if let Some(ref registry) = self.schema_registry {
+            if let Some(tls) = registry
+                .tls_secret
+                .as_ref()
+                .filter(|t| t.secret_provider_class.is_none())
+            {
+                names.push(tls.name.clone());
+            }
+            if let Some(ref auth) = registry.auth_secret {
+                names.push(auth.name.clone());
+            }
+        }
+        for security in self.listeners.iter().filter_map(|l| l.security.as_ref()) {
+            if let Some(ref tls) = security.tls {
+                names.push(tls.certificate_secret.name.clone());
+                if let Some(ref ca) = tls.client_ca_secret {
+                    names.push(ca.name.clone());
+                }
+            }
+            if let Some(ref sasl) = security.sasl {
+                names.push(sasl.credentials_secret.name.clone());
+            }
+        }
+
+        names.sort();
+        names.dedup();
+        names
+    }
+}
+
 /// TCP listener configuration for client connections
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct ListenSpec {
+pub struct ListenerSpec {
+    /// Listener name, used for the container and Service port names
+    /// (lowercase alphanumerics and '-', at most 15 characters)
+    #[serde(default = "default_listener_name")]
+    pub name: String,
+
     /// Port to listen on (container port)
     #[serde(default = "default_listen_port")]
     pub port: i32,
@@ -78,29 +631,89 @@ pub struct ListenSpec {
     pub advertised_address: Option<String>,
 
     /// Maximum concurrent client connections
-    #[serde(default = "default_max_connections")]
-    pub max_connections: u32,
+    ///
+    /// When unset it is derived from the proxy container's memory limit, or
+    /// 1000 without one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<u32>,
 
     /// Client-facing security configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub security: Option<ClientSecuritySpec>,
 }
 
+impl Default for ListenerSpec {
+    fn default() -> Self {
+        Self {
+            name: default_listener_name(),
+            port: default_listen_port(),
+            advertised_address: None,
+            max_connections: None,
+            security: None,
+        }
+    }
+}
+
+fn default_listeners() -> Vec<ListenerSpec> {
+    vec![ListenerSpec::default()]
+}
+
+fn default_listener_name() -> String {
+    "kafka".to_string()
+}
+
 fn default_listen_port() -> i32 {
     9092
 }
 
-fn default_max_connections() -> u32 {
-    1000
+/// Client user with operator-generated SCRAM credentials
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyUserSpec {
+    /// Username (lowercase alphanumerics and '-', used in the Secret name)
+    pub name: String,
+
+    /// SCRAM mechanism the credentials are issued for
+    /// (SCRAM-SHA-256 or SCRAM-SHA-512)
+    #[serde(default = "default_user_mechanism")]
+    pub mechanism: SaslMechanism,
+
+    /// Periodic password rotation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<CredentialRotationSpec>,
+}
+
+/// Password rotation schedule for a generated user
+///
+/// A rotation issues a new password while the proxy keeps accepting the
+/// previous one for the overlap window, giving clients time to pick up the
+/// updated Secret. Removing the schedule retires any previous password.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialRotationSpec {
+    /// Days between password rotations
+    pub interval_days: u32,
+
+    /// Hours the previous password stays valid after a rotation
+    #[serde(default = "default_overlap_hours")]
+    pub overlap_hours: u32,
+}
+
+fn default_overlap_hours() -> u32 {
+    24
+}
+
+fn default_user_mechanism() -> SaslMechanism {
+    SaslMechanism::ScramSha512
 }
 
 /// Client-facing security configuration
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ClientSecuritySpec {
-    /// Security protocol (PLAINTEXT, SSL, SASL_PLAINTEXT, SASL_SSL)
-    #[serde(default = "default_security_protocol")]
-    pub protocol: String,
+    /// Security protocol
+    #[serde(default)]
+    pub protocol: SecurityProtocol,
 
     /// TLS configuration for client connections (server-side TLS)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -111,8 +724,83 @@ pub struct ClientSecuritySpec {
     pub sasl: Option<ClientSaslSpec>,
 }
 
-fn default_security_protocol() -> String {
-    "PLAINTEXT".to_string()
+/// Kafka security protocol
+///
+/// Lowercase spellings are accepted and normalized by the mutating webhook.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SecurityProtocol {
+    /// Unencrypted and unauthenticated
+    #[default]
+    #[serde(alias = "plaintext")]
+    Plaintext,
+    /// TLS encrypted
+    #[serde(alias = "ssl")]
+    Ssl,
+    /// SASL authenticated, unencrypted
+    #[serde(alias = "sasl_plaintext")]
+    SaslPlaintext,
+    /// SASL authenticated over TLS
+    #[serde(alias = "sasl_ssl")]
+    SaslSsl,
+}
+
+impl SecurityProtocol {
+    /// Name of the protocol as Kafka spells it
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Plaintext => "PLAINTEXT",
+            Self::Ssl => "SSL",
+            Self::SaslPlaintext => "SASL_PLAINTEXT",
+            Self::SaslSsl => "SASL_SSL",
+        }
+    }
+
+    /// Whether connections are encrypted with TLS
+    pub fn uses_tls(&self) -> bool {
+        matches!(self, Self::Ssl | Self::SaslSsl)
+    }
+
+    /// Whether connections authenticate with SASL
+    pub fn uses_sasl(&self) -> bool {
+        matches!(self, Self::SaslPlaintext | Self::SaslSsl)
+    }
+}
+
+/// SASL mechanism
+///
+/// Lowercase spellings are accepted and normalized by the mutating webhook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub enum SaslMechanism {
+    /// Username and password in clear text (use with TLS)
+    #[serde(rename = "PLAIN", alias = "plain")]
+    Plain,
+    /// Salted challenge-response with SHA-256
+    #[serde(rename = "SCRAM-SHA-256", alias = "scram-sha-256")]
+    ScramSha256,
+    /// Salted challenge-response with SHA-512
+    #[serde(rename = "SCRAM-SHA-512", alias = "scram-sha-512")]
+    ScramSha512,
+    /// OAuth 2 bearer tokens
+    #[serde(rename = "OAUTHBEARER", alias = "oauthbearer")]
+    OauthBearer,
+}
+
+impl SaslMechanism {
+    /// Name of the mechanism as Kafka spells it
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Plain => "PLAIN",
+            Self::ScramSha256 => "SCRAM-SHA-256",
+            Self::ScramSha512 => "SCRAM-SHA-512",
+            Self::OauthBearer => "OAUTHBEARER",
+        }
+    }
+
+    /// Whether the mechanism is one of the SCRAM variants
+    pub fn is_scram(&self) -> bool {
+        matches!(self, Self::ScramSha256 | Self::ScramSha512)
+    }
 }
 
 /// TLS configuration for client-facing connections
@@ -172,16 +860,16 @@ fn default_ca_key() -> String {
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ClientSaslSpec {
-    /// Enabled SASL mechanisms (PLAIN, SCRAM-SHA-256, SCRAM-SHA-512, OAUTHBEARER)
+    /// Enabled SASL mechanisms
     #[serde(default = "default_sasl_mechanisms")]
-    pub enabled_mechanisms: Vec<String>,
+    pub enabled_mechanisms: Vec<SaslMechanism>,
 
     /// Credentials secret reference (username/password pairs)
     pub credentials_secret: CredentialsSecretRef,
 }
 
-fn default_sasl_mechanisms() -> Vec<String> {
-    vec!["PLAIN".to_string()]
+fn default_sasl_mechanisms() -> Vec<SaslMechanism> {
+    vec![SaslMechanism::Plain]
 }
 
 /// Credentials secret reference
@@ -197,7 +885,13 @@ pub struct CredentialsSecretRef {
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KafkaClusterSpec {
-    /// Bootstrap servers
+    /// Name of a KafkaClusterRef supplying bootstrap servers, security
+    /// protocol and secret references
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_ref: Option<String>,
+
+    /// Bootstrap servers (must be empty when clusterRef is set)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub bootstrap_servers: Vec<String>,
 
     /// Connection timeout in milliseconds
@@ -212,9 +906,9 @@ pub struct KafkaClusterSpec {
     #[serde(default = "default_metadata_refresh_interval_secs")]
     pub metadata_refresh_interval_secs: u64,
 
-    /// Security protocol (PLAINTEXT, SSL, SASL_PLAINTEXT, SASL_SSL)
-    #[serde(default = "default_security_protocol")]
-    pub security_protocol: String,
+    /// Security protocol
+    #[serde(default)]
+    pub security_protocol: SecurityProtocol,
 
     /// TLS configuration for broker connections
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -223,198 +917,1261 @@ pub struct KafkaClusterSpec {
     /// SASL configuration for broker connections
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sasl_secret: Option<SaslSecretRef>,
+
+    /// Kerberos (SASL GSSAPI) authentication for broker connections
+    /// Used instead of saslSecret with the SASL_PLAINTEXT or SASL_SSL protocol
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kerberos: Option<KerberosSpec>,
+
+    /// Broker addresses to dial instead of the ones advertised in metadata,
+    /// from advertised `host:port` to reachable `host:port`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub broker_address_map: BTreeMap<String, String>,
+
+    /// How brokers missing from brokerAddressMap are reached
+    #[serde(default)]
+    pub broker_address_mode: BrokerAddressMode,
+
+    /// Highest version the proxy negotiates for any Kafka API, for clients
+    /// newer than the proxy can translate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_api_version: Option<i16>,
+
+    /// Highest version negotiated per Kafka API by name (e.g. Fetch),
+    /// taking precedence over maxApiVersion
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub api_versions: BTreeMap<String, i16>,
+
+    /// Standby clusters in priority order, used by the proxy while none of
+    /// the bootstrap servers above are reachable
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failover_clusters: Vec<UpstreamClusterSpec>,
 }
 
-fn default_connection_timeout_ms() -> u64 {
-    10_000
+impl KafkaClusterSpec {
+    /// Host brokers are reached on when their address is derived from the
+    /// bootstrap servers
+    pub fn broker_address_host(&self) -> Option<String> {
+        if self.broker_address_mode != BrokerAddressMode::BootstrapHost {
+            return None;
+        }
+        let (host, _) = self.bootstrap_servers.first()?.rsplit_once(':')?;
+        Some(host.to_string())
+    }
 }
 
-fn default_request_timeout_ms() -> u64 {
-    30_000
+/// Address to dial for a broker advertising `advertised`
+///
+/// An entry in `map` wins; otherwise `host`, when set, replaces the
+/// advertised host and keeps its port.
+pub fn reachable_broker_address(
+    map: &BTreeMap<String, String>,
+    host: Option<&str>,
+    advertised: &str,
+) -> String {
+    if let Some(address) = map.get(advertised) {
+        return address.clone();
+    }
+    match (host, advertised.rsplit_once(':')) {
+        (Some(host), Some((_, port))) => format!("{}:{}", host, port),
+        _ => advertised.to_string(),
+    }
 }
 
-fn default_metadata_refresh_interval_secs() -> u64 {
-    30
+/// How brokers missing from `kafka.brokerAddressMap` are reached
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub enum BrokerAddressMode {
+    /// Dial the address each broker advertises
+    #[default]
+    Advertised,
+    /// Dial the host of the first bootstrap server on each broker's
+    /// advertised port, for brokers exposed on distinct ports of one load
+    /// balancer
+    BootstrapHost,
 }
 
-/// TLS secret reference for broker connections
+/// Kafka cluster reached alongside the primary one, with its own bootstrap
+/// servers and credentials
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct TlsSecretRef {
-    /// Secret name
+pub struct UpstreamClusterSpec {
+    /// Name identifying the cluster in the proxy config and pod volumes
     pub name: String,
-    /// CA certificate key in secret
-    #[serde(default = "default_ca_key")]
-    pub ca_key: String,
-    /// Client certificate key in secret (for mTLS)
+
+    /// Bootstrap servers of the cluster
+    pub bootstrap_servers: Vec<String>,
+
+    /// Security protocol
+    #[serde(default)]
+    pub security_protocol: SecurityProtocol,
+
+    /// TLS configuration for the cluster's brokers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_secret: Option<TlsSecretRef>,
+
+    /// SASL configuration for the cluster's brokers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sasl_secret: Option<SaslSecretRef>,
+}
+
+/// Routing of topics to clusters other than the primary one
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoutingSpec {
+    /// Clusters topics can be routed to
+    #[serde(default)]
+    pub clusters: Vec<UpstreamClusterSpec>,
+
+    /// Routes in priority order; topics matching none stay on the primary
+    /// cluster
+    #[serde(default)]
+    pub routes: Vec<TopicRouteSpec>,
+}
+
+/// Topic restrictions enforced by the proxy
+///
+/// Entries match a topic by exact name or as a regex over the whole name.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessSpec {
+    /// Topics clients may access; empty allows every topic
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topic_allow_list: Vec<String>,
+
+    /// Topics clients may not access, even when allowed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topic_deny_list: Vec<String>,
+}
+
+/// Client throttling enforced by the proxy
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QuotasSpec {
+    /// Limits for clients without an entry in clients
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<QuotaLimits>,
+
+    /// Limits for individual client ids or users
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub clients: Vec<ClientQuotaSpec>,
+}
+
+/// Limits for one client id or user
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientQuotaSpec {
+    /// Kafka client id the limits apply to (exclusive with user)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// Authenticated user the limits apply to (exclusive with clientId)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// The limits
+    #[serde(flatten)]
+    pub limits: QuotaLimits,
+}
+
+/// Throughput and connection limits; unset limits are unlimited
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QuotaLimits {
+    /// Produce traffic from the client, in bytes per second
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ingress_bytes_per_second: Option<u64>,
+
+    /// Fetch traffic to the client, in bytes per second
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub egress_bytes_per_second: Option<u64>,
+
+    /// New connections accepted from the client per second
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connections_per_second: Option<u32>,
+}
+
+/// Consumer group coordination settings
+///
+/// Group assignments reference virtual partitions, so the proxy rewrites
+/// them for the `consumer` protocol type.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsumerGroupsSpec {
+    /// Prefix added to group ids on the upstream cluster, keeping groups
+    /// behind the proxy apart from direct clients of the same cluster
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id_prefix: Option<String>,
+
+    /// Group protocol types whose assignments are forwarded unchanged
+    /// (e.g. connect)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub passthrough_protocol_types: Vec<String>,
+
+    /// Lower bound applied to the session timeout requested by members
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_session_timeout_ms: Option<u32>,
+
+    /// Upper bound applied to the session timeout requested by members
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_session_timeout_ms: Option<u32>,
+}
+
+/// Proxy performance settings; unset fields keep the proxy's defaults
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TuningSpec {
+    /// Runtime worker threads
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worker_threads: Option<u32>,
+
+    /// SO_SNDBUF size of client and broker sockets, in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub socket_send_buffer_bytes: Option<u32>,
+
+    /// SO_RCVBUF size of client and broker sockets, in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub socket_receive_buffer_bytes: Option<u32>,
+
+    /// Idle time before TCP keepalive probes are sent, in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tcp_keepalive_secs: Option<u32>,
+
+    /// Client connections idle this long are closed, in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_connection_timeout_secs: Option<u32>,
+
+    /// Requests a client connection may have outstanding before the proxy
+    /// stops reading from it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_in_flight_requests: Option<u32>,
+
+    /// Memory budgeted per client connection when deriving listener
+    /// maxConnections from the memory limit, in bytes (default 1Mi)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_memory_bytes: Option<u64>,
+}
+
+impl TuningSpec {
+    /// Settings that are set, by their proxy config key
+    pub fn values(&self) -> Vec<(&'static str, u32)> {
+        [
+            ("worker_threads", self.worker_threads),
+            ("socket_send_buffer_bytes", self.socket_send_buffer_bytes),
+            (
+                "socket_receive_buffer_bytes",
+                self.socket_receive_buffer_bytes,
+            ),
+            ("tcp_keepalive_secs", self.tcp_keepalive_secs),
+            (
+                "idle_connection_timeout_secs",
+                self.idle_connection_timeout_secs,
+            ),
+            ("max_in_flight_requests", self.max_in_flight_requests),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| Some((key, value?)))
+        .collect()
+    }
+}
+
+/// Topics matched by prefix or regex and the cluster serving them
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicRouteSpec {
+    /// Topic name prefix (exclusive with pattern)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+
+    /// Regex matched against the whole topic name (exclusive with prefix)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// Name of a routing cluster serving the matched topics
+    pub cluster: String,
+}
+
+/// Schema Registry reached through the proxy
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaRegistrySpec {
+    /// Registry URL (http or https)
+    pub url: String,
+
+    /// URL handed to clients in place of the registry URL, typically the
+    /// proxy's own forwarding endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertised_url: Option<String>,
+
+    /// TLS configuration for registry connections (requires an https URL)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_secret: Option<TlsSecretRef>,
+
+    /// HTTP basic auth credentials for the registry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_secret: Option<BasicAuthSecretRef>,
+
+    /// Port on which the proxy serves registry requests, forwarding them to
+    /// the registry URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_port: Option<i32>,
+}
+
+/// Secret holding a username and password for HTTP basic auth
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BasicAuthSecretRef {
+    /// Secret name
+    pub name: String,
+    /// Username key in secret
+    #[serde(default = "default_username_key")]
+    pub username_key: String,
+    /// Password key in secret
+    #[serde(default = "default_password_key")]
+    pub password_key: String,
+}
+
+fn default_connection_timeout_ms() -> u64 {
+    10_000
+}
+
+fn default_request_timeout_ms() -> u64 {
+    30_000
+}
+
+fn default_metadata_refresh_interval_secs() -> u64 {
+    30
+}
+
+/// TLS secret reference for broker connections
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TlsSecretRef {
+    /// Secret name (empty when secretProviderClass is set)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    /// SecretProviderClass mounted through the Secrets Store CSI driver
+    /// instead of a Secret, keeping the material in an external store
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_provider_class: Option<String>,
+    /// CA certificate key in secret
+    #[serde(default = "default_ca_key")]
+    pub ca_key: String,
+    /// Client certificate key in secret (for mTLS)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cert_key: Option<String>,
+    /// Client key key in secret (for mTLS)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_key: Option<String>,
+    /// Skip server verification (NOT recommended for production)
+    #[serde(default)]
+    pub insecure_skip_verify: bool,
+}
+
+/// SASL secret reference for broker connections
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SaslSecretRef {
+    /// Secret name (empty when secretProviderClass is set)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    /// SecretProviderClass mounted through the Secrets Store CSI driver
+    /// instead of a Secret; the username and password keys name its files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_provider_class: Option<String>,
+    /// SASL mechanism
+    pub mechanism: SaslMechanism,
+    /// Username key in secret
+    #[serde(default = "default_username_key")]
+    pub username_key: String,
+    /// Password key in secret
+    #[serde(default = "default_password_key")]
+    pub password_key: String,
+}
+
+/// Kerberos (SASL GSSAPI) configuration for broker connections
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KerberosSpec {
+    /// Principal the proxy authenticates as (e.g. kafka-proxy@EXAMPLE.COM)
+    pub principal: String,
+
+    /// Kerberos service name of the brokers
+    #[serde(default = "default_kerberos_service_name")]
+    pub service_name: String,
+
+    /// Secret containing the principal's keytab
+    pub keytab_secret: KeytabSecretRef,
+
+    /// ConfigMap containing krb5.conf
+    pub krb5_config_map: Krb5ConfigMapRef,
+}
+
+fn default_kerberos_service_name() -> String {
+    "kafka".to_string()
+}
+
+/// Keytab secret reference
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KeytabSecretRef {
+    /// Secret name
+    pub name: String,
+    /// Key for the keytab (default: krb5.keytab)
+    #[serde(default = "default_keytab_key")]
+    pub key: String,
+}
+
+fn default_keytab_key() -> String {
+    "krb5.keytab".to_string()
+}
+
+/// krb5.conf ConfigMap reference
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Krb5ConfigMapRef {
+    /// ConfigMap name
+    pub name: String,
+    /// Key for krb5.conf (default: krb5.conf)
+    #[serde(default = "default_krb5_config_key")]
+    pub key: String,
+}
+
+fn default_krb5_config_key() -> String {
+    "krb5.conf".to_string()
+}
+
+fn default_username_key() -> String {
+    "username".to_string()
+}
+
+fn default_password_key() -> String {
+    "password".to_string()
+}
+
+/// Partition remapping configuration
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MappingSpec {
+    /// Number of virtual partitions exposed to clients
+    pub virtual_partitions: u32,
+
+    /// Number of physical partitions in Kafka cluster
+    pub physical_partitions: u32,
+
+    /// Offset range per virtual partition group (default: 2^40)
+    #[serde(default = "default_offset_range")]
+    pub offset_range: u64,
+
+    /// Per-topic mapping overrides
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<TopicMappingOverride>,
+
+    /// Rewrites from client-facing topic names to physical topic names,
+    /// applied in order with the first matching rule winning
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topic_renames: Vec<TopicRenameSpec>,
+
+    /// Record headers the proxy sets on produced records, for lineage
+    /// tooling downstream
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_injection: Option<HeaderInjectionSpec>,
+}
+
+/// Maps a client-facing topic name onto its physical name
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicRenameSpec {
+    /// Client-facing prefix swapped for the replacement (exclusive with pattern)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+
+    /// Regex matched against the whole client-facing topic name
+    /// (exclusive with prefix)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// Physical prefix, or for patterns the substitution, which may
+    /// reference capture groups as $1 or ${name}
+    pub replacement: String,
+}
+
+/// Record headers set on produce
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HeaderInjectionSpec {
+    /// Header carrying the virtual partition a record was produced to
+    /// (e.g. x-virtual-partition)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub virtual_partition_header: Option<String>,
+
+    /// Fixed headers added to every record, such as environment tags
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+
+    /// Replace headers of the same name set by the producer instead of
+    /// keeping them
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+fn default_offset_range() -> u64 {
+    1 << 40 // 2^40 = 1,099,511,627,776
+}
+
+/// Per-topic mapping override
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicMappingOverride {
+    /// Topic name or regex pattern
+    pub topic: String,
+
+    /// Virtual partitions for this topic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub virtual_partitions: Option<u32>,
+
+    /// Physical partitions for this topic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub physical_partitions: Option<u32>,
+
+    /// Offset range for this topic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset_range: Option<u64>,
+}
+
+/// Metrics configuration
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsSpec {
+    /// Enable metrics endpoint
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Metrics port
+    #[serde(default = "default_metrics_port")]
+    pub port: i32,
+
+    /// Dedicated Service for the metrics port
+    /// When set the metrics port is removed from the client Service and
+    /// exposed as `<name>-metrics`, or on no Service at all when disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<MetricsServiceSpec>,
+}
+
+impl Default for MetricsSpec {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            port: 9090,
+            service: None,
+        }
+    }
+}
+
+/// Dedicated metrics Service configuration
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsServiceSpec {
+    /// Create the metrics Service
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Service type (ClusterIP, Headless, NodePort, LoadBalancer)
+    /// Headless gives every pod its own scrape address
+    #[serde(default = "default_metrics_service_type")]
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// Service annotations
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<String, String>,
+}
+
+impl MetricsSpec {
+    /// Whether the metrics port is exposed on the client Service
+    pub fn on_client_service(&self) -> bool {
+        self.enabled && self.service.is_none()
+    }
+
+    /// Settings of the dedicated metrics Service, when it is created
+    pub fn dedicated_service(&self) -> Option<&MetricsServiceSpec> {
+        self.service.as_ref().filter(|s| s.enabled && self.enabled)
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_metrics_port() -> i32 {
+    9090
+}
+
+/// Logging configuration
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LoggingSpec {
+    /// Log level
+    #[serde(default)]
+    pub level: LogLevel,
+
+    /// Output logs in JSON format
+    #[serde(default)]
+    pub json: bool,
+}
+
+impl Default for LoggingSpec {
+    fn default() -> Self {
+        Self {
+            level: LogLevel::Info,
+            json: false,
+        }
+    }
+}
+
+/// Proxy log level
+///
+/// Uppercase spellings are accepted and normalized by the mutating webhook.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    /// Everything, including per-request tracing
+    #[serde(alias = "TRACE")]
+    Trace,
+    /// Diagnostic detail
+    #[serde(alias = "DEBUG")]
+    Debug,
+    /// Normal operational messages
+    #[default]
+    #[serde(alias = "INFO")]
+    Info,
+    /// Recoverable problems
+    #[serde(alias = "WARN")]
+    Warn,
+    /// Failures only
+    #[serde(alias = "ERROR")]
+    Error,
+}
+
+impl LogLevel {
+    /// Level as the proxy's log filter spells it
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Trace => "trace",
+            Self::Debug => "debug",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Audit logging configuration
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLoggingSpec {
+    /// Write audit records
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Fraction of requests audited, from 0 (exclusive) to 1
+    #[serde(default = "default_audit_sample_rate")]
+    pub sample_rate: f64,
+
+    /// Where records are written (defaults to stdout)
+    #[serde(default = "default_audit_destinations")]
+    pub destinations: Vec<AuditDestinationSpec>,
+
+    /// Record the client id of each request
+    #[serde(default = "default_true")]
+    pub include_client_id: bool,
+
+    /// Record the authenticated principal of each request
+    #[serde(default = "default_true")]
+    pub include_principal: bool,
+}
+
+impl AuditLoggingSpec {
+    /// Whether records are written to files in the audit log directory
+    pub fn writes_files(&self) -> bool {
+        self.enabled
+            && self
+                .destinations
+                .iter()
+                .any(|d| d.type_ == AuditDestinationType::File)
+    }
+}
+
+fn default_audit_sample_rate() -> f64 {
+    1.0
+}
+
+fn default_audit_destinations() -> Vec<AuditDestinationSpec> {
+    vec![AuditDestinationSpec {
+        type_: AuditDestinationType::Stdout,
+        file_name: None,
+        address: None,
+    }]
+}
+
+/// Destination of audit records
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditDestinationSpec {
+    /// Destination type (Stdout, File, Syslog)
+    #[serde(rename = "type")]
+    pub type_: AuditDestinationType,
+
+    /// File in the pod's audit log directory, for File destinations
+    /// (defaults to audit.log)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+
+    /// Syslog server `host:port` records are sent to over UDP, for Syslog
+    /// destinations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+}
+
+/// Kind of audit record destination
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub enum AuditDestinationType {
+    /// The proxy container's standard output
+    Stdout,
+    /// A file on an emptyDir volume, for collection by a sidecar
+    File,
+    /// A syslog server
+    Syslog,
+}
+
+/// Kubernetes Service configuration
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceSpec {
+    /// Service type
+    #[serde(default)]
+    #[serde(rename = "type")]
+    pub type_: ServiceType,
+
+    /// Service annotations (for cloud load balancer configuration)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<String, String>,
+
+    /// LoadBalancer IP (if applicable)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_balancer_ip: Option<String>,
+
+    /// External traffic policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_traffic_policy: Option<ExternalTrafficPolicy>,
+
+    /// DNS name published for the Service through ExternalDNS
+    /// Also the default advertised address of every listener
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_hostname: Option<String>,
+
+    /// IP families of the Service (IPv4, IPv6), primary family first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ip_families: Vec<String>,
+
+    /// IP family policy (SingleStack, PreferDualStack, RequireDualStack)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_family_policy: Option<String>,
+
+    /// Internal traffic policy (Cluster, Local)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal_traffic_policy: Option<String>,
+
+    /// Load balancer implementation to use (LoadBalancer only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_balancer_class: Option<String>,
+
+    /// Client CIDRs allowed through the load balancer (LoadBalancer only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub load_balancer_source_ranges: Vec<String>,
+
+    /// Whether node ports are allocated for the load balancer (LoadBalancer
+    /// only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocate_load_balancer_node_ports: Option<bool>,
+
+    /// Fixed node ports by Service port name, a listener name or `metrics`
+    /// (NodePort and LoadBalancer only)
+    /// Ports left out are allocated by Kubernetes
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub node_ports: BTreeMap<String, i32>,
+
+    /// Session affinity (None, ClientIP)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_affinity: Option<String>,
+
+    /// How long a client sticks to the same pod with ClientIP session
+    /// affinity, in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_affinity_timeout_seconds: Option<i32>,
+}
+
+impl Default for ServiceSpec {
+    fn default() -> Self {
+        Self {
+            type_: ServiceType::ClusterIP,
+            annotations: BTreeMap::new(),
+            load_balancer_ip: None,
+            external_traffic_policy: None,
+            external_hostname: None,
+            ip_families: Vec::new(),
+            ip_family_policy: None,
+            internal_traffic_policy: None,
+            load_balancer_class: None,
+            load_balancer_source_ranges: Vec::new(),
+            allocate_load_balancer_node_ports: None,
+            node_ports: BTreeMap::new(),
+            session_affinity: None,
+            session_affinity_timeout_seconds: None,
+        }
+    }
+}
+
+/// Kubernetes Service type
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub enum ServiceType {
+    /// Reachable inside the cluster only
+    #[default]
+    ClusterIP,
+    /// Exposed on a port of every node
+    NodePort,
+    /// Exposed through a cloud load balancer
+    LoadBalancer,
+}
+
+impl ServiceType {
+    /// Type name as the Service API spells it
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ClusterIP => "ClusterIP",
+            Self::NodePort => "NodePort",
+            Self::LoadBalancer => "LoadBalancer",
+        }
+    }
+}
+
+/// How a Service routes external traffic to pods
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub enum ExternalTrafficPolicy {
+    /// Spread across pods on all nodes
+    Cluster,
+    /// Only to pods on the receiving node, preserving client source IPs
+    Local,
+}
+
+impl ExternalTrafficPolicy {
+    /// Policy name as the Service API spells it
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cluster => "Cluster",
+            Self::Local => "Local",
+        }
+    }
+}
+
+/// External Service configuration
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalServiceSpec {
+    /// Create the external Service
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Service type (LoadBalancer or NodePort)
+    #[serde(default = "default_external_service_type")]
+    #[serde(rename = "type")]
+    pub type_: ServiceType,
+
+    /// Service annotations (for cloud load balancer configuration)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<String, String>,
+
+    /// LoadBalancer IP (if applicable)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_balancer_ip: Option<String>,
+
+    /// External traffic policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_traffic_policy: Option<ExternalTrafficPolicy>,
+
+    /// Names of the listeners to expose (all listeners when empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub listeners: Vec<String>,
+}
+
+fn default_external_service_type() -> ServiceType {
+    ServiceType::LoadBalancer
+}
+
+fn default_metrics_service_type() -> String {
+    "ClusterIP".to_string()
+}
+
+/// Gateway API configuration
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewaySpec {
+    /// Create a TCPRoute attaching the proxy to the Gateway
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Gateway the TCPRoute attaches to
+    pub parent_ref: GatewayParentRef,
+
+    /// Listener routed through the Gateway (defaults to the primary listener)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listener: Option<String>,
+}
+
+/// Reference to a Gateway and optionally one of its listeners
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewayParentRef {
+    /// Gateway name
+    pub name: String,
+
+    /// Gateway namespace (defaults to the remapper's namespace)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// Name of the Gateway listener to attach to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section_name: Option<String>,
+
+    /// Port of the Gateway listener to attach to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<i32>,
+}
+
+/// Probe overrides for the proxy container
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbesSpec {
+    /// Liveness probe (defaults to HTTP /health on the metrics port)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liveness: Option<ProbeSpec>,
+
+    /// Readiness probe (defaults to HTTP /health on the metrics port, or
+    /// /ready when readiness.requireKafka is set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readiness: Option<ProbeSpec>,
+
+    /// Startup probe (none unless set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub startup: Option<ProbeSpec>,
+}
+
+/// Readiness gating
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadinessSpec {
+    /// Only report ready while the proxy is connected to the brokers, so the
+    /// Service routes to replicas that can actually serve traffic
+    /// Requires metrics.enabled; the readiness probe checks /ready
+    #[serde(default)]
+    pub require_kafka: bool,
+}
+
+/// A single probe; unset fields keep the operator's defaults
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbeSpec {
+    /// Check type (Tcp, Http, Exec); defaults to Http when metrics are
+    /// enabled and Tcp otherwise
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<ProbeType>,
+
+    /// Path requested by Http probes (defaults to /health, or /ready for
+    /// readiness when readiness.requireKafka is set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Port checked by Tcp and Http probes (defaults to the primary listener
+    /// for Tcp and the metrics port for Http)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "int_or_string_schema")]
+    pub port: Option<IntOrString>,
+
+    /// Command run by Exec probes
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub command: Vec<String>,
+
+    /// Seconds after container start before the first check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_delay_seconds: Option<i32>,
+
+    /// Seconds between checks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period_seconds: Option<i32>,
+
+    /// Seconds before a check times out
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<i32>,
+
+    /// Consecutive failures before the probe fails
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cert_key: Option<String>,
-    /// Client key key in secret (for mTLS)
+    pub failure_threshold: Option<i32>,
+
+    /// Consecutive successes before the probe passes again
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_key: Option<String>,
-    /// Skip server verification (NOT recommended for production)
-    #[serde(default)]
-    pub insecure_skip_verify: bool,
+    pub success_threshold: Option<i32>,
 }
 
-/// SASL secret reference for broker connections
+/// Probe check type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub enum ProbeType {
+    /// Open a TCP connection to the port
+    Tcp,
+    /// Send an HTTP GET to the path on the port
+    Http,
+    /// Run a command in the proxy container
+    Exec,
+}
+
+/// PodDisruptionBudget configuration
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SaslSecretRef {
-    /// Secret name
-    pub name: String,
-    /// SASL mechanism (PLAIN, SCRAM-SHA-256, SCRAM-SHA-512)
-    pub mechanism: String,
-    /// Username key in secret
-    #[serde(default = "default_username_key")]
-    pub username_key: String,
-    /// Password key in secret
-    #[serde(default = "default_password_key")]
-    pub password_key: String,
-}
+pub struct PodDisruptionBudgetSpec {
+    /// Create a PodDisruptionBudget for the proxy pods
+    #[serde(default = "default_true")]
+    pub enabled: bool,
 
-fn default_username_key() -> String {
-    "username".to_string()
-}
+    /// Minimum number (or percentage) of pods that must remain available
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "int_or_string_schema")]
+    pub min_available: Option<IntOrString>,
 
-fn default_password_key() -> String {
-    "password".to_string()
+    /// Maximum number (or percentage) of pods that can be unavailable
+    /// (defaults to 1 when neither minAvailable nor maxUnavailable is set)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "int_or_string_schema")]
+    pub max_unavailable: Option<IntOrString>,
 }
 
-/// Partition remapping configuration
+/// HorizontalPodAutoscaler configuration
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct MappingSpec {
-    /// Number of virtual partitions exposed to clients
-    pub virtual_partitions: u32,
+pub struct AutoscalingSpec {
+    /// Create a HorizontalPodAutoscaler for the proxy Deployment
+    #[serde(default = "default_true")]
+    pub enabled: bool,
 
-    /// Number of physical partitions in Kafka cluster
-    pub physical_partitions: u32,
+    /// Minimum number of replicas (defaults to 1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_replicas: Option<i32>,
 
-    /// Offset range per virtual partition group (default: 2^40)
-    #[serde(default = "default_offset_range")]
-    pub offset_range: u64,
+    /// Maximum number of replicas
+    pub max_replicas: i32,
 
-    /// Per-topic mapping overrides
+    /// Target average CPU utilization percentage
+    /// Defaults to 80 when no other metric is configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_cpu_utilization_percentage: Option<i32>,
+
+    /// Target average memory utilization percentage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_memory_utilization_percentage: Option<i32>,
+
+    /// Custom per-pod metrics (requires a custom metrics API adapter)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub topics: Vec<TopicMappingOverride>,
-}
+    pub custom_metrics: Vec<CustomMetricSpec>,
 
-fn default_offset_range() -> u64 {
-    1 << 40 // 2^40 = 1,099,511,627,776
+    /// Scale with a KEDA ScaledObject on proxy metrics queried from Prometheus
+    ///
+    /// Replaces the HorizontalPodAutoscaler; the resource targets are ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keda: Option<KedaSpec>,
 }
 
-/// Per-topic mapping override
+/// KEDA ScaledObject configuration
+///
+/// KEDA reads the proxy's connection and throughput metrics straight from
+/// Prometheus, so no custom metrics API adapter is needed.
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct TopicMappingOverride {
-    /// Topic name or regex pattern
-    pub topic: String,
+pub struct KedaSpec {
+    /// Create a KEDA ScaledObject instead of a HorizontalPodAutoscaler
+    #[serde(default = "default_true")]
+    pub enabled: bool,
 
-    /// Virtual partitions for this topic
+    /// Address of the Prometheus server scraping the proxy pods
+    pub server_address: String,
+
+    /// Target active client connections per pod
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_partitions: Option<u32>,
+    pub target_connections_per_pod: Option<i64>,
 
-    /// Physical partitions for this topic
+    /// Target bytes per second through each pod
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub physical_partitions: Option<u32>,
+    pub target_bytes_per_second_per_pod: Option<i64>,
 
-    /// Offset range for this topic
+    /// Proxy metric counting active client connections
+    #[serde(default = "default_keda_connections_metric")]
+    pub connections_metric: String,
+
+    /// Proxy counter of bytes transferred, scaled on its per-second rate
+    #[serde(default = "default_keda_throughput_metric")]
+    pub throughput_metric: String,
+
+    /// Seconds between KEDA trigger checks (KEDA defaults to 30)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub offset_range: Option<u64>,
+    pub polling_interval_seconds: Option<i32>,
+
+    /// Seconds to wait after the last active trigger before scaling in
+    /// (KEDA defaults to 300)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cooldown_period_seconds: Option<i32>,
 }
 
-/// Metrics configuration
+fn default_keda_connections_metric() -> String {
+    "kafka_proxy_active_connections".to_string()
+}
+
+fn default_keda_throughput_metric() -> String {
+    "kafka_proxy_bytes_total".to_string()
+}
+
+/// Custom per-pod metric target for autoscaling
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct MetricsSpec {
-    /// Enable metrics endpoint
-    #[serde(default = "default_true")]
-    pub enabled: bool,
+pub struct CustomMetricSpec {
+    /// Metric name (e.g. kafka_proxy_active_connections)
+    pub name: String,
 
-    /// Metrics port
-    #[serde(default = "default_metrics_port")]
-    pub port: i32,
+    /// Target average value per pod (Kubernetes quantity, e.g. 500 or 1k)
+    pub target_average_value: String,
 }
 
-impl Default for MetricsSpec {
-    fn default() -> Self {
-        Self {
-            enabled: true,
-            port: 9090,
-        }
-    }
+/// Partition-range sharding configuration
+///
+/// The virtual partition space is split into one contiguous range per
+/// replica, and every shard gets its own routing Service.
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ShardingSpec {
+    /// Assign each replica a disjoint range of virtual partitions
+    #[serde(default = "default_true")]
+    pub enabled: bool,
 }
 
-fn default_true() -> bool {
-    true
+/// Scheduled consistency audit
+///
+/// A CronJob running the operator image checks physical partition counts,
+/// offset range headroom and the existence of the listed topics, then
+/// records the outcome in `status.audit`.
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyAuditSpec {
+    /// Create the audit CronJob
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Cron schedule of the audit (defaults to hourly)
+    #[serde(default = "default_consistency_audit_schedule")]
+    pub schedule: String,
+
+    /// Topics that must exist in the upstream cluster
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<String>,
+
+    /// Image running the audit (defaults to the operator's own image)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
 }
 
-fn default_metrics_port() -> i32 {
-    9090
+fn default_consistency_audit_schedule() -> String {
+    "0 * * * *".to_string()
 }
 
-/// Logging configuration
-#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
-#[serde(rename_all = "camelCase")]
-pub struct LoggingSpec {
-    /// Log level (trace, debug, info, warn, error)
-    #[serde(default = "default_log_level")]
-    pub level: String,
+/// How config-only changes reach running proxies
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub enum ReloadPolicy {
+    /// Roll the pods on every config change
+    #[default]
+    Rolling,
+    /// Push log level, topic override and maintenance mode changes to running
+    /// pods through the proxy's reload endpoint on the metrics port; other
+    /// changes still roll
+    Hot,
+}
 
-    /// Output logs in JSON format
-    #[serde(default)]
-    pub json: bool,
+/// Client requests the proxy rejects during maintenance
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub enum MaintenanceMode {
+    /// Reject produce requests; fetches and metadata still work
+    ReadOnly,
+    /// Reject every request
+    Blocked,
 }
 
-impl Default for LoggingSpec {
-    fn default() -> Self {
-        Self {
-            level: "info".to_string(),
-            json: false,
+impl MaintenanceMode {
+    /// Mode name in the proxy config
+    pub fn config_value(&self) -> &'static str {
+        match self {
+            MaintenanceMode::ReadOnly => "read_only",
+            MaintenanceMode::Blocked => "blocked",
         }
     }
 }
 
-fn default_log_level() -> String {
-    "info".to_string()
-}
-
-/// Kubernetes Service configuration
+/// Rollout strategy for spec changes
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct ServiceSpec {
-    /// Service type (ClusterIP, LoadBalancer, NodePort)
-    #[serde(default = "default_service_type")]
-    #[serde(rename = "type")]
-    pub type_: String,
-
-    /// Service annotations (for cloud load balancer configuration)
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub annotations: BTreeMap<String, String>,
-
-    /// LoadBalancer IP (if applicable)
+pub struct RolloutStrategySpec {
+    /// Strategy type (RollingUpdate, Canary)
+    #[serde(default, rename = "type")]
+    pub type_: RolloutStrategyType,
+
+    /// Number of canary replicas running the new spec during the bake period
+    #[serde(default = "default_canary_replicas")]
+    pub canary_replicas: i32,
+
+    /// Seconds the canary must stay healthy before the remaining replicas are updated
+    #[serde(default = "default_bake_time_seconds")]
+    pub bake_time_seconds: u64,
+
+    /// Pods (or percentage) created above the desired count during a rolling
+    /// update (Deployment only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "int_or_string_schema")]
+    pub max_surge: Option<IntOrString>,
+
+    /// Pods (or percentage) that may be unavailable during a rolling update
+    /// (Deployment only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "int_or_string_schema")]
+    pub max_unavailable: Option<IntOrString>,
+
+    /// Seconds a rollout may make no progress before it is reported as failed
+    /// (Deployment only)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub load_balancer_ip: Option<String>,
+    pub progress_deadline_seconds: Option<i32>,
 
-    /// External traffic policy (Cluster, Local)
+    /// Old ReplicaSets or revisions kept for rollback
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub external_traffic_policy: Option<String>,
+    pub revision_history_limit: Option<i32>,
 }
 
-impl Default for ServiceSpec {
-    fn default() -> Self {
-        Self {
-            type_: "ClusterIP".to_string(),
-            annotations: BTreeMap::new(),
-            load_balancer_ip: None,
-            external_traffic_policy: None,
-        }
-    }
+fn default_canary_replicas() -> i32 {
+    1
 }
 
-fn default_service_type() -> String {
-    "ClusterIP".to_string()
+fn default_bake_time_seconds() -> u64 {
+    300
+}
+
+/// Rollout strategy type
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub enum RolloutStrategyType {
+    /// Update all replicas with the workload's rolling update
+    #[default]
+    RollingUpdate,
+    /// Update canary replicas first and promote after the bake period
+    Canary,
 }
 
 /// Pod template customizations
@@ -437,10 +2194,19 @@ pub struct PodTemplateSpec {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tolerations: Vec<TolerationSpec>,
 
-    /// Affinity rules (JSON/YAML format matching k8s affinity)
+    /// Affinity rules (k8s Affinity format)
+    ///
+    /// When unset and more than one replica may run, replicas are preferably
+    /// spread across nodes and zones.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schemars(schema_with = "any_object_schema")]
-    pub affinity: Option<serde_json::Value>,
+    pub affinity: Option<Affinity>,
+
+    /// Topology spread constraints (k8s TopologySpreadConstraint format)
+    ///
+    /// Constraints without a labelSelector select the remapper's pods.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(schema_with = "object_list_schema")]
+    pub topology_spread_constraints: Vec<TopologySpreadConstraint>,
 
     /// Resource requirements
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -466,18 +2232,126 @@ pub struct PodTemplateSpec {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub service_account_name: Option<String>,
 
-    /// Security context (JSON/YAML format matching k8s pod security context)
+    /// Run the proxy in the node's network namespace
+    ///
+    /// Listener and metrics ports are bound on the node, so they must be
+    /// unprivileged (>= 1024) and replicas are kept on distinct nodes.
+    #[serde(default)]
+    pub host_network: bool,
+
+    /// DNS policy (ClusterFirst, ClusterFirstWithHostNet, Default, None);
+    /// defaults to ClusterFirstWithHostNet with hostNetwork
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dns_policy: Option<String>,
+
+    /// DNS settings (JSON/YAML format matching k8s pod DNS config)
     #[serde(skip_serializing_if = "Option::is_none")]
     #[schemars(schema_with = "any_object_schema")]
-    pub security_context: Option<serde_json::Value>,
+    pub dns_config: Option<serde_json::Value>,
+
+    /// Priority class for the proxy pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority_class_name: Option<String>,
+
+    /// Runtime class for the proxy pods (e.g. gvisor or kata)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime_class_name: Option<String>,
+
+    /// Scheduler for the proxy pods (defaults to the cluster scheduler)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduler_name: Option<String>,
+
+    /// Security context (k8s PodSecurityContext format)
+    ///
+    /// Defaults to a non-root user with the RuntimeDefault seccomp profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security_context: Option<PodSecurityContext>,
+
+    /// Security context of the proxy container (k8s SecurityContext format)
+    ///
+    /// Defaults to the restricted Pod Security Standard: no privilege
+    /// escalation, a read-only root filesystem and all capabilities dropped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_security_context: Option<SecurityContext>,
+
+    /// Init containers run before the proxy starts (k8s Container format)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(schema_with = "object_list_schema")]
+    pub init_containers: Vec<Container>,
+
+    /// Containers run alongside the proxy (k8s Container format)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(schema_with = "object_list_schema")]
+    pub sidecars: Vec<Container>,
+
+    /// Extra environment variables for the proxy container (k8s EnvVar format)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(schema_with = "object_list_schema")]
+    pub env: Vec<EnvVar>,
+
+    /// Extra environment sources for the proxy container (k8s EnvFromSource format)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(schema_with = "object_list_schema")]
+    pub env_from: Vec<EnvFromSource>,
+
+    /// Extra pod volumes (k8s Volume format)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(schema_with = "object_list_schema")]
+    pub extra_volumes: Vec<Volume>,
+
+    /// Extra volume mounts for the proxy container (k8s VolumeMount format)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(schema_with = "object_list_schema")]
+    pub extra_volume_mounts: Vec<VolumeMount>,
+}
+
+/// Generate a schema for a list of Kubernetes objects
+///
+/// Unknown fields are preserved so full core/v1 types such as Container or
+/// Volume are accepted without embedding their schemas in the CRD.
+fn object_list_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    let mut item = schemars::schema::SchemaObject {
+        instance_type: Some(schemars::schema::InstanceType::Object.into()),
+        ..Default::default()
+    };
+    item.extensions.insert(
+        "x-kubernetes-preserve-unknown-fields".to_string(),
+        serde_json::Value::Bool(true),
+    );
+    schemars::schema::Schema::Object(schemars::schema::SchemaObject {
+        instance_type: Some(schemars::schema::InstanceType::Array.into()),
+        array: Some(Box::new(schemars::schema::ArrayValidation {
+            items: Some(schemars::schema::Schema::Object(item).into()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    })
 }
 
 /// Generate a schema for arbitrary JSON objects
+///
+/// Unknown fields are preserved, otherwise the API server would prune every
+/// field of the untyped object.
 fn any_object_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
-    schemars::schema::Schema::Object(schemars::schema::SchemaObject {
+    let mut schema = schemars::schema::SchemaObject {
         instance_type: Some(schemars::schema::InstanceType::Object.into()),
         ..Default::default()
-    })
+    };
+    schema.extensions.insert(
+        "x-kubernetes-preserve-unknown-fields".to_string(),
+        serde_json::Value::Bool(true),
+    );
+    schemars::schema::Schema::Object(schema)
+}
+
+/// Generate a schema for Kubernetes IntOrString values
+fn int_or_string_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    let mut schema = schemars::schema::SchemaObject::default();
+    schema.extensions.insert(
+        "x-kubernetes-int-or-string".to_string(),
+        serde_json::Value::Bool(true),
+    );
+    schemars::schema::Schema::Object(schema)
 }
 
 /// Toleration specification
@@ -522,7 +2396,7 @@ pub struct ResourceRequirementsSpec {
 #[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct KafkaPartitionRemapperStatus {
-    /// Current phase (Pending, Running, Degraded, Failed)
+    /// Current phase (Pending, Updating, Running, Degraded, Failed, Suspended, Paused)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phase: Option<String>,
 
@@ -534,6 +2408,10 @@ pub struct KafkaPartitionRemapperStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub service_endpoint: Option<String>,
 
+    /// Endpoint of the external Service, when one is configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_endpoint: Option<String>,
+
     /// Metrics endpoint URL
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics_endpoint: Option<String>,
@@ -546,6 +2424,22 @@ pub struct KafkaPartitionRemapperStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub replicas: Option<i32>,
 
+    /// Number of replicas running the latest pod template
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_replicas: Option<i32>,
+
+    /// Number of replicas available to serve clients
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_replicas: Option<i32>,
+
+    /// Number of desired replicas not yet available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unavailable_replicas: Option<i32>,
+
+    /// Label selector for proxy pods (used by the scale subresource)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
     /// ConfigMap name for proxy configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub config_map_name: Option<String>,
@@ -554,6 +2448,10 @@ pub struct KafkaPartitionRemapperStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deployment_name: Option<String>,
 
+    /// StatefulSet name (StatefulSet workload mode)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stateful_set_name: Option<String>,
+
     /// Service name
     #[serde(skip_serializing_if = "Option::is_none")]
     pub service_name: Option<String>,
@@ -562,6 +2460,38 @@ pub struct KafkaPartitionRemapperStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compression_ratio: Option<u32>,
 
+    /// Compression ratio as shown by kubectl, e.g. `10:1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ratio: Option<String>,
+
+    /// Shard assignments (sharded mode)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shards: Vec<ShardStatus>,
+
+    /// Mapping last applied to the proxy, used to guard breaking changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_mapping: Option<MappingSpec>,
+
+    /// Expiry of the certificates mounted from Secrets
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub certificates: Vec<CertificateStatus>,
+
+    /// Credential state of the generated users
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<UserStatus>,
+
+    /// Canary rollout in progress or last failed (Canary rollout strategy)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canary: Option<CanaryStatus>,
+
+    /// Suspension in effect, kept while an autoscaled remapper resumes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspension: Option<SuspensionStatus>,
+
+    /// Outcome of the last consistency audit (audit only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audit: Option<ConsistencyAuditStatus>,
+
     /// Observed generation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub observed_generation: Option<i64>,
@@ -575,20 +2505,127 @@ pub struct KafkaPartitionRemapperStatus {
     pub conditions: Vec<Condition>,
 }
 
+/// Outcome of one consistency audit run
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyAuditStatus {
+    /// Time the audit finished
+    pub last_run_time: DateTime<Utc>,
+
+    /// Whether no problems were found
+    pub passed: bool,
+
+    /// Problems found, such as topics with the wrong partition count
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub findings: Vec<String>,
+}
+
+/// Expiry of one certificate mounted from a Secret
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateStatus {
+    /// Secret holding the certificate
+    pub secret_name: String,
+
+    /// Key of the certificate within the Secret
+    pub key: String,
+
+    /// Time the certificate stops being valid
+    pub not_after: DateTime<Utc>,
+}
+
+/// Credential state of one generated user
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserStatus {
+    /// Username
+    pub name: String,
+
+    /// Secret holding the user's credentials
+    pub secret_name: String,
+
+    /// Time the current password was issued
+    pub rotated_at: DateTime<Utc>,
+
+    /// Time the password is next rotated (rotation schedule only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_rotation_at: Option<DateTime<Utc>>,
+
+    /// Time the previous password stops being accepted, during an overlap
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_password_expires_at: Option<DateTime<Utc>>,
+}
+
+/// Virtual partition range served by one shard
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ShardStatus {
+    /// Shard index (matches the StatefulSet pod ordinal)
+    pub index: i32,
+
+    /// Routing Service for this shard
+    pub service_name: String,
+
+    /// First virtual partition served (inclusive)
+    pub virtual_partition_start: u32,
+
+    /// Last virtual partition served (exclusive)
+    pub virtual_partition_end: u32,
+}
+
+/// When a remapper was suspended and the replicas it ran before
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuspensionStatus {
+    /// When the suspension was first observed
+    pub suspended_at: DateTime<Utc>,
+
+    /// Workload replicas when the remapper was suspended, restored on resume
+    /// when an HPA owns the replica count
+    pub previous_replicas: i32,
+
+    /// When the remapper resumed, set until the restored replicas take effect
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resumed_at: Option<DateTime<Utc>>,
+}
+
+/// Progress of a canary rollout
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CanaryStatus {
+    /// Config hash of the spec being rolled out
+    pub config_hash: String,
+
+    /// Canary state (Progressing, Baking, Passed, Failed)
+    pub state: String,
+
+    /// When all canary replicas first became ready
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub healthy_since: Option<DateTime<Utc>>,
+
+    /// Human-readable message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
 /// Status condition
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Condition {
-    /// Condition type (Ready, ConfigValid, DeploymentAvailable, ServiceReady)
+    /// Condition type (Ready, ConfigValid, DeploymentAvailable, KafkaReachable, TopicsConsistent, LoadBalancerReady, WorkloadHealthy, CertificatesFresh, SecretsValid, Paused, Suspended, CanaryRollout)
     #[serde(rename = "type")]
     pub type_: String,
 
     /// Status (True, False, Unknown)
     pub status: String,
 
-    /// Last transition time
+    /// Last time the status changed between True, False and Unknown
     pub last_transition_time: DateTime<Utc>,
 
+    /// Resource generation the condition was computed from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observed_generation: Option<i64>,
+
     /// Reason for the condition
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
@@ -0,0 +1,58 @@
+//! KafkaRemapperTopicOverride Custom Resource Definition
+
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::kafka_partition_remapper::TopicMappingOverride;
+
+/// Per-topic mapping override attached to a KafkaPartitionRemapper
+///
+/// Overrides are aggregated into the parent's generated configuration after
+/// any inline `mapping.topics` entries, which take precedence.
+#[derive(CustomResource, Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "kafka.oso.sh",
+    version = "v1alpha1",
+    kind = "KafkaRemapperTopicOverride",
+    plural = "kafkaremappertopicoverrides",
+    singular = "kafkaremappertopicoverride",
+    shortname = "kprto",
+    namespaced,
+    printcolumn = r#"{"name": "Remapper", "type": "string", "jsonPath": ".spec.remapperRef"}"#,
+    printcolumn = r#"{"name": "Topic", "type": "string", "jsonPath": ".spec.topic"}"#,
+    printcolumn = r#"{"name": "Age", "type": "date", "jsonPath": ".metadata.creationTimestamp"}"#,
+    category = "kafka"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaRemapperTopicOverrideSpec {
+    /// Name of the KafkaPartitionRemapper in the same namespace
+    pub remapper_ref: String,
+
+    /// Topic name or regex pattern
+    pub topic: String,
+
+    /// Virtual partitions for this topic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub virtual_partitions: Option<u32>,
+
+    /// Physical partitions for this topic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub physical_partitions: Option<u32>,
+
+    /// Offset range for this topic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset_range: Option<u64>,
+}
+
+impl KafkaRemapperTopicOverrideSpec {
+    /// Convert to the inline override form used in `mapping.topics`
+    pub fn to_topic_override(&self) -> TopicMappingOverride {
+        TopicMappingOverride {
+            topic: self.topic.clone(),
+            virtual_partitions: self.virtual_partitions,
+            physical_partitions: self.physical_partitions,
+            offset_range: self.offset_range,
+        }
+    }
+}
@@ -1,12 +1,40 @@
 //! Custom Resource Definitions for the Kafka Partition Remapper Operator
 
+mod kafka_cluster_ref;
+mod kafka_partition_remap_task;
 mod kafka_partition_remapper;
+mod kafka_remapper_topic_override;
 
+pub use kafka_cluster_ref::*;
+pub use kafka_partition_remap_task::*;
 pub use kafka_partition_remapper::*;
+pub use kafka_remapper_topic_override::*;
 
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
 use kube::CustomResourceExt;
 
+/// CRDs for all custom resources
+pub fn crds() -> Vec<CustomResourceDefinition> {
+    vec![
+        KafkaPartitionRemapper::crd(),
+        KafkaClusterRef::crd(),
+        KafkaRemapperTopicOverride::crd(),
+        KafkaPartitionRemapTask::crd(),
+    ]
+}
+
 /// Generate CRD YAML manifests for all custom resources
 pub fn generate_crds() -> Vec<String> {
-    vec![serde_yaml::to_string(&KafkaPartitionRemapper::crd()).unwrap()]
+    crds()
+        .iter()
+        .map(|crd| serde_yaml::to_string(crd).unwrap())
+        .collect()
+}
+
+/// Generate CRD JSON manifests for all custom resources
+pub fn generate_crds_json() -> Vec<String> {
+    crds()
+        .iter()
+        .map(|crd| serde_json::to_string_pretty(crd).unwrap())
+        .collect()
 }
@@ -1,38 +1,107 @@
 //! Error types for the Kafka Partition Remapper Operator
 
-use std::fmt;
+use std::time::Duration;
+
+use crate::settings::RequeueSettings;
 
 /// Result type for the operator
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// Error type for the operator
-#[derive(Debug)]
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
     /// Kubernetes API error
-    KubeError(String),
+    #[error("Kubernetes API error: {context}: {source}")]
+    KubeError {
+        /// What the operator was doing
+        context: String,
+        /// Error returned by the client
+        #[source]
+        source: kube::Error,
+    },
     /// Configuration error
+    #[error("Configuration error: {0}")]
     ConfigError(String),
     /// Validation error
+    #[error("Validation error: {0}")]
     ValidationError(String),
     /// Secret error
+    #[error("Secret error: {0}")]
     SecretError(String),
+    /// Kafka cluster communication error
+    #[error("Kafka error: {0}")]
+    KafkaError(String),
+    /// Proxy config reload error
+    #[error("Proxy reload error: {0}")]
+    ReloadError(String),
+    /// Server-side apply conflict with another field manager
+    #[error("Field conflict: {0}")]
+    FieldConflict(String),
     /// Finalizer error
-    FinalizerError(Box<kube::runtime::finalizer::Error<Error>>),
+    #[error("Finalizer error: {0}")]
+    FinalizerError(#[source] Box<kube::runtime::finalizer::Error<Error>>),
 }
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Error {
+    /// Kubernetes API error raised while doing `context`
+    pub fn kube(context: impl Into<String>, source: kube::Error) -> Self {
+        Error::KubeError {
+            context: context.into(),
+            source,
+        }
+    }
+
+    /// Error raised by the reconcile step itself, unwrapped from the finalizer
+    pub fn cause(&self) -> &Error {
+        use kube::runtime::finalizer::Error as FinalizerError;
         match self {
-            Error::KubeError(msg) => write!(f, "Kubernetes API error: {}", msg),
-            Error::ConfigError(msg) => write!(f, "Configuration error: {}", msg),
-            Error::ValidationError(msg) => write!(f, "Validation error: {}", msg),
-            Error::SecretError(msg) => write!(f, "Secret error: {}", msg),
-            Error::FinalizerError(e) => write!(f, "Finalizer error: {}", e),
+            Error::FinalizerError(e) => match e.as_ref() {
+                FinalizerError::ApplyFailed(e) | FinalizerError::CleanupFailed(e) => e.cause(),
+                _ => self,
+            },
+            _ => self,
+        }
+    }
+
+    /// Whether retrying without a spec change can succeed
+    ///
+    /// Rejected specs, field conflicts and API requests the server refuses
+    /// outright are terminal; timeouts, conflicts on update, throttling and
+    /// server errors are transient.
+    pub fn is_retryable(&self) -> bool {
+        match self.cause() {
+            Error::KubeError {
+                source: kube::Error::Api(ae),
+                ..
+            } => matches!(ae.code, 404 | 408 | 409 | 429) || ae.code >= 500,
+            Error::ConfigError(_) | Error::ValidationError(_) | Error::FieldConflict(_) => false,
+            _ => true,
+        }
+    }
+
+    /// Interval before the first retry, before backoff is applied
+    pub fn retry_after(&self, requeue: &RequeueSettings) -> Duration {
+        match self.cause() {
+            e if !e.is_retryable() => requeue.invalid_spec(),
+            Error::KubeError { .. } => requeue.api_error(),
+            _ => requeue.error(),
         }
     }
-}
 
-impl std::error::Error for Error {}
+    /// Variant name used as the `error` metrics label
+    pub fn metric_label(&self) -> &'static str {
+        match self.cause() {
+            Error::KubeError { .. } => "kube",
+            Error::ConfigError(_) => "config",
+            Error::ValidationError(_) => "validation",
+            Error::SecretError(_) => "secret",
+            Error::KafkaError(_) => "kafka",
+            Error::ReloadError(_) => "reload",
+            Error::FieldConflict(_) => "field_conflict",
+            Error::FinalizerError(_) => "finalizer",
+        }
+    }
+}
 
 impl From<kube::runtime::finalizer::Error<Error>> for Error {
     fn from(err: kube::runtime::finalizer::Error<Error>) -> Self {
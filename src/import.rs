@@ -0,0 +1,341 @@
+//! Import of hand-managed proxy configurations
+//!
+//! Converts a proxy `config.yaml` into an equivalent KafkaPartitionRemapper
+//! manifest. Connection settings, listeners, the mapping, metrics and logging
+//! carry over directly. Sections that need Secrets or have no direct field on
+//! the remapper are reported as warnings to be configured by hand.
+
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+use crate::crd::KafkaPartitionRemapper;
+use crate::reconcilers::remapper;
+use crate::{Error, Result};
+
+/// A generated manifest and what could not be carried over
+#[derive(Clone, Debug)]
+pub struct Import {
+    /// The KafkaPartitionRemapper manifest
+    pub manifest: Value,
+    /// Settings left out of the manifest, or why it is not yet valid
+    pub warnings: Vec<String>,
+}
+
+type Unmapped = BTreeMap<String, serde_yaml::Value>;
+
+#[derive(Deserialize)]
+struct ProxyConfig {
+    #[serde(default)]
+    listeners: Vec<ProxyListener>,
+    /// Single listener of older configurations
+    listen: Option<ProxyListener>,
+    kafka: ProxyKafka,
+    mapping: ProxyMapping,
+    metrics: Option<ProxyMetrics>,
+    logging: Option<ProxyLogging>,
+    #[serde(flatten)]
+    other: Unmapped,
+}
+
+#[derive(Deserialize)]
+struct ProxyListener {
+    name: Option<String>,
+    address: String,
+    advertised_address: Option<String>,
+    max_connections: Option<u32>,
+    security_protocol: Option<String>,
+    #[serde(flatten)]
+    other: Unmapped,
+}
+
+#[derive(Deserialize)]
+struct ProxyKafka {
+    bootstrap_servers: Vec<String>,
+    connection_timeout_ms: Option<u64>,
+    request_timeout_ms: Option<u64>,
+    metadata_refresh_interval_secs: Option<u64>,
+    security_protocol: Option<String>,
+    #[serde(default)]
+    broker_address_map: BTreeMap<String, String>,
+    max_api_version: Option<i16>,
+    #[serde(default)]
+    api_versions: BTreeMap<String, i16>,
+    #[serde(flatten)]
+    other: Unmapped,
+}
+
+#[derive(Deserialize)]
+struct ProxyMapping {
+    virtual_partitions: u32,
+    physical_partitions: u32,
+    offset_range: Option<u64>,
+    /// Overrides keyed by topic, in the order the proxy matches them
+    #[serde(default)]
+    topics: serde_yaml::Mapping,
+    #[serde(default)]
+    topic_renames: Vec<ProxyRename>,
+    #[serde(flatten)]
+    other: Unmapped,
+}
+
+#[derive(Deserialize)]
+struct ProxyTopic {
+    virtual_partitions: Option<u32>,
+    physical_partitions: Option<u32>,
+    offset_range: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct ProxyRename {
+    prefix: Option<String>,
+    pattern: Option<String>,
+    replacement: String,
+}
+
+#[derive(Deserialize)]
+struct ProxyMetrics {
+    enabled: Option<bool>,
+    address: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ProxyLogging {
+    level: Option<String>,
+    json: Option<bool>,
+}
+
+/// Convert a proxy configuration into a remapper called `name`
+///
+/// Fails when the configuration cannot be parsed. A manifest that parses but
+/// fails the operator's validation is still returned, with the reason among
+/// the warnings.
+pub fn import_proxy_config(config: &str, name: &str, namespace: Option<&str>) -> Result<Import> {
+    let config: ProxyConfig = serde_yaml::from_str(config)
+        .map_err(|e| Error::ConfigError(format!("Invalid proxy configuration: {}", e)))?;
+    let mut warnings = Vec::new();
+
+    let mut metadata = json!({ "name": name });
+    if let Some(namespace) = namespace {
+        metadata["namespace"] = json!(namespace);
+    }
+    let mut spec = Map::new();
+    spec.insert(
+        "kafka".to_string(),
+        kafka_spec(&config.kafka, &mut warnings),
+    );
+    spec.insert(
+        "mapping".to_string(),
+        mapping_spec(&config.mapping, &mut warnings)?,
+    );
+
+    let listeners: Vec<_> = config.listen.iter().chain(&config.listeners).collect();
+    if !listeners.is_empty() {
+        let listeners = listeners
+            .into_iter()
+            .enumerate()
+            .map(|(index, listener)| listener_spec(index, listener, &mut warnings))
+            .collect::<Result<Vec<_>>>()?;
+        spec.insert("listeners".to_string(), Value::Array(listeners));
+    }
+    if let Some(ref metrics) = config.metrics {
+        let mut entry = Map::new();
+        if let Some(enabled) = metrics.enabled {
+            entry.insert("enabled".to_string(), json!(enabled));
+        }
+        if let Some(ref address) = metrics.address {
+            entry.insert("port".to_string(), json!(port(address, "metrics.address")?));
+        }
+        spec.insert("metrics".to_string(), Value::Object(entry));
+    }
+    if let Some(ref logging) = config.logging {
+        let mut entry = Map::new();
+        if let Some(ref level) = logging.level {
+            entry.insert("level".to_string(), json!(level.to_lowercase()));
+        }
+        if let Some(json) = logging.json {
+            entry.insert("json".to_string(), json!(json));
+        }
+        spec.insert("logging".to_string(), Value::Object(entry));
+    }
+    unmapped("", &config.other, &mut warnings);
+
+    let manifest = json!({
+        "apiVersion": "kafka.oso.sh/v1alpha1",
+        "kind": "KafkaPartitionRemapper",
+        "metadata": metadata,
+        "spec": spec,
+    });
+    let remapper: KafkaPartitionRemapper = serde_json::from_value(manifest.clone())
+        .map_err(|e| Error::ConfigError(format!("Invalid proxy configuration: {}", e)))?;
+    if let Err(e) = remapper::validate(&remapper) {
+        warnings.push(format!("The manifest needs completing: {}", e));
+    }
+    Ok(Import { manifest, warnings })
+}
+
+fn kafka_spec(kafka: &ProxyKafka, warnings: &mut Vec<String>) -> Value {
+    let mut spec = Map::new();
+    spec.insert(
+        "bootstrapServers".to_string(),
+        json!(kafka.bootstrap_servers),
+    );
+    let timeouts = [
+        ("connectionTimeoutMs", kafka.connection_timeout_ms),
+        ("requestTimeoutMs", kafka.request_timeout_ms),
+        (
+            "metadataRefreshIntervalSecs",
+            kafka.metadata_refresh_interval_secs,
+        ),
+    ];
+    for (field, value) in timeouts {
+        if let Some(value) = value {
+            spec.insert(field.to_string(), json!(value));
+        }
+    }
+    if let Some(ref protocol) = kafka.security_protocol {
+        spec.insert(
+            "securityProtocol".to_string(),
+            json!(protocol.to_uppercase()),
+        );
+    }
+    if !kafka.broker_address_map.is_empty() {
+        spec.insert(
+            "brokerAddressMap".to_string(),
+            json!(kafka.broker_address_map),
+        );
+    }
+    if let Some(version) = kafka.max_api_version {
+        spec.insert("maxApiVersion".to_string(), json!(version));
+    }
+    if !kafka.api_versions.is_empty() {
+        spec.insert("apiVersions".to_string(), json!(kafka.api_versions));
+    }
+
+    for key in kafka.other.keys() {
+        let hint = match key.as_str() {
+            "tls" => "store the certificates in a Secret and set spec.kafka.tlsSecret",
+            "sasl" => "store the credentials in a Secret and set spec.kafka.saslSecret",
+            "kerberos" => "store the keytab in a Secret and set spec.kafka.kerberos",
+            _ => "configure it on the remapper by hand",
+        };
+        warnings.push(format!("kafka.{} is not imported: {}", key, hint));
+    }
+    Value::Object(spec)
+}
+
+fn mapping_spec(mapping: &ProxyMapping, warnings: &mut Vec<String>) -> Result<Value> {
+    let mut spec = Map::new();
+    spec.insert(
+        "virtualPartitions".to_string(),
+        json!(mapping.virtual_partitions),
+    );
+    spec.insert(
+        "physicalPartitions".to_string(),
+        json!(mapping.physical_partitions),
+    );
+    if let Some(range) = mapping.offset_range {
+        spec.insert("offsetRange".to_string(), json!(range));
+    }
+
+    let mut topics = Vec::new();
+    for (topic, overrides) in &mapping.topics {
+        let topic = topic.as_str().ok_or_else(|| {
+            Error::ConfigError("mapping.topics keys must be topic names".to_string())
+        })?;
+        let overrides: ProxyTopic = serde_yaml::from_value(overrides.clone())
+            .map_err(|e| Error::ConfigError(format!("Invalid mapping.topics.{}: {}", topic, e)))?;
+        let mut entry = json!({ "topic": topic });
+        if let Some(v) = overrides.virtual_partitions {
+            entry["virtualPartitions"] = json!(v);
+        }
+        if let Some(p) = overrides.physical_partitions {
+            entry["physicalPartitions"] = json!(p);
+        }
+        if let Some(r) = overrides.offset_range {
+            entry["offsetRange"] = json!(r);
+        }
+        topics.push(entry);
+    }
+    if !topics.is_empty() {
+        spec.insert("topics".to_string(), Value::Array(topics));
+    }
+
+    let renames: Vec<_> = mapping
+        .topic_renames
+        .iter()
+        .map(|rename| {
+            let mut entry = json!({ "replacement": rename.replacement });
+            if let Some(ref prefix) = rename.prefix {
+                entry["prefix"] = json!(prefix);
+            }
+            if let Some(ref pattern) = rename.pattern {
+                // The operator anchors patterns itself
+                let pattern = pattern
+                    .strip_prefix("^(?:")
+                    .and_then(|p| p.strip_suffix(")$"))
+                    .unwrap_or(pattern);
+                entry["pattern"] = json!(pattern);
+            }
+            entry
+        })
+        .collect();
+    if !renames.is_empty() {
+        spec.insert("topicRenames".to_string(), Value::Array(renames));
+    }
+
+    unmapped("mapping.", &mapping.other, warnings);
+    Ok(Value::Object(spec))
+}
+
+fn listener_spec(
+    index: usize,
+    listener: &ProxyListener,
+    warnings: &mut Vec<String>,
+) -> Result<Value> {
+    let field = format!("listeners[{}]", index);
+    let mut spec = json!({ "port": port(&listener.address, &format!("{}.address", field))? });
+    if let Some(ref name) = listener.name {
+        spec["name"] = json!(name);
+    }
+    if let Some(ref address) = listener.advertised_address {
+        spec["advertisedAddress"] = json!(address);
+    }
+    if let Some(max) = listener.max_connections {
+        spec["maxConnections"] = json!(max);
+    }
+    let plaintext = listener
+        .security_protocol
+        .as_deref()
+        .is_none_or(|p| p.eq_ignore_ascii_case("PLAINTEXT"));
+    if !plaintext || !listener.other.is_empty() {
+        warnings.push(format!(
+            "{} client security is not imported: configure spec.listeners[{}].security",
+            field, index
+        ));
+    }
+    Ok(spec)
+}
+
+/// Port of a `host:port` address
+fn port(address: &str, field: &str) -> Result<i32> {
+    address
+        .rsplit_once(':')
+        .and_then(|(_, port)| port.parse().ok())
+        .ok_or_else(|| {
+            Error::ConfigError(format!(
+                "{} '{}' is not a host:port address",
+                field, address
+            ))
+        })
+}
+
+/// Warn about each section without a direct remapper field
+fn unmapped(prefix: &str, other: &Unmapped, warnings: &mut Vec<String>) {
+    warnings.extend(other.keys().map(|key| {
+        format!(
+            "{}{} is not imported: configure it on the remapper by hand",
+            prefix, key
+        )
+    }));
+}
@@ -4,10 +4,17 @@
 //! using Custom Resource Definitions (CRDs).
 
 pub mod adapters;
+pub mod bundle;
 pub mod controllers;
 pub mod crd;
 pub mod error;
+pub mod import;
+pub mod logging;
+pub mod mapping;
 pub mod metrics;
+pub mod olm;
 pub mod reconcilers;
+pub mod settings;
+pub mod webhooks;
 
 pub use error::{Error, Result};
@@ -0,0 +1,83 @@
+//! Operator log output and runtime verbosity changes
+//!
+//! The level filter sits behind a reload handle so it can be raised during an
+//! incident through the metrics server's authenticated `/loglevel` endpoint,
+//! and restored to the startup filter with SIGHUP, without restarting the
+//! operator.
+
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use tracing_subscriber::{layer::SubscriberExt, reload, util::SubscriberInitExt};
+use tracing_subscriber::{EnvFilter, Registry};
+
+/// Filter used when neither `--log-level` nor `RUST_LOG` is set
+pub const DEFAULT_FILTER: &str =
+    "info,kafka_partition_remapper_operator=debug,kube=warn,hyper=warn";
+
+static HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+static INITIAL_FILTER: OnceLock<String> = OnceLock::new();
+
+/// Log line format
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// One JSON object per line
+    #[default]
+    Json,
+    /// Human-readable text
+    Text,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(LogFormat::Json),
+            "text" => Ok(LogFormat::Text),
+            other => Err(format!(
+                "unknown log format {}, expected json or text",
+                other
+            )),
+        }
+    }
+}
+
+/// Install the global subscriber with `filter` directives in `format`
+pub fn init(format: LogFormat, filter: &str) -> anyhow::Result<()> {
+    let env_filter = EnvFilter::try_new(filter)?;
+    let (filter_layer, handle) = reload::Layer::new(env_filter);
+
+    tracing_subscriber::registry()
+        .with(filter_layer)
+        .with((format == LogFormat::Json).then(|| tracing_subscriber::fmt::layer().json()))
+        .with((format == LogFormat::Text).then(tracing_subscriber::fmt::layer))
+        .try_init()?;
+
+    let _ = HANDLE.set(handle);
+    let _ = INITIAL_FILTER.set(filter.to_string());
+    Ok(())
+}
+
+/// Directives of the active filter, if logging is initialized
+pub fn current_filter() -> Option<String> {
+    HANDLE.get()?.with_current(|f| f.to_string()).ok()
+}
+
+/// Replace the active filter with `directives`
+pub fn set_filter(directives: &str) -> Result<(), String> {
+    let filter = EnvFilter::try_new(directives.trim()).map_err(|e| e.to_string())?;
+    HANDLE
+        .get()
+        .ok_or_else(|| "logging is not initialized".to_string())?
+        .reload(filter)
+        .map_err(|e| e.to_string())
+}
+
+/// Restore the filter the operator started with
+pub fn reset_filter() -> Result<(), String> {
+    let initial = INITIAL_FILTER
+        .get()
+        .ok_or_else(|| "logging is not initialized".to_string())?;
+    set_filter(initial)
+}
@@ -2,15 +2,35 @@
 //!
 //! Main entry point for the operator. Sets up the Kubernetes client,
 //! registers CRD controllers, and runs the reconciliation loops.
+//!
+//! `--render <file.yaml>` prints the resources a KafkaPartitionRemapper
+//! manifest would produce without connecting to a cluster.
+//!
+//! `--audit` runs one consistency audit, as the audit CronJob does.
+//!
+//! `--log-format json|text` and `--log-level <filter>` set the log output;
+//! the filter can be changed at runtime through `PUT /loglevel` on the
+//! metrics port, when metrics authentication is configured, and restored
+//! with SIGHUP.
 
-use kube::Client;
+use kube::{client::ClientBuilder, Client};
+use std::time::Duration;
 use tokio::signal;
-use tracing::{error, info};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+use tower::limit::RateLimitLayer;
+use tracing::{error, info, warn};
+use tracing_subscriber::EnvFilter;
 
 use kafka_partition_remapper_operator::{
-    controllers::{remapper_controller, Context},
-    metrics,
+    adapters::consistency_audit_builder::{AuditTarget, AUDIT_TARGET_ENV},
+    controllers::{
+        remap_task_controller, remapper_controller, Context, ControllerTuning, WatchScope,
+    },
+    crd::KafkaPartitionRemapper,
+    logging::{self, LogFormat},
+    metrics::{self, health, MetricsAuth, MetricsServerConfig},
+    reconcilers::{consistency_audit, remapper},
+    settings,
+    webhooks::server::{self as webhook_server, WebhookServerConfig},
 };
 
 /// Default metrics port
@@ -18,33 +38,92 @@ const METRICS_PORT: u16 = 8080;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    // Render a manifest offline instead of running the operator
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(i) = args.iter().position(|a| a == "--render") {
+        let Some(path) = args.get(i + 1) else {
+            anyhow::bail!("--render requires a manifest file");
+        };
+        return render(path);
+    }
+
     // Initialize tracing
-    init_tracing();
+    init_tracing(&args)?;
+
+    // Run a single consistency audit on behalf of the audit CronJob
+    if args.iter().any(|a| a == "--audit") {
+        return audit().await;
+    }
 
     info!("Starting OSO Kafka Partition Remapper Operator");
 
-    // Create Kubernetes client
-    let client = Client::try_default().await?;
+    tokio::spawn(reset_log_filter_on_hangup());
+
+    // Create a rate-limited Kubernetes client
+    let tuning = ControllerTuning::from_vars(|name| std::env::var(name).ok());
+    info!("Controller tuning: {:?}", tuning);
+    let client = kube_client(&tuning).await?;
     info!("Connected to Kubernetes API server");
 
+    // Load operator settings and keep them in sync with the ConfigMap
+    let (settings_namespace, settings_name) = settings_config_map();
+    if let Err(e) = settings::load(&client, &settings_namespace, &settings_name).await {
+        warn!("Failed to load operator settings, using defaults: {}", e);
+    }
+    let settings_handle = tokio::spawn(settings::watch(
+        client.clone(),
+        settings_namespace,
+        settings_name,
+    ));
+
     // Create shared context
     let context = Context::new(client.clone());
 
+    // Not ready until the remapper and task controllers below have listed
+    // their resources in every watched namespace
+    let scope = watch_scope();
+    health::expect_watches(2 * scope.controller_count());
+
     // Start metrics server
-    let metrics_handle = tokio::spawn(metrics::serve(METRICS_PORT));
+    let metrics_handle = tokio::spawn(metrics::serve(metrics_config()?));
     info!("Metrics server starting on port {}", METRICS_PORT);
 
+    // Start admission webhook server if enabled
+    let webhook_handle = match webhook_config() {
+        Some(config) => {
+            info!("Webhook server starting on port {}", config.port);
+            tokio::spawn(webhook_server::serve(config))
+        }
+        None => tokio::spawn(std::future::pending()),
+    };
+
     // Run the remapper controller
-    let controller_handle = tokio::spawn(remapper_controller::run(context));
+    let controller_handle = tokio::spawn(remapper_controller::run(
+        context.clone(),
+        scope.clone(),
+        tuning.clone(),
+    ));
+
+    // Run the remap task controller
+    let remap_task_handle = tokio::spawn(remap_task_controller::run(context, scope, tuning));
 
     // Handle graceful shutdown
     tokio::select! {
         _ = controller_handle => {
             error!("Remapper controller exited unexpectedly");
         }
+        _ = remap_task_handle => {
+            error!("Remap task controller exited unexpectedly");
+        }
+        _ = settings_handle => {
+            error!("Operator settings watcher exited unexpectedly");
+        }
         _ = metrics_handle => {
             error!("Metrics server exited unexpectedly");
         }
+        res = webhook_handle => {
+            error!("Webhook server exited unexpectedly: {:?}", res);
+        }
         _ = shutdown_signal() => {
             info!("Received shutdown signal, stopping operator");
         }
@@ -54,16 +133,198 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Initialize tracing subscriber
-fn init_tracing() {
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-        EnvFilter::new("info,kafka_partition_remapper_operator=debug,kube=warn,hyper=warn")
+/// Print the child resources a KafkaPartitionRemapper manifest produces
+///
+/// `-` reads the manifest from standard input. The remapper's namespace
+/// defaults to `default` when the manifest leaves it unset.
+fn render(path: &str) -> anyhow::Result<()> {
+    let manifest = if path == "-" {
+        std::io::read_to_string(std::io::stdin())?
+    } else {
+        std::fs::read_to_string(path)?
+    };
+    let mut remapper: KafkaPartitionRemapper = serde_yaml::from_str(&manifest)?;
+    remapper
+        .metadata
+        .namespace
+        .get_or_insert_with(|| "default".to_string());
+
+    for manifest in remapper::render_manifests(&remapper)? {
+        println!("---");
+        print!("{}", manifest);
+    }
+    Ok(())
+}
+
+/// Run one consistency audit of the target in `AUDIT_TARGET`
+///
+/// Findings are reported in the remapper status rather than through the
+/// exit code, which only fails when the audit could not be reported.
+async fn audit() -> anyhow::Result<()> {
+    let target: AuditTarget = serde_json::from_str(&std::env::var(AUDIT_TARGET_ENV)?)?;
+    let client = Client::try_default().await?;
+    let status = consistency_audit::run(&client, &target).await;
+    for finding in &status.findings {
+        warn!("Audit finding: {}", finding);
+    }
+    consistency_audit::report(&client, &target, &status).await?;
+    Ok(())
+}
+
+/// Read webhook server settings from the environment
+///
+/// The webhook is enabled with `WEBHOOK_ENABLED=true` and serves the
+/// `tls.crt`/`tls.key` pair found in `WEBHOOK_CERT_DIR`.
+fn webhook_config() -> Option<WebhookServerConfig> {
+    let enabled = std::env::var("WEBHOOK_ENABLED")
+        .map(|v| v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
+    if !enabled {
+        return None;
+    }
+
+    let port = std::env::var("WEBHOOK_PORT")
+        .ok()
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(webhook_server::DEFAULT_WEBHOOK_PORT);
+    let cert_dir = std::env::var("WEBHOOK_CERT_DIR")
+        .unwrap_or_else(|_| webhook_server::DEFAULT_CERT_DIR.to_string());
+
+    Some(WebhookServerConfig::from_cert_dir(port, cert_dir))
+}
+
+/// Read the controller watch scope from the environment
+///
+/// `WATCH_NAMESPACES` is a comma-separated namespace list (empty watches all
+/// namespaces) and `WATCH_LABEL_SELECTOR` restricts the remappers handled.
+fn watch_scope() -> WatchScope {
+    let namespaces = std::env::var("WATCH_NAMESPACES").unwrap_or_default();
+    let label_selector = std::env::var("WATCH_LABEL_SELECTOR").ok();
+    WatchScope::new(&namespaces, label_selector.as_deref())
+}
+
+/// Create a Kubernetes client limited to the tuned request rate
+async fn kube_client(tuning: &ControllerTuning) -> anyhow::Result<Client> {
+    let builder = ClientBuilder::try_from(kube::Config::infer().await?)?;
+    Ok(match tuning.client_rate_limit() {
+        Some((requests, per)) => builder
+            .with_layer(&RateLimitLayer::new(requests, per))
+            .build(),
+        None => builder.build(),
+    })
+}
+
+/// Read metrics server settings from the environment
+///
+/// `HEALTH_MAX_RECONCILE_AGE_SECS` fails `/healthz` once no reconcile has
+/// succeeded for that long; unset or 0 disables the check, which only suits
+/// fleets that reconcile periodically, e.g. with `WATCH_RESYNC_SECS`.
+/// `METRICS_TLS_CERT_DIR` serves HTTPS with its `tls.crt`/`tls.key` pair.
+/// `METRICS_BEARER_TOKEN`, or `METRICS_BASIC_AUTH_USERNAME` with
+/// `METRICS_BASIC_AUTH_PASSWORD`, protects the scrape endpoints.
+fn metrics_config() -> anyhow::Result<MetricsServerConfig> {
+    let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+
+    let max_reconcile_age = var("HEALTH_MAX_RECONCILE_AGE_SECS")
+        .and_then(|s| s.parse().ok())
+        .filter(|secs| *secs > 0)
+        .map(Duration::from_secs);
+    let tls = var("METRICS_TLS_CERT_DIR").map(|dir| {
+        let dir = std::path::Path::new(&dir);
+        (dir.join("tls.crt"), dir.join("tls.key"))
     });
+    let auth = match (
+        var("METRICS_BEARER_TOKEN"),
+        var("METRICS_BASIC_AUTH_USERNAME"),
+        var("METRICS_BASIC_AUTH_PASSWORD"),
+    ) {
+        (Some(_), Some(_), _) => {
+            anyhow::bail!("set either METRICS_BEARER_TOKEN or METRICS_BASIC_AUTH_USERNAME")
+        }
+        (Some(token), None, _) => MetricsAuth::Bearer(token),
+        (None, Some(username), Some(password)) => MetricsAuth::Basic { username, password },
+        (None, Some(_), None) => anyhow::bail!("METRICS_BASIC_AUTH_PASSWORD is not set"),
+        (None, None, _) => MetricsAuth::None,
+    };
+
+    Ok(MetricsServerConfig {
+        port: METRICS_PORT,
+        max_reconcile_age,
+        tls,
+        auth,
+    })
+}
+
+/// Locate the operator settings ConfigMap
+///
+/// Read from `OPERATOR_CONFIG_MAP` in the `OPERATOR_NAMESPACE` namespace.
+fn settings_config_map() -> (String, String) {
+    let namespace = std::env::var("OPERATOR_NAMESPACE").unwrap_or_else(|_| "default".to_string());
+    let name = std::env::var("OPERATOR_CONFIG_MAP")
+        .unwrap_or_else(|_| settings::DEFAULT_CONFIG_MAP_NAME.to_string());
+    (namespace, name)
+}
+
+/// Initialize tracing subscriber
+///
+/// `--log-format json|text` (or `LOG_FORMAT`) picks the output format and
+/// `--log-level` (or `RUST_LOG`) the filter directives.
+fn init_tracing(args: &[String]) -> anyhow::Result<()> {
+    let flag = |name: &str| -> anyhow::Result<Option<String>> {
+        match args.iter().position(|a| a == name) {
+            Some(i) => match args.get(i + 1) {
+                Some(value) => Ok(Some(value.clone())),
+                None => anyhow::bail!("{} requires a value", name),
+            },
+            None => Ok(None),
+        }
+    };
+
+    let format = match flag("--log-format")?.or_else(|| std::env::var("LOG_FORMAT").ok()) {
+        Some(format) => format.parse().map_err(anyhow::Error::msg)?,
+        None => LogFormat::default(),
+    };
+    // An invalid --log-level is rejected, but a bad RUST_LOG falls back to
+    // the default filter so an env typo does not crashloop the operator
+    let (filter, rejected_env) = match flag("--log-level")? {
+        Some(level) => (level, None),
+        None => match std::env::var(EnvFilter::DEFAULT_ENV) {
+            Ok(env) if EnvFilter::try_new(&env).is_ok() => (env, None),
+            Ok(env) => (logging::DEFAULT_FILTER.to_string(), Some(env)),
+            Err(_) => (logging::DEFAULT_FILTER.to_string(), None),
+        },
+    };
 
-    tracing_subscriber::registry()
-        .with(env_filter)
-        .with(tracing_subscriber::fmt::layer().json())
-        .init();
+    logging::init(format, &filter)?;
+    if let Some(env) = rejected_env {
+        warn!(
+            "Ignoring invalid {} filter {:?}, using {}",
+            EnvFilter::DEFAULT_ENV,
+            env,
+            filter
+        );
+    }
+    Ok(())
+}
+
+/// Restore the startup log filter on SIGHUP
+async fn reset_log_filter_on_hangup() {
+    #[cfg(unix)]
+    {
+        let mut hangup = match signal::unix::signal(signal::unix::SignalKind::hangup()) {
+            Ok(hangup) => hangup,
+            Err(e) => {
+                warn!("Failed to install SIGHUP handler: {}", e);
+                return;
+            }
+        };
+        while hangup.recv().await.is_some() {
+            match logging::reset_filter() {
+                Ok(()) => info!("Received SIGHUP, restored startup log filter"),
+                Err(e) => warn!("Failed to restore log filter: {}", e),
+            }
+        }
+    }
 }
 
 /// Wait for shutdown signal (SIGTERM or SIGINT)
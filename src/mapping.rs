@@ -0,0 +1,283 @@
+//! Virtual to physical partition and offset translation
+//!
+//! The proxy spreads `virtual_partitions` over `physical_partitions` by
+//! striping: virtual partition `v` lives on physical partition
+//! `v % physical_partitions`, in group `v / physical_partitions`. Every group
+//! owns a window of `offset_range` virtual offsets, so a virtual offset is the
+//! physical offset shifted into its group's window:
+//!
+//! ```text
+//! virtual_offset = group * offset_range + physical_offset
+//! ```
+//!
+//! The virtual partitions of one physical partition share its offsets, which
+//! therefore must stay below `offset_range`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::crd::MappingSpec;
+use crate::{Error, Result};
+
+/// Largest offset Kafka can represent
+pub const MAX_KAFKA_OFFSET: u64 = i64::MAX as u64;
+
+/// Partition layout of one topic
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Mapping {
+    virtual_partitions: u32,
+    physical_partitions: u32,
+    offset_range: u64,
+}
+
+impl Mapping {
+    /// Create a mapping, checking the layout can be translated
+    ///
+    /// Virtual partitions must be a non-zero multiple of physical partitions,
+    /// and the highest virtual offset must fit in a Kafka offset.
+    pub fn new(
+        virtual_partitions: u32,
+        physical_partitions: u32,
+        offset_range: u64,
+    ) -> Result<Self> {
+        if physical_partitions == 0 || offset_range == 0 {
+            return Err(Error::ValidationError(
+                "physical partitions and offset range must be >= 1".to_string(),
+            ));
+        }
+        if virtual_partitions < physical_partitions
+            || !virtual_partitions.is_multiple_of(physical_partitions)
+        {
+            return Err(Error::ValidationError(format!(
+                "{} virtual partitions are not a multiple of {} physical partitions",
+                virtual_partitions, physical_partitions
+            )));
+        }
+        let groups = u64::from(virtual_partitions / physical_partitions);
+        if groups
+            .checked_mul(offset_range)
+            .is_none_or(|top| top - 1 > MAX_KAFKA_OFFSET)
+        {
+            return Err(Error::ValidationError(format!(
+                "{} groups of {} offsets exceed the largest Kafka offset",
+                groups, offset_range
+            )));
+        }
+        Ok(Self {
+            virtual_partitions,
+            physical_partitions,
+            offset_range,
+        })
+    }
+
+    /// Mapping applied to a topic, honouring the first matching override
+    ///
+    /// `matches` decides whether an override's topic name or pattern applies.
+    pub fn for_topic(spec: &MappingSpec, matches: impl Fn(&str) -> bool) -> Result<Self> {
+        let topic = spec.topics.iter().find(|t| matches(&t.topic));
+        Self::new(
+            topic
+                .and_then(|t| t.virtual_partitions)
+                .unwrap_or(spec.virtual_partitions),
+            topic
+                .and_then(|t| t.physical_partitions)
+                .unwrap_or(spec.physical_partitions),
+            topic
+                .and_then(|t| t.offset_range)
+                .unwrap_or(spec.offset_range),
+        )
+    }
+
+    /// Number of virtual partitions
+    pub fn virtual_partitions(&self) -> u32 {
+        self.virtual_partitions
+    }
+
+    /// Number of physical partitions
+    pub fn physical_partitions(&self) -> u32 {
+        self.physical_partitions
+    }
+
+    /// Size of each group's virtual offset window
+    pub fn offset_range(&self) -> u64 {
+        self.offset_range
+    }
+
+    /// Virtual partitions sharing each physical partition
+    pub fn compression_ratio(&self) -> u32 {
+        self.virtual_partitions / self.physical_partitions
+    }
+
+    /// Offsets available to each virtual partition on average, since the
+    /// virtual partitions of a physical partition share its offset range
+    pub fn offset_headroom(&self) -> u64 {
+        self.offset_range / u64::from(self.compression_ratio())
+    }
+
+    /// Physical partition holding a virtual partition
+    pub fn physical_partition(&self, virtual_partition: u32) -> Result<u32> {
+        self.check_virtual_partition(virtual_partition)?;
+        Ok(virtual_partition % self.physical_partitions)
+    }
+
+    /// Virtual partitions stored on a physical partition, in group order
+    pub fn virtual_partitions_of(&self, physical_partition: u32) -> Result<Vec<u32>> {
+        if physical_partition >= self.physical_partitions {
+            return Err(Error::ValidationError(format!(
+                "physical partition {} is out of range 0..{}",
+                physical_partition, self.physical_partitions
+            )));
+        }
+        Ok((0..self.compression_ratio())
+            .map(|group| group * self.physical_partitions + physical_partition)
+            .collect())
+    }
+
+    /// Translate a virtual partition and offset to the physical ones
+    ///
+    /// Fails when the offset lies outside the virtual partition's window.
+    pub fn virtual_to_physical(&self, partition: u32, offset: u64) -> Result<(u32, u64)> {
+        let physical_partition = self.physical_partition(partition)?;
+        let window_start = self.window_start(partition);
+        let physical_offset = offset
+            .checked_sub(window_start)
+            .filter(|o| *o < self.offset_range)
+            .ok_or_else(|| {
+                Error::ValidationError(format!(
+                    "offset {} is outside virtual partition {}'s window {}..{}",
+                    offset,
+                    partition,
+                    window_start,
+                    window_start + self.offset_range
+                ))
+            })?;
+        Ok((physical_partition, physical_offset))
+    }
+
+    /// Translate a physical offset read for a virtual partition to its
+    /// virtual offset
+    ///
+    /// Fails when the physical offset has outgrown the offset range.
+    pub fn physical_to_virtual(&self, virtual_partition: u32, physical_offset: u64) -> Result<u64> {
+        self.check_virtual_partition(virtual_partition)?;
+        if physical_offset >= self.offset_range {
+            return Err(Error::ValidationError(format!(
+                "physical offset {} exceeds the offset range {}",
+                physical_offset, self.offset_range
+            )));
+        }
+        Ok(self.window_start(virtual_partition) + physical_offset)
+    }
+
+    fn window_start(&self, virtual_partition: u32) -> u64 {
+        u64::from(virtual_partition / self.physical_partitions) * self.offset_range
+    }
+
+    fn check_virtual_partition(&self, virtual_partition: u32) -> Result<()> {
+        if virtual_partition >= self.virtual_partitions {
+            return Err(Error::ValidationError(format!(
+                "virtual partition {} is out of range 0..{}",
+                virtual_partition, self.virtual_partitions
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Where a virtual partition and offset land on the upstream cluster
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Simulation {
+    /// Client-facing topic
+    pub topic: String,
+    /// Upstream topic after the first matching rename
+    pub physical_topic: String,
+    /// Topic override applied, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_override: Option<String>,
+    /// Virtual partitions the topic exposes to clients
+    pub virtual_partitions: u32,
+    /// Partitions of the upstream topic
+    pub physical_partitions: u32,
+    /// Width of each group's virtual offset window
+    pub offset_range: u64,
+    /// Requested virtual partition
+    pub virtual_partition: u32,
+    /// Requested offset in the virtual partition
+    pub virtual_offset: u64,
+    /// Upstream partition holding the record
+    pub physical_partition: u32,
+    /// Offset of the record in the upstream partition
+    pub physical_offset: u64,
+}
+
+/// Body of the metrics server's `POST /simulate` endpoint
+#[derive(Clone, Debug, Deserialize)]
+pub struct SimulationRequest {
+    /// The remapper's `spec.mapping`
+    pub mapping: MappingSpec,
+    /// Client-facing topic to trace
+    pub topic: String,
+    /// Virtual partition to trace
+    pub partition: u32,
+    /// Offset in the virtual partition to trace
+    pub offset: u64,
+}
+
+/// Trace a record at `offset` of a topic's virtual `partition` through
+/// the renames and mapping the proxy applies
+pub fn simulate(
+    spec: &MappingSpec,
+    topic: &str,
+    partition: u32,
+    offset: u64,
+) -> Result<Simulation> {
+    let matched_override = spec
+        .topics
+        .iter()
+        .find(|t| topic_matches(&t.topic, topic))
+        .map(|t| t.topic.clone());
+    let mapping = Mapping::for_topic(spec, |t| topic_matches(t, topic))?;
+    let (physical_partition, physical_offset) = mapping.virtual_to_physical(partition, offset)?;
+    Ok(Simulation {
+        topic: topic.to_string(),
+        physical_topic: physical_topic(spec, topic),
+        matched_override,
+        virtual_partitions: mapping.virtual_partitions,
+        physical_partitions: mapping.physical_partitions,
+        offset_range: mapping.offset_range,
+        virtual_partition: partition,
+        virtual_offset: offset,
+        physical_partition,
+        physical_offset,
+    })
+}
+
+/// Whether an override's topic name or pattern covers `topic`, matching
+/// patterns against the whole name as the proxy does
+fn topic_matches(pattern: &str, topic: &str) -> bool {
+    pattern == topic
+        || regex::Regex::new(&format!("^(?:{})$", pattern)).is_ok_and(|re| re.is_match(topic))
+}
+
+/// Upstream name of a client-facing topic after the first matching rename
+fn physical_topic(spec: &MappingSpec, topic: &str) -> String {
+    for rename in &spec.topic_renames {
+        match (&rename.prefix, &rename.pattern) {
+            (Some(prefix), None) => {
+                if let Some(rest) = topic.strip_prefix(prefix.as_str()) {
+                    return format!("{}{}", rename.replacement, rest);
+                }
+            }
+            (None, Some(pattern)) => {
+                let Ok(re) = regex::Regex::new(&format!("^(?:{})$", pattern)) else {
+                    continue;
+                };
+                if re.is_match(topic) {
+                    return re.replace(topic, rename.replacement.as_str()).into_owned();
+                }
+            }
+            _ => {}
+        }
+    }
+    topic.to_string()
+}
@@ -0,0 +1,63 @@
+//! Controller state behind the health and readiness endpoints
+//!
+//! The operator is ready once every controller's watches have listed their
+//! resources. It is healthy while some reconcile has succeeded recently
+//! enough; the age limit is optional since an idle fleet reconciles only on
+//! change unless a resync interval is set.
+
+use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use chrono::Utc;
+
+static WATCHES_EXPECTED: AtomicUsize = AtomicUsize::new(0);
+static WATCHES_PENDING: AtomicUsize = AtomicUsize::new(0);
+static LAST_RECONCILE: AtomicI64 = AtomicI64::new(0);
+
+/// Expect `count` controllers to report their watches established
+///
+/// Called once before any controller starts, so readiness cannot be
+/// reported while a controller has yet to begin listing.
+pub fn expect_watches(count: usize) {
+    WATCHES_EXPECTED.store(count, Ordering::SeqCst);
+    WATCHES_PENDING.store(count, Ordering::SeqCst);
+}
+
+/// Mark an expected controller's watches as established
+pub fn watch_ready() {
+    let _ = WATCHES_PENDING.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1));
+}
+
+/// Whether watches are expected and all of them are established
+pub fn is_ready() -> bool {
+    WATCHES_EXPECTED.load(Ordering::SeqCst) > 0 && WATCHES_PENDING.load(Ordering::SeqCst) == 0
+}
+
+/// Measure reconcile age from now until the first reconcile succeeds
+pub fn start() {
+    let _ = LAST_RECONCILE.compare_exchange(
+        0,
+        Utc::now().timestamp(),
+        Ordering::SeqCst,
+        Ordering::SeqCst,
+    );
+}
+
+/// Record a successful reconcile
+pub fn record_reconcile() {
+    LAST_RECONCILE.store(Utc::now().timestamp(), Ordering::SeqCst);
+}
+
+/// Time since the last successful reconcile, or since `start` before one
+pub fn last_reconcile_age() -> Duration {
+    let since = LAST_RECONCILE.load(Ordering::SeqCst);
+    if since == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_secs(Utc::now().timestamp().saturating_sub(since).max(0) as u64)
+}
+
+/// Whether the last successful reconcile is within `max_age`, if limited
+pub fn is_healthy(max_age: Option<Duration>) -> bool {
+    max_age.is_none_or(|max| last_reconcile_age() <= max)
+}
@@ -2,6 +2,7 @@
 //!
 //! This module exposes metrics for monitoring operator health and performance.
 
+pub mod health;
 pub mod prometheus;
 
 pub use prometheus::*;
@@ -1,19 +1,34 @@
 //! Prometheus metrics definitions and HTTP server
 
+use std::future::Future;
 use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 
-use http_body_util::Full;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
 use hyper::body::Bytes;
+use hyper::header::{AUTHORIZATION, WWW_AUTHENTICATE};
 use hyper::server::conn::http1;
 use hyper::service::service_fn;
-use hyper::{Request, Response, StatusCode};
+use hyper::{Method, Request, Response, StatusCode};
 use hyper_util::rt::TokioIo;
+use kube::runtime::controller::Action;
 use prometheus::{
     register_counter_vec, register_gauge_vec, register_histogram_vec, CounterVec, Encoder,
     GaugeVec, HistogramVec, TextEncoder,
 };
+use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::net::TcpListener;
-use tracing::{error, info};
+use tokio_rustls::TlsAcceptor;
+use tracing::{error, info, warn};
+
+use crate::logging;
+use crate::mapping::{self, SimulationRequest};
+use crate::metrics::health;
+use crate::webhooks::server::load_tls_config;
 
 lazy_static::lazy_static! {
     /// Total number of reconciliations
@@ -23,11 +38,12 @@ lazy_static::lazy_static! {
         &["kind"]
     ).unwrap();
 
-    /// Total number of reconciliation errors
+    /// Total number of reconciliation errors by error variant and whether
+    /// the error is transient
     pub static ref RECONCILIATION_ERRORS: CounterVec = register_counter_vec!(
         "kafka_partition_remapper_operator_reconciliation_errors_total",
         "Total number of reconciliation errors",
-        &["kind"]
+        &["kind", "error", "retryable"]
     ).unwrap();
 
     /// Reconciliation duration histogram
@@ -38,6 +54,21 @@ lazy_static::lazy_static! {
         vec![0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]
     ).unwrap();
 
+    /// Requeues scheduled, by why the object is checked again
+    pub static ref REQUEUES: CounterVec = register_counter_vec!(
+        "kafka_partition_remapper_operator_requeues_total",
+        "Total number of requeues by reason",
+        &["kind", "reason"]
+    ).unwrap();
+
+    /// Duration of server-side applies of individual child resources
+    pub static ref CHILD_APPLY_DURATION: HistogramVec = register_histogram_vec!(
+        "kafka_partition_remapper_operator_child_apply_duration_seconds",
+        "Duration of applying a child resource in seconds",
+        &["resource"],
+        vec![0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5]
+    ).unwrap();
+
     /// Currently managed resources
     pub static ref MANAGED_RESOURCES: GaugeVec = register_gauge_vec!(
         "kafka_partition_remapper_operator_managed_resources",
@@ -52,6 +83,12 @@ lazy_static::lazy_static! {
         &["namespace", "name"]
     ).unwrap();
 
+    /// Seconds since the last successful reconcile of any resource
+    pub static ref LAST_RECONCILE_AGE: prometheus::Gauge = prometheus::register_gauge!(
+        "kafka_partition_remapper_operator_last_successful_reconcile_age_seconds",
+        "Seconds since the last successful reconcile of any resource"
+    ).unwrap();
+
     /// Operator health (1 = healthy, 0 = unhealthy)
     pub static ref OPERATOR_HEALTH: prometheus::Gauge = prometheus::register_gauge!(
         "kafka_partition_remapper_operator_health",
@@ -59,37 +96,185 @@ lazy_static::lazy_static! {
     ).unwrap();
 }
 
+/// Count a requeue of a `kind` object and return the Action scheduling it
+pub fn requeue(kind: &str, reason: &str, after: Duration) -> Action {
+    REQUEUES.with_label_values(&[kind, reason]).inc();
+    Action::requeue(after)
+}
+
+/// Run the apply of a child resource, recording how long it took
+pub async fn time_apply<T>(resource: &str, apply: impl Future<Output = T>) -> T {
+    let start = Instant::now();
+    let result = apply.await;
+    CHILD_APPLY_DURATION
+        .with_label_values(&[resource])
+        .observe(start.elapsed().as_secs_f64());
+    result
+}
+
+/// Credentials a scraper must present on `/metrics`, `/simulate` and
+/// `/loglevel`
+///
+/// Health and readiness endpoints stay open for kubelet probes. `/loglevel`
+/// changes operator state, so it is only served when credentials are set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum MetricsAuth {
+    /// No credentials required
+    #[default]
+    None,
+    /// `Authorization: Bearer <token>`
+    Bearer(String),
+    /// `Authorization: Basic <base64 username:password>`
+    Basic {
+        /// Expected username
+        username: String,
+        /// Expected password
+        password: String,
+    },
+}
+
+impl MetricsAuth {
+    /// Whether an `Authorization` header value carries the credentials
+    pub fn authorizes(&self, header: Option<&str>) -> bool {
+        let expected = match self {
+            MetricsAuth::None => return true,
+            MetricsAuth::Bearer(token) => format!("Bearer {}", token),
+            MetricsAuth::Basic { username, password } => format!(
+                "Basic {}",
+                BASE64.encode(format!("{}:{}", username, password))
+            ),
+        };
+        header.is_some_and(|h| constant_time_eq(h.trim().as_bytes(), expected.as_bytes()))
+    }
+}
+
+/// Compare secrets without leaking where they differ through timing
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Metrics server settings
+#[derive(Clone, Debug, Default)]
+pub struct MetricsServerConfig {
+    /// Port to listen on
+    pub port: u16,
+    /// `/healthz` fails once no reconcile has succeeded for this long
+    pub max_reconcile_age: Option<Duration>,
+    /// PEM certificate chain and private key, serving HTTPS when set
+    pub tls: Option<(PathBuf, PathBuf)>,
+    /// Credentials required to scrape
+    pub auth: MetricsAuth,
+}
+
 /// Start the metrics HTTP server
-pub async fn serve(port: u16) -> anyhow::Result<()> {
-    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+///
+/// With TLS configured the certificate is reloaded when the mounted files
+/// change, as for the webhook server.
+pub async fn serve(config: MetricsServerConfig) -> anyhow::Result<()> {
+    let addr = SocketAddr::from(([0, 0, 0, 0], config.port));
     let listener = TcpListener::bind(addr).await?;
-    info!("Metrics server listening on {}", addr);
+    info!(
+        "Metrics server listening on {} (tls: {}, auth: {})",
+        addr,
+        config.tls.is_some(),
+        config.auth != MetricsAuth::None
+    );
+
+    let mut acceptor = match config.tls {
+        Some((ref cert, ref key)) => Some((
+            modified_time(cert),
+            TlsAcceptor::from(Arc::new(load_tls_config(cert, key)?)),
+        )),
+        None => None,
+    };
+    let config = Arc::new(config);
 
     // Set initial health
+    health::start();
     OPERATOR_HEALTH.set(1.0);
 
     loop {
-        let (stream, _) = listener.accept().await?;
-        let io = TokioIo::new(stream);
+        let (stream, peer) = listener.accept().await?;
+
+        let Some((ref mut loaded_at, ref mut tls)) = acceptor else {
+            tokio::spawn(serve_connection(stream, config.clone()));
+            continue;
+        };
 
+        if let Some((ref cert, ref key)) = config.tls {
+            let current = modified_time(cert);
+            if current != *loaded_at {
+                match load_tls_config(cert, key) {
+                    Ok(reloaded) => {
+                        info!("Reloaded metrics TLS certificate");
+                        *tls = TlsAcceptor::from(Arc::new(reloaded));
+                        *loaded_at = current;
+                    }
+                    Err(e) => warn!("Failed to reload metrics TLS certificate: {}", e),
+                }
+            }
+        }
+
+        let tls = tls.clone();
+        let config = config.clone();
         tokio::spawn(async move {
-            if let Err(e) = http1::Builder::new()
-                .serve_connection(io, service_fn(handle_request))
-                .await
-            {
-                error!("Error serving connection: {}", e);
+            match tls.accept(stream).await {
+                Ok(stream) => serve_connection(stream, config).await,
+                Err(e) => warn!("TLS handshake with {} failed: {}", peer, e),
             }
         });
     }
 }
 
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Serve HTTP requests on an accepted connection
+async fn serve_connection<S>(stream: S, config: Arc<MetricsServerConfig>)
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    if let Err(e) = http1::Builder::new()
+        .serve_connection(
+            TokioIo::new(stream),
+            service_fn(move |req| handle_request(req, config.clone())),
+        )
+        .await
+    {
+        error!("Error serving connection: {}", e);
+    }
+}
+
 /// Handle HTTP requests
 async fn handle_request(
     req: Request<hyper::body::Incoming>,
+    config: Arc<MetricsServerConfig>,
 ) -> Result<Response<Full<Bytes>>, hyper::Error> {
+    let authorized = config.auth.authorizes(
+        req.headers()
+            .get(AUTHORIZATION)
+            .and_then(|h| h.to_str().ok()),
+    );
     let response = match req.uri().path() {
+        "/metrics" | "/simulate" | "/loglevel" if !authorized => {
+            unauthorized_response(&config.auth)
+        }
         "/metrics" => metrics_response(),
-        "/healthz" | "/health" => health_response(),
+        "/simulate" if req.method() == Method::POST => match read_body(req.into_body()).await {
+            Ok(body) => simulate_response(&body),
+            Err(response) => response,
+        },
+        "/loglevel" if config.auth == MetricsAuth::None => not_found_response(),
+        "/loglevel" if req.method() == Method::PUT => match read_body(req.into_body()).await {
+            Ok(body) => set_log_level_response(&body),
+            Err(response) => response,
+        },
+        "/loglevel" => text_response(
+            StatusCode::OK,
+            logging::current_filter().unwrap_or_default(),
+        ),
+        "/healthz" | "/health" => health_response(config.max_reconcile_age),
         "/readyz" | "/ready" => ready_response(),
         _ => not_found_response(),
     };
@@ -99,6 +284,7 @@ async fn handle_request(
 
 /// Generate metrics response
 fn metrics_response() -> Response<Full<Bytes>> {
+    LAST_RECONCILE_AGE.set(health::last_reconcile_age().as_secs_f64());
     let encoder = TextEncoder::new();
     let metric_families = prometheus::gather();
     let mut buffer = Vec::new();
@@ -118,22 +304,115 @@ fn metrics_response() -> Response<Full<Bytes>> {
         .unwrap()
 }
 
-/// Health check response
-fn health_response() -> Response<Full<Bytes>> {
+/// Largest request body accepted by the metrics server
+const MAX_BODY_BYTES: usize = 8 * 1024;
+
+/// Read a request body, refusing one over `MAX_BODY_BYTES`
+async fn read_body(body: hyper::body::Incoming) -> Result<Bytes, Response<Full<Bytes>>> {
+    match Limited::new(body, MAX_BODY_BYTES).collect().await {
+        Ok(collected) => Ok(collected.to_bytes()),
+        Err(e) if e.is::<LengthLimitError>() => Err(text_response(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!("request body exceeds {} bytes", MAX_BODY_BYTES),
+        )),
+        Err(e) => Err(text_response(StatusCode::BAD_REQUEST, e.to_string())),
+    }
+}
+
+/// Translate the position posted as a `SimulationRequest`
+fn simulate_response(body: &[u8]) -> Response<Full<Bytes>> {
+    let result = serde_json::from_slice::<SimulationRequest>(body)
+        .map_err(|e| e.to_string())
+        .and_then(|req| {
+            mapping::simulate(&req.mapping, &req.topic, req.partition, req.offset)
+                .map_err(|e| e.to_string())
+        });
+    let (status, body) = match result {
+        Ok(simulation) => (
+            StatusCode::OK,
+            serde_json::to_vec(&simulation).unwrap_or_default(),
+        ),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            serde_json::to_vec(&serde_json::json!({ "error": e })).unwrap_or_default(),
+        ),
+    };
     Response::builder()
-        .status(StatusCode::OK)
-        .body(Full::new(Bytes::from("ok")))
+        .status(status)
+        .header("Content-Type", "application/json")
+        .body(Full::new(Bytes::from(body)))
+        .unwrap()
+}
+
+/// Health check response, failing when reconciles have stalled
+fn health_response(max_reconcile_age: Option<Duration>) -> Response<Full<Bytes>> {
+    let age = health::last_reconcile_age();
+    LAST_RECONCILE_AGE.set(age.as_secs_f64());
+    if health::is_healthy(max_reconcile_age) {
+        OPERATOR_HEALTH.set(1.0);
+        return Response::builder()
+            .status(StatusCode::OK)
+            .body(Full::new(Bytes::from("ok")))
+            .unwrap();
+    }
+
+    OPERATOR_HEALTH.set(0.0);
+    Response::builder()
+        .status(StatusCode::SERVICE_UNAVAILABLE)
+        .body(Full::new(Bytes::from(format!(
+            "no successful reconcile for {}s",
+            age.as_secs()
+        ))))
         .unwrap()
 }
 
-/// Readiness check response
+/// Readiness check response, failing until watches are established
 fn ready_response() -> Response<Full<Bytes>> {
+    if !health::is_ready() {
+        return Response::builder()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .body(Full::new(Bytes::from("watches not established")))
+            .unwrap();
+    }
+
     Response::builder()
         .status(StatusCode::OK)
         .body(Full::new(Bytes::from("ok")))
         .unwrap()
 }
 
+/// Replace the log filter with the directives in the request body
+fn set_log_level_response(body: &[u8]) -> Response<Full<Bytes>> {
+    let directives = String::from_utf8_lossy(body);
+    match logging::set_filter(&directives) {
+        Ok(()) => {
+            info!("Log filter changed to {}", directives.trim());
+            text_response(StatusCode::OK, directives.trim().to_string())
+        }
+        Err(e) => text_response(StatusCode::BAD_REQUEST, e),
+    }
+}
+
+fn text_response(status: StatusCode, body: String) -> Response<Full<Bytes>> {
+    Response::builder()
+        .status(status)
+        .body(Full::new(Bytes::from(body)))
+        .unwrap()
+}
+
+/// Unauthorized response asking for the configured scheme
+fn unauthorized_response(auth: &MetricsAuth) -> Response<Full<Bytes>> {
+    let challenge = match auth {
+        MetricsAuth::Basic { .. } => "Basic realm=\"metrics\"",
+        _ => "Bearer",
+    };
+    Response::builder()
+        .status(StatusCode::UNAUTHORIZED)
+        .header(WWW_AUTHENTICATE, challenge)
+        .body(Full::new(Bytes::from("Unauthorized")))
+        .unwrap()
+}
+
 /// Not found response
 fn not_found_response() -> Response<Full<Bytes>> {
     Response::builder()
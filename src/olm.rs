@@ -0,0 +1,270 @@
+//! Operator Lifecycle Manager bundle
+//!
+//! Renders a `registry+v1` bundle for OperatorHub: the ClusterServiceVersion
+//! with owned CRDs, `alm-examples` and the operator's permissions, the CRDs
+//! themselves and the bundle annotations. The install strategy reuses the
+//! Deployment and [`operator_rules`] of the plain install bundle, so the CSV
+//! never needs maintaining by hand.
+//!
+//! Admission webhooks are left out, since OLM provisions webhook
+//! certificates itself.
+
+use k8s_openapi::api::core::v1::{EnvVar, EnvVarSource, ObjectFieldSelector};
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use kube::CustomResourceExt;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::bundle::{build_operator_deployment, operator_rules, BundleOptions, OPERATOR_NAME};
+use crate::crd::{
+    KafkaClusterRef, KafkaPartitionRemapTask, KafkaPartitionRemapper, KafkaRemapperTopicOverride,
+};
+use crate::{Error, Result};
+
+/// Channel the bundle is published to unless told otherwise
+pub const DEFAULT_CHANNEL: &str = "alpha";
+
+/// Name of the ClusterServiceVersion for this release
+pub fn csv_name() -> String {
+    format!("{}.v{}", OPERATOR_NAME, env!("CARGO_PKG_VERSION"))
+}
+
+/// A custom resource the operator owns, as listed in the CSV
+struct OwnedCrd {
+    crd: CustomResourceDefinition,
+    display_name: &'static str,
+    description: &'static str,
+    /// Kinds the operator creates for each resource
+    resources: &'static [(&'static str, &'static str)],
+}
+
+fn owned_crds() -> Vec<OwnedCrd> {
+    vec![
+        OwnedCrd {
+            crd: KafkaPartitionRemapper::crd(),
+            display_name: "Kafka Partition Remapper",
+            description: "Partition remapping proxy in front of a Kafka cluster",
+            resources: &[
+                ("Deployment", "v1"),
+                ("StatefulSet", "v1"),
+                ("Service", "v1"),
+                ("ConfigMap", "v1"),
+                ("Secret", "v1"),
+                ("PodDisruptionBudget", "v1"),
+                ("HorizontalPodAutoscaler", "v2"),
+                ("CronJob", "v1"),
+            ],
+        },
+        OwnedCrd {
+            crd: KafkaClusterRef::crd(),
+            display_name: "Kafka Cluster Reference",
+            description: "Shared Kafka connection settings referenced by remappers",
+            resources: &[],
+        },
+        OwnedCrd {
+            crd: KafkaRemapperTopicOverride::crd(),
+            display_name: "Kafka Remapper Topic Override",
+            description: "Per-topic mapping override attached to a remapper",
+            resources: &[],
+        },
+        OwnedCrd {
+            crd: KafkaPartitionRemapTask::crd(),
+            display_name: "Kafka Partition Remap Task",
+            description: "Migration of a remapper to a new partition mapping",
+            resources: &[("Deployment", "v1"), ("Service", "v1"), ("ConfigMap", "v1")],
+        },
+    ]
+}
+
+/// Example of each custom resource, shown by the OperatorHub console
+pub fn alm_examples() -> Value {
+    json!([
+        {
+            "apiVersion": "kafka.oso.sh/v1alpha1",
+            "kind": "KafkaPartitionRemapper",
+            "metadata": { "name": "example-remapper" },
+            "spec": {
+                "kafka": { "bootstrapServers": ["kafka:9092"] },
+                "mapping": { "virtualPartitions": 100, "physicalPartitions": 10 }
+            }
+        },
+        {
+            "apiVersion": "kafka.oso.sh/v1alpha1",
+            "kind": "KafkaClusterRef",
+            "metadata": { "name": "example-cluster" },
+            "spec": {
+                "bootstrapServers": ["kafka:9092"],
+                "securityProtocol": "PLAINTEXT"
+            }
+        },
+        {
+            "apiVersion": "kafka.oso.sh/v1alpha1",
+            "kind": "KafkaRemapperTopicOverride",
+            "metadata": { "name": "example-orders" },
+            "spec": {
+                "remapperRef": "example-remapper",
+                "topic": "orders",
+                "virtualPartitions": 40,
+                "physicalPartitions": 4
+            }
+        },
+        {
+            "apiVersion": "kafka.oso.sh/v1alpha1",
+            "kind": "KafkaPartitionRemapTask",
+            "metadata": { "name": "example-remap" },
+            "spec": {
+                "remapperRef": "example-remapper",
+                "targetMapping": {
+                    "virtualPartitions": 100,
+                    "physicalPartitions": 20
+                }
+            }
+        }
+    ])
+}
+
+/// Build the ClusterServiceVersion running `image`
+pub fn build_cluster_service_version(image: &str) -> Result<Value> {
+    let options = BundleOptions {
+        image: image.to_string(),
+        webhooks: false,
+        ..Default::default()
+    };
+    let mut deployment = build_operator_deployment(&options);
+    // OLM records the namespaces selected by the OperatorGroup on the pod
+    if let Some(container) = deployment
+        .spec
+        .as_mut()
+        .and_then(|s| s.template.spec.as_mut())
+        .and_then(|p| p.containers.first_mut())
+    {
+        container.env.get_or_insert_with(Vec::new).push(EnvVar {
+            name: "WATCH_NAMESPACES".to_string(),
+            value_from: Some(EnvVarSource {
+                field_ref: Some(ObjectFieldSelector {
+                    field_path: "metadata.annotations['olm.targetNamespaces']".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+
+    let owned: Vec<Value> = owned_crds()
+        .iter()
+        .map(|owned| {
+            json!({
+                "name": owned.crd.metadata.name,
+                "version": owned.crd.spec.versions[0].name,
+                "kind": owned.crd.spec.names.kind,
+                "displayName": owned.display_name,
+                "description": owned.description,
+                "resources": owned
+                    .resources
+                    .iter()
+                    .map(|(kind, version)| json!({ "kind": kind, "version": version }))
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+    let examples = serde_json::to_string_pretty(&alm_examples())
+        .map_err(|e| Error::ConfigError(format!("Failed to render alm-examples: {}", e)))?;
+    let install_modes: Vec<Value> = [
+        "OwnNamespace",
+        "SingleNamespace",
+        "MultiNamespace",
+        "AllNamespaces",
+    ]
+    .iter()
+    .map(|mode| json!({ "type": mode, "supported": true }))
+    .collect();
+
+    Ok(json!({
+        "apiVersion": "operators.coreos.com/v1alpha1",
+        "kind": "ClusterServiceVersion",
+        "metadata": {
+            "name": csv_name(),
+            "annotations": {
+                "alm-examples": examples,
+                "capabilities": "Basic Install",
+                "categories": "Streaming & Messaging",
+                "containerImage": image,
+                "description": env!("CARGO_PKG_DESCRIPTION"),
+                "repository": env!("CARGO_PKG_REPOSITORY"),
+                "support": "OSO DevOps",
+            }
+        },
+        "spec": {
+            "displayName": "Kafka Partition Remapper Operator",
+            "description": "Runs Kafka Partition Remapper proxies, which present many \
+                virtual partitions to clients while storing them on fewer physical \
+                partitions of the upstream Kafka cluster.",
+            "version": env!("CARGO_PKG_VERSION"),
+            "maturity": DEFAULT_CHANNEL,
+            "provider": { "name": "OSO DevOps", "url": "https://oso.sh" },
+            "maintainers": [{ "name": "OSO DevOps", "email": "dev@oso.sh" }],
+            "links": [{ "name": "Source Code", "url": env!("CARGO_PKG_REPOSITORY") }],
+            "keywords": ["kafka", "proxy", "partition-remapping"],
+            "minKubeVersion": "1.26.0",
+            "installModes": install_modes,
+            "customresourcedefinitions": { "owned": owned },
+            "install": {
+                "strategy": "deployment",
+                "spec": {
+                    "clusterPermissions": [{
+                        "serviceAccountName": OPERATOR_NAME,
+                        "rules": operator_rules(),
+                    }],
+                    "deployments": [{
+                        "name": OPERATOR_NAME,
+                        "label": deployment.metadata.labels,
+                        "spec": deployment.spec,
+                    }],
+                }
+            }
+        }
+    }))
+}
+
+/// Bundle annotations describing the package and its channel
+pub fn build_bundle_annotations(channel: &str) -> Value {
+    json!({
+        "annotations": {
+            "operators.operatorframework.io.bundle.mediatype.v1": "registry+v1",
+            "operators.operatorframework.io.bundle.manifests.v1": "manifests/",
+            "operators.operatorframework.io.bundle.metadata.v1": "metadata/",
+            "operators.operatorframework.io.bundle.package.v1": OPERATOR_NAME,
+            "operators.operatorframework.io.bundle.channels.v1": channel,
+            "operators.operatorframework.io.bundle.channel.default.v1": channel,
+        }
+    })
+}
+
+/// Render the bundle as `(path, YAML)` pairs relative to the bundle root
+pub fn generate_olm_bundle(image: &str, channel: &str) -> Result<Vec<(String, String)>> {
+    fn to_yaml<T: Serialize>(path: &str, value: &T) -> Result<String> {
+        serde_yaml::to_string(value)
+            .map_err(|e| Error::ConfigError(format!("Failed to render {}: {}", path, e)))
+    }
+
+    let mut files = Vec::new();
+    let csv = format!("manifests/{}.clusterserviceversion.yaml", OPERATOR_NAME);
+    files.push((
+        csv.clone(),
+        to_yaml(&csv, &build_cluster_service_version(image)?)?,
+    ));
+    for owned in owned_crds() {
+        let path = format!(
+            "manifests/{}_{}.yaml",
+            owned.crd.spec.group, owned.crd.spec.names.plural
+        );
+        files.push((path.clone(), to_yaml(&path, &owned.crd)?));
+    }
+    let annotations = "metadata/annotations.yaml".to_string();
+    files.push((
+        annotations.clone(),
+        to_yaml(&annotations, &build_bundle_annotations(channel))?,
+    ));
+    Ok(files)
+}
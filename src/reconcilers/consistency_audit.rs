@@ -0,0 +1,99 @@
+//! Consistency audit run by the audit CronJob
+//!
+//! Checks the upstream cluster against the mapping and reports the outcome
+//! in the remapper's `status.audit`.
+
+use chrono::Utc;
+use kube::api::{Patch, PatchParams};
+use kube::{Api, Client};
+use tracing::info;
+
+use crate::adapters::consistency_audit_builder::AuditTarget;
+use crate::adapters::kafka_admin;
+use crate::crd::{ConsistencyAuditStatus, KafkaPartitionRemapper, MappingSpec};
+use crate::reconcilers::remapper::{self, KafkaProbe};
+use crate::{Error, Result};
+
+/// Largest number of findings recorded in the status
+const MAX_FINDINGS: usize = 20;
+
+/// Audit the cluster described by `target`
+pub async fn run(client: &Client, target: &AuditTarget) -> ConsistencyAuditStatus {
+    let probe =
+        remapper::probe_kafka_cluster(&target.kafka, client, &target.secrets_namespace).await;
+    let findings = audit_findings(&target.mapping, &target.topics, &probe);
+    ConsistencyAuditStatus {
+        last_run_time: Utc::now(),
+        passed: findings.is_empty(),
+        findings,
+    }
+}
+
+/// Problems found by comparing cluster metadata against the mapping
+///
+/// Covers offset range headroom, physical partition counts and the
+/// existence of the required topics. Without metadata only the headroom is
+/// checked, and the missing metadata is itself a finding.
+pub fn audit_findings(mapping: &MappingSpec, topics: &[String], probe: &KafkaProbe) -> Vec<String> {
+    let mut findings = Vec::new();
+
+    let headroom = remapper::offset_headroom_condition(mapping);
+    if headroom.status == "False" {
+        findings.extend(headroom.message);
+    }
+
+    match probe {
+        KafkaProbe::Reachable(metadata) => {
+            findings.extend(
+                kafka_admin::find_partition_mismatches(mapping, metadata)
+                    .iter()
+                    .map(|m| m.to_string()),
+            );
+            findings.extend(
+                topics
+                    .iter()
+                    .filter(|t| !metadata.iter().any(|m| m.name == **t))
+                    .map(|t| format!("{} does not exist", t)),
+            );
+        }
+        KafkaProbe::Unreachable(e) | KafkaProbe::Unsupported(e) => {
+            findings.push(format!("Topic metadata unavailable: {}", e));
+        }
+    }
+
+    if findings.len() > MAX_FINDINGS {
+        let more = findings.len() - MAX_FINDINGS;
+        findings.truncate(MAX_FINDINGS);
+        findings.push(format!("and {} more", more));
+    }
+    findings
+}
+
+/// Record an audit result in the remapper's status
+///
+/// Only `status.audit` is written, so the rest of the status kept by the
+/// operator is untouched.
+pub async fn report(
+    client: &Client,
+    target: &AuditTarget,
+    status: &ConsistencyAuditStatus,
+) -> Result<()> {
+    let remappers: Api<KafkaPartitionRemapper> = Api::namespaced(client.clone(), &target.namespace);
+    let patch = serde_json::json!({
+        "status": { "audit": status }
+    });
+
+    remappers
+        .patch_status(&target.name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map_err(|e| Error::kube("Failed to report audit", e))?;
+
+    info!(
+        "Audit of {}/{} {} with {} finding(s)",
+        target.namespace,
+        target.name,
+        if status.passed { "passed" } else { "failed" },
+        status.findings.len()
+    );
+    Ok(())
+}
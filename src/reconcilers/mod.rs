@@ -1,3 +1,5 @@
 //! Reconciliation logic for custom resources
 
+pub mod consistency_audit;
+pub mod remap_task;
 pub mod remapper;
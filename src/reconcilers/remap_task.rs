@@ -0,0 +1,413 @@
+//! Reconciliation logic for KafkaPartitionRemapTask resources
+
+use chrono::Utc;
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use k8s_openapi::api::core::v1::{ConfigMap, Pod, Service};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::api::{ListParams, Patch, PatchParams};
+use kube::{Api, Client, Resource, ResourceExt};
+use std::collections::BTreeMap;
+use tracing::info;
+
+use crate::adapters::kafka_admin::{self, GroupDescription};
+use crate::adapters::{deployment_builder, service_builder, stateful_set_builder, users};
+use crate::crd::{
+    safe_resource_name, ConsumerGroupProgress, KafkaPartitionRemapTask,
+    KafkaPartitionRemapTaskStatus, KafkaPartitionRemapper, WorkloadKind,
+    ALLOW_BREAKING_CHANGE_ANNOTATION, SERVING_GENERATION_ANNOTATION,
+};
+use crate::reconcilers::remapper;
+use crate::{Error, Result};
+
+/// Name of the proxy generation started by a task
+///
+/// Already shortened so the generation's children use it unchanged.
+pub fn generation_name(task: &KafkaPartitionRemapTask) -> String {
+    safe_resource_name(&format!("{}-{}", task.spec.remapper_ref, task.name_any()))
+}
+
+/// Build the remapper describing the new proxy generation
+///
+/// The generation copies the remapper's proxy settings with the target
+/// mapping, always runs as a fixed-size Deployment and advertises its own
+/// Service address.
+pub fn build_generation(
+    remapper: &KafkaPartitionRemapper,
+    task: &KafkaPartitionRemapTask,
+) -> KafkaPartitionRemapper {
+    let mut generation = remapper.clone();
+    generation.metadata = ObjectMeta {
+        name: Some(generation_name(task)),
+        namespace: remapper.metadata.namespace.clone(),
+        // Accept the users generated for the remapper
+        annotations: Some(BTreeMap::from([(
+            users::USERS_SECRET_ANNOTATION.to_string(),
+            users::users_secret_name(remapper),
+        )])),
+        ..Default::default()
+    };
+    generation.status = None;
+
+    let spec = &mut generation.spec;
+    spec.mapping = task.spec.target_mapping.clone();
+    spec.workload = WorkloadKind::Deployment;
+    spec.sharding = None;
+    spec.autoscaling = None;
+    spec.pod_disruption_budget = None;
+    spec.external_service = None;
+    spec.gateway = None;
+    spec.rollout_strategy = None;
+    spec.suspend = false;
+    spec.name_override = None;
+    spec.fullname_override = None;
+    for listener in spec.listeners.iter_mut() {
+        listener.advertised_address = None;
+    }
+    generation
+}
+
+/// Validate a task against the remapper it migrates
+pub fn validate(remapper: &KafkaPartitionRemapper, task: &KafkaPartitionRemapTask) -> Result<()> {
+    if remapper.spec.sharding_enabled() {
+        return Err(Error::ValidationError(
+            "remap tasks do not support sharded remappers".to_string(),
+        ));
+    }
+    if remapper.deploys_cross_namespace() {
+        return Err(Error::ValidationError(
+            "remap tasks do not support remappers with a targetNamespace".to_string(),
+        ));
+    }
+    if remapper.serving_generation().is_some() {
+        return Err(Error::ValidationError(format!(
+            "KafkaPartitionRemapper {} is already being cut over",
+            remapper.name_any()
+        )));
+    }
+    if task.spec.target_mapping == remapper.spec.mapping {
+        return Err(Error::ValidationError(
+            "targetMapping matches the current mapping".to_string(),
+        ));
+    }
+
+    remapper::validate(&build_generation(remapper, task))
+}
+
+/// Apply the generation's ConfigMap, Deployment and Service
+///
+/// Returns whether the generation's Deployment has fully rolled out.
+pub async fn reconcile_generation(
+    generation: &KafkaPartitionRemapper,
+    task: &KafkaPartitionRemapTask,
+    client: &Client,
+    namespace: &str,
+) -> Result<bool> {
+    let name = generation.name_any();
+    let owner_references = task
+        .controller_owner_ref(&())
+        .into_iter()
+        .collect::<Vec<_>>();
+    let patch_params = PatchParams::apply("kafka-partition-remapper-operator");
+
+    let mut config_map = remapper::build_config_map(generation, namespace)?;
+    config_map.metadata.owner_references = Some(owner_references.clone());
+    let config_map_name = config_map.name_any();
+    Api::<ConfigMap>::namespaced(client.clone(), namespace)
+        .patch(&config_map_name, &patch_params, &Patch::Apply(&config_map))
+        .await
+        .map_err(|e| Error::kube("Failed to create/update ConfigMap", e))?;
+
+    let config_hash = remapper::calculate_config_hash(generation);
+    let secrets_hash = remapper::calculate_secrets_hash(generation, client, namespace).await?;
+    let mut deployment = deployment_builder::build_deployment(
+        generation,
+        &config_map_name,
+        &config_hash,
+        secrets_hash.as_deref(),
+    );
+    deployment.metadata.owner_references = Some(owner_references.clone());
+    let deployment = Api::<Deployment>::namespaced(client.clone(), namespace)
+        .patch(&name, &patch_params, &Patch::Apply(&deployment))
+        .await
+        .map_err(|e| Error::kube("Failed to create/update Deployment", e))?;
+
+    let mut service = service_builder::build_service(generation);
+    service.metadata.owner_references = Some(owner_references);
+    Api::<Service>::namespaced(client.clone(), namespace)
+        .patch(&name, &patch_params, &Patch::Apply(&service))
+        .await
+        .map_err(|e| Error::kube("Failed to create/update Service", e))?;
+
+    info!("Reconciled proxy generation {}/{}", namespace, name);
+
+    Ok(deployment_builder::rolled_out(&deployment))
+}
+
+/// Report how far each tracked consumer group has moved to the generation
+pub async fn consumer_group_progress(
+    remapper: &KafkaPartitionRemapper,
+    task: &KafkaPartitionRemapTask,
+    client: &Client,
+    namespace: &str,
+) -> Result<Vec<ConsumerGroupProgress>> {
+    if task.spec.consumer_groups.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let selector = deployment_builder::build_label_selector(&generation_name(task));
+    let generation_hosts: Vec<String> = pods
+        .list(&ListParams::default().labels(&selector))
+        .await
+        .map_err(|e| Error::kube("Failed to list generation pods", e))?
+        .into_iter()
+        .filter_map(|p| p.status.and_then(|s| s.pod_ip))
+        .collect();
+
+    // Groups are named as clients see them; upstream ids carry the prefix
+    let prefix = remapper.spec.group_id_prefix();
+    let upstream_groups: Vec<String> = task
+        .spec
+        .consumer_groups
+        .iter()
+        .map(|g| format!("{}{}", prefix, g))
+        .collect();
+
+    let connection = kafka_admin::load_connection(client, namespace, &remapper.spec.kafka).await?;
+    let descriptions = kafka_admin::describe_consumer_groups(&connection, &upstream_groups).await?;
+
+    Ok(descriptions
+        .iter()
+        .map(|d| {
+            let mut progress = group_progress(d, &generation_hosts);
+            if let Some(group) = progress.group.strip_prefix(prefix) {
+                progress.group = group.to_string();
+            }
+            progress
+        })
+        .collect())
+}
+
+/// Migration progress of a group given the new generation's pod addresses
+///
+/// A group has migrated once none of its members connect through another
+/// proxy; groups without members carry no in-flight virtual offsets.
+pub fn group_progress(
+    description: &GroupDescription,
+    generation_hosts: &[String],
+) -> ConsumerGroupProgress {
+    let remaining = description
+        .member_hosts
+        .iter()
+        .filter(|h| !generation_hosts.contains(h))
+        .count();
+
+    ConsumerGroupProgress {
+        group: description.group_id.clone(),
+        state: description.state.clone(),
+        remaining_members: remaining as u32,
+        migrated: remaining == 0,
+    }
+}
+
+/// Route the remapper's Service to the generation and adopt the target mapping
+///
+/// The remapper rolls its own pods to the target mapping while the
+/// generation keeps serving clients.
+pub async fn begin_cutover(
+    task: &KafkaPartitionRemapTask,
+    client: &Client,
+    namespace: &str,
+) -> Result<()> {
+    let remappers: Api<KafkaPartitionRemapper> = Api::namespaced(client.clone(), namespace);
+    let patch = serde_json::json!({
+        "metadata": {
+            "annotations": {
+                SERVING_GENERATION_ANNOTATION: generation_name(task),
+                ALLOW_BREAKING_CHANGE_ANNOTATION: "true"
+            }
+        },
+        "spec": {
+            "mapping": task.spec.target_mapping
+        }
+    });
+
+    remappers
+        .patch(
+            &task.spec.remapper_ref,
+            &PatchParams::default(),
+            &Patch::Merge(&patch),
+        )
+        .await
+        .map_err(|e| Error::kube("Failed to cut over remapper", e))?;
+
+    info!(
+        "Cutting KafkaPartitionRemapper {}/{} over to {}",
+        namespace,
+        task.spec.remapper_ref,
+        generation_name(task)
+    );
+    Ok(())
+}
+
+/// Whether the remapper's own pods now serve the task's target mapping
+pub async fn remapper_converged(
+    remapper: &KafkaPartitionRemapper,
+    task: &KafkaPartitionRemapTask,
+    client: &Client,
+    namespace: &str,
+) -> Result<bool> {
+    // Topic overrides from KafkaRemapperTopicOverride resources are merged
+    // into the applied mapping, so it may hold more topics than the target
+    let target = &task.spec.target_mapping;
+    let applied = remapper
+        .status
+        .as_ref()
+        .and_then(|s| s.applied_mapping.as_ref());
+    if !applied.is_some_and(|a| {
+        a.virtual_partitions == target.virtual_partitions
+            && a.physical_partitions == target.physical_partitions
+            && a.offset_range == target.offset_range
+            && target.topics.iter().all(|t| a.topics.contains(t))
+    }) {
+        return Ok(false);
+    }
+
+    let name = remapper.resource_name();
+    let rolled_out = match remapper.spec.workload {
+        WorkloadKind::Deployment => Api::<Deployment>::namespaced(client.clone(), namespace)
+            .get_opt(&name)
+            .await
+            .map_err(|e| Error::kube("Failed to get Deployment", e))?
+            .is_some_and(|d| deployment_builder::rolled_out(&d)),
+        WorkloadKind::StatefulSet => Api::<StatefulSet>::namespaced(client.clone(), namespace)
+            .get_opt(&name)
+            .await
+            .map_err(|e| Error::kube("Failed to get StatefulSet", e))?
+            .is_some_and(|s| stateful_set_builder::rolled_out(&s)),
+    };
+    Ok(rolled_out)
+}
+
+/// Return the Service to the remapper's pods and remove the generation
+pub async fn finish_cutover(
+    task: &KafkaPartitionRemapTask,
+    client: &Client,
+    namespace: &str,
+) -> Result<()> {
+    release_remapper(task, client, namespace).await?;
+
+    let name = generation_name(task);
+    let owned = |meta: &ObjectMeta| is_owned_by_task(task, meta);
+    let services: Api<Service> = Api::namespaced(client.clone(), namespace);
+    remapper::delete_if_owned(&services, &name, namespace, "Service", owned).await?;
+    let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    remapper::delete_if_owned(&deployments, &name, namespace, "Deployment", owned).await?;
+    let config_maps: Api<ConfigMap> = Api::namespaced(client.clone(), namespace);
+    let config_map_name = remapper::config_map_name(&name);
+    remapper::delete_if_owned(
+        &config_maps,
+        &config_map_name,
+        namespace,
+        "ConfigMap",
+        owned,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Whether a generation child carries the task's owner reference
+fn is_owned_by_task(task: &KafkaPartitionRemapTask, meta: &ObjectMeta) -> bool {
+    let uid = task.metadata.uid.as_deref().unwrap_or_default();
+    meta.owner_references
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .any(|o| o.uid == uid)
+}
+
+/// Remove the cutover annotations this task placed on its remapper
+pub async fn release_remapper(
+    task: &KafkaPartitionRemapTask,
+    client: &Client,
+    namespace: &str,
+) -> Result<()> {
+    let remappers: Api<KafkaPartitionRemapper> = Api::namespaced(client.clone(), namespace);
+    let Some(remapper) = remappers
+        .get_opt(&task.spec.remapper_ref)
+        .await
+        .map_err(|e| Error::kube("Failed to get remapper", e))?
+    else {
+        return Ok(());
+    };
+    if remapper.serving_generation() != Some(generation_name(task).as_str()) {
+        return Ok(());
+    }
+
+    let patch = serde_json::json!({
+        "metadata": {
+            "annotations": {
+                SERVING_GENERATION_ANNOTATION: null,
+                ALLOW_BREAKING_CHANGE_ANNOTATION: null
+            }
+        }
+    });
+    remappers
+        .patch(
+            &task.spec.remapper_ref,
+            &PatchParams::default(),
+            &Patch::Merge(&patch),
+        )
+        .await
+        .map_err(|e| Error::kube("Failed to release remapper", e))?;
+
+    Ok(())
+}
+
+/// Build the next task status, carrying over timestamps
+pub fn next_status(
+    task: &KafkaPartitionRemapTask,
+    phase: &str,
+    message: impl Into<String>,
+    consumer_groups: Option<Vec<ConsumerGroupProgress>>,
+) -> KafkaPartitionRemapTaskStatus {
+    let previous = task.status.clone().unwrap_or_default();
+    let now = Utc::now();
+    let finished = matches!(phase, "Completed" | "Failed");
+
+    KafkaPartitionRemapTaskStatus {
+        phase: Some(phase.to_string()),
+        message: Some(message.into()),
+        generation_name: Some(generation_name(task)),
+        consumer_groups: consumer_groups.unwrap_or(previous.consumer_groups),
+        start_time: previous.start_time.or(Some(now)),
+        completion_time: if finished {
+            previous.completion_time.or(Some(now))
+        } else {
+            None
+        },
+    }
+}
+
+/// Patch the task status
+pub async fn update_status(
+    task: &KafkaPartitionRemapTask,
+    client: &Client,
+    namespace: &str,
+    status: KafkaPartitionRemapTaskStatus,
+) -> Result<()> {
+    let tasks: Api<KafkaPartitionRemapTask> = Api::namespaced(client.clone(), namespace);
+    let patch = serde_json::json!({ "status": status });
+
+    tasks
+        .patch_status(
+            &task.name_any(),
+            &PatchParams::default(),
+            &Patch::Merge(&patch),
+        )
+        .await
+        .map_err(|e| Error::kube("Failed to update task status", e))?;
+
+    Ok(())
+}
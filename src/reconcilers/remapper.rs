@@ -1,30 +1,199 @@
 //! Reconciliation logic for KafkaPartitionRemapper resources
 
-use chrono::Utc;
-use k8s_openapi::api::apps::v1::Deployment;
-use k8s_openapi::api::core::v1::{ConfigMap, Service};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler;
+use k8s_openapi::api::batch::v1::CronJob;
+use k8s_openapi::api::core::v1::{ConfigMap, Pod, PodDNSConfig, Secret, Service, ServiceAccount};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::api::rbac::v1::{Role, RoleBinding};
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
-use kube::api::{Patch, PatchParams};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::api::{DeleteParams, DynamicObject, ListParams, Patch, PatchParams, Preconditions};
 use kube::{Api, Client, ResourceExt};
+use serde::de::DeserializeOwned;
 use sha2::{Digest, Sha256};
 use std::collections::BTreeMap;
-use tracing::info;
+use std::sync::Arc;
+use tracing::{info, warn};
 
-use crate::adapters::{deployment_builder, remapper_config, service_builder};
-use crate::crd::{Condition, KafkaPartitionRemapper, KafkaPartitionRemapperStatus};
+use crate::adapters::{
+    canary, certificates, consistency_audit_builder, deployment_builder,
+    horizontal_pod_autoscaler_builder, kafka_admin, keda_scaled_object_builder,
+    pod_disruption_budget_builder, proxy_reload, remapper_config, secrets, service_builder,
+    sharding, stateful_set_builder, tcp_route_builder, users,
+};
+use crate::controllers::ChildStores;
+use crate::crd::{
+    AuditDestinationType, BrokerAddressMode, CanaryStatus, CertificateStatus, Condition,
+    DeletionPolicy, KafkaClusterRef, KafkaClusterSpec, KafkaPartitionRemapper,
+    KafkaPartitionRemapperSpec, KafkaPartitionRemapperStatus, KafkaRemapperTopicOverride,
+    MappingSpec, ProbeType, QuotaLimits, ReloadPolicy, RolloutStrategyType, SaslMechanism,
+    ServiceType, ShardStatus, SuspensionStatus, UpstreamClusterSpec, UserStatus, WorkloadKind,
+    ADOPT_ANNOTATION, ALLOW_BREAKING_CHANGE_ANNOTATION, OWNER_NAMESPACE_LABEL, OWNER_NAME_LABEL,
+    PAUSED_ANNOTATION,
+};
+use crate::mapping::Mapping;
+use crate::settings;
 use crate::{Error, Result};
 
 /// Validate a KafkaPartitionRemapper spec
 pub fn validate(remapper: &KafkaPartitionRemapper) -> Result<()> {
     let spec = &remapper.spec;
 
+    validate_kafka_connection(spec)?;
+    validate_mapping(spec)?;
+
+    // Validate replicas
+    if spec.replicas < 0 {
+        return Err(Error::ValidationError("replicas must be >= 0".to_string()));
+    }
+
+    validate_kafka_security(spec)?;
+    validate_upstream_clusters(spec)?;
+    validate_schema_registry(spec)?;
+    validate_access_and_quotas(spec)?;
+    validate_consumer_groups(spec)?;
+    validate_tuning(spec)?;
+    validate_resources(spec)?;
+    validate_audit_logging(spec)?;
+    validate_listeners(spec)?;
+    validate_users(spec)?;
+    validate_service(spec)?;
+    validate_external_access(spec)?;
+
+    // Validate PodDisruptionBudget settings
+    if let Some(ref pdb) = spec.pod_disruption_budget {
+        if pdb.min_available.is_some() && pdb.max_unavailable.is_some() {
+            return Err(Error::ValidationError(
+                "podDisruptionBudget.minAvailable and podDisruptionBudget.maxUnavailable are mutually exclusive"
+                    .to_string(),
+            ));
+        }
+    }
+
+    validate_audit(spec)?;
+    validate_autoscaling(spec)?;
+    validate_sharding(spec)?;
+    validate_metrics(spec)?;
+    validate_rollout_strategy(spec)?;
+    validate_probes(spec)?;
+    validate_naming(remapper)?;
+    validate_pod_template(remapper)
+}
+
+/// Kafka API names accepted as `kafka.apiVersions` keys
+const KAFKA_API_NAMES: &[&str] = &[
+    "Produce",
+    "Fetch",
+    "ListOffsets",
+    "Metadata",
+    "OffsetCommit",
+    "OffsetFetch",
+    "FindCoordinator",
+    "JoinGroup",
+    "Heartbeat",
+    "LeaveGroup",
+    "SyncGroup",
+    "DescribeGroups",
+    "ListGroups",
+    "SaslHandshake",
+    "ApiVersions",
+    "CreateTopics",
+    "DeleteTopics",
+    "DeleteRecords",
+    "InitProducerId",
+    "OffsetForLeaderEpoch",
+    "AddPartitionsToTxn",
+    "AddOffsetsToTxn",
+    "EndTxn",
+    "TxnOffsetCommit",
+    "DescribeConfigs",
+    "AlterConfigs",
+    "SaslAuthenticate",
+    "CreatePartitions",
+    "DeleteGroups",
+    "IncrementalAlterConfigs",
+    "OffsetDelete",
+    "DescribeCluster",
+    "DescribeProducers",
+    "DescribeTransactions",
+    "ListTransactions",
+    "ConsumerGroupHeartbeat",
+    "ConsumerGroupDescribe",
+];
+
+/// Check how the proxy connects to the brokers
+fn validate_kafka_connection(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    // Connection settings come from the KafkaClusterRef when one is set
+    let inline_connection = spec.kafka.cluster_ref.is_none();
+    if !inline_connection
+        && (!spec.kafka.bootstrap_servers.is_empty()
+            || spec.kafka.tls_secret.is_some()
+            || spec.kafka.sasl_secret.is_some()
+            || spec.kafka.kerberos.is_some())
+    {
+        return Err(Error::ValidationError(
+            "kafka.clusterRef cannot be combined with kafka.bootstrapServers, kafka.tlsSecret, kafka.saslSecret or kafka.kerberos"
+                .to_string(),
+        ));
+    }
+
     // Validate bootstrap servers
-    if spec.kafka.bootstrap_servers.is_empty() {
+    if inline_connection && spec.kafka.bootstrap_servers.is_empty() {
         return Err(Error::ValidationError(
             "kafka.bootstrapServers cannot be empty".to_string(),
         ));
     }
 
+    // Validate broker address rewriting
+    if let Some(address) = spec
+        .kafka
+        .broker_address_map
+        .iter()
+        .flat_map(|(advertised, reachable)| [advertised, reachable])
+        .find(|a| !is_host_port(a))
+    {
+        return Err(Error::ValidationError(format!(
+            "kafka.brokerAddressMap entry {:?} must be a host:port address",
+            address
+        )));
+    }
+    if spec.kafka.broker_address_mode == BrokerAddressMode::BootstrapHost
+        && spec.kafka.broker_address_host().is_none()
+    {
+        return Err(Error::ValidationError(
+            "kafka.brokerAddressMode BootstrapHost requires a host:port bootstrap server"
+                .to_string(),
+        ));
+    }
+    validate_bootstrap_servers("kafka.bootstrapServers", &spec.kafka.bootstrap_servers)?;
+
+    // Validate protocol version ceilings
+    if spec.kafka.max_api_version.is_some_and(|v| v < 0) {
+        return Err(Error::ValidationError(
+            "kafka.maxApiVersion must be >= 0".to_string(),
+        ));
+    }
+    for (api, version) in &spec.kafka.api_versions {
+        if !KAFKA_API_NAMES.contains(&api.as_str()) {
+            return Err(Error::ValidationError(format!(
+                "kafka.apiVersions key {:?} is not a Kafka API name",
+                api
+            )));
+        }
+        if *version < 0 {
+            return Err(Error::ValidationError(format!(
+                "kafka.apiVersions.{} must be >= 0",
+                api
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Check the partition mapping, topic renames and per-topic overrides
+fn validate_mapping(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
     // Validate mapping
     if spec.mapping.physical_partitions == 0 {
         return Err(Error::ValidationError(
@@ -38,13 +207,89 @@ pub fn validate(remapper: &KafkaPartitionRemapper) -> Result<()> {
         ));
     }
 
-    if !spec.mapping.virtual_partitions.is_multiple_of(spec.mapping.physical_partitions) {
+    if !spec
+        .mapping
+        .virtual_partitions
+        .is_multiple_of(spec.mapping.physical_partitions)
+    {
         return Err(Error::ValidationError(
             "mapping.virtualPartitions must be evenly divisible by mapping.physicalPartitions"
                 .to_string(),
         ));
     }
 
+    // Validate topic renames
+    let group_ref = regex::Regex::new(r"\$(?:\$|\{(\w+)\}|(\w+))").unwrap();
+    for (i, rename) in spec.mapping.topic_renames.iter().enumerate() {
+        if rename.replacement.is_empty() {
+            return Err(Error::ValidationError(format!(
+                "mapping.topicRenames[{}].replacement cannot be empty",
+                i
+            )));
+        }
+        match (&rename.prefix, &rename.pattern) {
+            (Some(prefix), None) if !prefix.is_empty() => {}
+            (None, Some(pattern)) => {
+                let re = compile_topic_pattern(
+                    &format!("mapping.topicRenames[{}].pattern", i),
+                    pattern,
+                )?;
+                // Catch replacements referring to groups the pattern lacks,
+                // which would otherwise expand to an empty string
+                for caps in group_ref.captures_iter(&rename.replacement) {
+                    let Some(group) = caps.get(1).or_else(|| caps.get(2)) else {
+                        continue;
+                    };
+                    let group = group.as_str();
+                    let known = match group.parse::<usize>() {
+                        Ok(index) => index < re.captures_len(),
+                        Err(_) => re.capture_names().any(|n| n == Some(group)),
+                    };
+                    if !known {
+                        return Err(Error::ValidationError(format!(
+                            "mapping.topicRenames[{}].replacement references unknown group {:?}",
+                            i, group
+                        )));
+                    }
+                }
+            }
+            _ => {
+                return Err(Error::ValidationError(format!(
+                    "mapping.topicRenames[{}] requires exactly one of prefix and pattern",
+                    i
+                )));
+            }
+        }
+    }
+
+    // Validate header injection
+    if let Some(ref injection) = spec.mapping.header_injection {
+        let names = injection
+            .virtual_partition_header
+            .iter()
+            .chain(injection.headers.keys());
+        let mut seen = Vec::new();
+        for name in names {
+            if name.trim().is_empty() {
+                return Err(Error::ValidationError(
+                    "mapping.headerInjection header names cannot be empty".to_string(),
+                ));
+            }
+            if seen.contains(&name) {
+                return Err(Error::ValidationError(format!(
+                    "mapping.headerInjection sets header {:?} more than once",
+                    name
+                )));
+            }
+            seen.push(name);
+        }
+        if injection.virtual_partition_header.is_none() && injection.headers.is_empty() {
+            return Err(Error::ValidationError(
+                "mapping.headerInjection must set virtualPartitionHeader or headers".to_string(),
+            ));
+        }
+    }
+
     // Validate offset range
     let min_offset_range: u64 = 1 << 20; // 2^20
     if spec.mapping.offset_range < min_offset_range {
@@ -53,23 +298,112 @@ pub fn validate(remapper: &KafkaPartitionRemapper) -> Result<()> {
             min_offset_range
         )));
     }
-
-    // Validate replicas
-    if spec.replicas < 0 {
-        return Err(Error::ValidationError("replicas must be >= 0".to_string()));
-    }
-
-    // Validate security protocol
-    let valid_protocols = ["PLAINTEXT", "SSL", "SASL_PLAINTEXT", "SASL_SSL"];
-    if !valid_protocols.contains(&spec.kafka.security_protocol.as_str()) {
+    let headroom = offset_headroom(
+        spec.mapping.offset_range,
+        spec.mapping.virtual_partitions,
+        spec.mapping.physical_partitions,
+    );
+    if headroom < MIN_OFFSET_HEADROOM {
         return Err(Error::ValidationError(format!(
-            "kafka.securityProtocol must be one of: {:?}",
-            valid_protocols
+            "mapping.offsetRange leaves {} offsets per virtual partition after dividing by the {}:{} compression ratio; at least {} (2^16) are required",
+            headroom,
+            spec.mapping.virtual_partitions,
+            spec.mapping.physical_partitions,
+            MIN_OFFSET_HEADROOM
         )));
     }
+    Mapping::new(
+        spec.mapping.virtual_partitions,
+        spec.mapping.physical_partitions,
+        spec.mapping.offset_range,
+    )
+    .map_err(|e| mapping_error("mapping", e))?;
+
+    // Validate per-topic overrides against the same rules as the global mapping
+    let mut topic_ratios = Vec::new();
+    for (i, topic) in spec.mapping.topics.iter().enumerate() {
+        if topic.topic.is_empty() {
+            return Err(Error::ValidationError(format!(
+                "mapping.topics[{}].topic cannot be empty",
+                i
+            )));
+        }
+        if spec.mapping.topics[..i]
+            .iter()
+            .any(|t| t.topic == topic.topic)
+        {
+            return Err(Error::ValidationError(format!(
+                "mapping.topics overrides topic {:?} more than once",
+                topic.topic
+            )));
+        }
+        let pattern = compile_topic_pattern(&format!("mapping.topics[{}].topic", i), &topic.topic)?;
+
+        let virtual_partitions = topic
+            .virtual_partitions
+            .unwrap_or(spec.mapping.virtual_partitions);
+        let physical_partitions = topic
+            .physical_partitions
+            .unwrap_or(spec.mapping.physical_partitions);
+        if physical_partitions == 0 {
+            return Err(Error::ValidationError(format!(
+                "mapping.topics[{}].physicalPartitions must be >= 1",
+                i
+            )));
+        }
+        if virtual_partitions < physical_partitions
+            || !virtual_partitions.is_multiple_of(physical_partitions)
+        {
+            return Err(Error::ValidationError(format!(
+                "mapping.topics[{}] maps {} virtual onto {} physical partitions; virtual partitions must be a multiple of physical partitions",
+                i, virtual_partitions, physical_partitions
+            )));
+        }
+        if topic.offset_range.is_some_and(|r| r < min_offset_range) {
+            return Err(Error::ValidationError(format!(
+                "mapping.topics[{}].offsetRange must be >= {} (2^20)",
+                i, min_offset_range
+            )));
+        }
+        let offset_range = topic.offset_range.unwrap_or(spec.mapping.offset_range);
+        let headroom = offset_headroom(offset_range, virtual_partitions, physical_partitions);
+        if headroom < MIN_OFFSET_HEADROOM {
+            return Err(Error::ValidationError(format!(
+                "mapping.topics[{}] leaves {} offsets per virtual partition after dividing by the {}:{} compression ratio; at least {} (2^16) are required",
+                i, headroom, virtual_partitions, physical_partitions, MIN_OFFSET_HEADROOM
+            )));
+        }
+        Mapping::new(virtual_partitions, physical_partitions, offset_range)
+            .map_err(|e| mapping_error(&format!("mapping.topics[{}]", i), e))?;
+        topic_ratios.push((topic, pattern, (virtual_partitions, physical_partitions)));
+    }
+    // Overlap between two patterns can't be decided in general, so only a
+    // pattern matching another entry's plain topic name is caught
+    for (topic, pattern, ratio) in &topic_ratios {
+        let conflict = topic_ratios.iter().find(|(other, _, other_ratio)| {
+            other.topic != topic.topic
+                && other_ratio != ratio
+                && is_topic_name(&other.topic)
+                && pattern.is_match(&other.topic)
+        });
+        if let Some((other, _, _)) = conflict {
+            return Err(Error::ValidationError(format!(
+                "mapping.topics entries {:?} and {:?} both match topic {:?} with different partition ratios",
+                topic.topic, other.topic, other.topic
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Check the TLS, SASL and Kerberos settings of every broker connection
+fn validate_kafka_security(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    // Connection settings come from the KafkaClusterRef when one is set
+    let inline_connection = spec.kafka.cluster_ref.is_none();
 
     // Validate that TLS secret is provided for SSL protocols
-    if (spec.kafka.security_protocol == "SSL" || spec.kafka.security_protocol == "SASL_SSL")
+    if inline_connection
+        && spec.kafka.security_protocol.uses_tls()
         && spec.kafka.tls_secret.is_none()
     {
         return Err(Error::ValidationError(
@@ -77,158 +411,3059 @@ pub fn validate(remapper: &KafkaPartitionRemapper) -> Result<()> {
         ));
     }
 
-    // Validate that SASL secret is provided for SASL protocols
-    if (spec.kafka.security_protocol == "SASL_PLAINTEXT"
-        || spec.kafka.security_protocol == "SASL_SSL")
+    // Validate that SASL secret or Kerberos is provided for SASL protocols
+    let uses_sasl = spec.kafka.security_protocol.uses_sasl();
+    if inline_connection
+        && uses_sasl
         && spec.kafka.sasl_secret.is_none()
+        && spec.kafka.kerberos.is_none()
     {
         return Err(Error::ValidationError(
-            "kafka.saslSecret is required when using SASL_PLAINTEXT or SASL_SSL protocol"
+            "kafka.saslSecret or kafka.kerberos is required when using SASL_PLAINTEXT or SASL_SSL protocol"
                 .to_string(),
         ));
     }
 
+    // Each broker credential comes from exactly one Secret or SecretProviderClass
+    let broker_credentials = std::iter::once((
+        "kafka".to_string(),
+        &spec.kafka.tls_secret,
+        &spec.kafka.sasl_secret,
+    ))
+    .chain(
+        upstream_clusters(spec)
+            .into_iter()
+            .map(|(field, c)| (field, &c.tls_secret, &c.sasl_secret)),
+    );
+    let credential_sources = broker_credentials.flat_map(|(prefix, tls, sasl)| {
+        [
+            (
+                format!("{}.tlsSecret", prefix),
+                tls.as_ref().map(|t| (&t.name, &t.secret_provider_class)),
+            ),
+            (
+                format!("{}.saslSecret", prefix),
+                sasl.as_ref().map(|s| (&s.name, &s.secret_provider_class)),
+            ),
+        ]
+    });
+    for (field, source) in credential_sources {
+        if let Some((name, provider_class)) = source {
+            let provider_class = provider_class.as_deref().filter(|p| !p.is_empty());
+            if name.is_empty() == provider_class.is_none() {
+                return Err(Error::ValidationError(format!(
+                    "{} requires exactly one of name and secretProviderClass",
+                    field
+                )));
+            }
+        }
+    }
+
+    // Validate Kerberos settings
+    if let Some(ref kerberos) = spec.kafka.kerberos {
+        if spec.kafka.sasl_secret.is_some() {
+            return Err(Error::ValidationError(
+                "kafka.kerberos and kafka.saslSecret are mutually exclusive".to_string(),
+            ));
+        }
+        if inline_connection && !uses_sasl {
+            return Err(Error::ValidationError(
+                "kafka.kerberos requires the SASL_PLAINTEXT or SASL_SSL protocol".to_string(),
+            ));
+        }
+        if kerberos.principal.is_empty() || kerberos.service_name.is_empty() {
+            return Err(Error::ValidationError(
+                "kafka.kerberos.principal and kafka.kerberos.serviceName cannot be empty"
+                    .to_string(),
+            ));
+        }
+    }
     Ok(())
 }
 
-/// Reconcile the ConfigMap for proxy configuration
-pub async fn reconcile_config_map(
-    remapper: &KafkaPartitionRemapper,
-    client: &Client,
-    namespace: &str,
-) -> Result<String> {
-    let name = remapper.name_any();
-    let config_map_name = format!("{}-config", name);
-
-    // Determine advertised address
-    let advertised_address = remapper
-        .spec
-        .listen
-        .advertised_address
-        .clone()
-        .unwrap_or_else(|| {
-            format!(
-                "{}.{}.svc.cluster.local:{}",
-                name, namespace, remapper.spec.listen.port
-            )
-        });
-
-    // Build the proxy configuration YAML
-    let config_yaml = remapper_config::build_proxy_config(&remapper.spec, &advertised_address)?;
-
-    // Create ConfigMap
-    let mut data = BTreeMap::new();
-    data.insert("config.yaml".to_string(), config_yaml);
+/// Failover and routed clusters, with the field each is reported under
+fn upstream_clusters(spec: &KafkaPartitionRemapperSpec) -> Vec<(String, &UpstreamClusterSpec)> {
+    spec.kafka
+        .failover_clusters
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (format!("kafka.failoverClusters[{}]", i), c))
+        .chain(
+            spec.routing
+                .iter()
+                .flat_map(|r| r.clusters.iter().enumerate())
+                .map(|(i, c)| (format!("routing.clusters[{}]", i), c)),
+        )
+        .collect()
+}
 
-    let config_map = ConfigMap {
-        metadata: ObjectMeta {
-            name: Some(config_map_name.clone()),
-            namespace: Some(namespace.to_string()),
-            owner_references: Some(vec![build_owner_reference(remapper)]),
-            ..Default::default()
-        },
-        data: Some(data),
-        ..Default::default()
-    };
+/// Check the failover and routed clusters and the topic routes
+fn validate_upstream_clusters(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    let upstream_clusters = upstream_clusters(spec);
+    for (i, (field, cluster)) in upstream_clusters.iter().enumerate() {
+        if !is_dns_label(&cluster.name) || cluster.name.len() > 40 {
+            return Err(Error::ValidationError(format!(
+                "{}.name {:?} must be a lowercase DNS label of at most 40 characters",
+                field, cluster.name
+            )));
+        }
+        if upstream_clusters[..i]
+            .iter()
+            .any(|(_, other)| other.name == cluster.name)
+        {
+            return Err(Error::ValidationError(format!(
+                "cluster name {:?} is used by more than one failover or routing cluster",
+                cluster.name
+            )));
+        }
+        if cluster.bootstrap_servers.is_empty() {
+            return Err(Error::ValidationError(format!(
+                "{}.bootstrapServers cannot be empty",
+                field
+            )));
+        }
+        validate_bootstrap_servers(
+            &format!("{}.bootstrapServers", field),
+            &cluster.bootstrap_servers,
+        )?;
+        if cluster.security_protocol.uses_tls() && cluster.tls_secret.is_none() {
+            return Err(Error::ValidationError(format!(
+                "{}.tlsSecret is required when using SSL or SASL_SSL protocol",
+                field
+            )));
+        }
+        if cluster.security_protocol.uses_sasl() && cluster.sasl_secret.is_none() {
+            return Err(Error::ValidationError(format!(
+                "{}.saslSecret is required when using SASL_PLAINTEXT or SASL_SSL protocol",
+                field
+            )));
+        }
+    }
 
-    let config_maps: Api<ConfigMap> = Api::namespaced(client.clone(), namespace);
-    let patch_params = PatchParams::apply("kafka-partition-remapper-operator");
+    // Validate topic routes
+    if let Some(ref routing) = spec.routing {
+        for (i, route) in routing.routes.iter().enumerate() {
+            match (&route.prefix, &route.pattern) {
+                (Some(prefix), None) if !prefix.is_empty() => {}
+                (None, Some(pattern)) => {
+                    compile_topic_pattern(&format!("routing.routes[{}].pattern", i), pattern)?;
+                }
+                _ => {
+                    return Err(Error::ValidationError(format!(
+                        "routing.routes[{}] requires exactly one of prefix and pattern",
+                        i
+                    )));
+                }
+            }
+            if !routing.clusters.iter().any(|c| c.name == route.cluster) {
+                return Err(Error::ValidationError(format!(
+                    "routing.routes[{}].cluster {:?} is not a routing cluster",
+                    i, route.cluster
+                )));
+            }
+        }
+    }
+    Ok(())
+}
 
-    config_maps
-        .patch(&config_map_name, &patch_params, &Patch::Apply(&config_map))
-        .await
-        .map_err(|e| Error::KubeError(format!("Failed to create/update ConfigMap: {}", e)))?;
+/// Check the Schema Registry proxying settings
+fn validate_schema_registry(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    if let Some(ref registry) = spec.schema_registry {
+        if !is_http_url(&registry.url) {
+            return Err(Error::ValidationError(format!(
+                "schemaRegistry.url {:?} must be an http or https URL",
+                registry.url
+            )));
+        }
+        if let Some(ref advertised_url) = registry.advertised_url {
+            if !is_http_url(advertised_url) {
+                return Err(Error::ValidationError(format!(
+                    "schemaRegistry.advertisedUrl {:?} must be an http or https URL",
+                    advertised_url
+                )));
+            }
+        }
+        if let Some(ref tls) = registry.tls_secret {
+            let provider_class = tls
+                .secret_provider_class
+                .as_deref()
+                .filter(|p| !p.is_empty());
+            if tls.name.is_empty() == provider_class.is_none() {
+                return Err(Error::ValidationError(
+                    "schemaRegistry.tlsSecret requires exactly one of name and secretProviderClass"
+                        .to_string(),
+                ));
+            }
+            if !registry.url.starts_with("https://") {
+                return Err(Error::ValidationError(
+                    "schemaRegistry.tlsSecret requires an https URL".to_string(),
+                ));
+            }
+        }
+        if registry
+            .auth_secret
+            .as_ref()
+            .is_some_and(|a| a.name.is_empty())
+        {
+            return Err(Error::ValidationError(
+                "schemaRegistry.authSecret.name cannot be empty".to_string(),
+            ));
+        }
+        if let Some(port) = registry.forward_port {
+            if !(1..=65535).contains(&port) {
+                return Err(Error::ValidationError(format!(
+                    "schemaRegistry.forwardPort {} must be between 1 and 65535",
+                    port
+                )));
+            }
+            let metrics_port = spec.metrics.enabled.then_some(spec.metrics.port);
+            if spec.listeners.iter().any(|l| l.port == port) || metrics_port == Some(port) {
+                return Err(Error::ValidationError(format!(
+                    "schemaRegistry.forwardPort {} conflicts with a proxy port",
+                    port
+                )));
+            }
+            if spec
+                .listeners
+                .iter()
+                .any(|l| l.name == deployment_builder::SCHEMA_REGISTRY_PORT_NAME)
+            {
+                return Err(Error::ValidationError(format!(
+                    "listener name {:?} is reserved when schemaRegistry.forwardPort is set",
+                    deployment_builder::SCHEMA_REGISTRY_PORT_NAME
+                )));
+            }
+        }
+    }
+    Ok(())
+}
 
-    info!("Reconciled ConfigMap {}/{}", namespace, config_map_name);
+/// Check the topic access lists and client quotas
+fn validate_access_and_quotas(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    // Validate topic access lists
+    if let Some(ref access) = spec.access {
+        for (field, topics) in [
+            ("access.topicAllowList", &access.topic_allow_list),
+            ("access.topicDenyList", &access.topic_deny_list),
+        ] {
+            for (i, topic) in topics.iter().enumerate() {
+                if topic.is_empty() {
+                    return Err(Error::ValidationError(format!(
+                        "{} entries cannot be empty",
+                        field
+                    )));
+                }
+                compile_topic_pattern(&format!("{}[{}]", field, i), topic)?;
+            }
+        }
+    }
 
-    Ok(config_map_name)
+    // Validate client quotas
+    if let Some(ref quotas) = spec.quotas {
+        let limits = quotas
+            .default
+            .iter()
+            .map(|l| ("quotas.default".to_string(), l))
+            .chain(
+                quotas
+                    .clients
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| (format!("quotas.clients[{}]", i), &c.limits)),
+            );
+        for (field, limits) in limits {
+            if *limits == QuotaLimits::default() {
+                return Err(Error::ValidationError(format!(
+                    "{} must set at least one limit",
+                    field
+                )));
+            }
+            let values = [
+                limits.ingress_bytes_per_second,
+                limits.egress_bytes_per_second,
+                limits.connections_per_second.map(u64::from),
+            ];
+            if values.contains(&Some(0)) {
+                return Err(Error::ValidationError(format!(
+                    "{} limits must be >= 1",
+                    field
+                )));
+            }
+        }
+        for (i, client) in quotas.clients.iter().enumerate() {
+            let subject = match (&client.client_id, &client.user) {
+                (Some(client_id), None) if !client_id.is_empty() => ("clientId", client_id),
+                (None, Some(user)) if !user.is_empty() => ("user", user),
+                _ => {
+                    return Err(Error::ValidationError(format!(
+                        "quotas.clients[{}] requires exactly one of clientId and user",
+                        i
+                    )));
+                }
+            };
+            let duplicate = quotas.clients[..i].iter().any(|other| {
+                other.client_id.as_ref() == client.client_id.as_ref()
+                    && other.user.as_ref() == client.user.as_ref()
+            });
+            if duplicate {
+                return Err(Error::ValidationError(format!(
+                    "quotas.clients has more than one entry for {} {:?}",
+                    subject.0, subject.1
+                )));
+            }
+        }
+    }
+    Ok(())
 }
 
-/// Reconcile the Deployment for proxy pods
-pub async fn reconcile_deployment(
-    remapper: &KafkaPartitionRemapper,
-    client: &Client,
-    namespace: &str,
-    config_map_name: &str,
-) -> Result<String> {
-    let name = remapper.name_any();
+/// Check the consumer group settings
+fn validate_consumer_groups(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    if let Some(ref groups) = spec.consumer_groups {
+        if let Some(ref prefix) = groups.group_id_prefix {
+            let valid = !prefix.is_empty()
+                && prefix
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+            if !valid {
+                return Err(Error::ValidationError(format!(
+                    "consumerGroups.groupIdPrefix {:?} may only contain letters, digits, '.', '_' and '-'",
+                    prefix
+                )));
+            }
+        }
+        for (i, protocol_type) in groups.passthrough_protocol_types.iter().enumerate() {
+            if protocol_type.is_empty() || protocol_type == "consumer" {
+                return Err(Error::ValidationError(format!(
+                    "consumerGroups.passthroughProtocolTypes entry {:?} must be a protocol type other than \"consumer\"",
+                    protocol_type
+                )));
+            }
+            if groups.passthrough_protocol_types[..i].contains(protocol_type) {
+                return Err(Error::ValidationError(format!(
+                    "consumerGroups.passthroughProtocolTypes lists {:?} more than once",
+                    protocol_type
+                )));
+            }
+        }
+        if groups.min_session_timeout_ms == Some(0) || groups.max_session_timeout_ms == Some(0) {
+            return Err(Error::ValidationError(
+                "consumerGroups session timeout bounds must be >= 1".to_string(),
+            ));
+        }
+        if let (Some(min), Some(max)) =
+            (groups.min_session_timeout_ms, groups.max_session_timeout_ms)
+        {
+            if min > max {
+                return Err(Error::ValidationError(
+                    "consumerGroups.minSessionTimeoutMs must be <= consumerGroups.maxSessionTimeoutMs"
+                        .to_string(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
 
-    // Calculate config hash for rolling updates
-    let config_hash = calculate_config_hash(remapper);
+/// Check the proxy tuning settings
+fn validate_tuning(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    if let Some(ref tuning) = spec.tuning {
+        let settings = [
+            ("tuning.workerThreads", tuning.worker_threads),
+            (
+                "tuning.socketSendBufferBytes",
+                tuning.socket_send_buffer_bytes,
+            ),
+            (
+                "tuning.socketReceiveBufferBytes",
+                tuning.socket_receive_buffer_bytes,
+            ),
+            ("tuning.tcpKeepaliveSecs", tuning.tcp_keepalive_secs),
+            (
+                "tuning.idleConnectionTimeoutSecs",
+                tuning.idle_connection_timeout_secs,
+            ),
+            ("tuning.maxInFlightRequests", tuning.max_in_flight_requests),
+        ];
+        if let Some((field, _)) = settings.iter().find(|(_, value)| *value == Some(0)) {
+            return Err(Error::ValidationError(format!("{} must be >= 1", field)));
+        }
+        if tuning.connection_memory_bytes == Some(0) {
+            return Err(Error::ValidationError(
+                "tuning.connectionMemoryBytes must be >= 1".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
 
-    // Build Deployment
-    let deployment = deployment_builder::build_deployment(remapper, config_map_name, &config_hash);
+/// Check the proxy container resources
+fn validate_resources(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    if let Some(resources) = spec
+        .pod_template
+        .as_ref()
+        .and_then(|pt| pt.resources.as_ref())
+    {
+        for (kind, values) in [
+            ("limits", &resources.limits),
+            ("requests", &resources.requests),
+        ] {
+            for (resource, value) in values {
+                if deployment_builder::parse_quantity(value).is_none_or(|q| q < 0.0) {
+                    return Err(Error::ValidationError(format!(
+                        "podTemplate.resources.{}.{} {:?} is not a valid quantity (e.g. 500m, 2, 512Mi, 1Gi)",
+                        kind, resource, value
+                    )));
+                }
+            }
+        }
+        for (resource, request) in &resources.requests {
+            let Some(limit) = resources.limits.get(resource) else {
+                continue;
+            };
+            let exceeds = deployment_builder::parse_quantity(request)
+                .zip(deployment_builder::parse_quantity(limit))
+                .is_some_and(|(request, limit)| request > limit);
+            if exceeds {
+                return Err(Error::ValidationError(format!(
+                    "podTemplate.resources.requests.{} {:?} exceeds the limit {:?}",
+                    resource, request, limit
+                )));
+            }
+        }
+    }
+    Ok(())
+}
 
-    let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
-    let patch_params = PatchParams::apply("kafka-partition-remapper-operator");
+/// Check the audit logging destinations
+fn validate_audit_logging(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    if let Some(audit) = spec.audit_logging.as_ref().filter(|a| a.enabled) {
+        if !(audit.sample_rate > 0.0 && audit.sample_rate <= 1.0) {
+            return Err(Error::ValidationError(
+                "auditLogging.sampleRate must be > 0 and <= 1".to_string(),
+            ));
+        }
+        if audit.destinations.is_empty() {
+            return Err(Error::ValidationError(
+                "auditLogging.destinations cannot be empty".to_string(),
+            ));
+        }
+        let mut file_names = Vec::new();
+        for (i, destination) in audit.destinations.iter().enumerate() {
+            let field = format!("auditLogging.destinations[{}]", i);
+            match destination.type_ {
+                AuditDestinationType::File => {
+                    let file_name = destination.file_name.as_deref().unwrap_or("audit.log");
+                    if file_name.is_empty() || file_name.contains('/') || file_name.starts_with('.')
+                    {
+                        return Err(Error::ValidationError(format!(
+                            "{}.fileName {:?} must be a plain file name",
+                            field, file_name
+                        )));
+                    }
+                    if file_names.contains(&file_name) {
+                        return Err(Error::ValidationError(format!(
+                            "auditLogging writes {:?} from more than one destination",
+                            file_name
+                        )));
+                    }
+                    file_names.push(file_name);
+                }
+                AuditDestinationType::Syslog => {
+                    if !destination.address.as_deref().is_some_and(is_host_port) {
+                        return Err(Error::ValidationError(format!(
+                            "{}.address must be a host:port syslog server",
+                            field
+                        )));
+                    }
+                }
+                AuditDestinationType::Stdout => {}
+            }
+            let stray = match destination.type_ {
+                AuditDestinationType::File => destination.address.is_some().then_some("address"),
+                AuditDestinationType::Syslog => {
+                    destination.file_name.is_some().then_some("fileName")
+                }
+                AuditDestinationType::Stdout => destination
+                    .address
+                    .as_ref()
+                    .map(|_| "address")
+                    .or(destination.file_name.as_ref().map(|_| "fileName")),
+            };
+            if let Some(stray) = stray {
+                return Err(Error::ValidationError(format!(
+                    "{}.{} does not apply to {:?} destinations",
+                    field, stray, destination.type_
+                )));
+            }
+        }
+    }
+    Ok(())
+}
 
-    deployments
-        .patch(&name, &patch_params, &Patch::Apply(&deployment))
-        .await
-        .map_err(|e| Error::KubeError(format!("Failed to create/update Deployment: {}", e)))?;
+/// Check the client listeners and their security
+fn validate_listeners(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    if spec.listeners.is_empty() {
+        return Err(Error::ValidationError(
+            "listeners must contain at least one listener".to_string(),
+        ));
+    }
+    if spec.metrics.enabled && !(1..=65535).contains(&spec.metrics.port) {
+        return Err(Error::ValidationError(format!(
+            "metrics.port {} must be between 1 and 65535",
+            spec.metrics.port
+        )));
+    }
+    for (i, listener) in spec.listeners.iter().enumerate() {
+        if !(1..=65535).contains(&listener.port) {
+            return Err(Error::ValidationError(format!(
+                "listeners[{}].port {} must be between 1 and 65535",
+                i, listener.port
+            )));
+        }
+        if let Some(ref address) = listener.advertised_address {
+            if !is_host_port(address) {
+                return Err(Error::ValidationError(format!(
+                    "listeners[{}].advertisedAddress {:?} must be a host:port address",
+                    i, address
+                )));
+            }
+        }
+        let valid_name = is_dns_label(&listener.name) && listener.name.len() <= 15;
+        if !valid_name || listener.name == "metrics" {
+            return Err(Error::ValidationError(format!(
+                "listener name {:?} must be a lowercase port name of at most 15 characters other than \"metrics\"",
+                listener.name
+            )));
+        }
+        if spec.metrics.enabled && listener.port == spec.metrics.port {
+            return Err(Error::ValidationError(format!(
+                "listener {} port {} conflicts with metrics.port",
+                listener.name, listener.port
+            )));
+        }
+        if let Some(other) = spec.listeners[..i]
+            .iter()
+            .find(|l| l.name == listener.name || l.port == listener.port)
+        {
+            return Err(Error::ValidationError(format!(
+                "listeners {} and {} must have distinct names and ports",
+                other.name, listener.name
+            )));
+        }
 
-    info!("Reconciled Deployment {}/{}", namespace, name);
+        if let Some(ref security) = listener.security {
+            if security.protocol.uses_tls() && security.tls.is_none() {
+                return Err(Error::ValidationError(format!(
+                    "listener {} requires security.tls when using SSL or SASL_SSL protocol",
+                    listener.name
+                )));
+            }
+            if security.protocol.uses_sasl() && security.sasl.is_none() {
+                return Err(Error::ValidationError(format!(
+                    "listener {} requires security.sasl when using SASL_PLAINTEXT or SASL_SSL protocol",
+                    listener.name
+                )));
+            }
+            if !security.protocol.uses_tls() && security.tls.is_some() {
+                return Err(Error::ValidationError(format!(
+                    "listener {} security.tls requires the SSL or SASL_SSL protocol",
+                    listener.name
+                )));
+            }
+            if let Some(ref tls) = security.tls {
+                if tls.require_client_cert && tls.client_ca_secret.is_none() {
+                    return Err(Error::ValidationError(format!(
+                        "listener {} requires security.tls.clientCaSecret when requireClientCert is set",
+                        listener.name
+                    )));
+                }
+            }
+            if let Some(ref sasl) = security.sasl {
+                if sasl.enabled_mechanisms.is_empty() {
+                    return Err(Error::ValidationError(format!(
+                        "listener {} security.sasl.enabledMechanisms cannot be empty",
+                        listener.name
+                    )));
+                }
+            }
+        }
+    }
+    Ok(())
+}
 
-    Ok(name)
+/// Check the generated SASL users
+fn validate_users(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    for (i, user) in spec.users.iter().enumerate() {
+        if !is_dns_label(&user.name) {
+            return Err(Error::ValidationError(format!(
+                "user name {:?} must be a lowercase DNS label",
+                user.name
+            )));
+        }
+        if spec.users[..i].iter().any(|u| u.name == user.name) {
+            return Err(Error::ValidationError(format!(
+                "user {} is listed more than once",
+                user.name
+            )));
+        }
+        if !users::USER_MECHANISMS.contains(&user.mechanism) {
+            return Err(Error::ValidationError(format!(
+                "user {} mechanism must be one of: {}",
+                user.name,
+                users::USER_MECHANISMS.map(|m| m.as_str()).join(", ")
+            )));
+        }
+        if let Some(ref rotation) = user.rotation {
+            if !(1..=3650).contains(&rotation.interval_days) {
+                return Err(Error::ValidationError(format!(
+                    "user {} rotation.intervalDays must be between 1 and 3650",
+                    user.name
+                )));
+            }
+            if rotation.overlap_hours >= rotation.interval_days * 24 {
+                return Err(Error::ValidationError(format!(
+                    "user {} rotation.overlapHours must be shorter than rotation.intervalDays",
+                    user.name
+                )));
+            }
+        }
+        let accepted = spec.listeners.iter().any(|l| {
+            l.security
+                .as_ref()
+                .and_then(|s| s.sasl.as_ref())
+                .is_some_and(|sasl| sasl.enabled_mechanisms.contains(&user.mechanism))
+        });
+        if !accepted {
+            return Err(Error::ValidationError(format!(
+                "user {} requires a SASL listener with {} enabled",
+                user.name,
+                user.mechanism.as_str()
+            )));
+        }
+    }
+    Ok(())
 }
 
-/// Reconcile the Service for proxy access
-pub async fn reconcile_service(
-    remapper: &KafkaPartitionRemapper,
-    client: &Client,
-    namespace: &str,
-) -> Result<String> {
-    let name = remapper.name_any();
+/// Check the client Service settings
+fn validate_service(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    // Validate the ExternalDNS hostname
+    if let Some(ref hostname) = spec.service.external_hostname {
+        let valid =
+            !hostname.is_empty() && hostname.len() <= 253 && hostname.split('.').all(is_dns_label);
+        if !valid {
+            return Err(Error::ValidationError(format!(
+                "service.externalHostname {:?} must be a lowercase DNS name without a port",
+                hostname
+            )));
+        }
+    }
 
-    // Build Service
-    let service = service_builder::build_service(remapper);
+    // Validate Service networking options
+    let service = &spec.service;
+    if let Some(family) = service
+        .ip_families
+        .iter()
+        .find(|f| !["IPv4", "IPv6"].contains(&f.as_str()))
+    {
+        return Err(Error::ValidationError(format!(
+            "service.ipFamilies contains unknown family {:?}, expected IPv4 or IPv6",
+            family
+        )));
+    }
+    if service.ip_families.len() > 2
+        || first_duplicate(service.ip_families.iter().map(String::as_str)).is_some()
+    {
+        return Err(Error::ValidationError(
+            "service.ipFamilies may list IPv4 and IPv6 at most once each".to_string(),
+        ));
+    }
+    if let Some(ref policy) = service.ip_family_policy {
+        if !["SingleStack", "PreferDualStack", "RequireDualStack"].contains(&policy.as_str()) {
+            return Err(Error::ValidationError(
+                "service.ipFamilyPolicy must be SingleStack, PreferDualStack or RequireDualStack"
+                    .to_string(),
+            ));
+        }
+        if policy == "SingleStack" && service.ip_families.len() > 1 {
+            return Err(Error::ValidationError(
+                "service.ipFamilyPolicy SingleStack allows a single entry in service.ipFamilies"
+                    .to_string(),
+            ));
+        }
+    }
+    if let Some(ref policy) = service.internal_traffic_policy {
+        if !["Cluster", "Local"].contains(&policy.as_str()) {
+            return Err(Error::ValidationError(
+                "service.internalTrafficPolicy must be Cluster or Local".to_string(),
+            ));
+        }
+    }
+    if service.type_ != ServiceType::LoadBalancer
+        && (service.load_balancer_class.is_some()
+            || !service.load_balancer_source_ranges.is_empty()
+            || service.allocate_load_balancer_node_ports.is_some())
+    {
+        return Err(Error::ValidationError(
+            "service.loadBalancerClass, service.loadBalancerSourceRanges and service.allocateLoadBalancerNodePorts require service.type LoadBalancer"
+                .to_string(),
+        ));
+    }
+    if let Some(range) = service
+        .load_balancer_source_ranges
+        .iter()
+        .find(|r| !is_cidr(r))
+    {
+        return Err(Error::ValidationError(format!(
+            "service.loadBalancerSourceRanges entry {:?} is not a CIDR",
+            range
+        )));
+    }
 
-    let services: Api<Service> = Api::namespaced(client.clone(), namespace);
-    let patch_params = PatchParams::apply("kafka-partition-remapper-operator");
+    if !service.node_ports.is_empty() {
+        if service.type_ == ServiceType::ClusterIP {
+            return Err(Error::ValidationError(
+                "service.nodePorts require service.type NodePort or LoadBalancer".to_string(),
+            ));
+        }
+        if service.allocate_load_balancer_node_ports == Some(false) {
+            return Err(Error::ValidationError(
+                "service.nodePorts cannot be combined with service.allocateLoadBalancerNodePorts false"
+                    .to_string(),
+            ));
+        }
+        if let Some(name) = service.node_ports.keys().find(|name| {
+            let metrics = *name == "metrics" && spec.metrics.on_client_service();
+            let registry = *name == deployment_builder::SCHEMA_REGISTRY_PORT_NAME
+                && deployment_builder::schema_registry_forward_port(spec).is_some();
+            !metrics && !registry && !spec.listeners.iter().any(|l| &l.name == *name)
+        }) {
+            return Err(Error::ValidationError(format!(
+                "service.nodePorts references unknown port {}",
+                name
+            )));
+        }
+        if let Some(port) = service
+            .node_ports
+            .values()
+            .find(|p| !(1..=65535).contains(*p))
+        {
+            return Err(Error::ValidationError(format!(
+                "service.nodePorts value {} must be between 1 and 65535",
+                port
+            )));
+        }
+        let ports: Vec<i32> = service.node_ports.values().copied().collect();
+        if let Some((_, port)) = ports
+            .iter()
+            .enumerate()
+            .find(|(i, p)| ports[..*i].contains(p))
+        {
+            return Err(Error::ValidationError(format!(
+                "service.nodePorts assigns node port {} more than once",
+                port
+            )));
+        }
+    }
+    if let Some(ref affinity) = service.session_affinity {
+        if !["None", "ClientIP"].contains(&affinity.as_str()) {
+            return Err(Error::ValidationError(
+                "service.sessionAffinity must be None or ClientIP".to_string(),
+            ));
+        }
+    }
+    if let Some(timeout) = service.session_affinity_timeout_seconds {
+        if service.session_affinity.as_deref() != Some("ClientIP") {
+            return Err(Error::ValidationError(
+                "service.sessionAffinityTimeoutSeconds requires service.sessionAffinity ClientIP"
+                    .to_string(),
+            ));
+        }
+        if !(1..=86400).contains(&timeout) {
+            return Err(Error::ValidationError(
+                "service.sessionAffinityTimeoutSeconds must be between 1 and 86400".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
 
-    services
-        .patch(&name, &patch_params, &Patch::Apply(&service))
-        .await
-        .map_err(|e| Error::KubeError(format!("Failed to create/update Service: {}", e)))?;
+/// Check the external Service and the Gateway API route
+fn validate_external_access(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    // Validate the external Service
+    if let Some(ref external) = spec.external_service {
+        if let Some(unknown) = external
+            .listeners
+            .iter()
+            .find(|name| !spec.listeners.iter().any(|l| &l.name == *name))
+        {
+            return Err(Error::ValidationError(format!(
+                "externalService.listeners references unknown listener {}",
+                unknown
+            )));
+        }
+        if external.type_ == ServiceType::ClusterIP {
+            return Err(Error::ValidationError(
+                "externalService.type must be LoadBalancer or NodePort".to_string(),
+            ));
+        }
+    }
 
-    info!("Reconciled Service {}/{}", namespace, name);
+    // Validate the Gateway API integration
+    if let Some(ref gateway) = spec.gateway {
+        if gateway.parent_ref.name.is_empty() {
+            return Err(Error::ValidationError(
+                "gateway.parentRef.name cannot be empty".to_string(),
+            ));
+        }
+        if let Some(ref listener) = gateway.listener {
+            if !spec.listeners.iter().any(|l| &l.name == listener) {
+                return Err(Error::ValidationError(format!(
+                    "gateway.listener references unknown listener {}",
+                    listener
+                )));
+            }
+        }
+    }
+    Ok(())
+}
 
-    Ok(name)
+/// Check the consistency audit settings
+///
+/// The schedule is parsed by the CronJob controller, so only its shape is
+/// checked here.
+fn validate_audit(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    if let Some(ref audit) = spec.audit {
+        if audit.schedule.split_whitespace().count() != 5 && !audit.schedule.starts_with('@') {
+            return Err(Error::ValidationError(format!(
+                "audit.schedule {:?} must be a cron expression with five fields",
+                audit.schedule
+            )));
+        }
+        if audit.topics.iter().any(|t| t.trim().is_empty()) {
+            return Err(Error::ValidationError(
+                "audit.topics must not contain empty names".to_string(),
+            ));
+        }
+    }
+    Ok(())
 }
 
-/// Update the status of a KafkaPartitionRemapper
-pub async fn update_status(
-    remapper: &KafkaPartitionRemapper,
-    client: &Client,
-    namespace: &str,
-    config_map_name: &str,
-    deployment_name: &str,
-    service_name: &str,
-) -> Result<()> {
-    let name = remapper.name_any();
-    let spec = &remapper.spec;
+/// Check the HPA and KEDA autoscaling settings
+fn validate_autoscaling(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    if let Some(ref autoscaling) = spec.autoscaling {
+        let min_replicas = autoscaling.min_replicas.unwrap_or(1);
+        if min_replicas < 1 {
+            return Err(Error::ValidationError(
+                "autoscaling.minReplicas must be >= 1".to_string(),
+            ));
+        }
+        if autoscaling.max_replicas < min_replicas {
+            return Err(Error::ValidationError(
+                "autoscaling.maxReplicas must be >= autoscaling.minReplicas".to_string(),
+            ));
+        }
+        let utilization_targets = [
+            autoscaling.target_cpu_utilization_percentage,
+            autoscaling.target_memory_utilization_percentage,
+        ];
+        if utilization_targets.iter().flatten().any(|t| *t < 1) {
+            return Err(Error::ValidationError(
+                "autoscaling target utilization percentages must be >= 1".to_string(),
+            ));
+        }
+        if let Some(keda) = autoscaling.keda.as_ref().filter(|k| k.enabled) {
+            if keda.server_address.trim().is_empty() {
+                return Err(Error::ValidationError(
+                    "autoscaling.keda.serverAddress must not be empty".to_string(),
+                ));
+            }
+            let targets = [
+                keda.target_connections_per_pod,
+                keda.target_bytes_per_second_per_pod,
+            ];
+            if targets.iter().all(Option::is_none) {
+                return Err(Error::ValidationError(
+                    "autoscaling.keda requires targetConnectionsPerPod or targetBytesPerSecondPerPod"
+                        .to_string(),
+                ));
+            }
+            if targets.iter().flatten().any(|t| *t < 1) {
+                return Err(Error::ValidationError(
+                    "autoscaling.keda targets must be >= 1".to_string(),
+                ));
+            }
+            if !spec.metrics.enabled {
+                return Err(Error::ValidationError(
+                    "autoscaling.keda requires metrics.enabled, its triggers query the proxy metrics"
+                        .to_string(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
 
-    // Get deployment status
-    let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
-    let deployment = deployments.get(deployment_name).await.ok();
+/// Check the sharding settings
+fn validate_sharding(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    if spec.sharding_enabled() {
+        if spec.workload != WorkloadKind::StatefulSet {
+            return Err(Error::ValidationError(
+                "sharding requires workload: StatefulSet".to_string(),
+            ));
+        }
+        if spec.autoscaling.as_ref().is_some_and(|a| a.enabled) {
+            return Err(Error::ValidationError(
+                "sharding cannot be combined with autoscaling".to_string(),
+            ));
+        }
+        if spec.replicas as u32 > spec.mapping.virtual_partitions {
+            return Err(Error::ValidationError(
+                "replicas must be <= mapping.virtualPartitions when sharding is enabled"
+                    .to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
 
-    let (ready_replicas, replicas) = deployment
-        .as_ref()
-        .and_then(|d| d.status.as_ref())
-        .map(|s| (s.ready_replicas.unwrap_or(0), s.replicas.unwrap_or(0)))
-        .unwrap_or((0, 0));
+/// Check the dedicated metrics Service and what relies on the metrics port
+fn validate_metrics(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    // Validate the dedicated metrics Service
+    if let Some(metrics_service) = spec.metrics.service.as_ref().filter(|s| s.enabled) {
+        if !spec.metrics.enabled {
+            return Err(Error::ValidationError(
+                "metrics.service requires metrics.enabled".to_string(),
+            ));
+        }
+        let types = ["ClusterIP", "Headless", "NodePort", "LoadBalancer"];
+        if !types.contains(&metrics_service.type_.as_str()) {
+            return Err(Error::ValidationError(format!(
+                "metrics.service.type must be one of: {:?}",
+                types
+            )));
+        }
+    }
 
-    // Get service endpoint
-    let services: Api<Service> = Api::namespaced(client.clone(), namespace);
-    let service = services.get(service_name).await.ok();
-    let service_endpoint = service
-        .as_ref()
-        .and_then(|s| service_builder::get_service_endpoint(s, spec));
+    // The reload endpoint is served on the metrics port
+    if spec.reload_policy == ReloadPolicy::Hot && !spec.metrics.enabled {
+        return Err(Error::ValidationError(
+            "reloadPolicy Hot requires metrics.enabled".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Check the rollout strategy settings
+fn validate_rollout_strategy(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    if let Some(ref rollout) = spec.rollout_strategy {
+        let deployment_only = rollout.max_surge.is_some()
+            || rollout.max_unavailable.is_some()
+            || rollout.progress_deadline_seconds.is_some();
+        if deployment_only && spec.workload != WorkloadKind::Deployment {
+            return Err(Error::ValidationError(
+                "rolloutStrategy maxSurge, maxUnavailable and progressDeadlineSeconds require workload: Deployment"
+                    .to_string(),
+            ));
+        }
+        let zero = |v: &Option<IntOrString>| {
+            matches!(v, Some(IntOrString::Int(0)))
+                || matches!(v, Some(IntOrString::String(s)) if s == "0%")
+        };
+        if zero(&rollout.max_surge) && zero(&rollout.max_unavailable) {
+            return Err(Error::ValidationError(
+                "rolloutStrategy maxSurge and maxUnavailable cannot both be 0".to_string(),
+            ));
+        }
+        if rollout.progress_deadline_seconds.is_some_and(|s| s < 1) {
+            return Err(Error::ValidationError(
+                "rolloutStrategy.progressDeadlineSeconds must be >= 1".to_string(),
+            ));
+        }
+        if rollout.revision_history_limit.is_some_and(|l| l < 0) {
+            return Err(Error::ValidationError(
+                "rolloutStrategy.revisionHistoryLimit must be >= 0".to_string(),
+            ));
+        }
+        if rollout.type_ == RolloutStrategyType::Canary {
+            if spec.workload != WorkloadKind::Deployment {
+                return Err(Error::ValidationError(
+                    "rolloutStrategy Canary requires workload: Deployment".to_string(),
+                ));
+            }
+            if rollout.canary_replicas < 1 {
+                return Err(Error::ValidationError(
+                    "rolloutStrategy.canaryReplicas must be >= 1".to_string(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check the probe overrides and the Kafka readiness requirement
+fn validate_probes(spec: &KafkaPartitionRemapperSpec) -> Result<()> {
+    if let Some(ref probes) = spec.probes {
+        let overrides = [
+            ("liveness", &probes.liveness),
+            ("readiness", &probes.readiness),
+            ("startup", &probes.startup),
+        ];
+        for (kind, probe) in overrides {
+            let Some(probe) = probe else { continue };
+            let type_ = probe.type_.unwrap_or(if spec.metrics.enabled {
+                ProbeType::Http
+            } else {
+                ProbeType::Tcp
+            });
+            match type_ {
+                ProbeType::Http if probe.port.is_none() && !spec.metrics.enabled => {
+                    return Err(Error::ValidationError(format!(
+                        "probes.{} type Http requires metrics.enabled or a port",
+                        kind
+                    )));
+                }
+                ProbeType::Http if probe.path.as_ref().is_some_and(|p| !p.starts_with('/')) => {
+                    return Err(Error::ValidationError(format!(
+                        "probes.{}.path must start with /",
+                        kind
+                    )));
+                }
+                ProbeType::Exec if probe.command.is_empty() => {
+                    return Err(Error::ValidationError(format!(
+                        "probes.{} type Exec requires a command",
+                        kind
+                    )));
+                }
+                _ => {}
+            }
+            let timings = [
+                probe.period_seconds,
+                probe.timeout_seconds,
+                probe.failure_threshold,
+                probe.success_threshold,
+            ];
+            if timings.iter().flatten().any(|t| *t < 1) {
+                return Err(Error::ValidationError(format!(
+                    "probes.{} periods, timeouts and thresholds must be >= 1",
+                    kind
+                )));
+            }
+            if probe.initial_delay_seconds.is_some_and(|d| d < 0) {
+                return Err(Error::ValidationError(format!(
+                    "probes.{}.initialDelaySeconds must be >= 0",
+                    kind
+                )));
+            }
+        }
+        // Kubernetes requires a liveness and startup successThreshold of 1
+        let single_success = [&probes.liveness, &probes.startup]
+            .into_iter()
+            .flatten()
+            .all(|p| p.success_threshold.unwrap_or(1) == 1);
+        if !single_success {
+            return Err(Error::ValidationError(
+                "probes liveness and startup successThreshold must be 1".to_string(),
+            ));
+        }
+    }
+
+    // Broker connectivity is reported on the metrics port
+    if spec.kafka_readiness_required() {
+        if !spec.metrics.enabled {
+            return Err(Error::ValidationError(
+                "readiness.requireKafka requires metrics.enabled".to_string(),
+            ));
+        }
+        let readiness_type = spec
+            .probes
+            .as_ref()
+            .and_then(|p| p.readiness.as_ref())
+            .and_then(|r| r.type_);
+        if readiness_type.is_some_and(|t| t != ProbeType::Http) {
+            return Err(Error::ValidationError(
+                "readiness.requireKafka requires an Http readiness probe".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check the names and labels given to child resources
+fn validate_naming(remapper: &KafkaPartitionRemapper) -> Result<()> {
+    let spec = &remapper.spec;
+
+    // Overrides become part of every child resource name
+    for (field, value) in [
+        ("nameOverride", &spec.name_override),
+        ("fullnameOverride", &spec.fullname_override),
+    ] {
+        let Some(value) = value else {
+            continue;
+        };
+        if !is_dns_label(value) {
+            return Err(Error::ValidationError(format!(
+                "{} {:?} must be a lowercase DNS label",
+                field, value
+            )));
+        }
+    }
+
+    // Children in another namespace record the remapper name in a label
+    if let Some(ref target) = spec.target_namespace {
+        if !is_dns_label(target) {
+            return Err(Error::ValidationError(format!(
+                "targetNamespace {:?} must be a lowercase DNS label",
+                target
+            )));
+        }
+        if remapper.deploys_cross_namespace() && remapper.name_any().len() > 63 {
+            return Err(Error::ValidationError(
+                "a remapper with a targetNamespace must have a name of at most 63 characters"
+                    .to_string(),
+            ));
+        }
+    }
+
+    // The standard and owner labels identify the proxy resources
+    let mut standard_labels = deployment_builder::build_labels("");
+    standard_labels.extend(deployment_builder::owner_labels(remapper));
+    if let Some(key) = spec
+        .common_labels
+        .keys()
+        .find(|k| standard_labels.contains_key(*k))
+    {
+        return Err(Error::ValidationError(format!(
+            "commonLabels cannot set the operator-managed label {}",
+            key
+        )));
+    }
+    Ok(())
+}
+
+/// Check the pod template settings
+fn validate_pod_template(remapper: &KafkaPartitionRemapper) -> Result<()> {
+    let spec = &remapper.spec;
+
+    if let Some(ref pt) = spec.pod_template {
+        if let Some(ref dns_config) = pt.dns_config {
+            if let Err(e) = serde_json::from_value::<PodDNSConfig>(dns_config.clone()) {
+                return Err(Error::ValidationError(format!(
+                    "podTemplate.dnsConfig is not a valid DNS config: {}",
+                    e
+                )));
+            }
+        }
+        let dns_policies = ["ClusterFirst", "ClusterFirstWithHostNet", "Default", "None"];
+        if let Some(ref policy) = pt.dns_policy {
+            if !dns_policies.contains(&policy.as_str()) {
+                return Err(Error::ValidationError(format!(
+                    "podTemplate.dnsPolicy must be one of: {:?}",
+                    dns_policies
+                )));
+            }
+            if policy == "None" && pt.dns_config.is_none() {
+                return Err(Error::ValidationError(
+                    "podTemplate.dnsPolicy None requires podTemplate.dnsConfig".to_string(),
+                ));
+            }
+        }
+
+        let proxy_ports: Vec<(&str, i32)> = spec
+            .listeners
+            .iter()
+            .map(|l| (l.name.as_str(), l.port))
+            .chain(
+                spec.metrics
+                    .enabled
+                    .then_some(("metrics", spec.metrics.port)),
+            )
+            .chain(
+                deployment_builder::schema_registry_forward_port(spec)
+                    .map(|port| (deployment_builder::SCHEMA_REGISTRY_PORT_NAME, port)),
+            )
+            .collect();
+
+        // Every port is bound on the node when sharing its network namespace
+        if pt.host_network {
+            if let Some((name, port)) = proxy_ports.iter().find(|(_, port)| *port < 1024) {
+                return Err(Error::ValidationError(format!(
+                    "port {} of {} must be >= 1024 with podTemplate.hostNetwork",
+                    port, name
+                )));
+            }
+        }
+
+        // Containers of a pod share its network namespace
+        let sidecar_ports = pt
+            .sidecars
+            .iter()
+            .flat_map(|c| c.ports.iter().flatten().map(move |p| (c, p.container_port)));
+        for (sidecar, port) in sidecar_ports {
+            if proxy_ports.iter().any(|(_, p)| *p == port) {
+                return Err(Error::ValidationError(format!(
+                    "sidecar {} port {} conflicts with a proxy port",
+                    sidecar.name, port
+                )));
+            }
+        }
+
+        let containers: Vec<_> = pt.init_containers.iter().chain(&pt.sidecars).collect();
+        for (i, container) in containers.iter().enumerate() {
+            if container.name.is_empty() {
+                return Err(Error::ValidationError(
+                    "podTemplate container names cannot be empty".to_string(),
+                ));
+            }
+            if container.name == deployment_builder::PROXY_CONTAINER_NAME
+                || containers[..i].iter().any(|c| c.name == container.name)
+            {
+                return Err(Error::ValidationError(format!(
+                    "podTemplate container name {} is already in use",
+                    container.name
+                )));
+            }
+        }
+
+        // Extra env vars, volumes and mounts must not collide with the
+        // operator's own, which server-side apply would reject
+        if !pt.env.is_empty() || !pt.extra_volumes.is_empty() || !pt.extra_volume_mounts.is_empty()
+        {
+            let pod_spec = deployment_builder::build_pod_template(remapper, "config", "", None)
+                .spec
+                .unwrap_or_default();
+            let proxy = &pod_spec.containers[0];
+            let volumes = pod_spec.volumes.as_deref().unwrap_or_default();
+            let mounts = proxy.volume_mounts.as_deref().unwrap_or_default();
+            let checks = [
+                (
+                    "env var",
+                    first_duplicate(proxy.env.iter().flatten().map(|e| e.name.as_str())),
+                ),
+                (
+                    "volume",
+                    first_duplicate(volumes.iter().map(|v| v.name.as_str())),
+                ),
+                (
+                    "volume mount path",
+                    first_duplicate(mounts.iter().map(|m| m.mount_path.as_str())),
+                ),
+            ];
+            if let Some((kind, name)) = checks
+                .into_iter()
+                .find_map(|(kind, dup)| dup.map(|name| (kind, name)))
+            {
+                return Err(Error::ValidationError(format!(
+                    "podTemplate {} {} is already in use",
+                    kind, name
+                )));
+            }
+            if let Some(mount) = pt
+                .extra_volume_mounts
+                .iter()
+                .find(|m| !volumes.iter().any(|v| v.name == m.name))
+            {
+                return Err(Error::ValidationError(format!(
+                    "podTemplate.extraVolumeMounts references unknown volume {}",
+                    mount.name
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether a value is a `host:port` address with a non-zero port
+fn is_host_port(value: &str) -> bool {
+    value
+        .rsplit_once(':')
+        .is_some_and(|(host, port)| !host.is_empty() && port.parse::<u16>().is_ok_and(|p| p > 0))
+}
+
+/// Check that every bootstrap server is a host:port address
+fn validate_bootstrap_servers(field: &str, servers: &[String]) -> Result<()> {
+    match servers.iter().position(|s| !is_host_port(s)) {
+        Some(i) => Err(Error::ValidationError(format!(
+            "{}[{}] {:?} must be a host:port address",
+            field, i, servers[i]
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Compile a topic pattern the way the proxy does, anchored to the whole
+/// topic name
+///
+/// Syntax errors name the field and the character position in the pattern
+/// the parser stopped at.
+fn compile_topic_pattern(field: &str, pattern: &str) -> Result<regex::Regex> {
+    let syntax_error = match regex_syntax::Parser::new().parse(pattern) {
+        Err(regex_syntax::Error::Parse(e)) => Some((e.kind().to_string(), e.span().start)),
+        Err(regex_syntax::Error::Translate(e)) => Some((e.kind().to_string(), e.span().start)),
+        _ => None,
+    };
+    if let Some((kind, position)) = syntax_error {
+        return Err(Error::ValidationError(format!(
+            "{} {:?} is not a valid regex at position {}: {}",
+            field, pattern, position.column, kind
+        )));
+    }
+    // Size limits are only enforced when compiling
+    regex::Regex::new(&format!("^(?:{})$", pattern)).map_err(|e| {
+        Error::ValidationError(format!(
+            "{} {:?} is not a valid regex: {}",
+            field, pattern, e
+        ))
+    })
+}
+
+/// Whether a value is a plain Kafka topic name rather than a pattern
+fn is_topic_name(value: &str) -> bool {
+    value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+}
+
+/// Whether a value is an http or https URL with a host
+fn is_http_url(value: &str) -> bool {
+    value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+        .is_some_and(|rest| {
+            let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+            !host.is_empty() && !value.contains(char::is_whitespace)
+        })
+}
+
+/// Whether a value is a lowercase RFC 1123 DNS label
+fn is_dns_label(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= 63
+        && value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !value.starts_with('-')
+        && !value.ends_with('-')
+}
+
+/// Whether a value is an IPv4 or IPv6 CIDR such as `10.0.0.0/8`
+fn is_cidr(value: &str) -> bool {
+    let Some((address, prefix)) = value.split_once('/') else {
+        return false;
+    };
+    let max_prefix = match address.parse::<std::net::IpAddr>() {
+        Ok(std::net::IpAddr::V4(_)) => 32,
+        Ok(std::net::IpAddr::V6(_)) => 128,
+        Err(_) => return false,
+    };
+    prefix.parse::<u8>().is_ok_and(|p| p <= max_prefix)
+}
+
+/// First value yielded more than once
+fn first_duplicate<'a>(values: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let values: Vec<_> = values.collect();
+    values
+        .iter()
+        .enumerate()
+        .find(|(i, v)| values[..*i].contains(v))
+        .map(|(_, v)| *v)
+}
+
+/// Reject renames or moves of the child resources
+///
+/// Changing `nameOverride`, `fullnameOverride` or `targetNamespace` would
+/// orphan every child resource under its old name or namespace.
+pub fn validate_name_change(
+    remapper: &KafkaPartitionRemapper,
+    previous: &KafkaPartitionRemapperSpec,
+) -> Result<()> {
+    let spec = &remapper.spec;
+    if spec.name_override != previous.name_override {
+        return Err(Error::ValidationError(
+            "nameOverride cannot be changed".to_string(),
+        ));
+    }
+    if spec.fullname_override != previous.fullname_override {
+        return Err(Error::ValidationError(
+            "fullnameOverride cannot be changed".to_string(),
+        ));
+    }
+    if spec.target_namespace != previous.target_namespace {
+        return Err(Error::ValidationError(
+            "targetNamespace cannot be changed".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject mapping changes that would corrupt offset translation
+///
+/// Changing `offsetRange` or reducing `virtualPartitions`, globally or for a
+/// topic override, breaks offsets already handed to clients. Such changes are
+/// only allowed with the `kafka.oso.sh/allow-breaking-change: "true"`
+/// annotation.
+pub fn validate_mapping_change(
+    remapper: &KafkaPartitionRemapper,
+    previous: &MappingSpec,
+) -> Result<()> {
+    if remapper.breaking_change_allowed() {
+        return Ok(());
+    }
+
+    let current = &remapper.spec.mapping;
+    let mut violations = Vec::new();
+
+    if current.offset_range != previous.offset_range {
+        violations.push(format!(
+            "mapping.offsetRange changed from {} to {}",
+            previous.offset_range, current.offset_range
+        ));
+    }
+    if current.virtual_partitions < previous.virtual_partitions {
+        violations.push(format!(
+            "mapping.virtualPartitions reduced from {} to {}",
+            previous.virtual_partitions, current.virtual_partitions
+        ));
+    }
+
+    for old in &previous.topics {
+        let Some(new) = current.topics.iter().find(|t| t.topic == old.topic) else {
+            continue;
+        };
+        let old_range = old.offset_range.unwrap_or(previous.offset_range);
+        let new_range = new.offset_range.unwrap_or(current.offset_range);
+        if new_range != old_range {
+            violations.push(format!(
+                "offsetRange for topic {} changed from {} to {}",
+                old.topic, old_range, new_range
+            ));
+        }
+        let old_vp = old
+            .virtual_partitions
+            .unwrap_or(previous.virtual_partitions);
+        let new_vp = new.virtual_partitions.unwrap_or(current.virtual_partitions);
+        if new_vp < old_vp {
+            violations.push(format!(
+                "virtualPartitions for topic {} reduced from {} to {}",
+                old.topic, old_vp, new_vp
+            ));
+        }
+    }
+
+    if violations.is_empty() {
+        return Ok(());
+    }
+    Err(Error::ValidationError(format!(
+        "{}; this corrupts offset translation for existing clients, set the {} annotation to \"true\" to force it",
+        violations.join(", "),
+        ALLOW_BREAKING_CHANGE_ANNOTATION
+    )))
+}
+
+/// Resolve `kafka.clusterRef` into inline connection settings
+///
+/// Returns the remapper unchanged when no reference is set. The resolved copy
+/// is what gets validated and rendered, so edits to the KafkaClusterRef flow
+/// into the config hash and roll the proxy pods.
+pub async fn resolve_cluster_ref(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+) -> Result<KafkaPartitionRemapper> {
+    let Some(ref cluster_ref) = remapper.spec.kafka.cluster_ref else {
+        return Ok(remapper.clone());
+    };
+
+    let cluster_refs: Api<KafkaClusterRef> = Api::all(client.clone());
+    let cluster = cluster_refs
+        .get_opt(cluster_ref)
+        .await
+        .map_err(|e| Error::kube("Failed to get KafkaClusterRef", e))?
+        .ok_or_else(|| Error::ConfigError(format!("KafkaClusterRef {} not found", cluster_ref)))?;
+
+    let mut resolved = remapper.clone();
+    cluster.spec.resolve_into(&mut resolved.spec.kafka);
+    Ok(resolved)
+}
+
+/// Append KafkaRemapperTopicOverride children to `mapping.topics`
+///
+/// Children are applied in name order after the inline overrides; a child
+/// naming a topic that is already overridden is ignored.
+pub async fn aggregate_topic_overrides(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<KafkaPartitionRemapper> {
+    let overrides: Api<KafkaRemapperTopicOverride> = Api::namespaced(client.clone(), namespace);
+    let children = overrides
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| Error::kube("Failed to list KafkaRemapperTopicOverrides", e))?;
+
+    let mut resolved = remapper.clone();
+    merge_topic_overrides(&mut resolved, children.items);
+    Ok(resolved)
+}
+
+/// Merge child overrides belonging to the remapper into its mapping
+pub fn merge_topic_overrides(
+    remapper: &mut KafkaPartitionRemapper,
+    mut children: Vec<KafkaRemapperTopicOverride>,
+) {
+    let name = remapper.name_any();
+    children.retain(|c| c.spec.remapper_ref == name);
+    children.sort_by_key(|c| c.name_any());
+
+    for child in children {
+        let topics = &mut remapper.spec.mapping.topics;
+        if topics.iter().any(|t| t.topic == child.spec.topic) {
+            warn!(
+                "Ignoring KafkaRemapperTopicOverride {}: topic {} is already overridden",
+                child.name_any(),
+                child.spec.topic
+            );
+            continue;
+        }
+        topics.push(child.spec.to_topic_override());
+    }
+}
+
+/// Reconcile the ConfigMap for proxy configuration
+pub async fn reconcile_config_map(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<String> {
+    let config_map_name = config_map_name(&remapper.resource_name());
+    let config_map = build_config_map(remapper, namespace)?;
+
+    let config_maps: Api<ConfigMap> = Api::namespaced(client.clone(), namespace);
+    let patch_params = apply_params();
+
+    config_maps
+        .patch(&config_map_name, &patch_params, &Patch::Apply(&config_map))
+        .await
+        .map_err(|e| apply_error("ConfigMap", e))?;
+
+    info!("Reconciled ConfigMap {}/{}", namespace, config_map_name);
+
+    Ok(config_map_name)
+}
+
+/// Render the core child resources of a remapper without a cluster
+///
+/// Returns YAML documents for the ConfigMap, the workload and its Services,
+/// as the reconciler would apply them. Nothing is read from the cluster, so
+/// `clusterRef` and topic override resources are not resolved and pods carry
+/// no secrets hash.
+pub fn render_manifests(remapper: &KafkaPartitionRemapper) -> Result<Vec<String>> {
+    validate(remapper)?;
+
+    let name = remapper.resource_name();
+    let namespace = remapper.target_namespace();
+    let config_map_name = config_map_name(&name);
+    let config_hash = calculate_config_hash(remapper);
+
+    let to_yaml = |value: serde_yaml::Result<String>| {
+        value.map_err(|e| Error::ConfigError(format!("Failed to render manifest: {}", e)))
+    };
+
+    let mut manifests = vec![to_yaml(serde_yaml::to_string(&build_config_map(
+        remapper, &namespace,
+    )?))?];
+    match remapper.spec.workload {
+        WorkloadKind::Deployment => {
+            let deployment = deployment_builder::build_deployment(
+                remapper,
+                &config_map_name,
+                &config_hash,
+                None,
+            );
+            manifests.push(to_yaml(serde_yaml::to_string(&deployment))?);
+        }
+        WorkloadKind::StatefulSet => {
+            let headless_service = service_builder::build_headless_service(remapper);
+            manifests.push(to_yaml(serde_yaml::to_string(&headless_service))?);
+            let stateful_set = stateful_set_builder::build_stateful_set(
+                remapper,
+                &config_map_name,
+                &config_hash,
+                None,
+            );
+            manifests.push(to_yaml(serde_yaml::to_string(&stateful_set))?);
+        }
+    }
+    manifests.push(to_yaml(serde_yaml::to_string(
+        &service_builder::build_service(remapper),
+    ))?);
+    if let Some(external) = service_builder::build_external_service(remapper) {
+        manifests.push(to_yaml(serde_yaml::to_string(&external))?);
+    }
+    Ok(manifests)
+}
+
+/// Build the ConfigMap holding the rendered proxy configuration
+pub fn build_config_map(remapper: &KafkaPartitionRemapper, namespace: &str) -> Result<ConfigMap> {
+    let name = remapper.resource_name();
+    let config_map_name = config_map_name(&name);
+
+    // Each listener advertises its own address, defaulting to the Service
+    // or its ExternalDNS hostname
+    let listeners = &remapper.spec.listeners;
+    let advertised_addresses: Vec<String> = listeners
+        .iter()
+        .map(|listener| {
+            listener.advertised_address.clone().unwrap_or_else(|| {
+                service_builder::default_advertised_address(
+                    &name,
+                    namespace,
+                    &remapper.spec,
+                    listener.port,
+                )
+            })
+        })
+        .collect();
+
+    // Build the proxy configuration YAML
+    let config_yaml = remapper_config::build_proxy_config(&remapper.spec, &advertised_addresses)?;
+
+    // Create ConfigMap
+    let mut data = BTreeMap::new();
+    data.insert("config.yaml".to_string(), config_yaml);
+
+    // Sharded pods advertise their shard's routing Service and only serve
+    // their own partition range
+    let shards = sharding::shard_ranges_for(&remapper.spec);
+    for shard in &shards {
+        let shard_service = sharding::shard_service_name(&name, shard.index);
+        let shard_addresses: Vec<String> = listeners
+            .iter()
+            .map(|l| service_builder::cluster_dns_address(&shard_service, namespace, l.port))
+            .collect();
+        let shard_config =
+            remapper_config::build_shard_proxy_config(&remapper.spec, &shard_addresses, shard)?;
+        data.insert(format!("{}-{}.yaml", name, shard.index), shard_config);
+    }
+
+    // Other StatefulSet pods each advertise their own stable DNS name, so
+    // render one config per possible ordinal alongside the shared one
+    if remapper.spec.workload == WorkloadKind::StatefulSet && shards.is_empty() {
+        for ordinal in 0..remapper.spec.max_replica_count() {
+            let pod_addresses: Vec<String> = listeners
+                .iter()
+                .map(|l| service_builder::pod_dns_address(&name, ordinal, namespace, l.port))
+                .collect();
+            let pod_config = remapper_config::build_proxy_config(&remapper.spec, &pod_addresses)?;
+            data.insert(format!("{}-{}.yaml", name, ordinal), pod_config);
+        }
+    }
+
+    let config_map = ConfigMap {
+        metadata: ObjectMeta {
+            name: Some(config_map_name),
+            namespace: Some(namespace.to_string()),
+            labels: Some(deployment_builder::resource_labels(remapper)),
+            annotations: deployment_builder::resource_annotations(remapper, &BTreeMap::new()),
+            owner_references: deployment_builder::owner_references(remapper),
+            ..Default::default()
+        },
+        data: Some(data),
+        ..Default::default()
+    };
+
+    Ok(config_map)
+}
+
+/// Reconcile the workload running the proxy pods
+///
+/// Applies a Deployment or StatefulSet depending on `spec.workload` and
+/// removes the resources belonging to the other mode.
+pub async fn reconcile_workload(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+    config_map_name: &str,
+) -> Result<String> {
+    let name = remapper.resource_name();
+
+    match remapper.spec.workload {
+        WorkloadKind::Deployment => {
+            let stateful_sets: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+            delete_owned_child(remapper, &stateful_sets, &name, namespace, "StatefulSet").await?;
+
+            let services: Api<Service> = Api::namespaced(client.clone(), namespace);
+            let headless_name = service_builder::headless_service_name(&name);
+            delete_owned_child(remapper, &services, &headless_name, namespace, "Service").await?;
+
+            let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+            restore_replicas(remapper, &deployments, &name, namespace, "Deployment").await?;
+
+            reconcile_deployment(remapper, client, namespace, config_map_name).await
+        }
+        WorkloadKind::StatefulSet => {
+            let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+            delete_owned_child(remapper, &deployments, &name, namespace, "Deployment").await?;
+
+            let stateful_sets: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+            restore_replicas(remapper, &stateful_sets, &name, namespace, "StatefulSet").await?;
+
+            reconcile_stateful_set(remapper, client, namespace, config_map_name).await
+        }
+    }
+}
+
+/// Reconcile the Deployment for proxy pods
+pub async fn reconcile_deployment(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+    config_map_name: &str,
+) -> Result<String> {
+    let name = remapper.resource_name();
+
+    // Calculate config and secret hashes for rolling updates
+    let config_hash = calculate_config_hash(remapper);
+    let secrets_hash = calculate_secrets_hash(remapper, client, namespace).await?;
+
+    // Build Deployment
+    let mut deployment = deployment_builder::build_deployment(
+        remapper,
+        config_map_name,
+        &config_hash,
+        secrets_hash.as_deref(),
+    );
+
+    let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let existing = deployments
+        .get_opt(&name)
+        .await
+        .map_err(|e| Error::kube("Failed to get Deployment", e))?;
+    if let (Some(existing), Some(spec)) = (existing, deployment.spec.as_mut()) {
+        let current = existing.spec.and_then(|s| s.replicas);
+        spec.replicas = spec
+            .replicas
+            .or_else(|| handover_replicas(remapper, &existing.metadata, current));
+    }
+    let patch_params = child_patch_params(remapper, &deployments, &name, "Deployment").await?;
+
+    deployments
+        .patch(&name, &patch_params, &Patch::Apply(&deployment))
+        .await
+        .map_err(|e| apply_error("Deployment", e))?;
+
+    info!("Reconciled Deployment {}/{}", namespace, name);
+
+    Ok(name)
+}
+
+/// Reconcile the StatefulSet and its governing headless Service
+pub async fn reconcile_stateful_set(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+    config_map_name: &str,
+) -> Result<String> {
+    let name = remapper.resource_name();
+    let patch_params = apply_params();
+
+    // The headless Service must exist for pod DNS records to resolve
+    let headless_service = service_builder::build_headless_service(remapper);
+    let headless_name = service_builder::headless_service_name(&name);
+    let services: Api<Service> = Api::namespaced(client.clone(), namespace);
+
+    services
+        .patch(
+            &headless_name,
+            &patch_params,
+            &Patch::Apply(&headless_service),
+        )
+        .await
+        .map_err(|e| apply_error("headless Service", e))?;
+
+    info!(
+        "Reconciled headless Service {}/{}",
+        namespace, headless_name
+    );
+
+    // Calculate config and secret hashes for rolling updates
+    let config_hash = calculate_config_hash(remapper);
+    let secrets_hash = calculate_secrets_hash(remapper, client, namespace).await?;
+
+    let mut stateful_set = stateful_set_builder::build_stateful_set(
+        remapper,
+        config_map_name,
+        &config_hash,
+        secrets_hash.as_deref(),
+    );
+
+    let stateful_sets: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+    let existing = stateful_sets
+        .get_opt(&name)
+        .await
+        .map_err(|e| Error::kube("Failed to get StatefulSet", e))?;
+    if let (Some(existing), Some(spec)) = (existing, stateful_set.spec.as_mut()) {
+        let current = existing.spec.and_then(|s| s.replicas);
+        spec.replicas = spec
+            .replicas
+            .or_else(|| handover_replicas(remapper, &existing.metadata, current));
+    }
+    let patch_params = child_patch_params(remapper, &stateful_sets, &name, "StatefulSet").await?;
+
+    stateful_sets
+        .patch(&name, &patch_params, &Patch::Apply(&stateful_set))
+        .await
+        .map_err(|e| apply_error("StatefulSet", e))?;
+
+    info!("Reconciled StatefulSet {}/{}", namespace, name);
+
+    Ok(name)
+}
+
+/// Roll a spec change out to canary replicas before the stable Deployment
+///
+/// Returns `None` when no canary is needed because the strategy is not
+/// Canary, the stable Deployment does not exist yet or already runs the
+/// current spec. Otherwise the canary is applied and judged; until its status
+/// reaches `Passed` the stable ConfigMap and Deployment must be left alone.
+/// Failed and passed canaries are removed.
+pub async fn reconcile_canary(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<Option<CanaryStatus>> {
+    let name = remapper.resource_name();
+    let canary_name = canary::canary_name(&name);
+    let config_hash = calculate_config_hash(remapper);
+    let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+
+    let stable_hash = if remapper.spec.canary_enabled() && !remapper.spec.suspend {
+        deployments
+            .get_opt(&name)
+            .await
+            .map_err(|e| Error::kube("Failed to get Deployment", e))?
+            .and_then(|d| d.spec)
+            .and_then(|s| s.template.metadata)
+            .and_then(|m| m.annotations)
+            .and_then(|a| a.get("checksum/config").cloned())
+    } else {
+        None
+    };
+    if stable_hash.is_none_or(|h| h == config_hash) {
+        remove_canary(remapper, client, namespace, &canary_name).await?;
+        return Ok(None);
+    }
+
+    let previous = remapper.status.as_ref().and_then(|s| s.canary.as_ref());
+    if let Some(failed) = previous.filter(|p| p.config_hash == config_hash && p.state == "Failed") {
+        return Ok(Some(failed.clone()));
+    }
+
+    let patch_params = apply_params();
+    let mut config_map = build_config_map(remapper, namespace)?;
+    let config_map_name = config_map_name(&canary_name);
+    config_map.metadata.name = Some(config_map_name.clone());
+    Api::<ConfigMap>::namespaced(client.clone(), namespace)
+        .patch(&config_map_name, &patch_params, &Patch::Apply(&config_map))
+        .await
+        .map_err(|e| apply_error("ConfigMap", e))?;
+
+    let secrets_hash = calculate_secrets_hash(remapper, client, namespace).await?;
+    let deployment = canary::build_canary_deployment(
+        remapper,
+        &config_map_name,
+        &config_hash,
+        secrets_hash.as_deref(),
+    );
+    let deployment = deployments
+        .patch(&canary_name, &patch_params, &Patch::Apply(&deployment))
+        .await
+        .map_err(|e| apply_error("Deployment", e))?;
+
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let restarts: i32 = pods
+        .list(&ListParams::default().labels(&canary::canary_label_selector(&name)))
+        .await
+        .map_err(|e| Error::kube("Failed to list canary pods", e))?
+        .into_iter()
+        .filter_map(|p| p.status.and_then(|s| s.container_statuses))
+        .flatten()
+        .map(|c| c.restart_count)
+        .sum();
+
+    let status = canary::evaluate(
+        previous,
+        &config_hash,
+        &deployment,
+        restarts,
+        canary::bake_time(remapper),
+        Utc::now(),
+    );
+    info!("Canary {}/{} is {}", namespace, canary_name, status.state);
+
+    if status.state == "Failed" || status.state == "Passed" {
+        remove_canary(remapper, client, namespace, &canary_name).await?;
+    }
+    Ok(Some(status))
+}
+
+/// Remove the canary Deployment and ConfigMap this remapper created
+async fn remove_canary(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+    canary_name: &str,
+) -> Result<()> {
+    let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    delete_owned_child(remapper, &deployments, canary_name, namespace, "Deployment").await?;
+
+    let config_maps: Api<ConfigMap> = Api::namespaced(client.clone(), namespace);
+    let config_map_name = config_map_name(canary_name);
+    delete_owned_child(
+        remapper,
+        &config_maps,
+        &config_map_name,
+        namespace,
+        "ConfigMap",
+    )
+    .await
+}
+
+/// Push hot-reloadable config changes to running proxy pods
+///
+/// Only ready pods already running the current template are signalled; pods
+/// still being rolled start with the new config anyway. Each pod records the
+/// config it reloaded in an annotation so it is signalled once per change.
+/// Returns the number of pods that could not be reloaded yet.
+pub async fn reload_config(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<usize> {
+    let spec = &remapper.spec;
+    if spec.reload_policy != ReloadPolicy::Hot || spec.suspend {
+        return Ok(0);
+    }
+
+    let name = remapper.resource_name();
+    let config_map = build_config_map(remapper, namespace)?;
+    let data = config_map.data.unwrap_or_default();
+    let config_hash = calculate_config_hash(remapper);
+    let reload_hash = calculate_reload_hash(remapper);
+
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let selector = format!(
+        "{},!{}",
+        deployment_builder::build_label_selector(&name),
+        canary::CANARY_LABEL
+    );
+    let candidates = pods
+        .list(&ListParams::default().labels(&selector))
+        .await
+        .map_err(|e| Error::kube("Failed to list proxy pods", e))?;
+
+    let mut pending = 0;
+    for pod in candidates {
+        let annotations = pod.annotations();
+        if annotations.get("checksum/config") != Some(&config_hash)
+            || annotations.get(proxy_reload::RELOADED_CONFIG_ANNOTATION) == Some(&reload_hash)
+        {
+            continue;
+        }
+
+        let pod_name = pod.name_any();
+        let ready = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.conditions.as_ref())
+            .is_some_and(|c| c.iter().any(|c| c.type_ == "Ready" && c.status == "True"));
+        let pod_ip = pod.status.as_ref().and_then(|s| s.pod_ip.clone());
+        let config = data.get(&proxy_reload::pod_config_key(spec, &pod_name));
+        let (true, Some(pod_ip), Some(config)) = (ready, pod_ip, config) else {
+            pending += 1;
+            continue;
+        };
+
+        let address = format!("{}:{}", pod_ip, spec.metrics.port);
+        if let Err(e) = proxy_reload::reload(&address, config).await {
+            warn!("Hot reload of pod {}/{} failed: {}", namespace, pod_name, e);
+            pending += 1;
+            continue;
+        }
+
+        let patch = serde_json::json!({
+            "metadata": {
+                "annotations": { proxy_reload::RELOADED_CONFIG_ANNOTATION: reload_hash }
+            }
+        });
+        pods.patch(&pod_name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+            .map_err(|e| Error::kube("Failed to annotate pod", e))?;
+        info!("Hot reloaded config of pod {}/{}", namespace, pod_name);
+    }
+
+    Ok(pending)
+}
+
+/// Reconcile the Service for proxy access
+pub async fn reconcile_service(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<String> {
+    let name = remapper.resource_name();
+
+    // Build Service
+    let service = service_builder::build_service(remapper);
+
+    let services: Api<Service> = Api::namespaced(client.clone(), namespace);
+    let patch_params = child_patch_params(remapper, &services, &name, "Service").await?;
+
+    services
+        .patch(&name, &patch_params, &Patch::Apply(&service))
+        .await
+        .map_err(|e| apply_error("Service", e))?;
+
+    info!("Reconciled Service {}/{}", namespace, name);
+
+    Ok(name)
+}
+
+/// Reconcile the external Service
+///
+/// Deletes the external Service this remapper created when it is disabled or
+/// removed from the spec.
+pub async fn reconcile_external_service(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<Option<String>> {
+    let name = service_builder::external_service_name(&remapper.resource_name());
+    let services: Api<Service> = Api::namespaced(client.clone(), namespace);
+
+    let Some(service) = service_builder::build_external_service(remapper) else {
+        delete_owned_child(remapper, &services, &name, namespace, "Service").await?;
+        return Ok(None);
+    };
+
+    let patch_params = apply_params();
+
+    services
+        .patch(&name, &patch_params, &Patch::Apply(&service))
+        .await
+        .map_err(|e| apply_error("external Service", e))?;
+
+    info!("Reconciled external Service {}/{}", namespace, name);
+
+    Ok(Some(name))
+}
+
+/// Reconcile the dedicated metrics Service
+///
+/// Deletes the metrics Service this remapper created when it is disabled or
+/// removed from the spec.
+pub async fn reconcile_metrics_service(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<Option<String>> {
+    let name = service_builder::metrics_service_name(&remapper.resource_name());
+    let services: Api<Service> = Api::namespaced(client.clone(), namespace);
+
+    let Some(service) = service_builder::build_metrics_service(remapper) else {
+        delete_owned_child(remapper, &services, &name, namespace, "Service").await?;
+        return Ok(None);
+    };
+
+    let patch_params = apply_params();
+
+    services
+        .patch(&name, &patch_params, &Patch::Apply(&service))
+        .await
+        .map_err(|e| apply_error("metrics Service", e))?;
+
+    info!("Reconciled metrics Service {}/{}", namespace, name);
+
+    Ok(Some(name))
+}
+
+/// Reconcile the Gateway API TCPRoute
+///
+/// Deletes the route this remapper created when the Gateway integration is
+/// disabled; a missing TCPRoute CRD then reads as nothing to delete. Fails
+/// with a clear message when the route is requested but the CRD is absent.
+pub async fn reconcile_tcp_route(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<Option<String>> {
+    let name = remapper.resource_name();
+    let routes: Api<DynamicObject> = Api::namespaced_with(
+        client.clone(),
+        namespace,
+        &tcp_route_builder::tcp_route_resource(),
+    );
+
+    let Some(route) = tcp_route_builder::build_tcp_route(remapper) else {
+        delete_owned_child(remapper, &routes, &name, namespace, "TCPRoute").await?;
+        return Ok(None);
+    };
+
+    let patch_params = apply_params();
+
+    routes
+        .patch(&name, &patch_params, &Patch::Apply(&route))
+        .await
+        .map_err(|e| match e {
+            kube::Error::Api(ae) if ae.code == 404 => Error::ConfigError(
+                "gateway requires the Gateway API TCPRoute CRD to be installed".to_string(),
+            ),
+            e => apply_error("TCPRoute", e),
+        })?;
+
+    info!("Reconciled TCPRoute {}/{}", namespace, name);
+
+    Ok(Some(name))
+}
+
+/// Reconcile the per-shard routing Services
+///
+/// Services for shards that no longer exist are deleted, so scaling down or
+/// disabling sharding leaves no stale endpoints behind.
+pub async fn reconcile_shard_services(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<Vec<String>> {
+    let name = remapper.resource_name();
+    let services: Api<Service> = Api::namespaced(client.clone(), namespace);
+    let patch_params = apply_params();
+
+    let mut desired = Vec::new();
+    for shard in sharding::shard_ranges_for(&remapper.spec) {
+        let service = service_builder::build_shard_service(remapper, shard.index);
+        let service_name = sharding::shard_service_name(&name, shard.index);
+
+        services
+            .patch(&service_name, &patch_params, &Patch::Apply(&service))
+            .await
+            .map_err(|e| apply_error("shard Service", e))?;
+
+        desired.push(service_name);
+    }
+
+    let selector = format!(
+        "app.kubernetes.io/instance={},{}",
+        name,
+        sharding::SHARD_LABEL
+    );
+    let existing = services
+        .list(&ListParams::default().labels(&selector))
+        .await
+        .map_err(|e| Error::kube("Failed to list shard Services", e))?;
+
+    for service in existing {
+        if !desired.contains(&service.name_any()) && is_owned_by(remapper, &service.metadata) {
+            delete_child(&services, &service.metadata, namespace, "Service").await?;
+        }
+    }
+
+    if !desired.is_empty() {
+        info!(
+            "Reconciled {} shard Services for {}/{}",
+            desired.len(),
+            namespace,
+            name
+        );
+    }
+
+    Ok(desired)
+}
+
+/// Reconcile the PodDisruptionBudget for proxy pods
+///
+/// Deletes a budget this remapper created once it is no longer configured.
+pub async fn reconcile_pod_disruption_budget(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<Option<String>> {
+    let name = remapper.resource_name();
+    let pdbs: Api<PodDisruptionBudget> = Api::namespaced(client.clone(), namespace);
+
+    let Some(pdb) = pod_disruption_budget_builder::build_pod_disruption_budget(remapper) else {
+        delete_owned_child(remapper, &pdbs, &name, namespace, "PodDisruptionBudget").await?;
+        return Ok(None);
+    };
+
+    let patch_params = apply_params();
+
+    pdbs.patch(&name, &patch_params, &Patch::Apply(&pdb))
+        .await
+        .map_err(|e| apply_error("PodDisruptionBudget", e))?;
+
+    info!("Reconciled PodDisruptionBudget {}/{}", namespace, name);
+
+    Ok(Some(name))
+}
+
+/// Reconcile the Secrets holding generated user credentials
+///
+/// Passwords already stored in a user's Secret are kept until their rotation
+/// is due, so only new users get fresh credentials. Secrets of users removed
+/// from the spec are deleted. Returns the users whose password was rotated.
+pub async fn reconcile_users(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<Vec<String>> {
+    let name = remapper.resource_name();
+    let secrets_api: Api<Secret> = Api::namespaced(client.clone(), namespace);
+    let patch_params = apply_params();
+    let now = Utc::now();
+
+    let mut passwords = BTreeMap::new();
+    let mut previous_passwords = BTreeMap::new();
+    let mut rotated = Vec::new();
+    for user in &remapper.spec.users {
+        let secret_name = users::user_secret_name(&name, &user.name);
+        let existing = secrets::get_secret_opt(client, namespace, &secret_name).await?;
+        check_ownership(remapper, existing.as_ref().map(|s| &s.metadata), "Secret")?;
+
+        let (credentials, was_rotated) = users::next_credentials(
+            existing
+                .as_ref()
+                .and_then(users::UserCredentials::from_secret),
+            user.rotation.as_ref(),
+            now,
+        )?;
+        let secret = users::build_user_secret(remapper, user, &credentials);
+        secrets_api
+            .patch(&secret_name, &patch_params, &Patch::Apply(&secret))
+            .await
+            .map_err(|e| apply_error("Secret", e))?;
+        secrets::invalidate(namespace, &secret_name);
+
+        if was_rotated {
+            info!(
+                "Rotated password of user {} in {}/{}",
+                user.name, namespace, name
+            );
+            rotated.push(user.name.clone());
+        }
+        if let Some(previous) = credentials.previous_password {
+            previous_passwords.insert(user.name.clone(), previous);
+        }
+        passwords.insert(user.name.clone(), credentials.password);
+    }
+
+    // Remove Secrets of users no longer listed
+    let uid = remapper.metadata.uid.clone().unwrap_or_default();
+    let selector = format!(
+        "{},{}",
+        deployment_builder::build_label_selector(&name),
+        users::USER_LABEL
+    );
+    let user_secrets = secrets_api
+        .list(&ListParams::default().labels(&selector))
+        .await
+        .map_err(|e| Error::kube("Failed to list Secrets", e))?;
+    for secret in user_secrets {
+        let owned = secret.owner_references().iter().any(|o| o.uid == uid);
+        let listed = secret
+            .labels()
+            .get(users::USER_LABEL)
+            .is_some_and(|u| passwords.contains_key(u));
+        if owned && !listed {
+            delete_child(&secrets_api, &secret.metadata, namespace, "Secret").await?;
+            secrets::invalidate(namespace, &secret.name_any());
+        }
+    }
+
+    // The proxy reads current passwords, and with rotation the passwords
+    // still in their overlap window
+    let proxy_secrets = [
+        (
+            users::users_secret_name(remapper),
+            !passwords.is_empty(),
+            passwords,
+        ),
+        (
+            users::previous_users_secret_name(remapper),
+            remapper.spec.user_rotation_enabled(),
+            previous_passwords,
+        ),
+    ];
+    for (secret_name, wanted, passwords) in proxy_secrets {
+        secrets::invalidate(namespace, &secret_name);
+        if !wanted {
+            delete_owned_child(remapper, &secrets_api, &secret_name, namespace, "Secret").await?;
+            continue;
+        }
+        let secret = users::build_users_secret(remapper, &secret_name, &passwords);
+        secrets_api
+            .patch(&secret_name, &patch_params, &Patch::Apply(&secret))
+            .await
+            .map_err(|e| apply_error("Secret", e))?;
+    }
+
+    if !remapper.spec.users.is_empty() {
+        info!(
+            "Reconciled {} generated users for {}/{}",
+            remapper.spec.users.len(),
+            namespace,
+            name
+        );
+    }
+
+    Ok(rotated)
+}
+
+/// Read back the credential state of the generated users
+///
+/// Unreadable Secrets are skipped; they are recreated by the next reconcile.
+pub async fn user_statuses(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Vec<UserStatus> {
+    let name = remapper.resource_name();
+    let mut statuses = Vec::new();
+    for user in &remapper.spec.users {
+        let secret_name = users::user_secret_name(&name, &user.name);
+        match secrets::get_secret_opt(client, namespace, &secret_name).await {
+            Ok(secret) => {
+                if let Some(credentials) = secret
+                    .as_ref()
+                    .and_then(users::UserCredentials::from_secret)
+                {
+                    statuses.push(users::user_status(remapper, user, &credentials));
+                }
+            }
+            Err(e) => warn!("Failed to read user secret {}: {}", secret_name, e),
+        }
+    }
+    statuses
+}
+
+/// Reconcile the HorizontalPodAutoscaler for the proxy workload
+///
+/// Deletes the autoscaler this remapper created when autoscaling is disabled
+/// or the remapper is suspended.
+pub async fn reconcile_horizontal_pod_autoscaler(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<Option<String>> {
+    let name = remapper.resource_name();
+    let hpas: Api<HorizontalPodAutoscaler> = Api::namespaced(client.clone(), namespace);
+
+    let Some(hpa) = horizontal_pod_autoscaler_builder::build_horizontal_pod_autoscaler(remapper)
+    else {
+        delete_owned_child(remapper, &hpas, &name, namespace, "HorizontalPodAutoscaler").await?;
+        return Ok(None);
+    };
+
+    let patch_params = apply_params();
+
+    hpas.patch(&name, &patch_params, &Patch::Apply(&hpa))
+        .await
+        .map_err(|e| apply_error("HorizontalPodAutoscaler", e))?;
+
+    info!("Reconciled HorizontalPodAutoscaler {}/{}", namespace, name);
+
+    Ok(Some(name))
+}
+
+/// Reconcile the KEDA ScaledObject for the proxy workload
+///
+/// Deletes the ScaledObject this remapper created when KEDA autoscaling is
+/// disabled; a missing ScaledObject CRD then reads as nothing to delete.
+/// Fails with a clear message when KEDA is requested but not installed.
+pub async fn reconcile_keda_scaled_object(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<Option<String>> {
+    let name = remapper.resource_name();
+    let scaled_objects: Api<DynamicObject> = Api::namespaced_with(
+        client.clone(),
+        namespace,
+        &keda_scaled_object_builder::scaled_object_resource(),
+    );
+
+    let Some(scaled_object) = keda_scaled_object_builder::build_scaled_object(remapper) else {
+        delete_owned_child(remapper, &scaled_objects, &name, namespace, "ScaledObject").await?;
+        return Ok(None);
+    };
+
+    let patch_params = apply_params();
+
+    scaled_objects
+        .patch(&name, &patch_params, &Patch::Apply(&scaled_object))
+        .await
+        .map_err(|e| match e {
+            kube::Error::Api(ae) if ae.code == 404 => Error::ConfigError(
+                "autoscaling.keda requires the KEDA ScaledObject CRD to be installed".to_string(),
+            ),
+            e => apply_error("ScaledObject", e),
+        })?;
+
+    info!("Reconciled KEDA ScaledObject {}/{}", namespace, name);
+
+    Ok(Some(name))
+}
+
+/// Reconcile the consistency audit CronJob and the access it runs with
+///
+/// Deletes the CronJob, ServiceAccount, Roles and RoleBindings this remapper
+/// created when the audit is no longer configured.
+pub async fn reconcile_consistency_audit(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<Option<String>> {
+    let name = consistency_audit_builder::audit_name(&remapper.resource_name());
+    let cron_jobs: Api<CronJob> = Api::namespaced(client.clone(), namespace);
+    let service_accounts: Api<ServiceAccount> = Api::namespaced(client.clone(), namespace);
+
+    let Some(cron_job) = consistency_audit_builder::build_audit_cron_job(remapper)? else {
+        delete_owned_child(remapper, &cron_jobs, &name, namespace, "CronJob").await?;
+        delete_owned_child(
+            remapper,
+            &service_accounts,
+            &name,
+            namespace,
+            "ServiceAccount",
+        )
+        .await?;
+        let mut namespaces = vec![namespace.to_string()];
+        namespaces.extend(remapper.namespace().filter(|ns| ns != namespace));
+        for ns in &namespaces {
+            let role_bindings: Api<RoleBinding> = Api::namespaced(client.clone(), ns);
+            delete_owned_child(remapper, &role_bindings, &name, ns, "RoleBinding").await?;
+            let roles: Api<Role> = Api::namespaced(client.clone(), ns);
+            delete_owned_child(remapper, &roles, &name, ns, "Role").await?;
+        }
+        return Ok(None);
+    };
+
+    let patch_params = apply_params();
+
+    service_accounts
+        .patch(
+            &name,
+            &patch_params,
+            &Patch::Apply(&consistency_audit_builder::build_audit_service_account(
+                remapper,
+            )),
+        )
+        .await
+        .map_err(|e| apply_error("ServiceAccount", e))?;
+    for role in consistency_audit_builder::build_audit_roles(remapper) {
+        let roles: Api<Role> =
+            Api::namespaced(client.clone(), &role.namespace().unwrap_or_default());
+        roles
+            .patch(&name, &patch_params, &Patch::Apply(&role))
+            .await
+            .map_err(|e| apply_error("Role", e))?;
+    }
+    for binding in consistency_audit_builder::build_audit_role_bindings(remapper) {
+        let role_bindings: Api<RoleBinding> =
+            Api::namespaced(client.clone(), &binding.namespace().unwrap_or_default());
+        role_bindings
+            .patch(&name, &patch_params, &Patch::Apply(&binding))
+            .await
+            .map_err(|e| apply_error("RoleBinding", e))?;
+    }
+    cron_jobs
+        .patch(&name, &patch_params, &Patch::Apply(&cron_job))
+        .await
+        .map_err(|e| apply_error("CronJob", e))?;
+
+    info!("Reconciled audit CronJob {}/{}", namespace, name);
+
+    Ok(Some(name))
+}
+
+/// Outcome of probing the Kafka cluster
+#[derive(Debug)]
+pub enum KafkaProbe {
+    /// A broker answered the metadata request
+    Reachable(Vec<kafka_admin::TopicMetadata>),
+    /// No bootstrap server could be reached
+    Unreachable(String),
+    /// The operator cannot authenticate with the configured mechanism
+    Unsupported(String),
+}
+
+/// Probe the bootstrap servers with a metadata request
+pub async fn probe_kafka(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> KafkaProbe {
+    let probe = probe_kafka_cluster(&remapper.spec.kafka, client, namespace).await;
+    if let KafkaProbe::Unreachable(ref e) = probe {
+        warn!(
+            "Kafka probe for {}/{} failed: {}",
+            namespace,
+            remapper.name_any(),
+            e
+        );
+    }
+    probe
+}
+
+/// Probe a cluster's bootstrap servers, reading credentials from Secrets in
+/// `namespace`
+pub async fn probe_kafka_cluster(
+    kafka: &KafkaClusterSpec,
+    client: &Client,
+    namespace: &str,
+) -> KafkaProbe {
+    if kafka.kerberos.is_some() && kafka.security_protocol.uses_sasl() {
+        return KafkaProbe::Unsupported(
+            "SASL mechanism GSSAPI is not supported by the operator's probe".to_string(),
+        );
+    }
+    let from_secret_store = kafka
+        .tls_secret
+        .as_ref()
+        .is_some_and(|t| t.secret_provider_class.is_some())
+        || kafka
+            .sasl_secret
+            .as_ref()
+            .is_some_and(|s| s.secret_provider_class.is_some());
+    if from_secret_store {
+        return KafkaProbe::Unsupported(
+            "credentials from a SecretProviderClass are not readable by the operator's probe"
+                .to_string(),
+        );
+    }
+    if let Some(ref sasl) = kafka.sasl_secret {
+        if kafka.security_protocol.uses_sasl() && sasl.mechanism != SaslMechanism::Plain {
+            return KafkaProbe::Unsupported(format!(
+                "SASL mechanism {} is not supported by the operator's probe",
+                sasl.mechanism.as_str()
+            ));
+        }
+    }
+
+    let topics = match kafka_admin::load_connection(client, namespace, kafka).await {
+        Ok(connection) => kafka_admin::fetch_topic_metadata(&connection).await,
+        Err(e) => Err(e),
+    };
+
+    match topics {
+        Ok(topics) => KafkaProbe::Reachable(topics),
+        Err(e) => KafkaProbe::Unreachable(e.to_string()),
+    }
+}
+
+/// Build the `KafkaReachable` condition from a probe result
+pub fn kafka_reachable_condition(probe: &KafkaProbe) -> Condition {
+    let (status, reason, message) = match probe {
+        KafkaProbe::Reachable(_) => (
+            "True",
+            "BrokerResponded",
+            "Bootstrap servers answered a metadata request".to_string(),
+        ),
+        KafkaProbe::Unreachable(e) => ("False", "ConnectionFailed", e.clone()),
+        KafkaProbe::Unsupported(e) => ("Unknown", "ProbeUnsupported", e.clone()),
+    };
+
+    Condition {
+        type_: "KafkaReachable".to_string(),
+        status: status.to_string(),
+        last_transition_time: Utc::now(),
+        observed_generation: None,
+        reason: Some(reason.to_string()),
+        message: Some(message),
+    }
+}
+
+/// Describe referenced Secrets that are missing or lack required keys
+///
+/// `fetched` holds every Secret named in `required`, `None` when absent.
+pub fn secret_problems(
+    required: &BTreeMap<String, Vec<String>>,
+    fetched: &BTreeMap<String, Option<Secret>>,
+) -> Vec<String> {
+    required
+        .iter()
+        .filter_map(
+            |(name, keys)| match fetched.get(name).and_then(Option::as_ref) {
+                None => Some(format!("Secret {} not found", name)),
+                Some(secret) => {
+                    let missing = secrets::missing_keys(secret, keys);
+                    (!missing.is_empty())
+                        .then(|| format!("Secret {} is missing keys: {}", name, missing.join(", ")))
+                }
+            },
+        )
+        .collect()
+}
+
+/// Build the `SecretsValid` condition from the problems found
+pub fn secrets_valid_condition(problems: &[String]) -> Condition {
+    let (status, reason, message) = if problems.is_empty() {
+        (
+            "True",
+            "AllKeysPresent",
+            "Referenced Secrets contain the required keys".to_string(),
+        )
+    } else {
+        ("False", "SecretsIncomplete", problems.join("; "))
+    };
+
+    Condition {
+        type_: "SecretsValid".to_string(),
+        status: status.to_string(),
+        last_transition_time: Utc::now(),
+        observed_generation: None,
+        reason: Some(reason.to_string()),
+        message: Some(message),
+    }
+}
+
+/// Check that referenced Secrets exist and hold the keys the proxy mounts
+pub async fn check_secrets(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<Condition> {
+    let required = remapper.spec.required_secret_keys();
+    let mut fetched = BTreeMap::new();
+    for name in required.keys() {
+        let secret = secrets::get_secret_opt(client, namespace, name).await?;
+        fetched.insert(name.clone(), secret);
+    }
+    Ok(secrets_valid_condition(&secret_problems(
+        &required, &fetched,
+    )))
+}
+
+/// Prefix a mapping error with the field it was raised for
+fn mapping_error(field: &str, err: Error) -> Error {
+    match err {
+        Error::ValidationError(msg) => Error::ValidationError(format!("{}: {}", field, msg)),
+        e => e,
+    }
+}
+
+/// Offsets available to each virtual partition once `offset_range` is
+/// shared by the virtual partitions compressed onto one physical partition
+pub fn offset_headroom(
+    offset_range: u64,
+    virtual_partitions: u32,
+    physical_partitions: u32,
+) -> u64 {
+    let compression = (virtual_partitions / physical_partitions.max(1)).max(1);
+    offset_range / u64::from(compression)
+}
+
+/// Offsets per virtual partition below which a spec is rejected outright
+pub const MIN_OFFSET_HEADROOM: u64 = 1 << 16;
+
+/// Offsets per virtual partition below which `OffsetRangeHeadroom` warns
+///
+/// A virtual partition exhausting its share starts colliding with its
+/// neighbour's offsets, which at high produce rates can take only months.
+pub const RECOMMENDED_OFFSET_HEADROOM: u64 = 1 << 32;
+
+/// Build the `OffsetRangeHeadroom` condition for the global mapping and
+/// per-topic overrides
+pub fn offset_headroom_condition(mapping: &MappingSpec) -> Condition {
+    let global = std::iter::once((
+        "mapping".to_string(),
+        mapping.offset_range,
+        mapping.virtual_partitions,
+        mapping.physical_partitions,
+    ));
+    let topics = mapping.topics.iter().map(|t| {
+        (
+            format!("topic {}", t.topic),
+            t.offset_range.unwrap_or(mapping.offset_range),
+            t.virtual_partitions.unwrap_or(mapping.virtual_partitions),
+            t.physical_partitions.unwrap_or(mapping.physical_partitions),
+        )
+    });
+    let low: Vec<String> = global
+        .chain(topics)
+        .filter_map(
+            |(scope, offset_range, virtual_partitions, physical_partitions)| {
+                let headroom =
+                    offset_headroom(offset_range, virtual_partitions, physical_partitions);
+                (headroom < RECOMMENDED_OFFSET_HEADROOM).then(|| {
+                    format!(
+                        "{} leaves {} offsets per virtual partition",
+                        scope, headroom
+                    )
+                })
+            },
+        )
+        .collect();
+
+    let (status, reason, message) = if low.is_empty() {
+        (
+            "True",
+            "SufficientHeadroom",
+            format!(
+                "Every virtual partition has at least {} (2^32) offsets",
+                RECOMMENDED_OFFSET_HEADROOM
+            ),
+        )
+    } else {
+        (
+            "False",
+            "LowHeadroom",
+            format!(
+                "{}; raise offsetRange or lower the compression ratio to keep at least {} (2^32)",
+                low.join("; "),
+                RECOMMENDED_OFFSET_HEADROOM
+            ),
+        )
+    };
+
+    Condition {
+        type_: "OffsetRangeHeadroom".to_string(),
+        status: status.to_string(),
+        last_transition_time: Utc::now(),
+        observed_generation: None,
+        reason: Some(reason.to_string()),
+        message: Some(message),
+    }
+}
+
+/// Build the `TopicsConsistent` condition from a probe result
+///
+/// Physical partition counts are compared against the mapping; when no
+/// metadata is available the condition is `Unknown`.
+pub fn topics_consistent_condition(mapping: &MappingSpec, probe: &KafkaProbe) -> Condition {
+    const MAX_REPORTED: usize = 5;
+
+    let (status, reason, message) = match probe {
+        KafkaProbe::Reachable(topics) => {
+            let mismatches = kafka_admin::find_partition_mismatches(mapping, topics);
+            if mismatches.is_empty() {
+                (
+                    "True",
+                    "PartitionCountsMatch",
+                    "Physical partition counts match the mapping".to_string(),
+                )
+            } else {
+                let mut details: Vec<_> = mismatches
+                    .iter()
+                    .take(MAX_REPORTED)
+                    .map(|m| m.to_string())
+                    .collect();
+                if mismatches.len() > MAX_REPORTED {
+                    details.push(format!("and {} more", mismatches.len() - MAX_REPORTED));
+                }
+                ("False", "PartitionCountMismatch", details.join("; "))
+            }
+        }
+        KafkaProbe::Unreachable(e) | KafkaProbe::Unsupported(e) => {
+            ("Unknown", "MetadataUnavailable", e.clone())
+        }
+    };
+
+    Condition {
+        type_: "TopicsConsistent".to_string(),
+        status: status.to_string(),
+        last_transition_time: Utc::now(),
+        observed_generation: None,
+        reason: Some(reason.to_string()),
+        message: Some(message),
+    }
+}
+
+/// Container waiting reasons that mean a pod will not become ready on its own
+const FAILING_WAIT_REASONS: [&str; 6] = [
+    "CrashLoopBackOff",
+    "ImagePullBackOff",
+    "ErrImagePull",
+    "InvalidImageName",
+    "CreateContainerConfigError",
+    "CreateContainerError",
+];
+
+/// Build the WorkloadHealthy condition from the proxy pods
+///
+/// Reports the first concrete failure found: a pod that cannot be scheduled,
+/// a container that was OOMKilled, or one stuck in a failing waiting state
+/// such as CrashLoopBackOff or ImagePullBackOff.
+pub fn workload_healthy_condition(pods: &[Pod]) -> Condition {
+    let failure = pods.iter().find_map(|pod| {
+        let pod_name = pod.metadata.name.as_deref().unwrap_or_default();
+        let status = pod.status.as_ref()?;
+
+        let unschedulable = status.conditions.iter().flatten().find(|c| {
+            c.type_ == "PodScheduled"
+                && c.status == "False"
+                && c.reason.as_deref() == Some("Unschedulable")
+        });
+        if let Some(c) = unschedulable {
+            return Some((
+                "Unschedulable".to_string(),
+                format!(
+                    "Pod {} is unschedulable: {}",
+                    pod_name,
+                    c.message.as_deref().unwrap_or_default()
+                ),
+            ));
+        }
+
+        status.container_statuses.iter().flatten().find_map(|c| {
+            let waiting = c.state.as_ref().and_then(|s| s.waiting.as_ref());
+            let oom_killed = c
+                .last_state
+                .as_ref()
+                .and_then(|s| s.terminated.as_ref())
+                .is_some_and(|t| t.reason.as_deref() == Some("OOMKilled"));
+            if oom_killed && waiting.is_some() {
+                return Some((
+                    "OOMKilled".to_string(),
+                    format!(
+                        "Container {} in pod {} was OOMKilled; raise its memory limit",
+                        c.name, pod_name
+                    ),
+                ));
+            }
+
+            let waiting = waiting?;
+            let reason = waiting.reason.as_deref()?;
+            FAILING_WAIT_REASONS.contains(&reason).then(|| {
+                (
+                    reason.to_string(),
+                    format!(
+                        "Container {} in pod {} is in {}: {}",
+                        c.name,
+                        pod_name,
+                        reason,
+                        waiting.message.as_deref().unwrap_or_default()
+                    ),
+                )
+            })
+        })
+    });
+
+    let (status, reason, message) = match failure {
+        Some((reason, message)) => ("False", reason, message),
+        None => (
+            "True",
+            "PodsHealthy".to_string(),
+            "No failing proxy pods".to_string(),
+        ),
+    };
+    Condition {
+        type_: "WorkloadHealthy".to_string(),
+        status: status.to_string(),
+        last_transition_time: Utc::now(),
+        observed_generation: None,
+        reason: Some(reason),
+        message: Some(message),
+    }
+}
+
+/// Name of the ConfigMap holding the proxy configuration
+pub fn config_map_name(name: &str) -> String {
+    format!("{}-config", name)
+}
+
+/// Read the expiry of every certificate mounted from a Secret
+///
+/// Secrets or keys that are missing or do not hold a PEM certificate are
+/// skipped; the secrets checksum already reports their absence.
+pub async fn certificate_statuses(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Vec<CertificateStatus> {
+    let mut statuses = Vec::new();
+    for (secret_name, key) in remapper.spec.referenced_certificates() {
+        let secret = match secrets::get_secret_opt(client, namespace, &secret_name).await {
+            Ok(Some(secret)) => secret,
+            Ok(None) => continue,
+            Err(e) => {
+                warn!("Failed to read certificate secret {}: {}", secret_name, e);
+                continue;
+            }
+        };
+        let not_after = secret
+            .data
+            .as_ref()
+            .and_then(|data| data.get(&key))
+            .and_then(|value| certificates::pem_not_after(&value.0));
+        match not_after {
+            Some(not_after) => statuses.push(CertificateStatus {
+                secret_name,
+                key,
+                not_after,
+            }),
+            None => warn!(
+                "No readable certificate under {} in secret {}/{}",
+                key, namespace, secret_name
+            ),
+        }
+    }
+    statuses
+}
+
+/// Replica counts and rollout state read back from the workload
+#[derive(Default)]
+struct WorkloadProgress {
+    ready: i32,
+    current: i32,
+    updated: i32,
+    available: i32,
+    spec_replicas: Option<i32>,
+    updating: bool,
+}
+
+/// A cached child, or the live object when the cache has not seen it yet
+async fn cached_or_get<K>(cached: Option<Arc<K>>, api: &Api<K>, name: &str) -> Option<K>
+where
+    K: Clone + DeserializeOwned + std::fmt::Debug,
+{
+    match cached {
+        Some(child) => Some((*child).clone()),
+        None => api.get(name).await.ok(),
+    }
+}
+
+/// Update the status of a KafkaPartitionRemapper
+///
+/// Workloads and Services are read from `children` where cached, falling
+/// back to the API server.
+///
+/// `extra_conditions` are recorded alongside the built-in ones; any of them
+/// being `False` marks the remapper as `Failed`. `canary` is the state of a
+/// canary rollout, if one is active. Returns the status written.
+#[allow(clippy::too_many_arguments)]
+pub async fn update_status(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    children: &ChildStores,
+    namespace: &str,
+    config_map_name: &str,
+    workload_name: &str,
+    service_name: &str,
+    extra_conditions: Vec<Condition>,
+    canary: Option<CanaryStatus>,
+) -> Result<KafkaPartitionRemapperStatus> {
+    let name = remapper.name_any();
+    let resource_name = remapper.resource_name();
+    let spec = &remapper.spec;
+
+    // Get workload status, from the controller's cache when it has seen it
+    let progress = match spec.workload {
+        WorkloadKind::Deployment => cached_or_get(
+            children.deployments.get(namespace, workload_name),
+            &Api::<Deployment>::namespaced(client.clone(), namespace),
+            workload_name,
+        )
+        .await
+        .map(|d| {
+            let status = d.status.clone().unwrap_or_default();
+            WorkloadProgress {
+                ready: status.ready_replicas.unwrap_or(0),
+                current: status.replicas.unwrap_or(0),
+                updated: status.updated_replicas.unwrap_or(0),
+                available: status.available_replicas.unwrap_or(0),
+                spec_replicas: d.spec.as_ref().and_then(|s| s.replicas),
+                updating: deployment_builder::rollout_in_progress(&d),
+            }
+        })
+        .unwrap_or_default(),
+        WorkloadKind::StatefulSet => cached_or_get(
+            children.stateful_sets.get(namespace, workload_name),
+            &Api::<StatefulSet>::namespaced(client.clone(), namespace),
+            workload_name,
+        )
+        .await
+        .map(|s| {
+            let status = s.status.clone().unwrap_or_default();
+            WorkloadProgress {
+                ready: status.ready_replicas.unwrap_or(0),
+                current: status.replicas,
+                updated: status.updated_replicas.unwrap_or(0),
+                available: status.available_replicas.unwrap_or(0),
+                spec_replicas: s.spec.as_ref().and_then(|s| s.replicas),
+                updating: stateful_set_builder::rollout_in_progress(&s),
+            }
+        })
+        .unwrap_or_default(),
+    };
+    let ready_replicas = progress.ready;
+
+    // With autoscaling the HPA decides the desired count, so read it back
+    let desired_replicas = if spec.autoscaling_enabled() {
+        progress.spec_replicas.unwrap_or(spec.replicas)
+    } else {
+        spec.replicas
+    };
+
+    // Look for pods failing in ways the workload status does not explain
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let selector = format!(
+        "{},!{}",
+        deployment_builder::build_label_selector(&resource_name),
+        canary::CANARY_LABEL
+    );
+    let pods = pods
+        .list(&ListParams::default().labels(&selector))
+        .await
+        .map(|list| list.items)
+        .unwrap_or_default();
+    let workload_healthy = workload_healthy_condition(&pods);
+
+    // Read certificate expiry so renewals that stall are visible
+    let certificates = certificate_statuses(remapper, client, namespace).await;
+    let users = user_statuses(remapper, client, namespace).await;
+
+    // Get service endpoint
+    let services: Api<Service> = Api::namespaced(client.clone(), namespace);
+    let service = cached_or_get(
+        children.services.get(namespace, service_name),
+        &services,
+        service_name,
+    )
+    .await;
+    let service_endpoint = service
+        .as_ref()
+        .and_then(|s| service_builder::get_service_endpoint(s, spec));
+    let external_endpoint = if spec.external_listeners().is_empty() {
+        None
+    } else {
+        let external_name = service_builder::external_service_name(&resource_name);
+        cached_or_get(
+            children.services.get(namespace, &external_name),
+            &services,
+            &external_name,
+        )
+        .await
+        .and_then(|s| service_builder::get_external_endpoint(&s, spec))
+    };
+
+    // The workload is left untouched while Kafka is unreachable or a canary
+    // is being judged, so the previously applied mapping stays in effect
+    let workload_applied = !extra_conditions
+        .iter()
+        .any(|c| c.type_ == "KafkaReachable" && c.status == "False")
+        && canary.as_ref().is_none_or(|c| c.state == "Passed");
 
     // Determine phase
+    let failure_message = extra_conditions
+        .iter()
+        .find(|c| c.status == "False")
+        .map(|c| format!("{}: {}", c.type_, c.message.as_deref().unwrap_or_default()));
     let phase = if spec.suspend {
         "Suspended"
-    } else if ready_replicas == spec.replicas {
+    } else if failure_message.is_some() {
+        "Failed"
+    } else if progress.updating {
+        "Updating"
+    } else if ready_replicas == desired_replicas {
         "Running"
     } else if ready_replicas > 0 {
         "Degraded"
@@ -240,102 +3475,1286 @@ pub async fn update_status(
     let mut conditions = Vec::new();
     let now = Utc::now();
 
-    conditions.push(Condition {
-        type_: "ConfigValid".to_string(),
-        status: "True".to_string(),
-        last_transition_time: now,
-        reason: Some("ConfigurationValid".to_string()),
-        message: Some("Configuration is valid".to_string()),
-    });
+    conditions.push(Condition {
+        type_: "ConfigValid".to_string(),
+        status: "True".to_string(),
+        last_transition_time: now,
+        observed_generation: None,
+        reason: Some("ConfigurationValid".to_string()),
+        message: Some("Configuration is valid".to_string()),
+    });
+
+    conditions.push(Condition {
+        type_: "DeploymentAvailable".to_string(),
+        status: if ready_replicas > 0 { "True" } else { "False" }.to_string(),
+        last_transition_time: now,
+        observed_generation: None,
+        reason: Some(
+            if ready_replicas > 0 {
+                "ReplicasAvailable"
+            } else {
+                "NoReplicasAvailable"
+            }
+            .to_string(),
+        ),
+        message: Some(format!(
+            "{}/{} replicas ready",
+            ready_replicas, desired_replicas
+        )),
+    });
+
+    conditions.push(Condition {
+        type_: "Ready".to_string(),
+        status: if phase == "Running" { "True" } else { "False" }.to_string(),
+        last_transition_time: now,
+        observed_generation: None,
+        reason: Some(phase.to_string()),
+        message: Some(format!("Proxy is {}", phase.to_lowercase())),
+    });
+    conditions.extend(progress_conditions(phase, failure_message.as_deref(), now));
+    let suspension = suspension_status(remapper, progress.spec_replicas, now);
+    conditions.push(suspended_condition(suspension.as_ref(), now));
+
+    // A LoadBalancer only reports its address once the cloud provider
+    // assigns one; until then the endpoint falls back to cluster DNS
+    if spec.service.type_ == ServiceType::LoadBalancer {
+        let pending = service
+            .as_ref()
+            .is_none_or(service_builder::load_balancer_pending);
+        conditions.push(Condition {
+            type_: "LoadBalancerReady".to_string(),
+            status: if pending { "False" } else { "True" }.to_string(),
+            last_transition_time: now,
+            observed_generation: None,
+            reason: Some(
+                if pending {
+                    "Pending"
+                } else {
+                    "AddressAssigned"
+                }
+                .to_string(),
+            ),
+            message: Some(if pending {
+                "Waiting for the load balancer address".to_string()
+            } else {
+                format!(
+                    "Load balancer address assigned: {}",
+                    service_endpoint.as_deref().unwrap_or_default()
+                )
+            }),
+        });
+    }
+
+    // Pod failures explain a stalled rollout better than the replica counts
+    let unhealthy_message = (workload_healthy.status == "False")
+        .then(|| workload_healthy.message.clone())
+        .flatten();
+    conditions.push(workload_healthy);
+    if !spec.referenced_certificates().is_empty() {
+        conditions.push(certificates::certificates_fresh_condition(
+            &certificates,
+            now,
+        ));
+    }
+
+    conditions.extend(extra_conditions);
+    let conditions = merge_conditions(
+        remapper.status.as_ref().map(|s| s.conditions.as_slice()),
+        conditions,
+        remapper.metadata.generation,
+    );
+
+    // Calculate compression ratio
+    let compression_ratio = spec.mapping.virtual_partitions / spec.mapping.physical_partitions;
+
+    // Build status
+    let status = KafkaPartitionRemapperStatus {
+        phase: Some(phase.to_string()),
+        message: Some(
+            match failure_message
+                .or(unhealthy_message)
+                .filter(|_| !spec.suspend)
+            {
+                Some(message) => message,
+                None => format!(
+                    "{}/{} replicas ready, compression ratio {}:1",
+                    ready_replicas, desired_replicas, compression_ratio
+                ),
+            },
+        ),
+        service_endpoint,
+        external_endpoint,
+        metrics_endpoint: metrics_service_name(spec, service_name, &resource_name).map(|s| {
+            format!(
+                "http://{}.{}.svc.cluster.local:{}/metrics",
+                s, namespace, spec.metrics.port
+            )
+        }),
+        ready_replicas: Some(ready_replicas),
+        replicas: Some(progress.current),
+        updated_replicas: Some(progress.updated),
+        available_replicas: Some(progress.available),
+        unavailable_replicas: Some((desired_replicas - progress.available).max(0)),
+        selector: Some(deployment_builder::build_label_selector(&resource_name)),
+        config_map_name: Some(config_map_name.to_string()),
+        deployment_name: (spec.workload == WorkloadKind::Deployment)
+            .then(|| workload_name.to_string()),
+        stateful_set_name: (spec.workload == WorkloadKind::StatefulSet)
+            .then(|| workload_name.to_string()),
+        service_name: Some(service_name.to_string()),
+        compression_ratio: Some(compression_ratio),
+        ratio: Some(format!("{}:1", compression_ratio)),
+        shards: sharding::shard_ranges_for(spec)
+            .into_iter()
+            .map(|shard| ShardStatus {
+                index: shard.index,
+                service_name: sharding::shard_service_name(&resource_name, shard.index),
+                virtual_partition_start: shard.start,
+                virtual_partition_end: shard.end,
+            })
+            .collect(),
+        applied_mapping: if workload_applied {
+            Some(spec.mapping.clone())
+        } else {
+            remapper
+                .status
+                .as_ref()
+                .and_then(|s| s.applied_mapping.clone())
+        },
+        certificates,
+        users,
+        canary,
+        suspension,
+        audit: remapper.status.as_ref().and_then(|s| s.audit.clone()),
+        observed_generation: remapper.metadata.generation,
+        last_update_time: Some(now),
+        conditions,
+    };
+
+    // Patch status, which lives with the remapper rather than its children
+    let remapper_namespace = remapper.namespace().unwrap_or_default();
+    let remappers: Api<KafkaPartitionRemapper> =
+        Api::namespaced(client.clone(), &remapper_namespace);
+    let mut patch = serde_json::json!({
+        "status": status
+    });
+    // Merge patches only remove fields that are explicitly null
+    if status.canary.is_none() {
+        patch["status"]["canary"] = serde_json::Value::Null;
+    }
+    match status.suspension {
+        None => patch["status"]["suspension"] = serde_json::Value::Null,
+        Some(ref s) if s.resumed_at.is_none() => {
+            patch["status"]["suspension"]["resumedAt"] = serde_json::Value::Null
+        }
+        Some(_) => {}
+    }
+    // The audit job owns the audit result, so it is never written back from a
+    // possibly stale copy and only cleared once the audit is turned off
+    if spec.audit.as_ref().is_some_and(|a| a.enabled) {
+        if let Some(status) = patch["status"].as_object_mut() {
+            status.remove("audit");
+        }
+    } else {
+        patch["status"]["audit"] = serde_json::Value::Null;
+    }
+
+    remappers
+        .patch_status(&name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map_err(|e| Error::kube("Failed to update status", e))?;
+
+    info!(
+        "Updated status for {}/{}: phase={}, ready={}/{}",
+        remapper_namespace, name, phase, ready_replicas, desired_replicas
+    );
+
+    Ok(status)
+}
+
+/// Service the metrics port is exposed on, if any
+fn metrics_service_name(
+    spec: &KafkaPartitionRemapperSpec,
+    service_name: &str,
+    resource_name: &str,
+) -> Option<String> {
+    if spec.metrics.on_client_service() {
+        return Some(service_name.to_string());
+    }
+    spec.metrics
+        .dedicated_service()
+        .map(|_| service_builder::metrics_service_name(resource_name))
+}
+
+/// Requeue interval while a LoadBalancer address is pending
+///
+/// Starts at five seconds and grows with the time spent waiting, capped at
+/// `max`, so a quick assignment is reported promptly without polling a slow
+/// provider at a high rate.
+pub fn load_balancer_requeue(
+    pending_since: DateTime<Utc>,
+    now: DateTime<Utc>,
+    max: std::time::Duration,
+) -> std::time::Duration {
+    let waited = (now - pending_since).to_std().unwrap_or_default();
+    waited.max(std::time::Duration::from_secs(5)).min(max)
+}
+
+/// Suspension to record in the status, kept from the first reconcile that
+/// saw `suspend` until the remapper resumes
+///
+/// The previous replicas are the workload's last reported count, so an
+/// autoscaled remapper resumes at the size its HPA had chosen. On resume the
+/// record stays, with `resumedAt` set, until `workload_replicas` shows the
+/// restore took effect; a later suspension starts a new record.
+pub fn suspension_status(
+    remapper: &KafkaPartitionRemapper,
+    workload_replicas: Option<i32>,
+    now: DateTime<Utc>,
+) -> Option<SuspensionStatus> {
+    let status = remapper.status.as_ref();
+    let recorded = status.and_then(|s| s.suspension.clone());
+    if !remapper.spec.suspend {
+        let restored = workload_replicas.is_some_and(|r| r > 0);
+        return recorded
+            .filter(|_| remapper.spec.autoscaling_enabled() && !restored)
+            .map(|s| SuspensionStatus {
+                resumed_at: s.resumed_at.or(Some(now)),
+                ..s
+            });
+    }
+    let suspension = recorded
+        .filter(|s| s.resumed_at.is_none())
+        .unwrap_or_else(|| SuspensionStatus {
+            suspended_at: now,
+            previous_replicas: status
+                .and_then(|s| s.replicas)
+                .filter(|r| *r > 0)
+                .unwrap_or(remapper.spec.replicas),
+            resumed_at: None,
+        });
+    Some(suspension)
+}
+
+/// Replicas to restore on an autoscaled remapper that has just resumed
+///
+/// An HPA cannot scale up from zero, so the workload is set back to the
+/// count recorded at suspension, until the status records the resume.
+pub fn replicas_to_restore(remapper: &KafkaPartitionRemapper) -> Option<i32> {
+    if remapper.spec.suspend || !remapper.spec.autoscaling_enabled() {
+        return None;
+    }
+    remapper
+        .status
+        .as_ref()?
+        .suspension
+        .as_ref()
+        .filter(|s| s.resumed_at.is_none())
+        .map(|s| s.previous_replicas)
+}
+
+/// Replicas to keep applying while an HPA takes the workload over
+///
+/// Dropping `replicas` from an apply that owned it resets the workload to a
+/// single replica, so the current count stays applied until the HPA's first
+/// scale takes ownership of the field.
+pub fn handover_replicas(
+    remapper: &KafkaPartitionRemapper,
+    existing: &ObjectMeta,
+    current: Option<i32>,
+) -> Option<i32> {
+    if deployment_builder::desired_replicas(remapper).is_some() {
+        return None;
+    }
+    let owns_replicas = existing
+        .managed_fields
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter(|f| f.manager.as_deref() == Some(FIELD_MANAGER))
+        .filter(|f| f.operation.as_deref() == Some("Apply"))
+        .filter_map(|f| f.fields_v1.as_ref())
+        .any(|f| f.0.pointer("/f:spec/f:replicas").is_some());
+    current.filter(|_| owns_replicas)
+}
 
-    conditions.push(Condition {
-        type_: "DeploymentAvailable".to_string(),
-        status: if ready_replicas > 0 { "True" } else { "False" }.to_string(),
+/// Scale a resuming autoscaled workload back to its recorded replicas
+///
+/// Goes through the scale subresource, as the HPA does, so `replicas` never
+/// becomes one of the operator's applied fields and is not dropped when the
+/// next apply leaves it to the HPA.
+async fn restore_replicas<K>(
+    remapper: &KafkaPartitionRemapper,
+    api: &Api<K>,
+    name: &str,
+    namespace: &str,
+    kind: &str,
+) -> Result<()>
+where
+    K: Clone + DeserializeOwned,
+{
+    let Some(replicas) = replicas_to_restore(remapper) else {
+        return Ok(());
+    };
+    let patch = serde_json::json!({ "spec": { "replicas": replicas } });
+    match api
+        .patch_scale(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+    {
+        Ok(_) => {
+            info!(
+                "Restored {} {}/{} to {} replicas after resume",
+                kind, namespace, name, replicas
+            );
+            Ok(())
+        }
+        // Nothing to restore until the workload exists
+        Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(()),
+        Err(e) => Err(Error::kube(
+            format!("restoring replicas of {} {}/{}", kind, namespace, name),
+            e,
+        )),
+    }
+}
+
+/// Condition reporting whether the remapper is suspended
+pub fn suspended_condition(suspension: Option<&SuspensionStatus>, now: DateTime<Utc>) -> Condition {
+    let suspension = suspension.filter(|s| s.resumed_at.is_none());
+    Condition {
+        type_: "Suspended".to_string(),
+        status: if suspension.is_some() {
+            "True"
+        } else {
+            "False"
+        }
+        .to_string(),
         last_transition_time: now,
+        observed_generation: None,
         reason: Some(
-            if ready_replicas > 0 {
-                "ReplicasAvailable"
+            if suspension.is_some() {
+                "SuspendedBySpec"
             } else {
-                "NoReplicasAvailable"
+                "NotSuspended"
             }
             .to_string(),
         ),
-        message: Some(format!(
-            "{}/{} replicas ready",
-            ready_replicas, spec.replicas
-        )),
-    });
+        message: Some(match suspension {
+            Some(s) => format!(
+                "Suspended at {} with {} replicas",
+                s.suspended_at.to_rfc3339(),
+                s.previous_replicas
+            ),
+            None => "Proxy is not suspended".to_string(),
+        }),
+    }
+}
 
-    conditions.push(Condition {
-        type_: "Ready".to_string(),
-        status: if phase == "Running" { "True" } else { "False" }.to_string(),
+/// Phases in which the proxy is still converging on its spec
+const RECONCILING_PHASES: &[&str] = &["Pending", "Updating", "Degraded"];
+
+/// `Reconciling` and `Stalled` conditions for a phase
+///
+/// Follows the kstatus conventions read by Flux and Argo CD: `Reconciling`
+/// is True while the proxy converges on the spec and `Stalled` is True once
+/// it cannot without intervention. Both have abnormal-true polarity, and
+/// `Ready` is only True in the Running phase.
+pub fn progress_conditions(
+    phase: &str,
+    message: Option<&str>,
+    now: DateTime<Utc>,
+) -> Vec<Condition> {
+    let message = message
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("Proxy is {}", phase.to_lowercase()));
+    let condition = |type_: &str, active: bool| Condition {
+        type_: type_.to_string(),
+        status: if active { "True" } else { "False" }.to_string(),
         last_transition_time: now,
+        observed_generation: None,
         reason: Some(phase.to_string()),
-        message: Some(format!("Proxy is {}", phase.to_lowercase())),
-    });
+        message: Some(message.clone()),
+    };
+    vec![
+        condition("Reconciling", RECONCILING_PHASES.contains(&phase)),
+        condition("Stalled", phase == "Failed"),
+    ]
+}
 
-    // Calculate compression ratio
-    let compression_ratio = spec.mapping.virtual_partitions / spec.mapping.physical_partitions;
+/// Stamp conditions with the observed generation, keeping transition times
+///
+/// Follows `metav1.Condition` semantics: `lastTransitionTime` is only bumped
+/// when a condition's status differs from the one recorded in `existing`.
+/// Conditions that are no longer reported are dropped.
+pub fn merge_conditions(
+    existing: Option<&[Condition]>,
+    conditions: Vec<Condition>,
+    observed_generation: Option<i64>,
+) -> Vec<Condition> {
+    conditions
+        .into_iter()
+        .map(|mut condition| {
+            if let Some(previous) = existing
+                .unwrap_or_default()
+                .iter()
+                .find(|c| c.type_ == condition.type_ && c.status == condition.status)
+            {
+                condition.last_transition_time = previous.last_transition_time;
+            }
+            condition.observed_generation = observed_generation;
+            condition
+        })
+        .collect()
+}
 
-    // Build status
-    let status = KafkaPartitionRemapperStatus {
-        phase: Some(phase.to_string()),
-        message: Some(format!(
-            "{}/{} replicas ready, compression ratio {}:1",
-            ready_replicas, spec.replicas, compression_ratio
-        )),
-        service_endpoint,
-        metrics_endpoint: Some(format!(
-            "http://{}.{}.svc.cluster.local:{}/metrics",
-            service_name, namespace, spec.metrics.port
-        )),
-        ready_replicas: Some(ready_replicas),
-        replicas: Some(replicas),
-        config_map_name: Some(config_map_name.to_string()),
-        deployment_name: Some(deployment_name.to_string()),
-        service_name: Some(service_name.to_string()),
-        compression_ratio: Some(compression_ratio),
-        observed_generation: remapper.metadata.generation,
-        last_update_time: Some(now),
+/// Build the status reported while reconciliation is paused
+///
+/// Keeps the last observed status and only swaps in the Paused condition,
+/// since child resources are left exactly as they were.
+pub fn paused_status(remapper: &KafkaPartitionRemapper) -> KafkaPartitionRemapperStatus {
+    let now = Utc::now();
+    let mut status = remapper.status.clone().unwrap_or_default();
+
+    let conditions = status
+        .conditions
+        .iter()
+        .filter(|c| !["Paused", "Ready", "Reconciling", "Stalled"].contains(&c.type_.as_str()))
+        .cloned()
+        .chain(progress_conditions("Paused", None, now))
+        .chain([
+            Condition {
+                type_: "Ready".to_string(),
+                status: "False".to_string(),
+                last_transition_time: now,
+                observed_generation: None,
+                reason: Some("Paused".to_string()),
+                message: Some("Proxy is paused".to_string()),
+            },
+            Condition {
+                type_: "Paused".to_string(),
+                status: "True".to_string(),
+                last_transition_time: now,
+                observed_generation: None,
+                reason: Some("PausedByAnnotation".to_string()),
+                message: Some(format!(
+                    "Reconciliation paused by the {} annotation",
+                    PAUSED_ANNOTATION
+                )),
+            },
+        ])
+        .collect();
+    status.conditions = merge_conditions(
+        Some(&status.conditions),
         conditions,
-    };
+        remapper.metadata.generation,
+    );
 
-    // Patch status
+    status.phase = Some("Paused".to_string());
+    status.message = Some(format!(
+        "Reconciliation paused; remove the {} annotation to resume",
+        PAUSED_ANNOTATION
+    ));
+    status.last_update_time = Some(now);
+    status
+}
+
+/// Update the remapper status while reconciliation is paused
+pub async fn update_paused_status(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<()> {
+    let name = remapper.name_any();
     let remappers: Api<KafkaPartitionRemapper> = Api::namespaced(client.clone(), namespace);
     let patch = serde_json::json!({
-        "status": status
+        "status": paused_status(remapper)
     });
 
     remappers
         .patch_status(&name, &PatchParams::default(), &Patch::Merge(&patch))
         .await
-        .map_err(|e| Error::KubeError(format!("Failed to update status: {}", e)))?;
-
-    info!(
-        "Updated status for {}/{}: phase={}, ready={}/{}",
-        namespace, name, phase, ready_replicas, spec.replicas
-    );
+        .map_err(|e| Error::kube("Failed to update status", e))?;
 
+    info!("Reconciliation of {}/{} is paused", namespace, name);
     Ok(())
 }
 
 /// Calculate a hash of the configuration for rolling updates
-fn calculate_config_hash(remapper: &KafkaPartitionRemapper) -> String {
+///
+/// Scaling fields are excluded so that `kubectl scale` or an autoscaler
+/// changing the replica count does not roll every proxy pod. In sharded mode
+/// the replica count determines every shard's range, so it is kept. With the
+/// Hot reload policy, log level, topic overrides and maintenance mode are
+/// pushed to running pods instead and are excluded as well.
+pub fn calculate_config_hash(remapper: &KafkaPartitionRemapper) -> String {
+    let mut spec = remapper.spec.clone();
+    if spec.reload_policy == ReloadPolicy::Hot {
+        spec.logging = Default::default();
+        spec.mapping.topics.clear();
+        spec.maintenance_mode = None;
+    }
+    hash_spec(spec)
+}
+
+/// Calculate a hash of the configuration pods should have reloaded
+///
+/// Unlike [`calculate_config_hash`] this covers the hot-reloadable fields,
+/// so it changes whenever the rendered proxy config does.
+pub fn calculate_reload_hash(remapper: &KafkaPartitionRemapper) -> String {
+    hash_spec(remapper.spec.clone())
+}
+
+fn hash_spec(mut spec: KafkaPartitionRemapperSpec) -> String {
+    if !spec.sharding_enabled() {
+        spec.replicas = 0;
+    }
+    spec.suspend = false;
+    spec.deletion_policy = DeletionPolicy::default();
+    spec.rollout_strategy = None;
+    spec.reload_policy = ReloadPolicy::default();
+    spec.autoscaling = None;
+    spec.pod_disruption_budget = None;
+    spec.external_service = None;
+    spec.metrics.service = None;
+    spec.gateway = None;
+
     let mut hasher = Sha256::new();
-    let spec_json = serde_json::to_string(&remapper.spec).unwrap_or_default();
+    let spec_json = serde_json::to_string(&spec).unwrap_or_default();
     hasher.update(spec_json.as_bytes());
     format!("{:x}", hasher.finalize())[..16].to_string()
 }
 
-fn build_owner_reference(
+/// Calculate a hash of the referenced Secret contents
+///
+/// Returns `None` when the spec references no Secrets. Missing Secrets are
+/// hashed as absent so their later creation also rolls the pods.
+pub async fn calculate_secrets_hash(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<Option<String>> {
+    let mut names = remapper.spec.referenced_secrets();
+    if !remapper.spec.users.is_empty() {
+        names.push(users::users_secret_name(remapper));
+    }
+    if remapper.spec.user_rotation_enabled() {
+        names.push(users::previous_users_secret_name(remapper));
+    }
+    if names.is_empty() {
+        return Ok(None);
+    }
+
+    let mut fetched = Vec::with_capacity(names.len());
+    for name in names {
+        let secret = secrets::get_secret_opt(client, namespace, &name).await?;
+        fetched.push((name, secret));
+    }
+
+    Ok(Some(hash_secrets(&fetched)))
+}
+
+/// Hash Secret names and data in a stable order
+pub fn hash_secrets(secrets: &[(String, Option<Secret>)]) -> String {
+    let mut hasher = Sha256::new();
+    for (name, secret) in secrets {
+        hasher.update(name.as_bytes());
+        match secret.as_ref().and_then(|s| s.data.as_ref()) {
+            Some(data) => {
+                for (key, value) in data {
+                    hasher.update(key.as_bytes());
+                    hasher.update(&value.0);
+                }
+            }
+            None => hasher.update(b"<absent>"),
+        }
+    }
+    format!("{:x}", hasher.finalize())[..16].to_string()
+}
+
+/// Release or remove child resources when a remapper is deleted
+///
+/// With `Retain`, owner references and owner labels pointing at the remapper
+/// are stripped so garbage collection leaves the children running. With
+/// `Delete`, children are removed explicitly rather than waiting on garbage
+/// collection, which never reaches children in another namespace.
+///
+/// These children are the remapper's only footprint. The operator never
+/// creates topics, SCRAM users or client quotas in Kafka: generated users
+/// and quotas are enforced by the proxy itself, so there is no broker-side
+/// state to remove or retain.
+pub async fn cleanup_children(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<()> {
+    let policy = remapper.spec.deletion_policy;
+    cleanup_children_of::<Deployment>(remapper, client, namespace, policy, "Deployment").await?;
+    cleanup_children_of::<StatefulSet>(remapper, client, namespace, policy, "StatefulSet").await?;
+    cleanup_children_of::<Service>(remapper, client, namespace, policy, "Service").await?;
+    cleanup_children_of::<ConfigMap>(remapper, client, namespace, policy, "ConfigMap").await?;
+    cleanup_children_of::<Secret>(remapper, client, namespace, policy, "Secret").await?;
+    cleanup_children_of::<PodDisruptionBudget>(
+        remapper,
+        client,
+        namespace,
+        policy,
+        "PodDisruptionBudget",
+    )
+    .await?;
+    cleanup_children_of::<HorizontalPodAutoscaler>(
+        remapper,
+        client,
+        namespace,
+        policy,
+        "HorizontalPodAutoscaler",
+    )
+    .await?;
+    let routes = Api::namespaced_with(
+        client.clone(),
+        namespace,
+        &tcp_route_builder::tcp_route_resource(),
+    );
+    cleanup_children_in::<DynamicObject>(remapper, &routes, namespace, policy, "TCPRoute").await?;
+    let scaled_objects = Api::namespaced_with(
+        client.clone(),
+        namespace,
+        &keda_scaled_object_builder::scaled_object_resource(),
+    );
+    cleanup_children_in::<DynamicObject>(
+        remapper,
+        &scaled_objects,
+        namespace,
+        policy,
+        "ScaledObject",
+    )
+    .await?;
+    // A retained audit would keep reporting to a remapper that is gone
+    let delete = DeletionPolicy::Delete;
+    cleanup_children_of::<CronJob>(remapper, client, namespace, delete, "CronJob").await?;
+    cleanup_children_of::<ServiceAccount>(remapper, client, namespace, delete, "ServiceAccount")
+        .await?;
+    cleanup_children_of::<RoleBinding>(remapper, client, namespace, delete, "RoleBinding").await?;
+    cleanup_children_of::<Role>(remapper, client, namespace, delete, "Role").await?;
+    Ok(())
+}
+
+async fn cleanup_children_of<K>(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+    policy: DeletionPolicy,
+    kind: &str,
+) -> Result<()>
+where
+    K: kube::Resource<Scope = k8s_openapi::NamespaceResourceScope, DynamicType = ()>
+        + Clone
+        + DeserializeOwned
+        + std::fmt::Debug,
+{
+    let api: Api<K> = Api::namespaced(client.clone(), namespace);
+    cleanup_children_in(remapper, &api, namespace, policy, kind).await
+}
+
+/// Delete or release the remapper's children served by `api`
+///
+/// Takes the API handle so custom resources, which are only reachable as
+/// dynamic objects, share the same cleanup.
+async fn cleanup_children_in<K>(
+    remapper: &KafkaPartitionRemapper,
+    api: &Api<K>,
+    namespace: &str,
+    policy: DeletionPolicy,
+    kind: &str,
+) -> Result<()>
+where
+    K: kube::Resource + Clone + DeserializeOwned + std::fmt::Debug,
+{
+    let uid = remapper.metadata.uid.clone().unwrap_or_default();
+    let selector = format!(
+        "app.kubernetes.io/instance={},app.kubernetes.io/managed-by=kafka-partition-remapper-operator",
+        remapper.resource_name()
+    );
+
+    for child in list_children(api, &selector, kind).await? {
+        if !is_owned_by(remapper, child.meta()) {
+            continue;
+        }
+        let owner_references = child.owner_references();
+
+        let child_name = child.name_any();
+        match policy {
+            DeletionPolicy::Delete => {
+                delete_child(api, child.meta(), namespace, kind).await?;
+            }
+            DeletionPolicy::Retain => {
+                let remaining: Vec<_> = owner_references
+                    .iter()
+                    .filter(|o| o.uid != uid)
+                    .cloned()
+                    .collect();
+                let patch = serde_json::json!({
+                    "metadata": {
+                        "ownerReferences": if remaining.is_empty() {
+                            serde_json::Value::Null
+                        } else {
+                            serde_json::json!(remaining)
+                        },
+                        "labels": {
+                            OWNER_NAME_LABEL: null,
+                            OWNER_NAMESPACE_LABEL: null
+                        }
+                    }
+                });
+                api.patch(&child_name, &PatchParams::default(), &Patch::Merge(&patch))
+                    .await
+                    .map_err(|e| Error::kube(format!("Failed to release {}", kind), e))?;
+                info!("Retained {} {}/{}", kind, namespace, child_name);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Delete children left behind under a previous resource name
+///
+/// Changing `nameOverride` or `fullnameOverride` gives every child a new name,
+/// and the children created under the old one would otherwise linger. They
+/// are found through the instance label, which carries the resource name
+/// they were generated for.
+pub async fn prune_renamed_children(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+) -> Result<()> {
+    prune_renamed_children_of::<Deployment>(remapper, client, namespace, "Deployment").await?;
+    prune_renamed_children_of::<StatefulSet>(remapper, client, namespace, "StatefulSet").await?;
+    prune_renamed_children_of::<Service>(remapper, client, namespace, "Service").await?;
+    prune_renamed_children_of::<ConfigMap>(remapper, client, namespace, "ConfigMap").await?;
+    prune_renamed_children_of::<Secret>(remapper, client, namespace, "Secret").await?;
+    prune_renamed_children_of::<PodDisruptionBudget>(
+        remapper,
+        client,
+        namespace,
+        "PodDisruptionBudget",
+    )
+    .await?;
+    prune_renamed_children_of::<HorizontalPodAutoscaler>(
+        remapper,
+        client,
+        namespace,
+        "HorizontalPodAutoscaler",
+    )
+    .await?;
+    let routes = Api::namespaced_with(
+        client.clone(),
+        namespace,
+        &tcp_route_builder::tcp_route_resource(),
+    );
+    prune_renamed_children_in::<DynamicObject>(remapper, &routes, namespace, "TCPRoute").await?;
+    let scaled_objects = Api::namespaced_with(
+        client.clone(),
+        namespace,
+        &keda_scaled_object_builder::scaled_object_resource(),
+    );
+    prune_renamed_children_in::<DynamicObject>(
+        remapper,
+        &scaled_objects,
+        namespace,
+        "ScaledObject",
+    )
+    .await?;
+    prune_renamed_children_of::<CronJob>(remapper, client, namespace, "CronJob").await?;
+    prune_renamed_children_of::<ServiceAccount>(remapper, client, namespace, "ServiceAccount")
+        .await?;
+    // The audit's status Role of a cross-namespace remapper lives with the
+    // remapper itself
+    let mut namespaces = vec![namespace.to_string()];
+    namespaces.extend(remapper.namespace().filter(|ns| ns != namespace));
+    for ns in &namespaces {
+        prune_renamed_children_of::<RoleBinding>(remapper, client, ns, "RoleBinding").await?;
+        prune_renamed_children_of::<Role>(remapper, client, ns, "Role").await?;
+    }
+    Ok(())
+}
+
+async fn prune_renamed_children_of<K>(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+) -> Result<()>
+where
+    K: kube::Resource<Scope = k8s_openapi::NamespaceResourceScope, DynamicType = ()>
+        + Clone
+        + DeserializeOwned
+        + std::fmt::Debug,
+{
+    let api: Api<K> = Api::namespaced(client.clone(), namespace);
+    prune_renamed_children_in(remapper, &api, namespace, kind).await
+}
+
+/// Delete the remapper's children served by `api` that carry an old name
+async fn prune_renamed_children_in<K>(
+    remapper: &KafkaPartitionRemapper,
+    api: &Api<K>,
+    namespace: &str,
+    kind: &str,
+) -> Result<()>
+where
+    K: kube::Resource + Clone + DeserializeOwned + std::fmt::Debug,
+{
+    let selector = format!(
+        "app.kubernetes.io/instance!={},app.kubernetes.io/managed-by=kafka-partition-remapper-operator",
+        remapper.resource_name()
+    );
+
+    for child in list_children(api, &selector, kind).await? {
+        if is_renamed_child(remapper, child.meta()) {
+            delete_child(api, child.meta(), namespace, kind).await?;
+        }
+    }
+    Ok(())
+}
+
+/// List the children matching a label selector
+///
+/// A custom resource whose CRD is not installed has no children to list.
+async fn list_children<K>(api: &Api<K>, selector: &str, kind: &str) -> Result<Vec<K>>
+where
+    K: kube::Resource + Clone + DeserializeOwned + std::fmt::Debug,
+{
+    match api.list(&ListParams::default().labels(selector)).await {
+        Ok(children) => Ok(children.items),
+        Err(kube::Error::Api(ae)) if ae.code == 404 => Ok(Vec::new()),
+        Err(e) => Err(Error::kube(format!("Failed to list {}s", kind), e)),
+    }
+}
+
+/// Whether a child belongs to the remapper but was generated under another
+/// resource name
+pub fn is_renamed_child(remapper: &KafkaPartitionRemapper, meta: &ObjectMeta) -> bool {
+    let instance = meta
+        .labels
+        .as_ref()
+        .and_then(|l| l.get("app.kubernetes.io/instance"));
+    is_owned_by(remapper, meta) && instance.is_some_and(|i| *i != remapper.resource_name())
+}
+
+/// Check whether an existing child may be managed by this remapper
+///
+/// Returns `true` when the child exists without an owner and must be adopted.
+/// Unowned children are only adopted when the remapper carries the
+/// `kafka.oso.sh/adopt: "true"` annotation; children controlled by another
+/// owner are never taken over.
+pub fn check_ownership(
+    remapper: &KafkaPartitionRemapper,
+    existing: Option<&ObjectMeta>,
+    kind: &str,
+) -> Result<bool> {
+    let Some(meta) = existing else {
+        return Ok(false);
+    };
+    let owners = meta.owner_references.as_deref().unwrap_or_default();
+    let name = meta.name.as_deref().unwrap_or_default();
+
+    if is_owned_by(remapper, meta) {
+        return Ok(false);
+    }
+    if let Some(controller) = owners.iter().find(|o| o.controller == Some(true)) {
+        return Err(Error::ConfigError(format!(
+            "{} {} is controlled by {} {}",
+            kind, name, controller.kind, controller.name
+        )));
+    }
+    if !remapper.adoption_requested() {
+        return Err(Error::ConfigError(format!(
+            "{} {} already exists and is not managed by this remapper; \
+             set the {} annotation to \"true\" to adopt it",
+            kind, name, ADOPT_ANNOTATION
+        )));
+    }
+    Ok(true)
+}
+
+/// Whether a child belongs to the remapper
+///
+/// Children in the remapper's namespace carry its owner reference; children
+/// in another namespace carry its owner labels instead.
+pub fn is_owned_by(remapper: &KafkaPartitionRemapper, meta: &ObjectMeta) -> bool {
+    let uid = remapper.metadata.uid.as_deref().unwrap_or_default();
+    let owned_by_reference = meta
+        .owner_references
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .any(|o| o.uid == uid);
+    let labels = meta.labels.as_ref();
+    let owned_by_labels = deployment_builder::owner_labels(remapper)
+        .iter()
+        .all(|(k, v)| labels.and_then(|l| l.get(k)) == Some(v));
+    owned_by_reference || owned_by_labels
+}
+
+/// Field manager the operator applies child resources as
+pub const FIELD_MANAGER: &str = "kafka-partition-remapper-operator";
+
+/// Server-side apply parameters for child resources
+///
+/// Fields owned by another manager are only taken over when the `forceApply`
+/// operator setting is enabled; otherwise the apply fails with a conflict.
+fn apply_params() -> PatchParams {
+    let params = PatchParams::apply(FIELD_MANAGER);
+    if settings::current().force_apply {
+        params.force()
+    } else {
+        params
+    }
+}
+
+/// Map a failed server-side apply of a child to an operator error
+///
+/// Conflicts become [`Error::FieldConflict`] naming the managers that own
+/// the contested fields.
+pub fn apply_error(kind: &str, err: kube::Error) -> Error {
+    match err {
+        kube::Error::Api(ae) if ae.code == 409 => {
+            let managers = conflicting_managers(&ae.message);
+            let owners = if managers.is_empty() {
+                "another field manager".to_string()
+            } else {
+                managers.join(", ")
+            };
+            Error::FieldConflict(format!(
+                "{} has fields owned by {}: {}",
+                kind, owners, ae.message
+            ))
+        }
+        e => Error::kube(format!("Failed to create/update {}", kind), e),
+    }
+}
+
+/// Field managers named in a server-side apply conflict message
+///
+/// The API server reports conflicts as `conflict with "<manager>" using
+/// <apiVersion>: <fields>`, once per manager.
+pub fn conflicting_managers(message: &str) -> Vec<String> {
+    let mut managers: Vec<String> = Vec::new();
+    for rest in message.split("with \"").skip(1) {
+        let Some((manager, _)) = rest.split_once('"') else {
+            continue;
+        };
+        if !managers.iter().any(|m| m == manager) {
+            managers.push(manager.to_string());
+        }
+    }
+    managers
+}
+
+/// Build the status reported when applying a child hits a field conflict
+///
+/// Keeps the last observed status and swaps in a `FieldConflict` condition,
+/// which is dropped again by the next successful reconcile.
+pub fn field_conflict_status(
+    remapper: &KafkaPartitionRemapper,
+    message: &str,
+) -> KafkaPartitionRemapperStatus {
+    let now = Utc::now();
+    let mut status = remapper.status.clone().unwrap_or_default();
+
+    let conditions = status
+        .conditions
+        .iter()
+        .filter(|c| {
+            !["FieldConflict", "Ready", "Reconciling", "Stalled"].contains(&c.type_.as_str())
+        })
+        .cloned()
+        .chain(progress_conditions("Failed", Some(message), now))
+        .chain([
+            Condition {
+                type_: "Ready".to_string(),
+                status: "False".to_string(),
+                last_transition_time: now,
+                observed_generation: None,
+                reason: Some("FieldConflict".to_string()),
+                message: Some("Child resources could not be applied".to_string()),
+            },
+            Condition {
+                type_: "FieldConflict".to_string(),
+                status: "True".to_string(),
+                last_transition_time: now,
+                observed_generation: None,
+                reason: Some("OwnedByAnotherManager".to_string()),
+                message: Some(message.to_string()),
+            },
+        ])
+        .collect();
+    status.conditions = merge_conditions(
+        Some(&status.conditions),
+        conditions,
+        remapper.metadata.generation,
+    );
+    status.phase = Some("Failed".to_string());
+    status.message = Some(message.to_string());
+    status.observed_generation = remapper.metadata.generation;
+    status.last_update_time = Some(now);
+    status
+}
+
+/// Record a field conflict in the remapper status
+pub async fn update_field_conflict_status(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+    message: &str,
+) -> Result<()> {
+    let name = remapper.name_any();
+    let remappers: Api<KafkaPartitionRemapper> = Api::namespaced(client.clone(), namespace);
+    let patch = serde_json::json!({
+        "status": field_conflict_status(remapper, message)
+    });
+
+    remappers
+        .patch_status(&name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map_err(|e| Error::kube("Failed to update status", e))?;
+    Ok(())
+}
+
+/// Build the status reported when the spec is rejected
+///
+/// The rest of the last observed status is kept, since child resources are
+/// left as they were. The remapper is marked Failed and Stalled for the
+/// current generation, so GitOps tools report it as degraded until the spec
+/// is fixed.
+pub fn invalid_spec_status(
+    remapper: &KafkaPartitionRemapper,
+    message: &str,
+) -> KafkaPartitionRemapperStatus {
+    let now = Utc::now();
+    let mut status = remapper.status.clone().unwrap_or_default();
+
+    let conditions = status
+        .conditions
+        .iter()
+        .filter(|c| !["ConfigValid", "Ready", "Reconciling", "Stalled"].contains(&c.type_.as_str()))
+        .cloned()
+        .chain(progress_conditions("Failed", Some(message), now))
+        .chain([
+            Condition {
+                type_: "ConfigValid".to_string(),
+                status: "False".to_string(),
+                last_transition_time: now,
+                observed_generation: None,
+                reason: Some("InvalidSpec".to_string()),
+                message: Some(message.to_string()),
+            },
+            Condition {
+                type_: "Ready".to_string(),
+                status: "False".to_string(),
+                last_transition_time: now,
+                observed_generation: None,
+                reason: Some("InvalidSpec".to_string()),
+                message: Some("The spec was rejected".to_string()),
+            },
+        ])
+        .collect();
+    status.conditions = merge_conditions(
+        Some(&status.conditions),
+        conditions,
+        remapper.metadata.generation,
+    );
+    status.phase = Some("Failed".to_string());
+    status.message = Some(message.to_string());
+    status.observed_generation = remapper.metadata.generation;
+    status.last_update_time = Some(now);
+    status
+}
+
+/// Record a rejected spec in the remapper status
+pub async fn update_invalid_spec_status(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+    message: &str,
+) -> Result<()> {
+    let name = remapper.name_any();
+    let remappers: Api<KafkaPartitionRemapper> = Api::namespaced(client.clone(), namespace);
+    let patch = serde_json::json!({
+        "status": invalid_spec_status(remapper, message)
+    });
+
+    remappers
+        .patch_status(&name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map_err(|e| Error::kube("Failed to update status", e))?;
+    Ok(())
+}
+
+/// Build the status reported once reconcile failures exceed the retry budget
+///
+/// Marks the remapper Failed and Stalled with the last error while retries
+/// continue at the longest backoff. The next successful reconcile replaces
+/// the status as usual.
+pub fn retries_exhausted_status(
+    remapper: &KafkaPartitionRemapper,
+    failures: u32,
+    message: &str,
+) -> KafkaPartitionRemapperStatus {
+    let now = Utc::now();
+    let mut status = remapper.status.clone().unwrap_or_default();
+    let message = format!("Reconcile failed {} times in a row: {}", failures, message);
+
+    let conditions = status
+        .conditions
+        .iter()
+        .filter(|c| !["Ready", "Reconciling", "Stalled"].contains(&c.type_.as_str()))
+        .cloned()
+        .chain(progress_conditions("Failed", Some(&message), now))
+        .chain([Condition {
+            type_: "Ready".to_string(),
+            status: "False".to_string(),
+            last_transition_time: now,
+            observed_generation: None,
+            reason: Some("RetriesExhausted".to_string()),
+            message: Some(message.clone()),
+        }])
+        .collect();
+    status.conditions = merge_conditions(
+        Some(&status.conditions),
+        conditions,
+        remapper.metadata.generation,
+    );
+    status.phase = Some("Failed".to_string());
+    status.message = Some(message);
+    status.observed_generation = remapper.metadata.generation;
+    status.last_update_time = Some(now);
+    status
+}
+
+/// Record an exhausted retry budget in the remapper status
+pub async fn update_retries_exhausted_status(
+    remapper: &KafkaPartitionRemapper,
+    client: &Client,
+    namespace: &str,
+    failures: u32,
+    message: &str,
+) -> Result<()> {
+    let name = remapper.name_any();
+    let remappers: Api<KafkaPartitionRemapper> = Api::namespaced(client.clone(), namespace);
+    let patch = serde_json::json!({
+        "status": retries_exhausted_status(remapper, failures, message)
+    });
+
+    remappers
+        .patch_status(&name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map_err(|e| Error::kube("Failed to update status", e))?;
+    Ok(())
+}
+
+/// Server-side apply parameters for a named child, adopting it if allowed
+///
+/// Adoption forces the apply so fields owned by previous managers converge
+/// to the operator's desired state.
+async fn child_patch_params<K>(
     remapper: &KafkaPartitionRemapper,
-) -> k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference {
-    k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference {
-        api_version: "kafka.oso.sh/v1alpha1".to_string(),
-        kind: "KafkaPartitionRemapper".to_string(),
-        name: remapper.name_any(),
-        uid: remapper.metadata.uid.clone().unwrap_or_default(),
-        controller: Some(true),
-        block_owner_deletion: Some(true),
+    api: &Api<K>,
+    name: &str,
+    kind: &str,
+) -> Result<PatchParams>
+where
+    K: kube::Resource + Clone + DeserializeOwned + std::fmt::Debug,
+{
+    let existing = api
+        .get_opt(name)
+        .await
+        .map_err(|e| Error::kube(format!("Failed to get {}", kind), e))?;
+
+    let patch_params = apply_params();
+    if check_ownership(remapper, existing.as_ref().map(|e| e.meta()), kind)? {
+        info!("Adopting {} {}/{}", kind, remapper.target_namespace(), name);
+        return Ok(patch_params.force());
+    }
+    Ok(patch_params)
+}
+
+/// Delete a child of the remapper, leaving missing and foreign objects alone
+pub(crate) async fn delete_owned_child<K>(
+    remapper: &KafkaPartitionRemapper,
+    api: &Api<K>,
+    name: &str,
+    namespace: &str,
+    kind: &str,
+) -> Result<()>
+where
+    K: kube::Resource + Clone + DeserializeOwned + std::fmt::Debug,
+{
+    delete_if_owned(api, name, namespace, kind, |meta| {
+        is_owned_by(remapper, meta)
+    })
+    .await
+}
+
+/// Delete a namespaced resource only when it exists and `owned` accepts it
+///
+/// An object of the same name created by someone else is left in place.
+pub(crate) async fn delete_if_owned<K>(
+    api: &Api<K>,
+    name: &str,
+    namespace: &str,
+    kind: &str,
+    owned: impl Fn(&ObjectMeta) -> bool,
+) -> Result<()>
+where
+    K: kube::Resource + Clone + DeserializeOwned + std::fmt::Debug,
+{
+    let existing = match api.get_opt(name).await {
+        Ok(existing) => existing,
+        // A missing custom resource definition also answers 404
+        Err(kube::Error::Api(ae)) if ae.code == 404 => None,
+        Err(e) => return Err(Error::kube(format!("Failed to get {}", kind), e)),
+    };
+    match existing {
+        Some(existing) if owned(existing.meta()) => {
+            delete_child(api, existing.meta(), namespace, kind).await
+        }
+        Some(_) => {
+            warn!(
+                "Not deleting {} {}/{}: it is not managed by this operator",
+                kind, namespace, name
+            );
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
+
+/// Delete a child already checked for ownership
+///
+/// The delete is conditioned on the child's uid so an object recreated under
+/// the same name since it was read is never removed.
+pub(crate) async fn delete_child<K>(
+    api: &Api<K>,
+    meta: &ObjectMeta,
+    namespace: &str,
+    kind: &str,
+) -> Result<()>
+where
+    K: kube::Resource + Clone + DeserializeOwned + std::fmt::Debug,
+{
+    let name = meta.name.as_deref().unwrap_or_default();
+    let params = DeleteParams {
+        preconditions: Some(Preconditions {
+            uid: meta.uid.clone(),
+            resource_version: None,
+        }),
+        ..DeleteParams::default()
+    };
+    match api.delete(name, &params).await {
+        Ok(_) => {
+            info!("Deleted {} {}/{}", kind, namespace, name);
+            Ok(())
+        }
+        Err(kube::Error::Api(ae)) if ae.code == 404 || ae.code == 409 => Ok(()),
+        Err(e) => Err(Error::kube(format!("Failed to delete {}", kind), e)),
     }
 }
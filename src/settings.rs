@@ -0,0 +1,261 @@
+//! Operator-wide settings loaded from a ConfigMap
+//!
+//! The ConfigMap named by `OPERATOR_CONFIG_MAP` in the operator namespace
+//! holds a `config.yaml` key. It is read at startup and watched afterwards,
+//! so edits take effect on the next reconcile without a restart.
+
+use futures::StreamExt;
+use k8s_openapi::api::core::v1::ConfigMap;
+use kube::runtime::watcher::{self, watcher, Event};
+use kube::runtime::WatchStreamExt;
+use kube::{Api, Client};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::{Error, Result};
+
+/// Default name of the settings ConfigMap
+pub const DEFAULT_CONFIG_MAP_NAME: &str = "kafka-partition-remapper-operator-config";
+
+/// Key holding the settings document inside the ConfigMap
+pub const CONFIG_KEY: &str = "config.yaml";
+
+lazy_static::lazy_static! {
+    static ref CURRENT: RwLock<Arc<OperatorSettings>> =
+        RwLock::new(Arc::new(OperatorSettings::default()));
+}
+
+/// Operator-wide defaults and behaviour
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OperatorSettings {
+    /// Proxy image used when a remapper does not override it
+    pub default_image: String,
+
+    /// Proxy image tag used when a remapper does not override it
+    pub default_image_tag: String,
+
+    /// Resources applied to proxy containers for any unset entry
+    pub default_resources: DefaultResources,
+
+    /// Requeue intervals in seconds
+    pub requeue: RequeueSettings,
+
+    /// Namespaces whose remappers are reconciled (empty means all)
+    pub watch_namespaces: Vec<String>,
+
+    /// Take over fields of child resources owned by other field managers
+    /// instead of reporting a FieldConflict
+    pub force_apply: bool,
+}
+
+impl Default for OperatorSettings {
+    fn default() -> Self {
+        Self {
+            default_image: "ghcr.io/osodevops/kafka-partition-remapper".to_string(),
+            default_image_tag: "latest".to_string(),
+            default_resources: DefaultResources::default(),
+            requeue: RequeueSettings::default(),
+            watch_namespaces: Vec::new(),
+            force_apply: false,
+        }
+    }
+}
+
+impl OperatorSettings {
+    /// Parse settings from YAML, filling unset fields with defaults
+    pub fn from_yaml(yaml: &str) -> Result<Self> {
+        if yaml.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        serde_yaml::from_str(yaml)
+            .map_err(|e| Error::ConfigError(format!("Invalid operator settings: {}", e)))
+    }
+
+    /// Whether remappers in `namespace` should be reconciled
+    pub fn watches_namespace(&self, namespace: &str) -> bool {
+        self.watch_namespaces.is_empty() || self.watch_namespaces.iter().any(|n| n == namespace)
+    }
+}
+
+/// Default proxy container resources
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DefaultResources {
+    /// Default resource requests
+    pub requests: BTreeMap<String, String>,
+
+    /// Default resource limits
+    pub limits: BTreeMap<String, String>,
+}
+
+impl Default for DefaultResources {
+    fn default() -> Self {
+        let mut requests = BTreeMap::new();
+        requests.insert("cpu".to_string(), "100m".to_string());
+        requests.insert("memory".to_string(), "128Mi".to_string());
+        Self {
+            requests,
+            limits: BTreeMap::new(),
+        }
+    }
+}
+
+/// Requeue intervals in seconds
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RequeueSettings {
+    /// While a rollout is converging
+    pub progressing_secs: u64,
+
+    /// While the Kafka cluster is unreachable
+    pub kafka_unreachable_secs: u64,
+
+    /// After a Kubernetes API error
+    pub api_error_secs: u64,
+
+    /// After a configuration or validation error
+    pub invalid_spec_secs: u64,
+
+    /// After any other error
+    pub error_secs: u64,
+
+    /// Longest delay consecutive failures back off to
+    pub max_backoff_secs: u64,
+
+    /// Consecutive failures after which an object is marked Stalled
+    pub retry_budget: u32,
+}
+
+impl Default for RequeueSettings {
+    fn default() -> Self {
+        Self {
+            progressing_secs: 30,
+            kafka_unreachable_secs: 10,
+            api_error_secs: 30,
+            invalid_spec_secs: 300,
+            error_secs: 60,
+            max_backoff_secs: 900,
+            retry_budget: 8,
+        }
+    }
+}
+
+impl RequeueSettings {
+    /// Interval while a rollout is converging
+    pub fn progressing(&self) -> Duration {
+        Duration::from_secs(self.progressing_secs)
+    }
+
+    /// Interval while the Kafka cluster is unreachable
+    pub fn kafka_unreachable(&self) -> Duration {
+        Duration::from_secs(self.kafka_unreachable_secs)
+    }
+
+    /// Interval after a Kubernetes API error
+    pub fn api_error(&self) -> Duration {
+        Duration::from_secs(self.api_error_secs)
+    }
+
+    /// Interval after a configuration or validation error
+    pub fn invalid_spec(&self) -> Duration {
+        Duration::from_secs(self.invalid_spec_secs)
+    }
+
+    /// Interval after any other error
+    pub fn error(&self) -> Duration {
+        Duration::from_secs(self.error_secs)
+    }
+
+    /// Delay after the `failures`-th consecutive error, starting from `base`
+    ///
+    /// The delay doubles with each failure up to `max_backoff_secs` (or
+    /// `base`, if longer). `jitter` in `[0, 1)` then picks a point in its
+    /// upper half, so objects that failed together do not retry together.
+    pub fn backoff(&self, base: Duration, failures: u32, jitter: f64) -> Duration {
+        let max = Duration::from_secs(self.max_backoff_secs).max(base);
+        let delay = base
+            .checked_mul(1 << failures.saturating_sub(1).min(16))
+            .map_or(max, |d| d.min(max));
+        delay / 2 + delay.mul_f64(jitter.clamp(0.0, 1.0) / 2.0)
+    }
+
+    /// Whether `failures` consecutive errors exceed the retry budget
+    pub fn budget_exhausted(&self, failures: u32) -> bool {
+        failures > self.retry_budget
+    }
+}
+
+/// Currently active settings
+pub fn current() -> Arc<OperatorSettings> {
+    CURRENT.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Replace the active settings
+pub fn replace(settings: OperatorSettings) {
+    *CURRENT.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(settings);
+}
+
+/// Load settings from the ConfigMap, keeping defaults if it does not exist
+pub async fn load(client: &Client, namespace: &str, name: &str) -> Result<()> {
+    let config_maps: Api<ConfigMap> = Api::namespaced(client.clone(), namespace);
+    let config_map = config_maps
+        .get_opt(name)
+        .await
+        .map_err(|e| Error::kube("Failed to get operator ConfigMap", e))?;
+
+    match config_map {
+        Some(cm) => apply_config_map(&cm)?,
+        None => info!(
+            "Operator ConfigMap {}/{} not found, using defaults",
+            namespace, name
+        ),
+    }
+    Ok(())
+}
+
+/// Watch the ConfigMap and hot-reload settings on change
+///
+/// Invalid updates are logged and the previous settings are kept.
+pub async fn watch(client: Client, namespace: String, name: String) {
+    let config_maps: Api<ConfigMap> = Api::namespaced(client, &namespace);
+    let config = watcher::Config::default().fields(&format!("metadata.name={}", name));
+
+    watcher(config_maps, config)
+        .default_backoff()
+        .for_each(|event| async move {
+            match event {
+                Ok(Event::Apply(cm)) | Ok(Event::InitApply(cm)) => {
+                    if let Err(e) = apply_config_map(&cm) {
+                        warn!("Keeping previous operator settings: {}", e);
+                    }
+                }
+                Ok(Event::Delete(_)) => {
+                    info!("Operator ConfigMap deleted, reverting to default settings");
+                    replace(OperatorSettings::default());
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Operator ConfigMap watch error: {}", e),
+            }
+        })
+        .await;
+}
+
+fn apply_config_map(config_map: &ConfigMap) -> Result<()> {
+    let yaml = config_map
+        .data
+        .as_ref()
+        .and_then(|d| d.get(CONFIG_KEY))
+        .map(String::as_str)
+        .unwrap_or_default();
+
+    let settings = OperatorSettings::from_yaml(yaml)?;
+    if *current() != settings {
+        info!("Loaded operator settings: {:?}", settings);
+        replace(settings);
+    }
+    Ok(())
+}
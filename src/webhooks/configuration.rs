@@ -0,0 +1,104 @@
+//! Admission webhook configuration manifests
+
+use k8s_openapi::api::admissionregistration::v1::{
+    MutatingWebhook, MutatingWebhookConfiguration, RuleWithOperations, ServiceReference,
+    ValidatingWebhook, ValidatingWebhookConfiguration, WebhookClientConfig,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+use crate::webhooks::{mutating, validating};
+
+/// Name of the generated webhook configuration objects
+pub const WEBHOOK_CONFIGURATION_NAME: &str = "kafka-partition-remapper-operator";
+
+/// Build the ValidatingWebhookConfiguration pointing at the operator Service
+pub fn build_validating_webhook_configuration(
+    service_name: &str,
+    namespace: &str,
+    port: i32,
+) -> ValidatingWebhookConfiguration {
+    ValidatingWebhookConfiguration {
+        metadata: ObjectMeta {
+            name: Some(WEBHOOK_CONFIGURATION_NAME.to_string()),
+            ..Default::default()
+        },
+        webhooks: Some(vec![ValidatingWebhook {
+            name: "validate.kafkapartitionremappers.kafka.oso.sh".to_string(),
+            admission_review_versions: vec!["v1".to_string()],
+            side_effects: "None".to_string(),
+            failure_policy: Some("Fail".to_string()),
+            client_config: client_config(service_name, namespace, port, validating::VALIDATE_PATH),
+            rules: Some(remapper_rules()),
+            ..Default::default()
+        }]),
+    }
+}
+
+/// Build the MutatingWebhookConfiguration pointing at the operator Service
+pub fn build_mutating_webhook_configuration(
+    service_name: &str,
+    namespace: &str,
+    port: i32,
+) -> MutatingWebhookConfiguration {
+    MutatingWebhookConfiguration {
+        metadata: ObjectMeta {
+            name: Some(WEBHOOK_CONFIGURATION_NAME.to_string()),
+            ..Default::default()
+        },
+        webhooks: Some(vec![MutatingWebhook {
+            name: "default.kafkapartitionremappers.kafka.oso.sh".to_string(),
+            admission_review_versions: vec!["v1".to_string()],
+            side_effects: "None".to_string(),
+            failure_policy: Some("Fail".to_string()),
+            reinvocation_policy: Some("IfNeeded".to_string()),
+            client_config: client_config(service_name, namespace, port, mutating::MUTATE_PATH),
+            rules: Some(remapper_rules()),
+            ..Default::default()
+        }]),
+    }
+}
+
+fn client_config(
+    service_name: &str,
+    namespace: &str,
+    port: i32,
+    path: &str,
+) -> WebhookClientConfig {
+    WebhookClientConfig {
+        service: Some(ServiceReference {
+            name: service_name.to_string(),
+            namespace: namespace.to_string(),
+            path: Some(path.to_string()),
+            port: Some(port),
+        }),
+        ..Default::default()
+    }
+}
+
+fn remapper_rules() -> Vec<RuleWithOperations> {
+    vec![RuleWithOperations {
+        api_groups: Some(vec!["kafka.oso.sh".to_string()]),
+        api_versions: Some(vec!["v1alpha1".to_string()]),
+        operations: Some(vec!["CREATE".to_string(), "UPDATE".to_string()]),
+        resources: Some(vec!["kafkapartitionremappers".to_string()]),
+        scope: Some("Namespaced".to_string()),
+    }]
+}
+
+/// Generate webhook configuration YAML manifests
+pub fn generate_webhook_configurations(service_name: &str, namespace: &str) -> Vec<String> {
+    vec![
+        serde_yaml::to_string(&build_mutating_webhook_configuration(
+            service_name,
+            namespace,
+            443,
+        ))
+        .unwrap(),
+        serde_yaml::to_string(&build_validating_webhook_configuration(
+            service_name,
+            namespace,
+            443,
+        ))
+        .unwrap(),
+    ]
+}
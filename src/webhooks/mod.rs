@@ -0,0 +1,9 @@
+//! Admission webhooks for KafkaPartitionRemapper resources
+//!
+//! The webhook server runs alongside the controller so invalid specs are
+//! rejected at admission time instead of failing later in the reconcile loop.
+
+pub mod configuration;
+pub mod mutating;
+pub mod server;
+pub mod validating;
@@ -0,0 +1,149 @@
+//! Mutating admission webhook for KafkaPartitionRemapper resources
+//!
+//! Fills in defaults the operator would otherwise derive at reconcile time so
+//! the stored object reflects what is actually deployed.
+
+use kube::core::admission::{AdmissionRequest, AdmissionResponse};
+use kube::ResourceExt;
+use tracing::warn;
+
+use crate::adapters::{deployment_builder, service_builder};
+use crate::crd::{KafkaPartitionRemapper, ResourceRequirementsSpec};
+
+/// Path the mutating webhook is served on
+pub const MUTATE_PATH: &str = "/mutate-kafkapartitionremapper";
+
+/// Apply defaults and normalization to a KafkaPartitionRemapper
+pub fn apply_defaults(remapper: &mut KafkaPartitionRemapper) {
+    let name = remapper.resource_name();
+    let namespace = remapper
+        .spec
+        .target_namespace
+        .clone()
+        .or(remapper.namespace());
+    let spec = &mut remapper.spec;
+
+    // Advertised addresses default to the Service address or hostname
+    if let (false, Some(ns)) = (name.is_empty(), namespace.as_ref()) {
+        let defaults: Vec<String> = spec
+            .listeners
+            .iter()
+            .map(|l| service_builder::default_advertised_address(&name, ns, spec, l.port))
+            .collect();
+        for (listener, default) in spec.listeners.iter_mut().zip(defaults) {
+            listener.advertised_address.get_or_insert(default);
+        }
+    }
+
+    // Inject recommended resource requests when omitted
+    let pod_template = spec.pod_template.get_or_insert_with(Default::default);
+    let resources = pod_template
+        .resources
+        .get_or_insert_with(|| ResourceRequirementsSpec {
+            limits: Default::default(),
+            requests: Default::default(),
+        });
+    for (resource, value) in deployment_builder::default_resource_requests() {
+        // A default above the user's limit would make the pod invalid
+        let above_limit = resources
+            .limits
+            .get(&resource)
+            .and_then(|limit| deployment_builder::parse_quantity(limit))
+            .zip(deployment_builder::parse_quantity(&value))
+            .is_some_and(|(limit, default)| default > limit);
+        if !above_limit {
+            resources.requests.entry(resource).or_insert(value);
+        }
+    }
+}
+
+/// Spec fields, relative to `spec`, that hold protocol, mechanism or log
+/// level enums
+const CANONICAL_FIELDS: &[&[&str]] = &[
+    &["kafka", "securityProtocol"],
+    &["kafka", "saslSecret"],
+    &["kafka", "failoverClusters"],
+    &["routing"],
+    &["listeners"],
+    &["logging"],
+    &["users"],
+];
+
+/// Respond to an admission request with a JSON patch applying defaults
+///
+/// Requests without an object (deletes) are always allowed unmodified.
+pub fn mutate(req: &AdmissionRequest<KafkaPartitionRemapper>) -> AdmissionResponse {
+    let response = AdmissionResponse::from(req);
+
+    let Some(ref original) = req.object else {
+        return response;
+    };
+
+    let mut defaulted = original.clone();
+
+    // Re-derive advertised addresses that were previously defaulted so they
+    // follow listener port and hostname changes instead of pinning the old
+    // value
+    if let (Some(old), Some(ns)) = (req.old_object.as_ref(), original.namespace()) {
+        let ns = old.spec.target_namespace.clone().unwrap_or(ns);
+        for listener in defaulted.spec.listeners.iter_mut() {
+            let old_default = old
+                .spec
+                .listeners
+                .iter()
+                .find(|l| l.name == listener.name)
+                .map(|l| {
+                    service_builder::default_advertised_address(
+                        &old.resource_name(),
+                        &ns,
+                        &old.spec,
+                        l.port,
+                    )
+                });
+            if old_default.is_some() && listener.advertised_address == old_default {
+                listener.advertised_address = None;
+            }
+        }
+    }
+
+    apply_defaults(&mut defaulted);
+
+    let (mut before, after) = match (
+        serde_json::to_value(original),
+        serde_json::to_value(&defaulted),
+    ) {
+        (Ok(before), Ok(after)) => (before, after),
+        (Err(e), _) | (_, Err(e)) => {
+            warn!("Failed to serialize KafkaPartitionRemapper: {}", e);
+            return response;
+        }
+    };
+
+    // Enum fields also accept spellings the schema does not list, and the
+    // typed object no longer knows which one was submitted, so subtrees
+    // holding them are always rewritten in their canonical form. The
+    // submitted object may also omit these and rely on serde defaults, which
+    // rules out patching paths inside them.
+    for path in CANONICAL_FIELDS {
+        let (field, parents) = path.split_last().expect("non-empty path");
+        let parent = parents
+            .iter()
+            .try_fold(&mut before["spec"], |value, key| value.get_mut(*key));
+        if let Some(parent) = parent.and_then(|p| p.as_object_mut()) {
+            parent.remove(*field);
+        }
+    }
+
+    let patch = json_patch::diff(&before, &after);
+    if patch.0.is_empty() {
+        return response;
+    }
+
+    match response.clone().with_patch(patch) {
+        Ok(patched) => patched,
+        Err(e) => {
+            warn!("Failed to build defaulting patch: {}", e);
+            response
+        }
+    }
+}
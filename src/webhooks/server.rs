@@ -0,0 +1,217 @@
+//! HTTPS server for admission webhooks
+
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use kube::core::admission::{AdmissionRequest, AdmissionResponse, AdmissionReview};
+use kube::core::DynamicObject;
+use tokio::net::TcpListener;
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use tokio_rustls::rustls::{self, ServerConfig};
+use tokio_rustls::TlsAcceptor;
+use tracing::{error, info, warn};
+
+use crate::crd::KafkaPartitionRemapper;
+use crate::webhooks::{mutating, validating};
+
+/// Default HTTPS port for the webhook server
+pub const DEFAULT_WEBHOOK_PORT: u16 = 8443;
+
+/// Default directory containing the mounted webhook certificate secret
+pub const DEFAULT_CERT_DIR: &str = "/etc/webhook/certs";
+
+/// Webhook server settings
+#[derive(Clone, Debug)]
+pub struct WebhookServerConfig {
+    /// Port to listen on
+    pub port: u16,
+    /// PEM encoded certificate chain
+    pub cert_path: PathBuf,
+    /// PEM encoded private key
+    pub key_path: PathBuf,
+}
+
+impl WebhookServerConfig {
+    /// Build the server settings from a certificate directory containing
+    /// `tls.crt` and `tls.key` (the layout of a mounted TLS secret)
+    pub fn from_cert_dir(port: u16, cert_dir: impl AsRef<Path>) -> Self {
+        let cert_dir = cert_dir.as_ref();
+        Self {
+            port,
+            cert_path: cert_dir.join("tls.crt"),
+            key_path: cert_dir.join("tls.key"),
+        }
+    }
+}
+
+/// Load a rustls server configuration from PEM files
+pub fn load_tls_config(cert_path: &Path, key_path: &Path) -> anyhow::Result<ServerConfig> {
+    let cert_pem = std::fs::read(cert_path)?;
+    let certs: Vec<CertificateDer<'static>> =
+        rustls_pemfile::certs(&mut cert_pem.as_slice()).collect::<Result<_, _>>()?;
+    if certs.is_empty() {
+        anyhow::bail!("no certificates found in {}", cert_path.display());
+    }
+
+    let key_pem = std::fs::read(key_path)?;
+    let key: PrivateKeyDer<'static> = rustls_pemfile::private_key(&mut key_pem.as_slice())?
+        .ok_or_else(|| anyhow::anyhow!("no private key found in {}", key_path.display()))?;
+
+    let config =
+        ServerConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+            .with_safe_default_protocol_versions()?
+            .with_no_client_auth()
+            .with_single_cert(certs, key)?;
+
+    Ok(config)
+}
+
+/// Start the webhook HTTPS server
+///
+/// The certificate is reloaded when the mounted files change so that
+/// cert-manager renewals are picked up without restarting the operator.
+pub async fn serve(config: WebhookServerConfig) -> anyhow::Result<()> {
+    let addr = SocketAddr::from(([0, 0, 0, 0], config.port));
+    let listener = TcpListener::bind(addr).await?;
+    info!("Webhook server listening on {}", addr);
+
+    let mut loaded_at = modified_time(&config.cert_path);
+    let mut acceptor = TlsAcceptor::from(Arc::new(load_tls_config(
+        &config.cert_path,
+        &config.key_path,
+    )?));
+
+    loop {
+        let (stream, peer) = listener.accept().await?;
+
+        let current = modified_time(&config.cert_path);
+        if current != loaded_at {
+            match load_tls_config(&config.cert_path, &config.key_path) {
+                Ok(tls) => {
+                    info!("Reloaded webhook TLS certificate");
+                    acceptor = TlsAcceptor::from(Arc::new(tls));
+                    loaded_at = current;
+                }
+                Err(e) => warn!("Failed to reload webhook TLS certificate: {}", e),
+            }
+        }
+
+        let acceptor = acceptor.clone();
+        tokio::spawn(async move {
+            let tls_stream = match acceptor.accept(stream).await {
+                Ok(s) => s,
+                Err(e) => {
+                    warn!("TLS handshake with {} failed: {}", peer, e);
+                    return;
+                }
+            };
+
+            if let Err(e) = http1::Builder::new()
+                .serve_connection(TokioIo::new(tls_stream), service_fn(handle_request))
+                .await
+            {
+                error!("Error serving webhook connection: {}", e);
+            }
+        });
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Handle HTTP requests
+async fn handle_request(
+    req: Request<hyper::body::Incoming>,
+) -> Result<Response<Full<Bytes>>, hyper::Error> {
+    if req.method() != Method::POST {
+        return Ok(status_response(StatusCode::METHOD_NOT_ALLOWED));
+    }
+
+    let path = req.uri().path().to_string();
+    let body = match read_body(req.into_body()).await {
+        Ok(body) => body,
+        Err(response) => return Ok(response),
+    };
+
+    let review = match path.as_str() {
+        validating::VALIDATE_PATH => admit(&body, validating::validate),
+        mutating::MUTATE_PATH => admit(&body, mutating::mutate),
+        _ => return Ok(status_response(StatusCode::NOT_FOUND)),
+    };
+
+    Ok(json_response(&review))
+}
+
+/// Largest AdmissionReview accepted, the API server's own request size limit
+const MAX_BODY_BYTES: usize = 3 * 1024 * 1024;
+
+/// Read a request body, refusing one over `MAX_BODY_BYTES`
+async fn read_body(body: hyper::body::Incoming) -> Result<Bytes, Response<Full<Bytes>>> {
+    match Limited::new(body, MAX_BODY_BYTES).collect().await {
+        Ok(collected) => Ok(collected.to_bytes()),
+        Err(e) if e.is::<LengthLimitError>() => {
+            warn!("Refusing AdmissionReview over {} bytes", MAX_BODY_BYTES);
+            Err(status_response(StatusCode::PAYLOAD_TOO_LARGE))
+        }
+        Err(e) => {
+            warn!("Failed to read AdmissionReview: {}", e);
+            Err(status_response(StatusCode::BAD_REQUEST))
+        }
+    }
+}
+
+/// Decode an AdmissionReview, run the handler and wrap its response
+fn admit<F>(body: &[u8], handler: F) -> AdmissionReview<DynamicObject>
+where
+    F: Fn(&AdmissionRequest<KafkaPartitionRemapper>) -> AdmissionResponse,
+{
+    let review: AdmissionReview<KafkaPartitionRemapper> = match serde_json::from_slice(body) {
+        Ok(r) => r,
+        Err(e) => {
+            warn!("Failed to decode AdmissionReview: {}", e);
+            return AdmissionResponse::invalid(e.to_string()).into_review();
+        }
+    };
+
+    let req: AdmissionRequest<KafkaPartitionRemapper> = match review.try_into() {
+        Ok(r) => r,
+        Err(e) => {
+            warn!("Invalid AdmissionReview: {}", e);
+            return AdmissionResponse::invalid(e.to_string()).into_review();
+        }
+    };
+
+    handler(&req).into_review()
+}
+
+fn json_response(review: &AdmissionReview<DynamicObject>) -> Response<Full<Bytes>> {
+    match serde_json::to_vec(review) {
+        Ok(body) => Response::builder()
+            .status(StatusCode::OK)
+            .header("Content-Type", "application/json")
+            .body(Full::new(Bytes::from(body)))
+            .unwrap(),
+        Err(e) => {
+            error!("Failed to encode AdmissionReview: {}", e);
+            status_response(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+fn status_response(status: StatusCode) -> Response<Full<Bytes>> {
+    Response::builder()
+        .status(status)
+        .body(Full::new(Bytes::from(
+            status.canonical_reason().unwrap_or_default(),
+        )))
+        .unwrap()
+}
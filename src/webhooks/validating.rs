@@ -0,0 +1,41 @@
+//! Validating admission webhook for KafkaPartitionRemapper resources
+
+use kube::core::admission::{AdmissionRequest, AdmissionResponse};
+use tracing::info;
+
+use crate::crd::KafkaPartitionRemapper;
+use crate::reconcilers::remapper;
+
+/// Path the validating webhook is served on
+pub const VALIDATE_PATH: &str = "/validate-kafkapartitionremapper";
+
+/// Run the reconciler validation against an admission request
+///
+/// Updates are additionally checked for renames and breaking mapping
+/// changes. Requests without an object (deletes) are always allowed.
+pub fn validate(req: &AdmissionRequest<KafkaPartitionRemapper>) -> AdmissionResponse {
+    let response = AdmissionResponse::from(req);
+
+    let Some(ref remapper) = req.object else {
+        return response;
+    };
+
+    let result = remapper::validate(remapper).and_then(|()| match req.old_object {
+        Some(ref old) => remapper::validate_name_change(remapper, &old.spec)
+            .and_then(|()| remapper::validate_mapping_change(remapper, &old.spec.mapping)),
+        None => Ok(()),
+    });
+
+    match result {
+        Ok(()) => response,
+        Err(e) => {
+            info!(
+                "Rejected KafkaPartitionRemapper {}/{}: {}",
+                req.namespace.as_deref().unwrap_or_default(),
+                req.name,
+                e
+            );
+            response.deny(e.to_string())
+        }
+    }
+}
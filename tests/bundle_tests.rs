@@ -0,0 +1,185 @@
+//! Integration tests for the generated install bundle
+
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::rbac::v1::PolicyRule;
+use kafka_partition_remapper_operator::bundle::{self, BundleOptions};
+use kafka_partition_remapper_operator::crd::{
+    KafkaClusterRef, KafkaPartitionRemapTask, KafkaPartitionRemapper, KafkaRemapperTopicOverride,
+};
+use kafka_partition_remapper_operator::olm;
+use kube::CustomResourceExt;
+use std::collections::BTreeSet;
+
+fn kinds(documents: &[String]) -> Vec<String> {
+    documents
+        .iter()
+        .map(|d| {
+            let value: serde_yaml::Value = serde_yaml::from_str(d).unwrap();
+            value["kind"].as_str().unwrap().to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn bundle_installs_operator_after_its_crds() {
+    let documents = bundle::generate_bundle(&BundleOptions::default()).unwrap();
+    let kinds = kinds(&documents);
+    let first_non_crd = kinds
+        .iter()
+        .position(|k| k != "CustomResourceDefinition")
+        .unwrap();
+    assert!(first_non_crd > 0);
+    assert_eq!(
+        &kinds[first_non_crd..],
+        [
+            "Namespace",
+            "ServiceAccount",
+            "ClusterRole",
+            "ClusterRoleBinding",
+            "Deployment",
+            "Service"
+        ]
+    );
+}
+
+#[test]
+fn bundle_with_webhooks_mounts_certificates_and_registers_both_webhooks() {
+    let options = BundleOptions {
+        namespace: "kpr".to_string(),
+        image: "example.com/kpr:test".to_string(),
+        webhooks: true,
+    };
+    let documents = bundle::generate_bundle(&options).unwrap();
+    let installed = kinds(&documents);
+    assert!(installed.contains(&"MutatingWebhookConfiguration".to_string()));
+    assert!(installed.contains(&"ValidatingWebhookConfiguration".to_string()));
+
+    let deployment = bundle::build_operator_deployment(&options);
+    assert_eq!(deployment.metadata.namespace.as_deref(), Some("kpr"));
+    let pod = deployment.spec.unwrap().template.spec.unwrap();
+    let container = &pod.containers[0];
+    assert_eq!(container.image.as_deref(), Some("example.com/kpr:test"));
+    let env = container.env.as_ref().unwrap();
+    assert!(env
+        .iter()
+        .any(|e| e.name == "OPERATOR_IMAGE" && e.value.as_deref() == Some("example.com/kpr:test")));
+    assert!(env.iter().any(|e| e.name == "WEBHOOK_ENABLED"));
+    assert!(pod.volumes.unwrap().iter().any(|v| v
+        .secret
+        .as_ref()
+        .and_then(|s| s.secret_name.as_deref())
+        == Some("kafka-partition-remapper-operator-webhook-cert")));
+
+    let documents = bundle::generate_bundle(&BundleOptions::default()).unwrap();
+    assert!(!kinds(&documents).iter().any(|k| k.contains("Webhook")));
+    let _: Deployment = serde_yaml::from_str(
+        documents
+            .iter()
+            .find(|d| d.contains("kind: Deployment"))
+            .unwrap(),
+    )
+    .unwrap();
+}
+
+/// Every (group, resource, verb) a set of rules grants
+fn permissions(rules: &[PolicyRule]) -> BTreeSet<(String, String, String)> {
+    let mut permissions = BTreeSet::new();
+    for rule in rules {
+        for group in rule.api_groups.iter().flatten() {
+            for resource in rule.resources.iter().flatten() {
+                for verb in &rule.verbs {
+                    permissions.insert((group.clone(), resource.clone(), verb.clone()));
+                }
+            }
+        }
+    }
+    permissions
+}
+
+/// The Helm chart must grant exactly what the bundle does, apart from its
+/// optional leader election rule
+#[test]
+fn helm_cluster_role_matches_bundle_rules() {
+    let template = std::fs::read_to_string(
+        "deploy/helm/kafka-partition-remapper-operator/templates/clusterrole.yaml",
+    )
+    .unwrap();
+    let rendered: String = template
+        .lines()
+        .filter(|l| !l.contains("{{"))
+        .map(|l| format!("{}\n", l))
+        .collect();
+    let role: serde_yaml::Value = serde_yaml::from_str(&rendered).unwrap();
+    let chart_rules: Vec<PolicyRule> = serde_yaml::from_value(role["rules"].clone()).unwrap();
+    let mut chart = permissions(&chart_rules);
+    chart.retain(|(group, _, _)| group != "coordination.k8s.io");
+
+    assert_eq!(chart, permissions(&bundle::operator_rules()));
+}
+
+#[test]
+fn olm_examples_are_valid_custom_resources() {
+    let examples = olm::alm_examples();
+    let examples = examples.as_array().unwrap();
+    for example in examples {
+        let parsed = match example["kind"].as_str().unwrap() {
+            "KafkaPartitionRemapper" => {
+                serde_json::from_value::<KafkaPartitionRemapper>(example.clone()).map(|_| ())
+            }
+            "KafkaClusterRef" => {
+                serde_json::from_value::<KafkaClusterRef>(example.clone()).map(|_| ())
+            }
+            "KafkaRemapperTopicOverride" => {
+                serde_json::from_value::<KafkaRemapperTopicOverride>(example.clone()).map(|_| ())
+            }
+            "KafkaPartitionRemapTask" => {
+                serde_json::from_value::<KafkaPartitionRemapTask>(example.clone()).map(|_| ())
+            }
+            other => panic!("unexpected example kind {}", other),
+        };
+        assert!(parsed.is_ok(), "{}: {:?}", example["kind"], parsed);
+    }
+    assert_eq!(examples.len(), 4);
+}
+
+#[test]
+fn olm_csv_owns_every_crd_and_grants_operator_rules() {
+    let csv = olm::build_cluster_service_version("example.com/kpr:test").unwrap();
+    let owned: BTreeSet<_> = csv["spec"]["customresourcedefinitions"]["owned"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|o| o["name"].as_str().unwrap().to_string())
+        .collect();
+    let crds: BTreeSet<_> = [
+        KafkaPartitionRemapper::crd(),
+        KafkaClusterRef::crd(),
+        KafkaRemapperTopicOverride::crd(),
+        KafkaPartitionRemapTask::crd(),
+    ]
+    .into_iter()
+    .map(|crd| crd.metadata.name.unwrap())
+    .collect();
+    assert_eq!(owned, crds);
+
+    let install = &csv["spec"]["install"]["spec"];
+    let rules: Vec<PolicyRule> =
+        serde_json::from_value(install["clusterPermissions"][0]["rules"].clone()).unwrap();
+    assert_eq!(rules, bundle::operator_rules());
+    let deployment = &install["deployments"][0]["spec"]["template"]["spec"]["containers"][0];
+    assert_eq!(deployment["image"], "example.com/kpr:test");
+    assert!(deployment["env"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|e| e["name"] == "WATCH_NAMESPACES"));
+
+    let files = olm::generate_olm_bundle("example.com/kpr:test", "stable").unwrap();
+    let paths: Vec<_> = files.iter().map(|(path, _)| path.as_str()).collect();
+    assert!(
+        paths.contains(&"manifests/kafka-partition-remapper-operator.clusterserviceversion.yaml")
+    );
+    assert!(paths.contains(&"manifests/kafka.oso.sh_kafkapartitionremappers.yaml"));
+    assert!(paths.contains(&"metadata/annotations.yaml"));
+    assert_eq!(paths.len(), 6);
+}
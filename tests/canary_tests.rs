@@ -0,0 +1,244 @@
+//! Integration tests for canary rollouts
+//!
+//! These tests verify the canary Deployment and how a canary is judged over
+//! its bake period.
+
+use chrono::{Duration as ChronoDuration, Utc};
+use k8s_openapi::api::apps::v1::{
+    Deployment, DeploymentCondition, DeploymentSpec, DeploymentStatus,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kafka_partition_remapper_operator::adapters::canary;
+use kafka_partition_remapper_operator::crd::{
+    CanaryStatus, KafkaPartitionRemapper, KafkaPartitionRemapperSpec,
+};
+use kafka_partition_remapper_operator::reconcilers::remapper;
+use std::time::Duration;
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn canary_remapper() -> KafkaPartitionRemapper {
+    let spec: KafkaPartitionRemapperSpec = serde_json::from_value(serde_json::json!({
+        "replicas": 4,
+        "kafka": { "bootstrapServers": ["kafka:9092"] },
+        "mapping": { "virtualPartitions": 1000, "physicalPartitions": 100 },
+        "rolloutStrategy": { "type": "Canary", "canaryReplicas": 2, "bakeTimeSeconds": 120 }
+    }))
+    .expect("valid spec");
+
+    KafkaPartitionRemapper {
+        metadata: ObjectMeta {
+            name: Some("orders-proxy".to_string()),
+            namespace: Some("kafka".to_string()),
+            uid: Some("1234".to_string()),
+            ..Default::default()
+        },
+        spec,
+        status: None,
+    }
+}
+
+/// A canary Deployment with `ready` of its two replicas ready
+fn canary_deployment(ready: i32) -> Deployment {
+    Deployment {
+        metadata: ObjectMeta {
+            generation: Some(1),
+            ..Default::default()
+        },
+        spec: Some(DeploymentSpec {
+            replicas: Some(2),
+            ..Default::default()
+        }),
+        status: Some(DeploymentStatus {
+            observed_generation: Some(1),
+            replicas: Some(2),
+            updated_replicas: Some(2),
+            ready_replicas: Some(ready),
+            ..Default::default()
+        }),
+    }
+}
+
+fn baking_since(minutes_ago: i64) -> CanaryStatus {
+    CanaryStatus {
+        config_hash: "abc".to_string(),
+        state: "Baking".to_string(),
+        healthy_since: Some(Utc::now() - ChronoDuration::minutes(minutes_ago)),
+        message: None,
+    }
+}
+
+const BAKE: Duration = Duration::from_secs(120);
+
+// ============================================================================
+// Builder Tests
+// ============================================================================
+
+#[test]
+fn canary_deployment_selects_only_canary_pods() {
+    let remapper = canary_remapper();
+    let deployment =
+        canary::build_canary_deployment(&remapper, "orders-proxy-canary-config", "abc", None);
+
+    assert_eq!(
+        deployment.metadata.name.as_deref(),
+        Some("orders-proxy-canary")
+    );
+    let spec = deployment.spec.unwrap();
+    assert_eq!(spec.replicas, Some(2));
+
+    let selector = spec.selector.match_labels.unwrap();
+    assert_eq!(
+        selector.get(canary::CANARY_LABEL).map(String::as_str),
+        Some("true")
+    );
+    let pod_labels = spec.template.metadata.unwrap().labels.unwrap();
+    assert_eq!(
+        pod_labels
+            .get("app.kubernetes.io/instance")
+            .map(String::as_str),
+        Some("orders-proxy")
+    );
+    assert_eq!(
+        pod_labels.get(canary::CANARY_LABEL).map(String::as_str),
+        Some("true")
+    );
+}
+
+#[test]
+fn canary_objects_are_recognised_as_owned_for_removal() {
+    let remapper = canary_remapper();
+    let deployment =
+        canary::build_canary_deployment(&remapper, "orders-proxy-canary-config", "abc", None);
+    assert!(remapper::is_owned_by(&remapper, &deployment.metadata));
+
+    let config_map = remapper::build_config_map(&remapper, "kafka").unwrap();
+    assert!(remapper::is_owned_by(&remapper, &config_map.metadata));
+
+    let mut other = remapper.clone();
+    other.metadata.uid = Some("5678".to_string());
+    assert!(!remapper::is_owned_by(&other, &deployment.metadata));
+}
+
+#[test]
+fn rollout_strategy_is_excluded_from_config_hash() {
+    let remapper = canary_remapper();
+    let mut rolling = remapper.clone();
+    rolling.spec.rollout_strategy = None;
+
+    assert_eq!(
+        remapper::calculate_config_hash(&remapper),
+        remapper::calculate_config_hash(&rolling)
+    );
+}
+
+// ============================================================================
+// Evaluation Tests
+// ============================================================================
+
+#[test]
+fn canary_progresses_then_bakes_then_passes() {
+    let now = Utc::now();
+
+    let progressing = canary::evaluate(None, "abc", &canary_deployment(1), 0, BAKE, now);
+    assert_eq!(progressing.state, "Progressing");
+    assert!(progressing.healthy_since.is_none());
+
+    let baking = canary::evaluate(
+        Some(&progressing),
+        "abc",
+        &canary_deployment(2),
+        0,
+        BAKE,
+        now,
+    );
+    assert_eq!(baking.state, "Baking");
+    assert_eq!(baking.healthy_since, Some(now));
+
+    let passed = canary::evaluate(
+        Some(&baking_since(3)),
+        "abc",
+        &canary_deployment(2),
+        0,
+        BAKE,
+        Utc::now(),
+    );
+    assert_eq!(passed.state, "Passed");
+}
+
+#[test]
+fn canary_fails_on_restarts_or_unready_during_bake() {
+    let now = Utc::now();
+
+    let restarted = canary::evaluate(None, "abc", &canary_deployment(2), 1, BAKE, now);
+    assert_eq!(restarted.state, "Failed");
+
+    let unready = canary::evaluate(
+        Some(&baking_since(1)),
+        "abc",
+        &canary_deployment(1),
+        0,
+        BAKE,
+        now,
+    );
+    assert_eq!(unready.state, "Failed");
+    assert!(unready.message.unwrap().contains("bake period"));
+}
+
+#[test]
+fn canary_fails_when_progress_deadline_exceeded() {
+    let mut deployment = canary_deployment(0);
+    deployment.status.as_mut().unwrap().conditions = Some(vec![DeploymentCondition {
+        type_: "Progressing".to_string(),
+        status: "False".to_string(),
+        reason: Some("ProgressDeadlineExceeded".to_string()),
+        ..Default::default()
+    }]);
+
+    let status = canary::evaluate(None, "abc", &deployment, 0, BAKE, Utc::now());
+    assert_eq!(status.state, "Failed");
+}
+
+#[test]
+fn failed_canary_is_kept_until_spec_changes() {
+    let failed = CanaryStatus {
+        config_hash: "abc".to_string(),
+        state: "Failed".to_string(),
+        healthy_since: None,
+        message: Some("Canary pods restarted 3 times".to_string()),
+    };
+
+    let same = canary::evaluate(
+        Some(&failed),
+        "abc",
+        &canary_deployment(2),
+        0,
+        BAKE,
+        Utc::now(),
+    );
+    assert_eq!(same, failed);
+
+    let changed = canary::evaluate(
+        Some(&failed),
+        "def",
+        &canary_deployment(2),
+        0,
+        BAKE,
+        Utc::now(),
+    );
+    assert_eq!(changed.state, "Baking");
+    assert_eq!(changed.config_hash, "def");
+}
+
+#[test]
+fn canary_condition_is_false_only_on_failure() {
+    let baking = canary::canary_condition(&baking_since(0));
+    assert_eq!(baking.type_, "CanaryRollout");
+    assert_eq!(baking.status, "True");
+
+    let mut failed = baking_since(0);
+    failed.state = "Failed".to_string();
+    assert_eq!(canary::canary_condition(&failed).status, "False");
+}
@@ -0,0 +1,147 @@
+//! Integration tests for certificate expiry tracking
+//!
+//! These tests verify that certificate expiry is read from PEM data and
+//! turned into the CertificatesFresh condition.
+
+use chrono::{Duration as ChronoDuration, TimeZone, Utc};
+use kafka_partition_remapper_operator::adapters::certificates;
+use kafka_partition_remapper_operator::crd::{CertificateStatus, KafkaPartitionRemapperSpec};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+/// Self-signed certificate valid until 2035-01-01T00:00:00Z (UTCTime)
+const PROXY_CERT: &str = "-----BEGIN CERTIFICATE-----
+MIIBjjCCATOgAwIBAgIUGjEWPotS1RBw5AsFFKgTXzewNOUwCgYIKoZIzj0EAwIw
+HDEaMBgGA1UEAwwRcHJveHkuZXhhbXBsZS5jb20wHhcNMjUwMTAxMDAwMDAwWhcN
+MzUwMTAxMDAwMDAwWjAcMRowGAYDVQQDDBFwcm94eS5leGFtcGxlLmNvbTBZMBMG
+ByqGSM49AgEGCCqGSM49AwEHA0IABOmS0+34YqnprO2JdA7MWu9NuUZYpFkigJhZ
+iSNSkCh6M3gtI9IXjsgdwW5wa+QQimn4XQkgUcLMcKOwEGvV0VmjUzBRMB0GA1Ud
+DgQWBBTKvGdSVfuboGN6UTGPTpq5HSqldDAfBgNVHSMEGDAWgBTKvGdSVfuboGN6
+UTGPTpq5HSqldDAPBgNVHRMBAf8EBTADAQH/MAoGCCqGSM49BAMCA0kAMEYCIQCZ
+hmDWssTHmxoXC4t8wxcIwdufZYdykEXf32jc9lDidAIhALFAdC/0g+HFgyf4vbCs
+4EXvIdKhVgLiLQvwpLUuOKfG
+-----END CERTIFICATE-----
+";
+
+/// Self-signed certificate valid until 2051-06-01T12:00:00Z (GeneralizedTime)
+const CA_CERT: &str = "-----BEGIN CERTIFICATE-----
+MIIBfTCCASOgAwIBAgIUN5J6TE3/MAgE1f8+/3HCK3A4L88wCgYIKoZIzj0EAwIw
+EzERMA8GA1UEAwwIa2Fma2EtY2EwIBcNMjUwMTAxMDAwMDAwWhgPMjA1MTA2MDEx
+MjAwMDBaMBMxETAPBgNVBAMMCGthZmthLWNhMFkwEwYHKoZIzj0CAQYIKoZIzj0D
+AQcDQgAEN9g5iQizD75qnika2W+eRmn2p867tKIsXBN/Psj14Dd01hkTdi7Hvczz
+obd4Kn7iXjQeARFhZvmYORm93nQ3n6NTMFEwHQYDVR0OBBYEFO+bgqLRH7SjwfRM
+2fB7bz7gmFHTMB8GA1UdIwQYMBaAFO+bgqLRH7SjwfRM2fB7bz7gmFHTMA8GA1Ud
+EwEB/wQFMAMBAf8wCgYIKoZIzj0EAwIDSAAwRQIhAM4wDaNp8nowqE19TqP4lM3r
+tciyniBybYNGrg/x+HeOAiB5h7+1gp99JOJt0c4j1DP2id9/wlJFTuSPg+29p6uS
+Og==
+-----END CERTIFICATE-----
+";
+
+fn certificate(key: &str, not_after: chrono::DateTime<Utc>) -> CertificateStatus {
+    CertificateStatus {
+        secret_name: "proxy-tls".to_string(),
+        key: key.to_string(),
+        not_after,
+    }
+}
+
+// ============================================================================
+// Parsing Tests
+// ============================================================================
+
+#[test]
+fn not_after_is_read_from_utc_and_generalized_time() {
+    assert_eq!(
+        certificates::pem_not_after(PROXY_CERT.as_bytes()),
+        Some(Utc.with_ymd_and_hms(2035, 1, 1, 0, 0, 0).unwrap())
+    );
+    assert_eq!(
+        certificates::pem_not_after(CA_CERT.as_bytes()),
+        Some(Utc.with_ymd_and_hms(2051, 6, 1, 12, 0, 0).unwrap())
+    );
+}
+
+#[test]
+fn malformed_certificates_are_ignored() {
+    assert_eq!(certificates::pem_not_after(b"not a certificate"), None);
+    assert_eq!(certificates::der_not_after(&[0x30, 0x82, 0xff]), None);
+}
+
+#[test]
+fn referenced_certificates_cover_broker_and_listener_secrets() {
+    let spec: KafkaPartitionRemapperSpec = serde_json::from_value(serde_json::json!({
+        "listeners": [{
+            "name": "external",
+            "security": {
+                "protocol": "SSL",
+                "tls": {
+                    "certificateSecret": { "name": "proxy-tls" },
+                    "clientCaSecret": { "name": "client-ca" }
+                }
+            }
+        }],
+        "kafka": {
+            "bootstrapServers": ["kafka:9093"],
+            "securityProtocol": "SSL",
+            "tlsSecret": { "name": "kafka-tls", "certKey": "user.crt" }
+        },
+        "mapping": { "virtualPartitions": 100, "physicalPartitions": 10 }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        spec.referenced_certificates(),
+        vec![
+            ("client-ca".to_string(), "ca.crt".to_string()),
+            ("kafka-tls".to_string(), "ca.crt".to_string()),
+            ("kafka-tls".to_string(), "user.crt".to_string()),
+            ("proxy-tls".to_string(), "tls.crt".to_string()),
+        ]
+    );
+}
+
+// ============================================================================
+// Condition Tests
+// ============================================================================
+
+#[test]
+fn certificates_fresh_reports_earliest_expiry() {
+    let now = Utc::now();
+    let fresh = certificates::certificates_fresh_condition(
+        &[
+            certificate("tls.crt", now + ChronoDuration::days(90)),
+            certificate("ca.crt", now + ChronoDuration::days(30)),
+        ],
+        now,
+    );
+    assert_eq!(fresh.type_, "CertificatesFresh");
+    assert_eq!(fresh.status, "True");
+    assert!(fresh.message.unwrap().contains("proxy-tls/ca.crt"));
+
+    let expiring = certificates::certificates_fresh_condition(
+        &[certificate("tls.crt", now + ChronoDuration::days(2))],
+        now,
+    );
+    assert_eq!(expiring.status, "False");
+    assert_eq!(expiring.reason.as_deref(), Some("CertificateExpiring"));
+
+    let expired = certificates::certificates_fresh_condition(
+        &[certificate("tls.crt", now - ChronoDuration::hours(1))],
+        now,
+    );
+    assert_eq!(expired.reason.as_deref(), Some("CertificateExpired"));
+}
+
+#[test]
+fn recheck_happens_when_renewal_window_opens() {
+    let now = Utc::now();
+    let certs = [certificate("tls.crt", now + ChronoDuration::days(10))];
+
+    let after = certificates::recheck_after(&certs, now).unwrap();
+    assert_eq!(after.as_secs(), 3 * 24 * 60 * 60);
+
+    let expired = [certificate("tls.crt", now - ChronoDuration::days(1))];
+    assert!(certificates::recheck_after(&expired, now).is_none());
+}
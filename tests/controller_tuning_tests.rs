@@ -0,0 +1,49 @@
+//! Integration tests for controller concurrency and rate limit tuning
+
+use kafka_partition_remapper_operator::controllers::ControllerTuning;
+use std::collections::HashMap;
+use std::time::Duration;
+
+fn tuning(vars: &[(&str, &str)]) -> ControllerTuning {
+    let vars: HashMap<String, String> = vars
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    ControllerTuning::from_vars(|name| vars.get(name).cloned())
+}
+
+#[test]
+fn unset_variables_keep_defaults() {
+    assert_eq!(tuning(&[]), ControllerTuning::default());
+    assert_eq!(
+        tuning(&[("MAX_CONCURRENT_RECONCILES", "lots")]),
+        ControllerTuning::default()
+    );
+}
+
+#[test]
+fn variables_override_defaults() {
+    let tuned = tuning(&[
+        ("MAX_CONCURRENT_RECONCILES", "50"),
+        ("KUBE_CLIENT_QPS", "100"),
+        ("KUBE_CLIENT_BURST", "200"),
+        ("WATCH_PAGE_SIZE", "0"),
+        ("WATCH_RESYNC_SECS", "600"),
+    ]);
+    assert_eq!(tuned.max_concurrent_reconciles, 50);
+    assert_eq!(tuned.watch_page_size, None);
+    assert_eq!(tuned.watcher_config().page_size, None);
+    assert_eq!(tuned.resync_interval, Some(Duration::from_secs(600)));
+    assert_eq!(
+        tuned.client_rate_limit(),
+        Some((200, Duration::from_secs(2)))
+    );
+}
+
+#[test]
+fn zero_qps_disables_the_rate_limit() {
+    let disabled = tuning(&[("KUBE_CLIENT_QPS", "0"), ("WATCH_RESYNC_SECS", "0")]);
+    assert_eq!(disabled.client_rate_limit(), None);
+    assert_eq!(disabled.resync_interval, None);
+    assert_eq!(tuning(&[("KUBE_CLIENT_QPS", "-1")]).client_qps, 20.0);
+}
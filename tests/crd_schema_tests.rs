@@ -0,0 +1,139 @@
+//! Integration tests for the generated CustomResourceDefinitions
+
+use kafka_partition_remapper_operator::crd::{
+    self, KafkaClusterRef, KafkaPartitionRemapper, KafkaRemapperTopicOverride,
+};
+use kube::CustomResourceExt;
+
+#[test]
+fn remapper_crd_exposes_scale_subresource() {
+    let crd = KafkaPartitionRemapper::crd();
+    let version = &crd.spec.versions[0];
+    let scale = version
+        .subresources
+        .as_ref()
+        .and_then(|s| s.scale.as_ref())
+        .expect("scale subresource should be defined");
+
+    assert_eq!(scale.spec_replicas_path, ".spec.replicas");
+    assert_eq!(scale.status_replicas_path, ".status.replicas");
+    assert_eq!(
+        scale.label_selector_path.as_deref(),
+        Some(".status.selector")
+    );
+}
+
+#[test]
+fn cluster_ref_crd_is_cluster_scoped() {
+    let crd = KafkaClusterRef::crd();
+    assert_eq!(crd.spec.scope, "Cluster");
+    assert_eq!(crd.spec.names.kind, "KafkaClusterRef");
+}
+
+#[test]
+fn topic_override_crd_is_namespaced() {
+    let crd = KafkaRemapperTopicOverride::crd();
+    assert_eq!(crd.spec.scope, "Namespaced");
+    assert_eq!(crd.spec.names.kind, "KafkaRemapperTopicOverride");
+}
+
+#[test]
+fn remapper_crd_lists_enum_values() {
+    let crd = KafkaPartitionRemapper::crd();
+    let schema = serde_json::to_value(&crd.spec.versions[0].schema).unwrap();
+    let spec = &schema["openAPIV3Schema"]["properties"]["spec"]["properties"];
+
+    assert_eq!(
+        spec["kafka"]["properties"]["securityProtocol"]["enum"],
+        serde_json::json!(["PLAINTEXT", "SSL", "SASL_PLAINTEXT", "SASL_SSL"])
+    );
+    assert_eq!(
+        spec["logging"]["properties"]["level"]["enum"],
+        serde_json::json!(["trace", "debug", "info", "warn", "error"])
+    );
+    assert_eq!(
+        spec["service"]["properties"]["type"]["enum"],
+        serde_json::json!(["ClusterIP", "NodePort", "LoadBalancer"])
+    );
+}
+
+/// The API server must reject malformed affinity and security contexts, so
+/// they carry the Kubernetes schemas rather than preserving unknown fields
+#[test]
+fn remapper_crd_types_pod_template_affinity_and_security_contexts() {
+    let crd = KafkaPartitionRemapper::crd();
+    let schema = serde_json::to_value(&crd.spec.versions[0].schema).unwrap();
+    let pod_template =
+        &schema["openAPIV3Schema"]["properties"]["spec"]["properties"]["podTemplate"];
+    let fields = &pod_template["properties"];
+
+    for field in ["affinity", "securityContext", "containerSecurityContext"] {
+        assert!(
+            fields[field]
+                .get("x-kubernetes-preserve-unknown-fields")
+                .is_none(),
+            "{} preserves unknown fields",
+            field
+        );
+    }
+    assert_eq!(
+        fields["affinity"]["properties"]["podAntiAffinity"]["properties"]
+            ["preferredDuringSchedulingIgnoredDuringExecution"]["type"],
+        "array"
+    );
+    assert_eq!(
+        fields["securityContext"]["properties"]["runAsUser"]["type"],
+        "integer"
+    );
+    assert_eq!(
+        fields["containerSecurityContext"]["properties"]["allowPrivilegeEscalation"]["type"],
+        "boolean"
+    );
+
+    let required = pod_template["required"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    for field in ["affinity", "securityContext", "containerSecurityContext"] {
+        assert!(!required.contains(&serde_json::json!(field)), "{}", field);
+    }
+}
+
+#[test]
+fn crds_are_listed_in_the_kafka_category() {
+    for crd in crd::crds() {
+        let categories = crd.spec.names.categories.unwrap_or_default();
+        assert!(categories.contains(&"kafka".to_string()));
+    }
+    let remapper = KafkaPartitionRemapper::crd();
+    assert_eq!(
+        remapper.spec.names.categories,
+        Some(vec!["all".to_string(), "kafka".to_string()])
+    );
+}
+
+#[test]
+fn remapper_crd_prints_suspend_and_ratio_columns() {
+    let crd = KafkaPartitionRemapper::crd();
+    let columns = crd.spec.versions[0]
+        .additional_printer_columns
+        .clone()
+        .unwrap_or_default();
+    let column = |name: &str| {
+        columns
+            .iter()
+            .find(|c| c.name == name)
+            .map(|c| c.json_path.clone())
+    };
+    assert_eq!(column("Ratio").as_deref(), Some(".status.ratio"));
+    assert_eq!(column("Suspend").as_deref(), Some(".spec.suspend"));
+}
+
+#[test]
+fn json_crds_match_yaml_crds() {
+    for (yaml, json) in crd::generate_crds().iter().zip(crd::generate_crds_json()) {
+        let yaml: serde_json::Value = serde_yaml::from_str(yaml).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(yaml, json);
+    }
+}
@@ -0,0 +1,35 @@
+//! Integration tests for the health and readiness state
+
+use kafka_partition_remapper_operator::controllers::WatchScope;
+use kafka_partition_remapper_operator::metrics::health;
+use std::time::Duration;
+
+#[test]
+fn readiness_waits_for_every_watch_and_health_for_reconciles() {
+    // Nothing has started yet
+    assert!(!health::is_ready());
+
+    health::expect_watches(2);
+    health::watch_ready();
+    assert!(!health::is_ready());
+    health::watch_ready();
+    assert!(health::is_ready());
+
+    // With several namespaces each kind runs one controller per namespace,
+    // and readiness waits for every one of them
+    let scope = WatchScope::new("team-a,team-b,team-c", None);
+    health::expect_watches(2 * scope.controller_count());
+    for _ in 0..5 {
+        health::watch_ready();
+    }
+    assert!(!health::is_ready());
+    health::watch_ready();
+    assert!(health::is_ready());
+
+    // Without a limit the reconcile age never fails the health check
+    assert!(health::is_healthy(None));
+    health::start();
+    health::record_reconcile();
+    assert!(health::last_reconcile_age() < Duration::from_secs(5));
+    assert!(health::is_healthy(Some(Duration::from_secs(60))));
+}
@@ -0,0 +1,98 @@
+//! Integration tests for importing proxy configurations
+
+use kafka_partition_remapper_operator::adapters::remapper_config;
+use kafka_partition_remapper_operator::crd::{KafkaPartitionRemapper, KafkaPartitionRemapperSpec};
+use kafka_partition_remapper_operator::import::import_proxy_config;
+
+#[test]
+fn generated_config_imports_back_to_the_same_spec() {
+    let spec: KafkaPartitionRemapperSpec = serde_json::from_value(serde_json::json!({
+        "kafka": {
+            "bootstrapServers": ["kafka-0:9092", "kafka-1:9092"],
+            "requestTimeoutMs": 45000,
+            "maxApiVersion": 9
+        },
+        "mapping": {
+            "virtualPartitions": 12,
+            "physicalPartitions": 4,
+            "offsetRange": 1048576,
+            "topics": [{ "topic": "orders-.*", "virtualPartitions": 8, "physicalPartitions": 2 }],
+            "topicRenames": [{ "pattern": "orders-(.*)", "replacement": "prod.orders.$1" }]
+        },
+        "listeners": [{ "name": "internal", "port": 19092 }],
+        "metrics": { "port": 9191 },
+        "logging": { "level": "debug", "json": true }
+    }))
+    .unwrap();
+    let config = remapper_config::build_proxy_config(&spec, &["proxy:19092".to_string()]).unwrap();
+
+    let import = import_proxy_config(&config, "orders", Some("kafka")).unwrap();
+    assert!(import.warnings.is_empty(), "{:?}", import.warnings);
+    let remapper: KafkaPartitionRemapper = serde_json::from_value(import.manifest).unwrap();
+    assert_eq!(remapper.metadata.namespace.as_deref(), Some("kafka"));
+
+    let imported = remapper.spec;
+    assert_eq!(
+        serde_json::to_value(&imported.mapping).unwrap(),
+        serde_json::to_value(&spec.mapping).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_value(&imported.kafka).unwrap(),
+        serde_json::to_value(&spec.kafka).unwrap()
+    );
+    assert_eq!(imported.listeners[0].name, "internal");
+    assert_eq!(imported.listeners[0].port, 19092);
+    assert_eq!(imported.metrics.port, 9191);
+    assert!(imported.logging.json);
+}
+
+#[test]
+fn secrets_and_unknown_sections_are_reported() {
+    let config = r#"
+listen:
+  address: "0.0.0.0:9092"
+  advertised_address: "proxy.example.com:9092"
+kafka:
+  bootstrap_servers: ["kafka:9093"]
+  security_protocol: sasl_ssl
+  sasl:
+    mechanism: PLAIN
+    username: app
+    password: secret
+mapping:
+  virtual_partitions: 100
+  physical_partitions: 10
+tuning:
+  buffer_size: 65536
+"#;
+    let import = import_proxy_config(config, "legacy", None).unwrap();
+    assert_eq!(
+        import.manifest["spec"]["listeners"][0]["advertisedAddress"],
+        "proxy.example.com:9092"
+    );
+    assert_eq!(
+        import.manifest["spec"]["kafka"]["securityProtocol"],
+        "SASL_SSL"
+    );
+    assert!(import
+        .warnings
+        .iter()
+        .any(|w| w.starts_with("kafka.sasl") && w.contains("spec.kafka.saslSecret")));
+    assert!(import.warnings.iter().any(|w| w.starts_with("tuning")));
+}
+
+#[test]
+fn malformed_configs_are_rejected() {
+    assert!(import_proxy_config("kafka: {}", "x", None).is_err());
+
+    let config = r#"
+listeners:
+  - address: "no-port"
+kafka:
+  bootstrap_servers: ["kafka:9092"]
+mapping:
+  virtual_partitions: 100
+  physical_partitions: 10
+"#;
+    assert!(import_proxy_config(config, "x", None).is_err());
+}
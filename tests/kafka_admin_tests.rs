@@ -0,0 +1,303 @@
+//! Integration tests for the Kafka admin adapter
+//!
+//! These tests verify Metadata request encoding, response decoding and the
+//! partition consistency check, including a round trip against a fake broker.
+
+use kafka_partition_remapper_operator::adapters::kafka_admin::{
+    self, KafkaConnection, TopicMetadata,
+};
+use kafka_partition_remapper_operator::crd::MappingSpec;
+use kafka_partition_remapper_operator::reconcilers::consistency_audit;
+use kafka_partition_remapper_operator::reconcilers::remapper::{self, KafkaProbe};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn mapping(physical_partitions: u32, topics: serde_json::Value) -> MappingSpec {
+    serde_json::from_value(serde_json::json!({
+        "virtualPartitions": physical_partitions * 10,
+        "physicalPartitions": physical_partitions,
+        "topics": topics
+    }))
+    .expect("valid mapping")
+}
+
+fn topic(name: &str, partitions: usize, internal: bool) -> TopicMetadata {
+    TopicMetadata {
+        name: name.to_string(),
+        partitions,
+        internal,
+    }
+}
+
+fn put_string(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(&(value.len() as i16).to_be_bytes());
+    buf.extend_from_slice(value.as_bytes());
+}
+
+/// Encode a Metadata v1 response body for the given (name, partitions, internal) topics
+fn metadata_response(correlation_id: i32, topics: &[(&str, i32, bool)]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&correlation_id.to_be_bytes());
+
+    // One broker
+    buf.extend_from_slice(&1i32.to_be_bytes());
+    buf.extend_from_slice(&0i32.to_be_bytes());
+    put_string(&mut buf, "broker-0");
+    buf.extend_from_slice(&9092i32.to_be_bytes());
+    buf.extend_from_slice(&(-1i16).to_be_bytes());
+
+    // Controller id
+    buf.extend_from_slice(&0i32.to_be_bytes());
+
+    buf.extend_from_slice(&(topics.len() as i32).to_be_bytes());
+    for (name, partitions, internal) in topics {
+        buf.extend_from_slice(&0i16.to_be_bytes());
+        put_string(&mut buf, name);
+        buf.push(u8::from(*internal));
+        buf.extend_from_slice(&partitions.to_be_bytes());
+        for p in 0..*partitions {
+            buf.extend_from_slice(&0i16.to_be_bytes());
+            buf.extend_from_slice(&p.to_be_bytes());
+            buf.extend_from_slice(&0i32.to_be_bytes());
+            buf.extend_from_slice(&1i32.to_be_bytes());
+            buf.extend_from_slice(&0i32.to_be_bytes());
+            buf.extend_from_slice(&1i32.to_be_bytes());
+            buf.extend_from_slice(&0i32.to_be_bytes());
+        }
+    }
+    buf
+}
+
+// ============================================================================
+// Protocol Tests
+// ============================================================================
+
+#[test]
+fn metadata_request_has_frame_and_header() {
+    let request = kafka_admin::encode_metadata_request(7);
+
+    let size = i32::from_be_bytes(request[0..4].try_into().unwrap());
+    assert_eq!(size as usize, request.len() - 4);
+    assert_eq!(i16::from_be_bytes(request[4..6].try_into().unwrap()), 3);
+    assert_eq!(i16::from_be_bytes(request[6..8].try_into().unwrap()), 1);
+    assert_eq!(i32::from_be_bytes(request[8..12].try_into().unwrap()), 7);
+    // Null topic array requests all topics
+    assert_eq!(&request[request.len() - 4..], &(-1i32).to_be_bytes());
+}
+
+#[test]
+fn metadata_response_is_decoded() {
+    let response = metadata_response(1, &[("orders", 3, false), ("__consumer_offsets", 2, true)]);
+
+    let topics = kafka_admin::decode_metadata_response(&response).unwrap();
+    assert_eq!(
+        topics,
+        vec![
+            topic("orders", 3, false),
+            topic("__consumer_offsets", 2, true)
+        ]
+    );
+}
+
+#[test]
+fn truncated_metadata_response_is_rejected() {
+    let response = metadata_response(1, &[("orders", 3, false)]);
+    assert!(kafka_admin::decode_metadata_response(&response[..response.len() - 3]).is_err());
+}
+
+#[test]
+fn find_coordinator_round_trip() {
+    let request = kafka_admin::encode_find_coordinator_request(4, "billing");
+    assert_eq!(i16::from_be_bytes(request[4..6].try_into().unwrap()), 10);
+    assert!(request.ends_with(b"billing"));
+
+    let mut response = Vec::new();
+    response.extend_from_slice(&4i32.to_be_bytes());
+    response.extend_from_slice(&0i16.to_be_bytes());
+    response.extend_from_slice(&2i32.to_be_bytes());
+    put_string(&mut response, "broker-2");
+    response.extend_from_slice(&9093i32.to_be_bytes());
+    assert_eq!(
+        kafka_admin::decode_find_coordinator_response(&response).unwrap(),
+        "broker-2:9093"
+    );
+
+    // GROUP_COORDINATOR_NOT_AVAILABLE
+    response[4..6].copy_from_slice(&15i16.to_be_bytes());
+    assert!(kafka_admin::decode_find_coordinator_response(&response).is_err());
+}
+
+#[test]
+fn describe_groups_response_lists_member_hosts() {
+    let request = kafka_admin::encode_describe_groups_request(5, &["billing".to_string()]);
+    assert_eq!(i16::from_be_bytes(request[4..6].try_into().unwrap()), 15);
+
+    let mut response = Vec::new();
+    response.extend_from_slice(&5i32.to_be_bytes());
+    response.extend_from_slice(&1i32.to_be_bytes());
+    response.extend_from_slice(&0i16.to_be_bytes());
+    put_string(&mut response, "billing");
+    put_string(&mut response, "Stable");
+    put_string(&mut response, "consumer");
+    put_string(&mut response, "range");
+    response.extend_from_slice(&2i32.to_be_bytes());
+    for host in ["/10.0.0.5", "/10.0.0.6"] {
+        put_string(&mut response, "member");
+        put_string(&mut response, "client");
+        put_string(&mut response, host);
+        response.extend_from_slice(&0i32.to_be_bytes());
+        response.extend_from_slice(&0i32.to_be_bytes());
+    }
+
+    let groups = kafka_admin::decode_describe_groups_response(&response).unwrap();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].group_id, "billing");
+    assert_eq!(groups[0].state, "Stable");
+    assert_eq!(groups[0].member_hosts, vec!["10.0.0.5", "10.0.0.6"]);
+}
+
+// ============================================================================
+// Consistency Check Tests
+// ============================================================================
+
+#[test]
+fn mismatches_respect_overrides_and_skip_internal_topics() {
+    let mapping = mapping(
+        10,
+        serde_json::json!([
+            { "topic": "payments", "physicalPartitions": 20 },
+            { "topic": "logs-.*", "physicalPartitions": 5 }
+        ]),
+    );
+    let topics = vec![
+        topic("orders", 10, false),
+        topic("payments", 20, false),
+        topic("logs-app", 6, false),
+        topic("users", 12, false),
+        topic("__consumer_offsets", 50, true),
+    ];
+
+    let mismatches = kafka_admin::find_partition_mismatches(&mapping, &topics);
+    let names: Vec<_> = mismatches.iter().map(|m| m.topic.as_str()).collect();
+    assert_eq!(names, vec!["logs-app", "users"]);
+    assert_eq!(
+        mismatches[0].to_string(),
+        "logs-app has 6 partitions, expected 5"
+    );
+}
+
+#[test]
+fn topics_consistent_condition_reflects_probe_result() {
+    let mapping = mapping(10, serde_json::json!([]));
+
+    let ok = remapper::topics_consistent_condition(
+        &mapping,
+        &KafkaProbe::Reachable(vec![topic("orders", 10, false)]),
+    );
+    assert_eq!(ok.type_, "TopicsConsistent");
+    assert_eq!(ok.status, "True");
+
+    let mismatch = remapper::topics_consistent_condition(
+        &mapping,
+        &KafkaProbe::Reachable(vec![topic("orders", 4, false)]),
+    );
+    assert_eq!(mismatch.status, "False");
+    assert_eq!(mismatch.reason.as_deref(), Some("PartitionCountMismatch"));
+
+    let unreachable = remapper::topics_consistent_condition(
+        &mapping,
+        &KafkaProbe::Unreachable("connection refused".to_string()),
+    );
+    assert_eq!(unreachable.status, "Unknown");
+}
+
+#[test]
+fn audit_reports_mismatched_and_missing_topics() {
+    let mapping = mapping(10, serde_json::json!([]));
+    let required = vec!["orders".to_string(), "payments".to_string()];
+
+    let findings = consistency_audit::audit_findings(
+        &mapping,
+        &required,
+        &KafkaProbe::Reachable(vec![topic("orders", 10, false)]),
+    );
+    assert_eq!(findings, vec!["payments does not exist"]);
+
+    let findings = consistency_audit::audit_findings(
+        &mapping,
+        &required[..1],
+        &KafkaProbe::Reachable(vec![topic("orders", 4, false)]),
+    );
+    assert_eq!(findings, vec!["orders has 4 partitions, expected 10"]);
+
+    let findings = consistency_audit::audit_findings(
+        &mapping,
+        &required,
+        &KafkaProbe::Unreachable("connection refused".to_string()),
+    );
+    assert_eq!(
+        findings,
+        vec!["Topic metadata unavailable: connection refused"]
+    );
+}
+
+#[test]
+fn kafka_reachable_condition_reflects_probe_result() {
+    let reachable = remapper::kafka_reachable_condition(&KafkaProbe::Reachable(vec![]));
+    assert_eq!(reachable.type_, "KafkaReachable");
+    assert_eq!(reachable.status, "True");
+
+    let unreachable = remapper::kafka_reachable_condition(&KafkaProbe::Unreachable(
+        "connection refused".to_string(),
+    ));
+    assert_eq!(unreachable.status, "False");
+    assert_eq!(unreachable.reason.as_deref(), Some("ConnectionFailed"));
+    assert_eq!(unreachable.message.as_deref(), Some("connection refused"));
+
+    // An unsupported probe must not block the reconcile
+    let unsupported =
+        remapper::kafka_reachable_condition(&KafkaProbe::Unsupported("SCRAM-SHA-512".to_string()));
+    assert_eq!(unsupported.status, "Unknown");
+}
+
+#[tokio::test]
+async fn metadata_is_fetched_from_broker() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut size = [0u8; 4];
+        socket.read_exact(&mut size).await.unwrap();
+        let mut request = vec![0u8; i32::from_be_bytes(size) as usize];
+        socket.read_exact(&mut request).await.unwrap();
+        let correlation_id = i32::from_be_bytes(request[4..8].try_into().unwrap());
+
+        let body = metadata_response(correlation_id, &[("orders", 4, false)]);
+        socket
+            .write_all(&(body.len() as i32).to_be_bytes())
+            .await
+            .unwrap();
+        socket.write_all(&body).await.unwrap();
+    });
+
+    let connection = KafkaConnection {
+        bootstrap_servers: vec!["127.0.0.1:1".to_string(), address],
+        connection_timeout: Duration::from_secs(5),
+        request_timeout: Duration::from_secs(5),
+        tls: None,
+        sasl: None,
+        broker_address_map: Default::default(),
+        broker_address_host: None,
+    };
+
+    let topics = kafka_admin::fetch_topic_metadata(&connection)
+        .await
+        .unwrap();
+    assert_eq!(topics, vec![topic("orders", 4, false)]);
+}
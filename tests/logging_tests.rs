@@ -0,0 +1,20 @@
+//! Integration tests for log format and filter handling
+
+use kafka_partition_remapper_operator::logging::{self, LogFormat};
+
+#[test]
+fn log_formats_parse_case_insensitively() {
+    assert_eq!("json".parse::<LogFormat>(), Ok(LogFormat::Json));
+    assert_eq!("TEXT".parse::<LogFormat>(), Ok(LogFormat::Text));
+    assert!("logfmt".parse::<LogFormat>().is_err());
+}
+
+#[test]
+fn filters_are_validated_and_need_initialized_logging() {
+    assert!(logging::set_filter("info,kube=verbose").is_err());
+    assert_eq!(
+        logging::set_filter("debug"),
+        Err("logging is not initialized".to_string())
+    );
+    assert_eq!(logging::current_filter(), None);
+}
@@ -0,0 +1,125 @@
+//! Integration tests for partition and offset translation
+
+use kafka_partition_remapper_operator::crd::MappingSpec;
+use kafka_partition_remapper_operator::mapping::{self, Mapping, MAX_KAFKA_OFFSET};
+use proptest::prelude::*;
+
+/// Valid layouts: physical partitions, compression ratio and offset range
+fn mappings() -> impl Strategy<Value = Mapping> {
+    (1u32..64, 1u32..32, 20u32..41).prop_map(|(physical, ratio, range_bits)| {
+        Mapping::new(physical * ratio, physical, 1 << range_bits).unwrap()
+    })
+}
+
+#[test]
+fn virtual_partitions_are_striped_over_physical_partitions() {
+    let mapping = Mapping::new(12, 4, 1000).unwrap();
+    assert_eq!(mapping.compression_ratio(), 3);
+    assert_eq!(mapping.virtual_partitions_of(1).unwrap(), vec![1, 5, 9]);
+
+    assert_eq!(mapping.virtual_to_physical(9, 2042).unwrap(), (1, 42));
+    assert_eq!(mapping.physical_to_virtual(9, 42).unwrap(), 2042);
+}
+
+#[test]
+fn out_of_window_positions_are_rejected() {
+    let mapping = Mapping::new(12, 4, 1000).unwrap();
+    assert!(mapping.virtual_to_physical(12, 0).is_err());
+    assert!(mapping.virtual_to_physical(9, 1999).is_err());
+    assert!(mapping.virtual_to_physical(9, 3000).is_err());
+    assert!(mapping.physical_to_virtual(9, 1000).is_err());
+    assert!(mapping.virtual_partitions_of(4).is_err());
+}
+
+#[test]
+fn invalid_layouts_are_rejected() {
+    assert!(Mapping::new(10, 0, 1000).is_err());
+    assert!(Mapping::new(10, 4, 1000).is_err());
+    assert!(Mapping::new(2, 4, 1000).is_err());
+    // Two groups of 2^62 offsets reach the largest Kafka offset exactly
+    assert!(Mapping::new(2, 1, 1 << 62).is_ok());
+    assert!(Mapping::new(4, 1, 1 << 62).is_err());
+    assert!(Mapping::new(2, 1, MAX_KAFKA_OFFSET).is_err());
+}
+
+fn mapping_spec() -> MappingSpec {
+    serde_json::from_value(serde_json::json!({
+        "virtualPartitions": 12,
+        "physicalPartitions": 4,
+        "offsetRange": 1000,
+        "topics": [{ "topic": "orders-.*", "virtualPartitions": 8, "physicalPartitions": 2 }],
+        "topicRenames": [{ "pattern": "orders-(.*)", "replacement": "prod.orders.$1" }]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn simulation_applies_matching_override_and_rename() {
+    let simulation = mapping::simulate(&mapping_spec(), "orders-eu", 5, 2007).unwrap();
+    assert_eq!(simulation.matched_override.as_deref(), Some("orders-.*"));
+    assert_eq!(simulation.physical_topic, "prod.orders.eu");
+    assert_eq!(simulation.physical_partitions, 2);
+    assert_eq!(
+        (simulation.physical_partition, simulation.physical_offset),
+        (1, 7)
+    );
+
+    let simulation = mapping::simulate(&mapping_spec(), "payments", 5, 1007).unwrap();
+    assert_eq!(simulation.matched_override, None);
+    assert_eq!(simulation.physical_topic, "payments");
+    assert_eq!(
+        (simulation.physical_partition, simulation.physical_offset),
+        (1, 7)
+    );
+}
+
+#[test]
+fn simulation_rejects_positions_outside_the_mapping() {
+    assert!(mapping::simulate(&mapping_spec(), "orders-eu", 8, 0).is_err());
+    assert!(mapping::simulate(&mapping_spec(), "payments", 5, 7).is_err());
+}
+
+proptest! {
+    #[test]
+    fn translation_round_trips(mapping in mappings(), partition: u32, offset: u64) {
+        let partition = partition % mapping.virtual_partitions();
+        let physical_offset = offset % mapping.offset_range();
+
+        let virtual_offset = mapping.physical_to_virtual(partition, physical_offset).unwrap();
+        prop_assert!(virtual_offset <= MAX_KAFKA_OFFSET);
+        prop_assert_eq!(
+            mapping.virtual_to_physical(partition, virtual_offset).unwrap(),
+            (mapping.physical_partition(partition).unwrap(), physical_offset)
+        );
+    }
+
+    #[test]
+    fn co_located_partitions_never_share_offsets(
+        mapping in mappings(),
+        physical: u32,
+        a: u32,
+        b: u32,
+        offset: u64,
+    ) {
+        let homes = mapping
+            .virtual_partitions_of(physical % mapping.physical_partitions())
+            .unwrap();
+        let (a, b) = (homes[a as usize % homes.len()], homes[b as usize % homes.len()]);
+        prop_assume!(a != b);
+        let physical_offset = offset % mapping.offset_range();
+
+        prop_assert_ne!(
+            mapping.physical_to_virtual(a, physical_offset).unwrap(),
+            mapping.physical_to_virtual(b, physical_offset).unwrap()
+        );
+    }
+
+    #[test]
+    fn every_virtual_partition_has_one_home(mapping in mappings()) {
+        let mut homes: Vec<u32> = (0..mapping.physical_partitions())
+            .flat_map(|p| mapping.virtual_partitions_of(p).unwrap())
+            .collect();
+        homes.sort_unstable();
+        prop_assert_eq!(homes, (0..mapping.virtual_partitions()).collect::<Vec<_>>());
+    }
+}
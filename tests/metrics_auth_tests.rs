@@ -0,0 +1,29 @@
+//! Integration tests for metrics endpoint authentication
+
+use kafka_partition_remapper_operator::metrics::MetricsAuth;
+
+#[test]
+fn no_auth_allows_anonymous_scrapes() {
+    assert!(MetricsAuth::None.authorizes(None));
+}
+
+#[test]
+fn bearer_token_must_match() {
+    let auth = MetricsAuth::Bearer("s3cret".to_string());
+    assert!(auth.authorizes(Some("Bearer s3cret")));
+    assert!(!auth.authorizes(Some("Bearer s3cre")));
+    assert!(!auth.authorizes(Some("Basic s3cret")));
+    assert!(!auth.authorizes(None));
+}
+
+#[test]
+fn basic_credentials_must_match() {
+    let auth = MetricsAuth::Basic {
+        username: "prometheus".to_string(),
+        password: "scrape".to_string(),
+    };
+    // base64("prometheus:scrape")
+    assert!(auth.authorizes(Some("Basic cHJvbWV0aGV1czpzY3JhcGU=")));
+    assert!(!auth.authorizes(Some("Basic cHJvbWV0aGV1czp3cm9uZw==")));
+    assert!(!auth.authorizes(None));
+}
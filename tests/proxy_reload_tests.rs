@@ -0,0 +1,202 @@
+//! Integration tests for proxy hot reload
+//!
+//! These tests verify which config changes are hot-reloadable, the reload
+//! request format and a round trip against a fake proxy.
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kafka_partition_remapper_operator::adapters::proxy_reload;
+use kafka_partition_remapper_operator::crd::{
+    KafkaPartitionRemapper, KafkaPartitionRemapperSpec, LogLevel, MaintenanceMode, ReloadPolicy,
+};
+use kafka_partition_remapper_operator::reconcilers::remapper;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn create_remapper(reload_policy: &str) -> KafkaPartitionRemapper {
+    let spec: KafkaPartitionRemapperSpec = serde_json::from_value(serde_json::json!({
+        "kafka": { "bootstrapServers": ["kafka:9092"] },
+        "mapping": { "virtualPartitions": 1000, "physicalPartitions": 100 },
+        "reloadPolicy": reload_policy
+    }))
+    .expect("valid spec");
+
+    KafkaPartitionRemapper {
+        metadata: ObjectMeta {
+            name: Some("orders-proxy".to_string()),
+            namespace: Some("kafka".to_string()),
+            ..Default::default()
+        },
+        spec,
+        status: None,
+    }
+}
+
+/// Serve one reload request, replying with `status_line`, and return the
+/// request received
+async fn fake_proxy(status_line: &'static str) -> (String, tokio::task::JoinHandle<Vec<u8>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    let handle = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !request.ends_with(b"level: debug\n") {
+            let n = socket.read(&mut buf).await.unwrap();
+            assert!(n > 0, "connection closed before the body was sent");
+            request.extend_from_slice(&buf[..n]);
+        }
+        socket
+            .write_all(format!("{}\r\nContent-Length: 0\r\n\r\n", status_line).as_bytes())
+            .await
+            .unwrap();
+        request
+    });
+
+    (address, handle)
+}
+
+// ============================================================================
+// Config Hash Tests
+// ============================================================================
+
+#[test]
+fn hot_policy_excludes_log_level_and_topic_overrides_from_config_hash() {
+    let hot = create_remapper("Hot");
+    assert_eq!(hot.spec.reload_policy, ReloadPolicy::Hot);
+
+    let mut changed = hot.clone();
+    changed.spec.logging.level = LogLevel::Debug;
+    changed.spec.mapping.topics = serde_json::from_value(serde_json::json!([
+        { "topic": "payments", "physicalPartitions": 20 }
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        remapper::calculate_config_hash(&hot),
+        remapper::calculate_config_hash(&changed)
+    );
+    assert_ne!(
+        remapper::calculate_reload_hash(&hot),
+        remapper::calculate_reload_hash(&changed)
+    );
+
+    // Other changes still roll the pods
+    let mut rolled = hot.clone();
+    rolled.spec.listeners[0].max_connections = Some(2000);
+    assert_ne!(
+        remapper::calculate_config_hash(&hot),
+        remapper::calculate_config_hash(&rolled)
+    );
+}
+
+#[test]
+fn hot_policy_reloads_maintenance_mode_without_rolling() {
+    let hot = create_remapper("Hot");
+    let mut frozen = hot.clone();
+    frozen.spec.maintenance_mode = Some(MaintenanceMode::ReadOnly);
+
+    assert_eq!(
+        remapper::calculate_config_hash(&hot),
+        remapper::calculate_config_hash(&frozen)
+    );
+    assert_ne!(
+        remapper::calculate_reload_hash(&hot),
+        remapper::calculate_reload_hash(&frozen)
+    );
+
+    let rolling = create_remapper("Rolling");
+    let mut frozen = rolling.clone();
+    frozen.spec.maintenance_mode = Some(MaintenanceMode::Blocked);
+    assert_ne!(
+        remapper::calculate_config_hash(&rolling),
+        remapper::calculate_config_hash(&frozen)
+    );
+}
+
+#[test]
+fn rolling_policy_rolls_on_log_level_change() {
+    let rolling = create_remapper("Rolling");
+    let mut changed = rolling.clone();
+    changed.spec.logging.level = LogLevel::Debug;
+
+    assert_ne!(
+        remapper::calculate_config_hash(&rolling),
+        remapper::calculate_config_hash(&changed)
+    );
+}
+
+#[test]
+fn hot_policy_requires_metrics_endpoint() {
+    let mut hot = create_remapper("Hot");
+    assert!(remapper::validate(&hot).is_ok());
+
+    hot.spec.metrics.enabled = false;
+    let err = remapper::validate(&hot).unwrap_err();
+    assert!(err.to_string().contains("metrics.enabled"));
+}
+
+// ============================================================================
+// Protocol Tests
+// ============================================================================
+
+#[test]
+fn reload_request_carries_config_body() {
+    let request = proxy_reload::encode_reload_request("10.0.0.5", "logging:\n  level: debug\n");
+    let request = String::from_utf8(request).unwrap();
+
+    assert!(request.starts_with("POST /-/reload HTTP/1.1\r\n"));
+    assert!(request.contains("Host: 10.0.0.5\r\n"));
+    assert!(request.contains("Content-Length: 24\r\n"));
+    assert!(request.ends_with("\r\n\r\nlogging:\n  level: debug\n"));
+}
+
+#[test]
+fn status_code_is_parsed_from_status_line() {
+    assert_eq!(
+        proxy_reload::parse_status_code(b"HTTP/1.1 204 No Content\r\n\r\n").unwrap(),
+        204
+    );
+    assert!(proxy_reload::parse_status_code(b"garbage").is_err());
+}
+
+#[test]
+fn pod_config_key_follows_workload_kind() {
+    let mut remapper = create_remapper("Hot");
+    assert_eq!(
+        proxy_reload::pod_config_key(&remapper.spec, "orders-proxy-abc"),
+        "config.yaml"
+    );
+
+    remapper.spec.workload = kafka_partition_remapper_operator::crd::WorkloadKind::StatefulSet;
+    assert_eq!(
+        proxy_reload::pod_config_key(&remapper.spec, "orders-proxy-2"),
+        "orders-proxy-2.yaml"
+    );
+}
+
+#[tokio::test]
+async fn reload_round_trip_against_proxy() {
+    let (address, handle) = fake_proxy("HTTP/1.1 200 OK").await;
+
+    proxy_reload::reload(&address, "logging:\n  level: debug\n")
+        .await
+        .unwrap();
+
+    let request = String::from_utf8(handle.await.unwrap()).unwrap();
+    assert!(request.starts_with("POST /-/reload"));
+}
+
+#[tokio::test]
+async fn rejected_reload_is_an_error() {
+    let (address, _handle) = fake_proxy("HTTP/1.1 400 Bad Request").await;
+
+    let err = proxy_reload::reload(&address, "logging:\n  level: debug\n")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("HTTP 400"));
+}
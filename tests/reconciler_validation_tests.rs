@@ -3,12 +3,26 @@
 //! These tests verify that the validation functions for KafkaPartitionRemapper
 //! correctly accept valid specs and reject invalid ones.
 
-use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use chrono::Utc;
+use k8s_openapi::api::core::v1::{
+    ContainerState, ContainerStateTerminated, ContainerStateWaiting, ContainerStatus, Pod,
+    PodCondition, PodStatus, Secret,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+use k8s_openapi::ByteString;
+use kafka_partition_remapper_operator::controllers::Retries;
 use kafka_partition_remapper_operator::crd::{
-    KafkaClusterSpec, KafkaPartitionRemapper, KafkaPartitionRemapperSpec, ListenSpec, LoggingSpec,
-    MappingSpec, MetricsSpec, ServiceSpec,
+    safe_resource_name, AccessSpec, BrokerAddressMode, ClientSecuritySpec, Condition,
+    HeaderInjectionSpec, KafkaClusterSpec, KafkaPartitionRemapper, KafkaPartitionRemapperSpec,
+    KafkaPartitionRemapperStatus, ListenerSpec, LogLevel, LoggingSpec, MappingSpec, MetricsSpec,
+    RolloutStrategySpec, RolloutStrategyType, SaslMechanism, SecurityProtocol, ServiceSpec,
+    ServiceType, TopicMappingOverride, TuningSpec, ADOPT_ANNOTATION,
+    ALLOW_BREAKING_CHANGE_ANNOTATION, MAX_RESOURCE_NAME_LEN, OWNER_NAMESPACE_LABEL,
+    OWNER_NAME_LABEL, PAUSED_ANNOTATION,
 };
 use kafka_partition_remapper_operator::reconcilers::remapper;
+use kafka_partition_remapper_operator::Error;
+use std::collections::BTreeMap;
 
 // ============================================================================
 // Test Helpers
@@ -16,20 +30,28 @@ use kafka_partition_remapper_operator::reconcilers::remapper;
 
 fn valid_kafka_cluster() -> KafkaClusterSpec {
     KafkaClusterSpec {
+        cluster_ref: None,
         bootstrap_servers: vec!["kafka:9092".to_string()],
-        security_protocol: "PLAINTEXT".to_string(),
+        security_protocol: SecurityProtocol::Plaintext,
         tls_secret: None,
         sasl_secret: None,
+        kerberos: None,
         connection_timeout_ms: 10000,
         request_timeout_ms: 30000,
         metadata_refresh_interval_secs: 30,
+        broker_address_map: BTreeMap::new(),
+        broker_address_mode: BrokerAddressMode::Advertised,
+        max_api_version: None,
+        api_versions: BTreeMap::new(),
+        failover_clusters: Vec::new(),
     }
 }
 
-fn valid_listen_spec() -> ListenSpec {
-    ListenSpec {
+fn valid_listener_spec() -> ListenerSpec {
+    ListenerSpec {
+        name: "kafka".to_string(),
         port: 9092,
-        max_connections: 1000,
+        max_connections: Some(1000),
         advertised_address: None,
         security: None,
     }
@@ -41,6 +63,8 @@ fn valid_mapping_spec() -> MappingSpec {
         physical_partitions: 100,
         offset_range: 1 << 40, // 2^40
         topics: vec![],
+        topic_renames: vec![],
+        header_injection: None,
     }
 }
 
@@ -55,25 +79,62 @@ fn default_metadata(name: &str) -> ObjectMeta {
 fn valid_remapper_spec() -> KafkaPartitionRemapperSpec {
     KafkaPartitionRemapperSpec {
         replicas: 2,
-        listen: valid_listen_spec(),
+        workload: Default::default(),
+        listeners: vec![valid_listener_spec()],
+        users: vec![],
         kafka: valid_kafka_cluster(),
+        routing: None,
+        access: None,
+        quotas: None,
+        consumer_groups: None,
+        tuning: None,
+        schema_registry: None,
         mapping: valid_mapping_spec(),
         metrics: MetricsSpec {
             enabled: true,
             port: 9090,
+            service: None,
         },
         logging: LoggingSpec {
-            level: "info".to_string(),
+            level: LogLevel::Info,
             json: false,
         },
+        audit_logging: None,
         service: ServiceSpec {
-            type_: "ClusterIP".to_string(),
+            type_: ServiceType::ClusterIP,
             annotations: Default::default(),
             load_balancer_ip: None,
             external_traffic_policy: None,
+            external_hostname: None,
+            ip_families: vec![],
+            ip_family_policy: None,
+            internal_traffic_policy: None,
+            load_balancer_class: None,
+            load_balancer_source_ranges: vec![],
+            allocate_load_balancer_node_ports: None,
+            node_ports: Default::default(),
+            session_affinity: None,
+            session_affinity_timeout_seconds: None,
         },
+        external_service: None,
+        gateway: None,
         pod_template: None,
+        common_labels: Default::default(),
+        common_annotations: Default::default(),
+        probes: None,
+        readiness: None,
+        pod_disruption_budget: None,
+        autoscaling: None,
+        sharding: None,
+        audit: None,
+        rollout_strategy: None,
+        reload_policy: Default::default(),
+        deletion_policy: Default::default(),
         suspend: false,
+        maintenance_mode: None,
+        name_override: None,
+        fullname_override: None,
+        target_namespace: None,
     }
 }
 
@@ -85,6 +146,18 @@ fn create_remapper(spec: KafkaPartitionRemapperSpec) -> KafkaPartitionRemapper {
     }
 }
 
+/// Validate the valid spec after `modify` has changed it
+fn validate_with(modify: impl FnOnce(&mut KafkaPartitionRemapperSpec)) -> Result<(), String> {
+    let mut spec = valid_remapper_spec();
+    modify(&mut spec);
+    remapper::validate(&create_remapper(spec)).map_err(|e| e.to_string())
+}
+
+/// Deserialize a spec fragment written as JSON
+fn from_json<T: serde::de::DeserializeOwned>(value: serde_json::Value) -> T {
+    serde_json::from_value(value).unwrap()
+}
+
 // ============================================================================
 // Basic Validation Tests
 // ============================================================================
@@ -280,43 +353,54 @@ fn remapper_valid_replicas_pass_validation() {
 // ============================================================================
 
 #[test]
-fn remapper_invalid_security_protocol_fails_validation() {
-    let mut spec = valid_remapper_spec();
-    spec.kafka.security_protocol = "INVALID".to_string();
-
-    let remapper = create_remapper(spec);
-    let result = remapper::validate(&remapper);
+fn remapper_invalid_security_protocol_is_rejected() {
+    let mut value = serde_json::to_value(valid_kafka_cluster()).unwrap();
+    value["securityProtocol"] = serde_json::json!("INVALID");
+    let err = serde_json::from_value::<KafkaClusterSpec>(value).unwrap_err();
+    assert!(err.to_string().contains("unknown variant `INVALID`"));
+}
 
-    assert!(result.is_err());
-    assert!(result
-        .unwrap_err()
-        .to_string()
-        .to_lowercase()
-        .contains("security"));
+#[test]
+fn remapper_lowercase_security_protocol_is_accepted() {
+    let mut value = serde_json::to_value(valid_kafka_cluster()).unwrap();
+    value["securityProtocol"] = serde_json::json!("sasl_ssl");
+    let kafka: KafkaClusterSpec = serde_json::from_value(value).unwrap();
+    assert_eq!(kafka.security_protocol, SecurityProtocol::SaslSsl);
+    assert_eq!(
+        serde_json::to_value(&kafka).unwrap()["securityProtocol"],
+        "SASL_SSL"
+    );
 }
 
 #[test]
 fn remapper_valid_security_protocols_pass_validation() {
-    let valid_protocols = vec!["PLAINTEXT", "SSL", "SASL_PLAINTEXT", "SASL_SSL"];
+    let valid_protocols = [
+        SecurityProtocol::Plaintext,
+        SecurityProtocol::Ssl,
+        SecurityProtocol::SaslPlaintext,
+        SecurityProtocol::SaslSsl,
+    ];
 
     for protocol in valid_protocols {
         let mut spec = valid_remapper_spec();
-        spec.kafka.security_protocol = protocol.to_string();
+        spec.kafka.security_protocol = protocol;
 
         // Add required secrets for protocols that need them
-        if protocol.contains("SSL") {
+        if protocol.uses_tls() {
             spec.kafka.tls_secret = Some(kafka_partition_remapper_operator::crd::TlsSecretRef {
                 name: "tls-secret".to_string(),
+                secret_provider_class: None,
                 ca_key: "ca.crt".to_string(),
                 cert_key: None,
                 key_key: None,
                 insecure_skip_verify: false,
             });
         }
-        if protocol.contains("SASL") {
+        if protocol.uses_sasl() {
             spec.kafka.sasl_secret = Some(kafka_partition_remapper_operator::crd::SaslSecretRef {
                 name: "sasl-secret".to_string(),
-                mechanism: "PLAIN".to_string(),
+                secret_provider_class: None,
+                mechanism: SaslMechanism::Plain,
                 username_key: "username".to_string(),
                 password_key: "password".to_string(),
             });
@@ -325,7 +409,7 @@ fn remapper_valid_security_protocols_pass_validation() {
         let remapper = create_remapper(spec);
         assert!(
             remapper::validate(&remapper).is_ok(),
-            "Protocol '{}' should be valid",
+            "Protocol {:?} should be valid",
             protocol
         );
     }
@@ -334,7 +418,7 @@ fn remapper_valid_security_protocols_pass_validation() {
 #[test]
 fn remapper_ssl_without_tls_secret_fails_validation() {
     let mut spec = valid_remapper_spec();
-    spec.kafka.security_protocol = "SSL".to_string();
+    spec.kafka.security_protocol = SecurityProtocol::Ssl;
     spec.kafka.tls_secret = None;
 
     let remapper = create_remapper(spec);
@@ -351,9 +435,10 @@ fn remapper_ssl_without_tls_secret_fails_validation() {
 #[test]
 fn remapper_sasl_ssl_without_sasl_secret_fails_validation() {
     let mut spec = valid_remapper_spec();
-    spec.kafka.security_protocol = "SASL_SSL".to_string();
+    spec.kafka.security_protocol = SecurityProtocol::SaslSsl;
     spec.kafka.tls_secret = Some(kafka_partition_remapper_operator::crd::TlsSecretRef {
         name: "tls-secret".to_string(),
+        secret_provider_class: None,
         ca_key: "ca.crt".to_string(),
         cert_key: None,
         key_key: None,
@@ -372,15 +457,2226 @@ fn remapper_sasl_ssl_without_sasl_secret_fails_validation() {
         .contains("sasl"));
 }
 
-// ============================================================================
-// Suspend Mode Tests
-// ============================================================================
+fn kerberos_spec() -> kafka_partition_remapper_operator::crd::KerberosSpec {
+    serde_json::from_value(serde_json::json!({
+        "principal": "kafka-proxy@EXAMPLE.COM",
+        "keytabSecret": { "name": "proxy-keytab" },
+        "krb5ConfigMap": { "name": "krb5" }
+    }))
+    .unwrap()
+}
 
 #[test]
-fn remapper_suspended_mode_passes_validation() {
+fn remapper_kerberos_replaces_the_sasl_secret() {
     let mut spec = valid_remapper_spec();
-    spec.suspend = true;
+    spec.kafka.kerberos = Some(kerberos_spec());
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("requires the SASL_PLAINTEXT or SASL_SSL"));
 
-    let remapper = create_remapper(spec);
-    assert!(remapper::validate(&remapper).is_ok());
+    spec.kafka.security_protocol = SecurityProtocol::SaslPlaintext;
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+    assert!(spec
+        .referenced_secrets()
+        .contains(&"proxy-keytab".to_string()));
+
+    spec.kafka.sasl_secret = Some(kafka_partition_remapper_operator::crd::SaslSecretRef {
+        name: "sasl-secret".to_string(),
+        secret_provider_class: None,
+        mechanism: SaslMechanism::Plain,
+        username_key: "username".to_string(),
+        password_key: "password".to_string(),
+    });
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("mutually exclusive"));
+}
+
+#[test]
+fn remapper_broker_credentials_need_a_secret_or_provider_class() {
+    let mut spec = valid_remapper_spec();
+    spec.kafka.security_protocol = SecurityProtocol::Ssl;
+    spec.kafka.tls_secret = Some(
+        serde_json::from_value(serde_json::json!({ "secretProviderClass": "vault-kafka-tls" }))
+            .unwrap(),
+    );
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+    assert!(spec.referenced_secrets().is_empty());
+    assert!(spec.referenced_certificates().is_empty());
+
+    spec.kafka.tls_secret.as_mut().unwrap().name = "kafka-tls".to_string();
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("exactly one of name and secretProviderClass"));
+
+    spec.kafka.tls_secret = Some(serde_json::from_value(serde_json::json!({})).unwrap());
+    assert!(remapper::validate(&create_remapper(spec)).is_err());
+}
+
+#[test]
+fn remapper_listeners_need_distinct_names_and_ports() {
+    let mut spec = valid_remapper_spec();
+    spec.listeners.push(ListenerSpec {
+        name: "external".to_string(),
+        ..valid_listener_spec()
+    });
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("distinct names and ports"));
+
+    spec.listeners[1].port = 9094;
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.listeners[1].name = "metrics".to_string();
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_err());
+
+    spec.listeners.clear();
+    assert!(remapper::validate(&create_remapper(spec)).is_err());
+}
+
+#[test]
+fn remapper_ssl_listener_requires_tls() {
+    let mut spec = valid_remapper_spec();
+    spec.listeners[0].security = Some(ClientSecuritySpec {
+        protocol: SecurityProtocol::Ssl,
+        tls: None,
+        sasl: None,
+    });
+
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("requires security.tls"));
+}
+
+#[test]
+fn remapper_client_security_is_validated() {
+    let mut spec = valid_remapper_spec();
+    spec.listeners[0].security = Some(
+        serde_json::from_value(serde_json::json!({
+            "protocol": "SSL",
+            "tls": { "certificateSecret": { "name": "proxy-tls" }, "requireClientCert": true }
+        }))
+        .unwrap(),
+    );
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("clientCaSecret"));
+
+    spec.listeners[0].security = Some(
+        serde_json::from_value(serde_json::json!({
+            "protocol": "SASL_PLAINTEXT",
+            "sasl": { "enabledMechanisms": [], "credentialsSecret": { "name": "users" } }
+        }))
+        .unwrap(),
+    );
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("enabledMechanisms"));
+
+    let unknown = serde_json::from_value::<ClientSecuritySpec>(serde_json::json!({
+        "protocol": "SASL_PLAINTEXT",
+        "sasl": { "enabledMechanisms": ["GSSAPI"], "credentialsSecret": { "name": "users" } }
+    }));
+    assert!(unknown.is_err());
+
+    spec.listeners[0].security = Some(
+        serde_json::from_value(serde_json::json!({
+            "protocol": "SASL_PLAINTEXT",
+            "sasl": { "enabledMechanisms": ["SCRAM-SHA-512"], "credentialsSecret": { "name": "users" } }
+        }))
+        .unwrap(),
+    );
+    assert!(remapper::validate(&create_remapper(spec)).is_ok());
+}
+
+#[test]
+fn remapper_users_require_a_matching_sasl_listener() {
+    let mut spec = valid_remapper_spec();
+    spec.users = serde_json::from_value(serde_json::json!([{ "name": "orders-app" }])).unwrap();
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("SCRAM-SHA-512 enabled"));
+
+    spec.listeners[0].security = Some(
+        serde_json::from_value(serde_json::json!({
+            "protocol": "SASL_PLAINTEXT",
+            "sasl": { "enabledMechanisms": ["SCRAM-SHA-512"], "credentialsSecret": { "name": "users" } }
+        }))
+        .unwrap(),
+    );
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.users[0].rotation = Some(
+        serde_json::from_value(serde_json::json!({ "intervalDays": 1, "overlapHours": 24 }))
+            .unwrap(),
+    );
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("overlapHours"));
+    spec.users[0].rotation = None;
+
+    spec.users[0].mechanism = SaslMechanism::Plain;
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("mechanism must be one of"));
+
+    spec.users = serde_json::from_value(serde_json::json!([
+        { "name": "orders-app" },
+        { "name": "orders-app" }
+    ]))
+    .unwrap();
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("more than once"));
+
+    spec.users = serde_json::from_value(serde_json::json!([{ "name": "Orders_App" }])).unwrap();
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("DNS label"));
+}
+
+#[test]
+fn remapper_external_service_must_reference_known_listeners() {
+    let mut spec = valid_remapper_spec();
+    spec.external_service =
+        Some(serde_json::from_value(serde_json::json!({ "listeners": ["external"] })).unwrap());
+
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("unknown listener external"));
+}
+
+#[test]
+fn remapper_external_hostname_must_be_a_dns_name() {
+    let mut spec = valid_remapper_spec();
+    spec.service.external_hostname = Some("kafka.example.com:9092".to_string());
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_err());
+
+    spec.service.external_hostname = Some("kafka.example.com".to_string());
+    assert!(remapper::validate(&create_remapper(spec)).is_ok());
+}
+
+#[test]
+fn remapper_pod_template_container_names_must_be_unique() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_template = Some(
+        serde_json::from_value(serde_json::json!({
+            "initContainers": [{ "name": "wait-for-kafka", "image": "busybox" }],
+            "sidecars": [{ "name": "proxy", "image": "envoyproxy/envoy" }]
+        }))
+        .unwrap(),
+    );
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("proxy is already in use"));
+
+    let pod_template = spec.pod_template.as_mut().unwrap();
+    pod_template.sidecars[0].name = "wait-for-kafka".to_string();
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_err());
+
+    spec.pod_template.as_mut().unwrap().sidecars[0].name = "envoy".to_string();
+    assert!(remapper::validate(&create_remapper(spec)).is_ok());
+}
+
+#[test]
+fn remapper_pod_template_extras_must_not_collide_with_generated_ones() {
+    let with_pod_template = |pod_template: serde_json::Value| {
+        let mut spec = valid_remapper_spec();
+        spec.pod_template = Some(serde_json::from_value(pod_template).unwrap());
+        remapper::validate(&create_remapper(spec))
+    };
+
+    let err = with_pod_template(serde_json::json!({
+        "extraVolumes": [{ "name": "config", "emptyDir": {} }]
+    }))
+    .unwrap_err();
+    assert!(err.to_string().contains("volume config is already in use"));
+
+    let err = with_pod_template(serde_json::json!({
+        "extraVolumes": [{ "name": "scratch", "emptyDir": {} }],
+        "extraVolumeMounts": [{ "name": "scratch", "mountPath": "/etc/kafka-proxy" }]
+    }))
+    .unwrap_err();
+    assert!(err.to_string().contains("mount path /etc/kafka-proxy"));
+
+    let err = with_pod_template(serde_json::json!({
+        "extraVolumeMounts": [{ "name": "missing", "mountPath": "/scratch" }]
+    }))
+    .unwrap_err();
+    assert!(err.to_string().contains("unknown volume missing"));
+
+    let err = with_pod_template(serde_json::json!({
+        "env": [{ "name": "FLAG", "value": "a" }, { "name": "FLAG", "value": "b" }]
+    }))
+    .unwrap_err();
+    assert!(err.to_string().contains("env var FLAG"));
+
+    assert!(with_pod_template(serde_json::json!({
+        "env": [{ "name": "FLAG", "value": "a" }],
+        "extraVolumes": [{ "name": "scratch", "emptyDir": {} }],
+        "extraVolumeMounts": [{ "name": "scratch", "mountPath": "/scratch" }]
+    }))
+    .is_ok());
+}
+
+#[test]
+fn remapper_probe_overrides_are_validated() {
+    let with_probes = |metrics_enabled: bool, probes: serde_json::Value| {
+        let mut spec = valid_remapper_spec();
+        spec.metrics.enabled = metrics_enabled;
+        spec.probes = Some(serde_json::from_value(probes).unwrap());
+        remapper::validate(&create_remapper(spec))
+    };
+
+    let err = with_probes(true, serde_json::json!({ "liveness": { "type": "Exec" } })).unwrap_err();
+    assert!(err.to_string().contains("requires a command"));
+
+    let err = with_probes(
+        false,
+        serde_json::json!({ "readiness": { "type": "Http" } }),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("metrics.enabled"));
+
+    let err = with_probes(
+        true,
+        serde_json::json!({ "startup": { "periodSeconds": 0 } }),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("probes.startup"));
+
+    let err = with_probes(
+        true,
+        serde_json::json!({ "liveness": { "successThreshold": 2 } }),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("successThreshold"));
+
+    assert!(with_probes(
+        false,
+        serde_json::json!({
+            "readiness": { "type": "Http", "port": 8080, "path": "/ready", "successThreshold": 2 },
+            "startup": { "type": "Exec", "command": ["cat", "/tmp/started"] }
+        })
+    )
+    .is_ok());
+}
+
+#[test]
+fn remapper_kafka_readiness_requires_http_metrics_probe() {
+    let mut spec = valid_remapper_spec();
+    spec.readiness =
+        Some(serde_json::from_value(serde_json::json!({ "requireKafka": true })).unwrap());
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.probes = Some(
+        serde_json::from_value(serde_json::json!({ "readiness": { "type": "Tcp" } })).unwrap(),
+    );
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("Http readiness probe"));
+
+    spec.probes = None;
+    spec.metrics.enabled = false;
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("metrics.enabled"));
+}
+
+#[test]
+fn remapper_host_network_requires_unprivileged_ports() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_template =
+        Some(serde_json::from_value(serde_json::json!({ "hostNetwork": true })).unwrap());
+    spec.listeners[0].port = 9092;
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.listeners[0].port = 443;
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("must be >= 1024"));
+}
+
+#[test]
+fn remapper_sidecar_ports_must_not_collide_with_proxy_ports() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_template = Some(
+        serde_json::from_value(serde_json::json!({
+            "sidecars": [{ "name": "exporter", "ports": [{ "containerPort": 9090 }] }]
+        }))
+        .unwrap(),
+    );
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("sidecar exporter port 9090"));
+}
+
+#[test]
+fn remapper_dns_policy_is_validated() {
+    let with_pod_template = |pod_template: serde_json::Value| {
+        let mut spec = valid_remapper_spec();
+        spec.pod_template = Some(serde_json::from_value(pod_template).unwrap());
+        remapper::validate(&create_remapper(spec))
+    };
+
+    assert!(with_pod_template(serde_json::json!({ "dnsPolicy": "Cluster" })).is_err());
+    let err = with_pod_template(serde_json::json!({ "dnsPolicy": "None" })).unwrap_err();
+    assert!(err.to_string().contains("dnsConfig"));
+    assert!(with_pod_template(serde_json::json!({
+        "dnsPolicy": "None",
+        "dnsConfig": { "nameservers": ["10.0.0.10"], "searches": ["kafka.svc.cluster.local"] }
+    }))
+    .is_ok());
+}
+
+#[test]
+fn remapper_common_labels_cannot_override_standard_labels() {
+    let mut spec = valid_remapper_spec();
+    spec.common_labels = BTreeMap::from([("team".to_string(), "payments".to_string())]);
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.common_labels.insert(
+        "app.kubernetes.io/instance".to_string(),
+        "other".to_string(),
+    );
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("app.kubernetes.io/instance"));
+}
+
+#[test]
+fn remapper_name_overrides_must_be_dns_labels() {
+    let mut spec = valid_remapper_spec();
+    spec.name_override = Some("proxy".to_string());
+    spec.fullname_override = Some("orders-proxy".to_string());
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.name_override = Some("Proxy".to_string());
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("nameOverride"));
+
+    spec.name_override = None;
+    spec.fullname_override = Some("orders.proxy".to_string());
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("fullnameOverride"));
+}
+
+#[test]
+fn dns_label_fields_share_the_same_rules() {
+    let label = |value: &str| {
+        let value = value.to_string();
+        validate_with(|spec| spec.target_namespace = Some(value))
+    };
+    assert!(label("apps").is_ok());
+    assert!(label(&"a".repeat(63)).is_ok());
+    for invalid in ["", "-apps", "apps-", "Apps", "apps.team", &"a".repeat(64)] {
+        let err = label(invalid).unwrap_err();
+        assert!(err.contains("targetNamespace"), "{}: {}", invalid, err);
+    }
+
+    let err = validate_with(|spec| spec.name_override = Some("proxy-".to_string())).unwrap_err();
+    assert!(err.contains("nameOverride"));
+}
+
+#[test]
+fn remapper_name_overrides_cannot_change() {
+    let previous = valid_remapper_spec();
+    let mut spec = valid_remapper_spec();
+    assert!(remapper::validate_name_change(&create_remapper(spec.clone()), &previous).is_ok());
+
+    spec.fullname_override = Some("orders-proxy".to_string());
+    let err = remapper::validate_name_change(&create_remapper(spec), &previous).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("fullnameOverride cannot be changed"));
+}
+
+#[test]
+fn long_resource_names_are_shortened_with_a_hash() {
+    let short = "orders-remapper";
+    assert_eq!(safe_resource_name(short), short);
+
+    let long = "a".repeat(30) + "-orders-remapper-east";
+    let other = "a".repeat(30) + "-orders-remapper-west";
+    let shortened = safe_resource_name(&long);
+    assert!(shortened.len() <= MAX_RESOURCE_NAME_LEN);
+    assert!(shortened.starts_with(&"a".repeat(30)));
+    assert_ne!(shortened, safe_resource_name(&other));
+    assert_eq!(safe_resource_name(&shortened), shortened);
+    // The full users Secret suffix still fits a DNS label
+    assert!(format!("{}-users-previous", shortened).len() <= 63);
+}
+
+#[test]
+fn resource_name_applies_overrides() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    assert_eq!(remapper.resource_name(), "test-remapper");
+
+    remapper.spec.name_override = Some("proxy".to_string());
+    assert_eq!(remapper.resource_name(), "test-remapper-proxy");
+
+    remapper.spec.fullname_override = Some("orders-proxy".to_string());
+    assert_eq!(remapper.resource_name(), "orders-proxy");
+}
+
+#[test]
+fn remapper_target_namespace_must_be_a_dns_label() {
+    let mut spec = valid_remapper_spec();
+    spec.target_namespace = Some("apps".to_string());
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.target_namespace = Some("Apps".to_string());
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("targetNamespace"));
+
+    spec.target_namespace = Some("apps".to_string());
+    let mut remapper = create_remapper(spec);
+    remapper.metadata.name = Some("r".repeat(64));
+    let err = remapper::validate(&remapper).unwrap_err();
+    assert!(err.to_string().contains("at most 63 characters"));
+}
+
+#[test]
+fn remapper_target_namespace_cannot_change() {
+    let previous = valid_remapper_spec();
+    let mut spec = valid_remapper_spec();
+    spec.target_namespace = Some("apps".to_string());
+
+    let err = remapper::validate_name_change(&create_remapper(spec), &previous).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("targetNamespace cannot be changed"));
+}
+
+#[test]
+fn remapper_service_ip_families_are_validated() {
+    let with_service = |service: serde_json::Value| {
+        let mut spec = valid_remapper_spec();
+        spec.service = serde_json::from_value(service).unwrap();
+        remapper::validate(&create_remapper(spec))
+    };
+
+    assert!(with_service(serde_json::json!({
+        "ipFamilies": ["IPv6", "IPv4"],
+        "ipFamilyPolicy": "PreferDualStack",
+        "internalTrafficPolicy": "Local"
+    }))
+    .is_ok());
+    assert!(with_service(serde_json::json!({ "ipFamilies": ["IPv5"] })).is_err());
+    assert!(with_service(serde_json::json!({ "ipFamilies": ["IPv4", "IPv4"] })).is_err());
+    let err = with_service(serde_json::json!({
+        "ipFamilies": ["IPv4", "IPv6"],
+        "ipFamilyPolicy": "SingleStack"
+    }))
+    .unwrap_err();
+    assert!(err.to_string().contains("SingleStack"));
+    assert!(with_service(serde_json::json!({ "internalTrafficPolicy": "Node" })).is_err());
+}
+
+#[test]
+fn remapper_load_balancer_options_require_load_balancer_service() {
+    let with_service = |service: serde_json::Value| {
+        let mut spec = valid_remapper_spec();
+        spec.service = serde_json::from_value(service).unwrap();
+        remapper::validate(&create_remapper(spec))
+    };
+
+    assert!(with_service(serde_json::json!({
+        "type": "LoadBalancer",
+        "loadBalancerClass": "service.k8s.aws/nlb",
+        "loadBalancerSourceRanges": ["10.0.0.0/8", "2001:db8::/32"],
+        "allocateLoadBalancerNodePorts": false
+    }))
+    .is_ok());
+    let err = with_service(serde_json::json!({ "loadBalancerClass": "service.k8s.aws/nlb" }))
+        .unwrap_err();
+    assert!(err.to_string().contains("LoadBalancer"));
+    let err = with_service(serde_json::json!({
+        "type": "LoadBalancer",
+        "loadBalancerSourceRanges": ["10.0.0.0/33"]
+    }))
+    .unwrap_err();
+    assert!(err.to_string().contains("10.0.0.0/33"));
+}
+
+#[test]
+fn remapper_node_ports_must_reference_known_ports() {
+    let with_service = |service: serde_json::Value| {
+        let mut spec = valid_remapper_spec();
+        spec.service = serde_json::from_value(service).unwrap();
+        remapper::validate(&create_remapper(spec))
+    };
+
+    assert!(with_service(serde_json::json!({
+        "type": "NodePort",
+        "nodePorts": { "kafka": 30092, "metrics": 30090 }
+    }))
+    .is_ok());
+    let err = with_service(serde_json::json!({ "nodePorts": { "kafka": 30092 } })).unwrap_err();
+    assert!(err.to_string().contains("NodePort"));
+    let err = with_service(serde_json::json!({
+        "type": "NodePort",
+        "nodePorts": { "admin": 30092 }
+    }))
+    .unwrap_err();
+    assert!(err.to_string().contains("unknown port admin"));
+    let err = with_service(serde_json::json!({
+        "type": "NodePort",
+        "nodePorts": { "kafka": 30092, "metrics": 30092 }
+    }))
+    .unwrap_err();
+    assert!(err.to_string().contains("more than once"));
+}
+
+#[test]
+fn remapper_session_affinity_timeout_requires_client_ip() {
+    let with_service = |service: serde_json::Value| {
+        let mut spec = valid_remapper_spec();
+        spec.service = serde_json::from_value(service).unwrap();
+        remapper::validate(&create_remapper(spec))
+    };
+
+    assert!(with_service(serde_json::json!({
+        "sessionAffinity": "ClientIP",
+        "sessionAffinityTimeoutSeconds": 3600
+    }))
+    .is_ok());
+    assert!(with_service(serde_json::json!({ "sessionAffinity": "Cookie" })).is_err());
+    assert!(with_service(serde_json::json!({ "sessionAffinityTimeoutSeconds": 3600 })).is_err());
+    assert!(with_service(serde_json::json!({
+        "sessionAffinity": "ClientIP",
+        "sessionAffinityTimeoutSeconds": 0
+    }))
+    .is_err());
+}
+
+#[test]
+fn remapper_metrics_service_requires_metrics_and_known_type() {
+    let with_metrics = |metrics: serde_json::Value| {
+        let mut spec = valid_remapper_spec();
+        spec.metrics = serde_json::from_value(metrics).unwrap();
+        remapper::validate(&create_remapper(spec))
+    };
+
+    assert!(with_metrics(serde_json::json!({ "service": { "type": "Headless" } })).is_ok());
+    assert!(with_metrics(serde_json::json!({ "service": { "enabled": false } })).is_ok());
+    let err =
+        with_metrics(serde_json::json!({ "service": { "type": "ExternalName" } })).unwrap_err();
+    assert!(err.to_string().contains("metrics.service.type"));
+    let err = with_metrics(serde_json::json!({ "enabled": false, "service": {} })).unwrap_err();
+    assert!(err.to_string().contains("metrics.enabled"));
+}
+
+#[test]
+fn remapper_listener_may_reuse_port_of_disabled_metrics() {
+    let mut spec = valid_remapper_spec();
+    spec.listeners[0].port = spec.metrics.port;
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_err());
+
+    spec.metrics.enabled = false;
+    assert!(remapper::validate(&create_remapper(spec)).is_ok());
+}
+
+#[test]
+fn remapper_broker_address_map_requires_host_port_entries() {
+    let mut spec = valid_remapper_spec();
+    spec.kafka.broker_address_map.insert(
+        "broker-0.internal:9092".to_string(),
+        "lb.example.com:30001".to_string(),
+    );
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.kafka.broker_address_map.insert(
+        "broker-1.internal:9092".to_string(),
+        "lb.example.com".to_string(),
+    );
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("lb.example.com"));
+}
+
+#[test]
+fn remapper_bootstrap_host_mode_requires_host_port_bootstrap_server() {
+    let mut spec = valid_remapper_spec();
+    spec.kafka.broker_address_mode = BrokerAddressMode::BootstrapHost;
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+    assert_eq!(spec.kafka.broker_address_host().as_deref(), Some("kafka"));
+
+    spec.kafka.bootstrap_servers = vec!["kafka".to_string()];
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("BootstrapHost"));
+}
+
+#[test]
+fn remapper_failover_cluster_is_validated() {
+    assert!(validate_with(|spec| {
+        spec.kafka.failover_clusters = vec![from_json(serde_json::json!({
+            "name": "dr",
+            "bootstrapServers": ["kafka-dr:9092"]
+        }))]
+    })
+    .is_ok());
+
+    let err = validate_with(|spec| {
+        spec.kafka.failover_clusters = vec![from_json(serde_json::json!({
+            "name": "DR",
+            "bootstrapServers": ["kafka-dr:9092"]
+        }))]
+    })
+    .unwrap_err();
+    assert!(err.contains("name"));
+
+    let err = validate_with(|spec| {
+        spec.kafka.failover_clusters = vec![from_json(serde_json::json!({
+            "name": "dr",
+            "bootstrapServers": []
+        }))]
+    })
+    .unwrap_err();
+    assert!(err.contains("bootstrapServers"));
+
+    let err = validate_with(|spec| {
+        spec.kafka.failover_clusters = vec![from_json(serde_json::json!({
+            "name": "dr",
+            "bootstrapServers": ["kafka-dr:9093"],
+            "securityProtocol": "SASL_SSL",
+            "tlsSecret": { "name": "dr-tls" }
+        }))]
+    })
+    .unwrap_err();
+    assert!(err.contains("failoverClusters[0].saslSecret"));
+
+    let err = validate_with(|spec| {
+        spec.kafka.failover_clusters = vec![from_json(serde_json::json!({
+            "name": "dr",
+            "bootstrapServers": ["kafka-dr:9093"],
+            "securityProtocol": "SSL",
+            "tlsSecret": { "name": "dr-tls", "secretProviderClass": "dr-store" }
+        }))]
+    })
+    .unwrap_err();
+    assert!(err.contains("exactly one of name and secretProviderClass"));
+}
+
+#[test]
+fn remapper_failover_cluster_names_must_be_unique() {
+    let mut spec = valid_remapper_spec();
+    let cluster: serde_json::Value = serde_json::json!({
+        "name": "dr",
+        "bootstrapServers": ["kafka-dr:9092"]
+    });
+    spec.kafka.failover_clusters = vec![
+        serde_json::from_value(cluster.clone()).unwrap(),
+        serde_json::from_value(cluster).unwrap(),
+    ];
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("more than one"));
+}
+
+#[test]
+fn remapper_topic_routes_are_validated() {
+    let clusters = serde_json::json!([{ "name": "legacy", "bootstrapServers": ["legacy:9092"] }]);
+    assert!(validate_with(|spec| {
+        spec.routing = Some(from_json(serde_json::json!({
+            "clusters": clusters,
+            "routes": [
+                { "prefix": "legacy.", "cluster": "legacy" },
+                { "pattern": "orders-.*", "cluster": "legacy" }
+            ]
+        })))
+    })
+    .is_ok());
+
+    let err = validate_with(|spec| {
+        spec.routing = Some(from_json(serde_json::json!({
+            "clusters": clusters,
+            "routes": [{ "prefix": "legacy.", "pattern": "legacy-.*", "cluster": "legacy" }]
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("exactly one of prefix and pattern"));
+
+    let err = validate_with(|spec| {
+        spec.routing = Some(from_json(serde_json::json!({
+            "clusters": clusters,
+            "routes": [{ "pattern": "orders-(", "cluster": "legacy" }]
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("not a valid regex"));
+
+    let err = validate_with(|spec| {
+        spec.routing = Some(from_json(serde_json::json!({
+            "clusters": clusters,
+            "routes": [{ "prefix": "legacy.", "cluster": "missing" }]
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("not a routing cluster"));
+}
+
+#[test]
+fn remapper_routing_cluster_names_cannot_reuse_failover_names() {
+    let mut spec = valid_remapper_spec();
+    spec.kafka.failover_clusters = vec![serde_json::from_value(serde_json::json!({
+        "name": "legacy",
+        "bootstrapServers": ["dr:9092"]
+    }))
+    .unwrap()];
+    spec.routing = Some(
+        serde_json::from_value(serde_json::json!({
+            "clusters": [{ "name": "legacy", "bootstrapServers": ["legacy:9092"] }]
+        }))
+        .unwrap(),
+    );
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("more than one"));
+}
+
+#[test]
+fn remapper_topic_access_patterns_must_compile() {
+    let mut spec = valid_remapper_spec();
+    spec.access = Some(AccessSpec {
+        topic_allow_list: vec!["orders".to_string(), "payments-.*".to_string()],
+        topic_deny_list: vec!["payments-internal".to_string()],
+    });
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.access.as_mut().unwrap().topic_deny_list = vec!["audit-[".to_string()];
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("access.topicDenyList"));
+    assert!(err.to_string().contains("audit-["));
+}
+
+#[test]
+fn remapper_quotas_are_validated() {
+    assert!(validate_with(|spec| {
+        spec.quotas = Some(from_json(serde_json::json!({
+            "default": { "ingressBytesPerSecond": 1048576 },
+            "clients": [
+                { "clientId": "batch-loader", "egressBytesPerSecond": 524288 },
+                { "user": "reporting", "connectionsPerSecond": 5 }
+            ]
+        })))
+    })
+    .is_ok());
+
+    let err =
+        validate_with(|spec| spec.quotas = Some(from_json(serde_json::json!({ "default": {} }))))
+            .unwrap_err();
+    assert!(err.contains("quotas.default must set at least one limit"));
+
+    let err = validate_with(|spec| {
+        spec.quotas = Some(from_json(serde_json::json!({
+            "clients": [{ "clientId": "batch-loader", "ingressBytesPerSecond": 0 }]
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains(">= 1"));
+
+    let err = validate_with(|spec| {
+        spec.quotas = Some(from_json(serde_json::json!({
+            "clients": [{ "clientId": "batch-loader", "user": "loader", "connectionsPerSecond": 1 }]
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("exactly one of clientId and user"));
+
+    let err = validate_with(|spec| {
+        spec.quotas = Some(from_json(serde_json::json!({
+            "clients": [
+                { "user": "reporting", "connectionsPerSecond": 5 },
+                { "user": "reporting", "egressBytesPerSecond": 1024 }
+            ]
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("more than one entry for user"));
+}
+
+#[test]
+fn remapper_consumer_group_settings_are_validated() {
+    assert!(validate_with(|spec| {
+        spec.consumer_groups = Some(from_json(serde_json::json!({
+            "groupIdPrefix": "remapped.",
+            "passthroughProtocolTypes": ["connect"],
+            "minSessionTimeoutMs": 6000,
+            "maxSessionTimeoutMs": 45000
+        })))
+    })
+    .is_ok());
+
+    let err = validate_with(|spec| {
+        spec.consumer_groups = Some(from_json(serde_json::json!({ "groupIdPrefix": "team a/" })))
+    })
+    .unwrap_err();
+    assert!(err.contains("groupIdPrefix"));
+
+    let err = validate_with(|spec| {
+        spec.consumer_groups = Some(from_json(serde_json::json!({
+            "passthroughProtocolTypes": ["consumer"]
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("passthroughProtocolTypes"));
+
+    let err = validate_with(|spec| {
+        spec.consumer_groups = Some(from_json(serde_json::json!({
+            "minSessionTimeoutMs": 45000,
+            "maxSessionTimeoutMs": 6000
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("minSessionTimeoutMs"));
+}
+
+#[test]
+fn remapper_api_version_ceilings_are_validated() {
+    let mut spec = valid_remapper_spec();
+    spec.kafka.max_api_version = Some(12);
+    spec.kafka.api_versions = BTreeMap::from([("Fetch".to_string(), 11)]);
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.kafka
+        .api_versions
+        .insert("FetchSnapshot2".to_string(), 1);
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("FetchSnapshot2"));
+
+    spec.kafka.api_versions = BTreeMap::from([("Produce".to_string(), -1)]);
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("kafka.apiVersions.Produce"));
+
+    spec.kafka.api_versions.clear();
+    spec.kafka.max_api_version = Some(-1);
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("kafka.maxApiVersion"));
+}
+
+#[test]
+fn remapper_tuning_settings_must_be_positive() {
+    let mut spec = valid_remapper_spec();
+    spec.tuning = Some(TuningSpec {
+        worker_threads: Some(4),
+        max_in_flight_requests: Some(64),
+        ..Default::default()
+    });
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.tuning.as_mut().unwrap().idle_connection_timeout_secs = Some(0);
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("tuning.idleConnectionTimeoutSecs must be >= 1"));
+}
+
+#[test]
+fn remapper_memory_limit_must_be_a_quantity() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_template = Some(
+        serde_json::from_value(serde_json::json!({
+            "resources": { "limits": { "memory": "512Mi" } }
+        }))
+        .unwrap(),
+    );
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.pod_template
+        .as_mut()
+        .unwrap()
+        .resources
+        .as_mut()
+        .unwrap()
+        .limits
+        .insert("memory".to_string(), "lots".to_string());
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("limits.memory"));
+}
+
+#[test]
+fn remapper_resource_quantities_are_validated() {
+    let with_resources = |resources: serde_json::Value| {
+        validate_with(|spec| {
+            spec.pod_template = Some(from_json(serde_json::json!({ "resources": resources })))
+        })
+    };
+
+    assert!(with_resources(serde_json::json!({
+        "requests": { "cpu": "250m", "memory": "128Mi" },
+        "limits": { "cpu": "1", "memory": "1Gi" }
+    }))
+    .is_ok());
+
+    let err = with_resources(serde_json::json!({ "requests": { "memory": "100mi" } })).unwrap_err();
+    assert!(err.contains("podTemplate.resources.requests.memory \"100mi\" is not a valid quantity"));
+
+    let err = with_resources(serde_json::json!({ "limits": { "memory": "2Gb" } })).unwrap_err();
+    assert!(err.contains("podTemplate.resources.limits.memory \"2Gb\""));
+
+    let err = with_resources(serde_json::json!({ "limits": { "cpu": "-1" } })).unwrap_err();
+    assert!(err.contains("limits.cpu"));
+
+    let err = with_resources(serde_json::json!({
+        "requests": { "cpu": "2" },
+        "limits": { "cpu": "1500m" }
+    }))
+    .unwrap_err();
+    assert!(err.contains("requests.cpu \"2\" exceeds the limit \"1500m\""));
+}
+
+#[test]
+fn remapper_audit_logging_is_validated() {
+    assert!(
+        validate_with(|spec| spec.audit_logging = Some(from_json(serde_json::json!({})))).is_ok()
+    );
+    assert!(validate_with(|spec| {
+        spec.audit_logging = Some(from_json(serde_json::json!({
+            "sampleRate": 0.25,
+            "destinations": [
+                { "type": "File", "fileName": "produce.log" },
+                { "type": "Syslog", "address": "syslog.logging:514" }
+            ]
+        })))
+    })
+    .is_ok());
+
+    let err = validate_with(|spec| {
+        spec.audit_logging = Some(from_json(serde_json::json!({ "sampleRate": 0 })))
+    })
+    .unwrap_err();
+    assert!(err.contains("sampleRate"));
+
+    let err = validate_with(|spec| {
+        spec.audit_logging = Some(from_json(serde_json::json!({
+            "destinations": [{ "type": "Syslog" }]
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("destinations[0].address"));
+
+    let err = validate_with(|spec| {
+        spec.audit_logging = Some(from_json(serde_json::json!({
+            "destinations": [{ "type": "File", "fileName": "../etc/passwd" }]
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("plain file name"));
+
+    let err = validate_with(|spec| {
+        spec.audit_logging = Some(from_json(serde_json::json!({
+            "destinations": [{ "type": "File" }, { "type": "File", "fileName": "audit.log" }]
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("more than one destination"));
+
+    let err = validate_with(|spec| {
+        spec.audit_logging = Some(from_json(serde_json::json!({
+            "destinations": [{ "type": "Stdout", "address": "syslog:514" }]
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("does not apply"));
+
+    // Disabled audit logging is not checked
+    assert!(validate_with(|spec| {
+        spec.audit_logging = Some(from_json(
+            serde_json::json!({ "enabled": false, "sampleRate": 0 }),
+        ))
+    })
+    .is_ok());
+}
+
+#[test]
+fn remapper_ports_and_addresses_are_validated() {
+    let mut spec = valid_remapper_spec();
+    spec.listeners[0].port = 70000;
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("listeners[0].port 70000 must be between 1 and 65535"));
+
+    let mut spec = valid_remapper_spec();
+    spec.metrics.port = 0;
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("metrics.port 0"));
+
+    let mut spec = valid_remapper_spec();
+    spec.listeners[0].advertised_address = Some("proxy.example.com".to_string());
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("listeners[0].advertisedAddress \"proxy.example.com\""));
+
+    let mut spec = valid_remapper_spec();
+    spec.kafka.bootstrap_servers.push("broker-2".to_string());
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("kafka.bootstrapServers[1] \"broker-2\" must be a host:port address"));
+}
+
+#[test]
+fn remapper_log_level_is_an_enum() {
+    let err = serde_json::from_value::<LoggingSpec>(serde_json::json!({ "level": "verbose" }))
+        .unwrap_err();
+    assert!(err.to_string().contains("unknown variant `verbose`"));
+
+    let logging: LoggingSpec =
+        serde_json::from_value(serde_json::json!({ "level": "WARN" })).unwrap();
+    assert_eq!(logging.level, LogLevel::Warn);
+}
+
+#[test]
+fn remapper_invalid_topic_pattern_reports_position() {
+    let err = validate_with(|spec| {
+        spec.mapping.topics = from_json(serde_json::json!([
+            { "topic": "orders" },
+            { "topic": "payments-(eu|us" }
+        ]))
+    })
+    .unwrap_err();
+    assert!(
+        err.contains(
+            "mapping.topics[1].topic \"payments-(eu|us\" is not a valid regex at position 10"
+        ),
+        "{}",
+        err
+    );
+
+    let mut spec = valid_remapper_spec();
+    spec.access = Some(
+        serde_json::from_value(serde_json::json!({
+            "topicDenyList": ["internal.*", "audit[0-9"]
+        }))
+        .unwrap(),
+    );
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("access.topicDenyList[1] \"audit[0-9\" is not a valid regex at position 6"));
+}
+
+#[test]
+fn remapper_topic_overrides_are_validated() {
+    assert!(validate_with(|spec| {
+        spec.mapping.topics = from_json(serde_json::json!([
+            { "topic": "orders", "virtualPartitions": 400 },
+            { "topic": "payments.*", "virtualPartitions": 200, "physicalPartitions": 50 },
+            { "topic": "payments.eu", "virtualPartitions": 200, "physicalPartitions": 50 }
+        ]))
+    })
+    .is_ok());
+
+    let err = validate_with(|spec| {
+        spec.mapping.topics = from_json(serde_json::json!([
+            { "topic": "orders", "virtualPartitions": 400 },
+            { "topic": "orders", "virtualPartitions": 200 }
+        ]))
+    })
+    .unwrap_err();
+    assert!(err.contains("more than once"));
+
+    let err = validate_with(|spec| {
+        spec.mapping.topics = from_json(serde_json::json!([
+            { "topic": "orders", "virtualPartitions": 450 }
+        ]))
+    })
+    .unwrap_err();
+    assert!(err.contains("mapping.topics[0] maps 450 virtual onto 100 physical"));
+
+    let err = validate_with(|spec| {
+        spec.mapping.topics = from_json(serde_json::json!([
+            { "topic": "orders", "physicalPartitions": 0 }
+        ]))
+    })
+    .unwrap_err();
+    assert!(err.contains("physicalPartitions must be >= 1"));
+
+    let err = validate_with(|spec| {
+        spec.mapping.topics = from_json(serde_json::json!([
+            { "topic": "orders", "offsetRange": 1024 }
+        ]))
+    })
+    .unwrap_err();
+    assert!(err.contains("mapping.topics[0].offsetRange"));
+
+    let err = validate_with(|spec| {
+        spec.mapping.topics = from_json(serde_json::json!([
+            { "topic": "orders.*", "virtualPartitions": 400 },
+            { "topic": "orders.eu", "virtualPartitions": 200 }
+        ]))
+    })
+    .unwrap_err();
+    assert!(err.contains("different partition ratios"));
+}
+
+#[test]
+fn remapper_offset_range_headroom_is_validated() {
+    // 2^20 offsets shared by 100 virtual partitions leaves ~2^13 each
+    let mut spec = valid_remapper_spec();
+    spec.mapping.offset_range = 1 << 20;
+    spec.mapping.physical_partitions = 10;
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("offsets per virtual partition"));
+
+    let err = validate_with(|spec| {
+        spec.mapping.topics = from_json(serde_json::json!([{
+            "topic": "orders",
+            "offsetRange": 1 << 22,
+            "virtualPartitions": 800,
+            "physicalPartitions": 10
+        }]))
+    })
+    .unwrap_err();
+    assert!(err.contains("mapping.topics[0] leaves 52428 offsets"));
+}
+
+#[test]
+fn offset_headroom_condition_warns_below_recommended_headroom() {
+    let mapping = valid_mapping_spec();
+    let condition = remapper::offset_headroom_condition(&mapping);
+    assert_eq!(condition.type_, "OffsetRangeHeadroom");
+    assert_eq!(condition.status, "True");
+
+    let mut mapping = valid_mapping_spec();
+    mapping.topics = vec![TopicMappingOverride {
+        topic: "orders".to_string(),
+        virtual_partitions: None,
+        physical_partitions: None,
+        offset_range: Some(1 << 32),
+    }];
+    let condition = remapper::offset_headroom_condition(&mapping);
+    assert_eq!(condition.status, "False");
+    assert_eq!(condition.reason.as_deref(), Some("LowHeadroom"));
+    let message = condition.message.unwrap();
+    assert!(message.contains("topic orders leaves 429496729 offsets"));
+    assert!(!message.contains("mapping leaves"));
+}
+
+#[test]
+fn remapper_schema_registry_is_validated() {
+    assert!(validate_with(|spec| {
+        spec.schema_registry = Some(from_json(serde_json::json!({
+            "url": "https://registry:8081",
+            "tlsSecret": { "name": "registry-tls" },
+            "authSecret": { "name": "registry-auth" },
+            "forwardPort": 8081
+        })))
+    })
+    .is_ok());
+
+    let err = validate_with(|spec| {
+        spec.schema_registry = Some(from_json(serde_json::json!({ "url": "registry:8081" })))
+    })
+    .unwrap_err();
+    assert!(err.contains("must be an http or https URL"));
+
+    let err = validate_with(|spec| {
+        spec.schema_registry = Some(from_json(serde_json::json!({
+            "url": "http://registry:8081",
+            "tlsSecret": { "name": "registry-tls" }
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("requires an https URL"));
+
+    let listener_port = valid_listener_spec().port;
+    let err = validate_with(|spec| {
+        spec.schema_registry = Some(from_json(serde_json::json!({
+            "url": "http://registry:8081",
+            "forwardPort": listener_port
+        })))
+    })
+    .unwrap_err();
+    assert!(err.contains("conflicts with a proxy port"));
+}
+
+#[test]
+fn remapper_topic_renames_are_validated() {
+    assert!(validate_with(|spec| {
+        spec.mapping.topic_renames = from_json(serde_json::json!([
+            { "prefix": "legacy.", "replacement": "prod.orders." },
+            { "pattern": "(?P<team>\\w+)-events", "replacement": "events.${team}" },
+            { "pattern": "old-(.*)", "replacement": "new-$1" }
+        ]))
+    })
+    .is_ok());
+
+    let err = validate_with(|spec| {
+        spec.mapping.topic_renames = from_json(serde_json::json!([
+            { "prefix": "a.", "pattern": "b.*", "replacement": "c." }
+        ]))
+    })
+    .unwrap_err();
+    assert!(err.contains("exactly one of prefix and pattern"));
+
+    let err = validate_with(|spec| {
+        spec.mapping.topic_renames = from_json(serde_json::json!([
+            { "pattern": "old-(.*)", "replacement": "new-$2" }
+        ]))
+    })
+    .unwrap_err();
+    assert!(err.contains("unknown group \"2\""));
+
+    let err = validate_with(|spec| {
+        spec.mapping.topic_renames =
+            from_json(serde_json::json!([{ "prefix": "a.", "replacement": "" }]))
+    })
+    .unwrap_err();
+    assert!(err.contains("replacement cannot be empty"));
+}
+
+#[test]
+fn remapper_header_injection_is_validated() {
+    let mut spec = valid_remapper_spec();
+    spec.mapping.header_injection = Some(HeaderInjectionSpec {
+        virtual_partition_header: Some("x-virtual-partition".to_string()),
+        headers: BTreeMap::from([("x-env".to_string(), "prod".to_string())]),
+        overwrite: false,
+    });
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    let injection = spec.mapping.header_injection.as_mut().unwrap();
+    injection
+        .headers
+        .insert("x-virtual-partition".to_string(), "fixed".to_string());
+    let err = remapper::validate(&create_remapper(spec.clone())).unwrap_err();
+    assert!(err.to_string().contains("more than once"));
+
+    spec.mapping.header_injection = Some(HeaderInjectionSpec::default());
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("virtualPartitionHeader or headers"));
+}
+
+// ============================================================================
+// Suspend Mode Tests
+// ============================================================================
+
+#[test]
+fn remapper_suspended_mode_passes_validation() {
+    let mut spec = valid_remapper_spec();
+    spec.suspend = true;
+
+    let remapper = create_remapper(spec);
+    assert!(remapper::validate(&remapper).is_ok());
+}
+
+// ============================================================================
+// PodDisruptionBudget Validation Tests
+// ============================================================================
+
+#[test]
+fn remapper_pdb_with_min_and_max_fails_validation() {
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+    let mut spec = valid_remapper_spec();
+    spec.pod_disruption_budget = Some(
+        kafka_partition_remapper_operator::crd::PodDisruptionBudgetSpec {
+            enabled: true,
+            min_available: Some(IntOrString::Int(1)),
+            max_unavailable: Some(IntOrString::String("50%".to_string())),
+        },
+    );
+
+    let remapper = create_remapper(spec);
+    let result = remapper::validate(&remapper);
+
+    assert!(result.is_err());
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("mutually exclusive"));
+}
+
+// ============================================================================
+// Autoscaling Validation Tests
+// ============================================================================
+
+fn autoscaling_spec(
+    min_replicas: Option<i32>,
+    max_replicas: i32,
+) -> kafka_partition_remapper_operator::crd::AutoscalingSpec {
+    kafka_partition_remapper_operator::crd::AutoscalingSpec {
+        enabled: true,
+        min_replicas,
+        max_replicas,
+        target_cpu_utilization_percentage: None,
+        target_memory_utilization_percentage: None,
+        custom_metrics: vec![],
+        keda: None,
+    }
+}
+
+#[test]
+fn remapper_autoscaling_max_below_min_fails_validation() {
+    let mut spec = valid_remapper_spec();
+    spec.autoscaling = Some(autoscaling_spec(Some(5), 3));
+
+    let remapper = create_remapper(spec);
+    let result = remapper::validate(&remapper);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("maxReplicas"));
+}
+
+#[test]
+fn remapper_autoscaling_valid_range_passes_validation() {
+    let mut spec = valid_remapper_spec();
+    spec.autoscaling = Some(autoscaling_spec(Some(2), 10));
+
+    let remapper = create_remapper(spec);
+    assert!(remapper::validate(&remapper).is_ok());
+}
+
+#[test]
+fn remapper_keda_autoscaling_requires_a_target() {
+    let mut spec = valid_remapper_spec();
+    let mut autoscaling = autoscaling_spec(Some(1), 10);
+    autoscaling.keda = Some(
+        serde_json::from_value(serde_json::json!({ "serverAddress": "http://prometheus:9090" }))
+            .unwrap(),
+    );
+    spec.autoscaling = Some(autoscaling);
+
+    let mut remapper = create_remapper(spec);
+    let result = remapper::validate(&remapper);
+    assert!(result.is_err());
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("targetConnectionsPerPod"));
+
+    let keda = remapper.spec.autoscaling.as_mut().unwrap().keda.as_mut();
+    keda.unwrap().target_connections_per_pod = Some(200);
+    assert!(remapper::validate(&remapper).is_ok());
+
+    remapper.spec.metrics.enabled = false;
+    let result = remapper::validate(&remapper);
+    assert!(result.unwrap_err().to_string().contains("metrics.enabled"));
+}
+
+// ============================================================================
+// Sharding Validation Tests
+// ============================================================================
+
+fn sharded_spec() -> KafkaPartitionRemapperSpec {
+    let mut spec = valid_remapper_spec();
+    spec.workload = kafka_partition_remapper_operator::crd::WorkloadKind::StatefulSet;
+    spec.sharding = Some(kafka_partition_remapper_operator::crd::ShardingSpec { enabled: true });
+    spec
+}
+
+#[test]
+fn remapper_sharding_requires_stateful_set() {
+    let mut spec = sharded_spec();
+    spec.workload = kafka_partition_remapper_operator::crd::WorkloadKind::Deployment;
+
+    let remapper = create_remapper(spec);
+    let result = remapper::validate(&remapper);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("StatefulSet"));
+}
+
+#[test]
+fn remapper_sharding_with_autoscaling_fails_validation() {
+    let mut spec = sharded_spec();
+    spec.autoscaling = Some(autoscaling_spec(Some(2), 10));
+
+    let remapper = create_remapper(spec);
+    let result = remapper::validate(&remapper);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("autoscaling"));
+}
+
+#[test]
+fn remapper_sharding_with_stateful_set_passes_validation() {
+    let remapper = create_remapper(sharded_spec());
+    assert!(remapper::validate(&remapper).is_ok());
+}
+
+// ============================================================================
+// Rollout Strategy Validation Tests
+// ============================================================================
+
+fn canary_spec(canary_replicas: i32) -> KafkaPartitionRemapperSpec {
+    let mut spec = valid_remapper_spec();
+    spec.rollout_strategy = Some(RolloutStrategySpec {
+        type_: RolloutStrategyType::Canary,
+        canary_replicas,
+        bake_time_seconds: 60,
+        max_surge: None,
+        max_unavailable: None,
+        progress_deadline_seconds: None,
+        revision_history_limit: None,
+    });
+    spec
+}
+
+#[test]
+fn remapper_canary_with_deployment_passes_validation() {
+    let remapper = create_remapper(canary_spec(1));
+    assert!(remapper::validate(&remapper).is_ok());
+}
+
+#[test]
+fn remapper_canary_requires_deployment_and_replicas() {
+    let mut spec = canary_spec(1);
+    spec.workload = kafka_partition_remapper_operator::crd::WorkloadKind::StatefulSet;
+    let result = remapper::validate(&create_remapper(spec));
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("workload: Deployment"));
+
+    let result = remapper::validate(&create_remapper(canary_spec(0)));
+    assert!(result.unwrap_err().to_string().contains("canaryReplicas"));
+}
+
+#[test]
+fn remapper_rolling_update_tuning_is_validated() {
+    let with_rollout = |workload: &str, rollout: serde_json::Value| {
+        let mut spec = valid_remapper_spec();
+        spec.workload = serde_json::from_value(serde_json::json!(workload)).unwrap();
+        spec.rollout_strategy = Some(serde_json::from_value(rollout).unwrap());
+        remapper::validate(&create_remapper(spec))
+    };
+
+    assert!(with_rollout(
+        "Deployment",
+        serde_json::json!({ "maxSurge": "50%", "maxUnavailable": 0, "progressDeadlineSeconds": 900 })
+    )
+    .is_ok());
+
+    let err = with_rollout("StatefulSet", serde_json::json!({ "maxSurge": 1 })).unwrap_err();
+    assert!(err.to_string().contains("workload: Deployment"));
+
+    let err = with_rollout(
+        "Deployment",
+        serde_json::json!({ "maxSurge": 0, "maxUnavailable": "0%" }),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("cannot both be 0"));
+
+    // Revision history applies to both workload kinds
+    assert!(with_rollout(
+        "StatefulSet",
+        serde_json::json!({ "revisionHistoryLimit": 3 })
+    )
+    .is_ok());
+}
+
+// ============================================================================
+// KafkaClusterRef Validation Tests
+// ============================================================================
+
+#[test]
+fn remapper_cluster_ref_without_inline_connection_passes_validation() {
+    let mut spec = valid_remapper_spec();
+    spec.kafka.cluster_ref = Some("shared".to_string());
+    spec.kafka.bootstrap_servers = vec![];
+
+    let remapper = create_remapper(spec);
+    assert!(remapper::validate(&remapper).is_ok());
+}
+
+#[test]
+fn remapper_cluster_ref_with_inline_bootstrap_servers_fails_validation() {
+    let mut spec = valid_remapper_spec();
+    spec.kafka.cluster_ref = Some("shared".to_string());
+
+    let remapper = create_remapper(spec);
+    let result = remapper::validate(&remapper);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("clusterRef"));
+}
+
+#[test]
+fn cluster_ref_resolves_into_inline_connection() {
+    let cluster: kafka_partition_remapper_operator::crd::KafkaClusterRefSpec =
+        serde_json::from_value(serde_json::json!({
+            "bootstrapServers": ["shared-kafka:9093"],
+            "securityProtocol": "SASL_SSL",
+            "tlsSecret": { "name": "kafka-tls" },
+            "saslSecret": { "name": "kafka-sasl", "mechanism": "PLAIN" }
+        }))
+        .unwrap();
+
+    let mut spec = valid_remapper_spec();
+    spec.kafka.cluster_ref = Some("shared".to_string());
+    spec.kafka.bootstrap_servers = vec![];
+    cluster.resolve_into(&mut spec.kafka);
+
+    assert_eq!(spec.kafka.cluster_ref, None);
+    assert_eq!(spec.kafka.bootstrap_servers, vec!["shared-kafka:9093"]);
+    assert_eq!(spec.kafka.connection_timeout_ms, 10000);
+    assert_eq!(
+        cluster.referenced_secrets(),
+        vec!["kafka-sasl".to_string(), "kafka-tls".to_string()]
+    );
+
+    let remapper = create_remapper(spec);
+    assert!(remapper::validate(&remapper).is_ok());
+}
+
+// ============================================================================
+// Pause Annotation Tests
+// ============================================================================
+
+fn paused_remapper(value: &str) -> KafkaPartitionRemapper {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.metadata.annotations = Some(
+        [(PAUSED_ANNOTATION.to_string(), value.to_string())]
+            .into_iter()
+            .collect(),
+    );
+    remapper
+}
+
+#[test]
+fn remapper_pause_annotation_is_detected() {
+    assert!(paused_remapper("true").is_paused());
+    assert!(paused_remapper("True").is_paused());
+    assert!(!paused_remapper("false").is_paused());
+    assert!(!create_remapper(valid_remapper_spec()).is_paused());
+}
+
+#[test]
+fn paused_status_keeps_observed_state_and_adds_paused_condition() {
+    let mut remapper = paused_remapper("true");
+    remapper.status = Some(KafkaPartitionRemapperStatus {
+        phase: Some("Running".to_string()),
+        ready_replicas: Some(2),
+        ..Default::default()
+    });
+
+    let status = remapper::paused_status(&remapper);
+
+    assert_eq!(status.phase.as_deref(), Some("Paused"));
+    assert_eq!(status.ready_replicas, Some(2));
+    let paused = status
+        .conditions
+        .iter()
+        .find(|c| c.type_ == "Paused")
+        .expect("Paused condition should be set");
+    assert_eq!(paused.status, "True");
+    let ready = status
+        .conditions
+        .iter()
+        .find(|c| c.type_ == "Ready")
+        .expect("Ready condition should be set");
+    assert_eq!(ready.status, "False");
+}
+
+#[test]
+fn paused_condition_keeps_transition_time_across_reconciles() {
+    let mut remapper = paused_remapper("true");
+    remapper.metadata.generation = Some(3);
+    let first = remapper::paused_status(&remapper);
+
+    remapper.status = Some(first.clone());
+    let second = remapper::paused_status(&remapper);
+
+    let paused = |status: &KafkaPartitionRemapperStatus| {
+        status
+            .conditions
+            .iter()
+            .find(|c| c.type_ == "Paused")
+            .cloned()
+            .unwrap()
+    };
+    assert_eq!(
+        paused(&first).last_transition_time,
+        paused(&second).last_transition_time
+    );
+    assert_eq!(paused(&second).observed_generation, Some(3));
+}
+
+// ============================================================================
+// Field Conflict Tests
+// ============================================================================
+
+fn conflict(message: &str) -> kube::Error {
+    kube::Error::Api(kube::core::ErrorResponse {
+        status: "Failure".to_string(),
+        message: message.to_string(),
+        reason: "Conflict".to_string(),
+        code: 409,
+    })
+}
+
+#[test]
+fn apply_conflict_names_the_owning_managers() {
+    let err = remapper::apply_error(
+        "Deployment",
+        conflict(
+            "Apply failed with 2 conflicts: conflict with \"kubectl-edit\" using apps/v1: \
+             .spec.replicas, conflict with \"argocd-controller\" using apps/v1: .spec.template",
+        ),
+    );
+    let Error::FieldConflict(message) = err else {
+        panic!("expected a field conflict, got {:?}", err);
+    };
+    assert!(message.starts_with("Deployment has fields owned by kubectl-edit, argocd-controller"));
+
+    let err = remapper::apply_error(
+        "Service",
+        kube::Error::Api(kube::core::ErrorResponse {
+            status: "Failure".to_string(),
+            message: "forbidden".to_string(),
+            reason: "Forbidden".to_string(),
+            code: 403,
+        }),
+    );
+    assert!(matches!(err, Error::KubeError { .. }));
+    assert!(!err.is_retryable());
+}
+
+#[test]
+fn field_conflict_status_reports_condition() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.status = Some(KafkaPartitionRemapperStatus {
+        phase: Some("Running".to_string()),
+        ..Default::default()
+    });
+
+    let status = remapper::field_conflict_status(&remapper, "Service has fields owned by kubectl");
+
+    assert_eq!(status.phase.as_deref(), Some("Failed"));
+    let conflict = status
+        .conditions
+        .iter()
+        .find(|c| c.type_ == "FieldConflict")
+        .expect("FieldConflict condition should be set");
+    assert_eq!(conflict.status, "True");
+    assert_eq!(
+        conflict.message.as_deref(),
+        Some("Service has fields owned by kubectl")
+    );
+}
+
+#[test]
+fn invalid_spec_status_stalls_the_current_generation() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.metadata.generation = Some(4);
+    remapper.status = Some(KafkaPartitionRemapperStatus {
+        phase: Some("Running".to_string()),
+        ready_replicas: Some(2),
+        observed_generation: Some(3),
+        ..Default::default()
+    });
+
+    let status = remapper::invalid_spec_status(&remapper, "Validation error: bad mapping");
+
+    assert_eq!(status.phase.as_deref(), Some("Failed"));
+    assert_eq!(status.ready_replicas, Some(2));
+    assert_eq!(status.observed_generation, Some(4));
+    let state = |type_: &str| {
+        status
+            .conditions
+            .iter()
+            .find(|c| c.type_ == type_)
+            .map(|c| c.status.as_str())
+    };
+    assert_eq!(state("ConfigValid"), Some("False"));
+    assert_eq!(state("Ready"), Some("False"));
+    assert_eq!(state("Stalled"), Some("True"));
+    assert_eq!(state("Reconciling"), Some("False"));
+}
+
+#[test]
+fn retries_exhausted_status_stalls_with_the_last_error() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.metadata.generation = Some(2);
+    remapper.status = Some(KafkaPartitionRemapperStatus {
+        phase: Some("Running".to_string()),
+        conditions: vec![condition("ConfigValid", "True", 10)],
+        ..Default::default()
+    });
+
+    let status =
+        remapper::retries_exhausted_status(&remapper, 9, "Kubernetes API error: timed out");
+
+    assert_eq!(status.phase.as_deref(), Some("Failed"));
+    assert_eq!(
+        status.message.as_deref(),
+        Some("Reconcile failed 9 times in a row: Kubernetes API error: timed out")
+    );
+    let find = |type_: &str| status.conditions.iter().find(|c| c.type_ == type_);
+    assert_eq!(find("Stalled").map(|c| c.status.as_str()), Some("True"));
+    assert_eq!(
+        find("Ready").and_then(|c| c.reason.as_deref()),
+        Some("RetriesExhausted")
+    );
+    assert_eq!(find("ConfigValid").map(|c| c.status.as_str()), Some("True"));
+}
+
+#[test]
+fn retries_are_counted_per_object_and_reset() {
+    let retries = Retries::default();
+    let first = create_remapper(valid_remapper_spec());
+    let mut second = create_remapper(valid_remapper_spec());
+    second.metadata.name = Some("other".to_string());
+
+    assert_eq!(retries.record_failure(&first), 1);
+    assert_eq!(retries.record_failure(&first), 2);
+    assert_eq!(retries.record_failure(&second), 1);
+    retries.reset(&first);
+    assert_eq!(retries.failures(&first), 0);
+    assert_eq!(retries.failures(&second), 1);
+}
+
+#[test]
+fn error_cause_unwraps_finalizer_failures() {
+    let err = Error::from(kube::runtime::finalizer::Error::ApplyFailed(
+        Error::KafkaError("timed out".to_string()),
+    ));
+    assert!(matches!(err.cause(), Error::KafkaError(_)));
+
+    let err = Error::ValidationError("bad".to_string());
+    assert!(matches!(err.cause(), Error::ValidationError(_)));
+}
+
+#[test]
+fn errors_are_classified_by_retryability() {
+    use kafka_partition_remapper_operator::settings::RequeueSettings;
+
+    let api_error = |code| {
+        Error::kube(
+            "Failed to get Deployment",
+            kube::Error::Api(kube::core::ErrorResponse {
+                status: "Failure".to_string(),
+                message: "failed".to_string(),
+                reason: "Failed".to_string(),
+                code,
+            }),
+        )
+    };
+    let requeue = RequeueSettings::default();
+
+    let throttled = api_error(429);
+    assert!(throttled.is_retryable());
+    assert_eq!(throttled.metric_label(), "kube");
+    assert_eq!(throttled.retry_after(&requeue), requeue.api_error());
+    assert!(api_error(503).is_retryable());
+
+    let forbidden = api_error(403);
+    assert!(!forbidden.is_retryable());
+    assert_eq!(forbidden.retry_after(&requeue), requeue.invalid_spec());
+
+    let invalid = Error::from(kube::runtime::finalizer::Error::ApplyFailed(
+        Error::ValidationError("bad".to_string()),
+    ));
+    assert!(!invalid.is_retryable());
+    assert_eq!(invalid.metric_label(), "validation");
+
+    let unreachable = Error::KafkaError("timed out".to_string());
+    assert!(unreachable.is_retryable());
+    assert_eq!(unreachable.retry_after(&requeue), requeue.error());
+    assert!(std::error::Error::source(&throttled).is_some());
+}
+
+#[test]
+fn progress_conditions_follow_kstatus_conventions() {
+    let active = |phase: &str| {
+        remapper::progress_conditions(phase, None, Utc::now())
+            .into_iter()
+            .filter(|c| c.status == "True")
+            .map(|c| c.type_)
+            .collect::<Vec<_>>()
+    };
+    for phase in ["Pending", "Updating", "Degraded"] {
+        assert_eq!(active(phase), ["Reconciling"], "{}", phase);
+    }
+    assert_eq!(active("Failed"), ["Stalled"]);
+    for phase in ["Running", "Suspended", "Paused"] {
+        assert!(active(phase).is_empty(), "{}", phase);
+    }
+}
+
+// ============================================================================
+// Condition Tests
+// ============================================================================
+
+fn condition(type_: &str, status: &str, minutes_ago: i64) -> Condition {
+    Condition {
+        type_: type_.to_string(),
+        status: status.to_string(),
+        last_transition_time: Utc::now() - chrono::Duration::minutes(minutes_ago),
+        observed_generation: Some(1),
+        reason: None,
+        message: None,
+    }
+}
+
+#[test]
+fn merged_conditions_only_transition_when_status_flips() {
+    let existing = vec![
+        condition("Ready", "True", 60),
+        condition("KafkaReachable", "True", 60),
+        condition("TopicsConsistent", "True", 60),
+    ];
+    let desired = vec![
+        condition("Ready", "True", 0),
+        condition("KafkaReachable", "False", 0),
+    ];
+
+    let merged = remapper::merge_conditions(Some(&existing), desired.clone(), Some(2));
+
+    assert_eq!(merged.len(), 2);
+    assert_eq!(
+        merged[0].last_transition_time,
+        existing[0].last_transition_time
+    );
+    assert_eq!(
+        merged[1].last_transition_time,
+        desired[1].last_transition_time
+    );
+    assert!(merged.iter().all(|c| c.observed_generation == Some(2)));
+}
+
+#[test]
+fn merged_conditions_without_history_keep_new_times() {
+    let desired = vec![condition("Ready", "False", 0)];
+    let merged = remapper::merge_conditions(None, desired.clone(), None);
+
+    assert_eq!(
+        merged[0].last_transition_time,
+        desired[0].last_transition_time
+    );
+    assert_eq!(merged[0].observed_generation, None);
+}
+
+// ============================================================================
+// Workload Health Tests
+// ============================================================================
+
+fn pod_waiting(reason: &str, last_terminated: Option<&str>) -> Pod {
+    Pod {
+        metadata: default_metadata("test-remapper-0"),
+        status: Some(PodStatus {
+            container_statuses: Some(vec![ContainerStatus {
+                name: "remapper".to_string(),
+                state: Some(ContainerState {
+                    waiting: Some(ContainerStateWaiting {
+                        reason: Some(reason.to_string()),
+                        message: Some("back-off restarting".to_string()),
+                    }),
+                    ..Default::default()
+                }),
+                last_state: last_terminated.map(|reason| ContainerState {
+                    terminated: Some(ContainerStateTerminated {
+                        reason: Some(reason.to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn workload_healthy_without_failing_pods() {
+    let condition = remapper::workload_healthy_condition(&[pod_waiting("ContainerCreating", None)]);
+    assert_eq!(condition.type_, "WorkloadHealthy");
+    assert_eq!(condition.status, "True");
+    assert_eq!(condition.reason.as_deref(), Some("PodsHealthy"));
+}
+
+#[test]
+fn workload_unhealthy_reports_container_reason() {
+    let crash = remapper::workload_healthy_condition(&[pod_waiting("CrashLoopBackOff", None)]);
+    assert_eq!(crash.status, "False");
+    assert_eq!(crash.reason.as_deref(), Some("CrashLoopBackOff"));
+    assert!(crash.message.unwrap().contains("test-remapper-0"));
+
+    let image = remapper::workload_healthy_condition(&[pod_waiting("ImagePullBackOff", None)]);
+    assert_eq!(image.reason.as_deref(), Some("ImagePullBackOff"));
+
+    let oom =
+        remapper::workload_healthy_condition(&[pod_waiting("CrashLoopBackOff", Some("OOMKilled"))]);
+    assert_eq!(oom.reason.as_deref(), Some("OOMKilled"));
+}
+
+#[test]
+fn workload_unhealthy_reports_unschedulable_pods() {
+    let pod = Pod {
+        metadata: default_metadata("test-remapper-0"),
+        status: Some(PodStatus {
+            conditions: Some(vec![PodCondition {
+                type_: "PodScheduled".to_string(),
+                status: "False".to_string(),
+                reason: Some("Unschedulable".to_string()),
+                message: Some("0/3 nodes are available: 3 Insufficient cpu".to_string()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let condition = remapper::workload_healthy_condition(&[pod]);
+    assert_eq!(condition.status, "False");
+    assert_eq!(condition.reason.as_deref(), Some("Unschedulable"));
+    assert!(condition.message.unwrap().contains("Insufficient cpu"));
+}
+
+// ============================================================================
+// Secret Content Tests
+// ============================================================================
+
+fn secret_with_keys(keys: &[&str]) -> Secret {
+    Secret {
+        data: Some(
+            keys.iter()
+                .map(|k| (k.to_string(), ByteString(b"value".to_vec())))
+                .collect(),
+        ),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn required_secret_keys_follow_the_spec() {
+    let mut spec = valid_remapper_spec();
+    spec.kafka.security_protocol = SecurityProtocol::SaslSsl;
+    spec.kafka.tls_secret = Some(
+        serde_json::from_value(serde_json::json!({ "name": "kafka-tls", "certKey": "tls.crt" }))
+            .unwrap(),
+    );
+    spec.kafka.sasl_secret = Some(
+        serde_json::from_value(serde_json::json!({ "name": "kafka-creds", "mechanism": "PLAIN" }))
+            .unwrap(),
+    );
+
+    let required = spec.required_secret_keys();
+    assert_eq!(required["kafka-tls"], vec!["ca.crt", "tls.crt"]);
+    assert_eq!(required["kafka-creds"], vec!["password", "username"]);
+}
+
+#[test]
+fn secrets_valid_reports_missing_secrets_and_keys() {
+    let required = BTreeMap::from([
+        ("kafka-tls".to_string(), vec!["ca.crt".to_string()]),
+        (
+            "kafka-creds".to_string(),
+            vec!["password".to_string(), "username".to_string()],
+        ),
+    ]);
+
+    let fetched = BTreeMap::from([
+        ("kafka-tls".to_string(), None),
+        (
+            "kafka-creds".to_string(),
+            Some(secret_with_keys(&["username"])),
+        ),
+    ]);
+    let problems = remapper::secret_problems(&required, &fetched);
+    assert_eq!(
+        problems,
+        vec![
+            "Secret kafka-creds is missing keys: password",
+            "Secret kafka-tls not found",
+        ]
+    );
+    let condition = remapper::secrets_valid_condition(&problems);
+    assert_eq!(condition.type_, "SecretsValid");
+    assert_eq!(condition.status, "False");
+
+    let fetched = BTreeMap::from([
+        ("kafka-tls".to_string(), Some(secret_with_keys(&["ca.crt"]))),
+        (
+            "kafka-creds".to_string(),
+            Some(secret_with_keys(&["username", "password"])),
+        ),
+    ]);
+    let problems = remapper::secret_problems(&required, &fetched);
+    assert!(problems.is_empty());
+    assert_eq!(remapper::secrets_valid_condition(&problems).status, "True");
+}
+
+// ============================================================================
+// Adoption Tests
+// ============================================================================
+
+fn existing_child(owners: Option<Vec<OwnerReference>>) -> ObjectMeta {
+    ObjectMeta {
+        name: Some("test-remapper".to_string()),
+        namespace: Some("default".to_string()),
+        owner_references: owners,
+        ..Default::default()
+    }
+}
+
+fn owner(kind: &str, uid: &str) -> OwnerReference {
+    OwnerReference {
+        api_version: "v1".to_string(),
+        kind: kind.to_string(),
+        name: "owner".to_string(),
+        uid: uid.to_string(),
+        controller: Some(true),
+        block_owner_deletion: None,
+    }
+}
+
+fn owned_remapper() -> KafkaPartitionRemapper {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.metadata.uid = Some("remapper-uid".to_string());
+    remapper
+}
+
+#[test]
+fn missing_or_owned_child_needs_no_adoption() {
+    let remapper = owned_remapper();
+    assert!(!remapper::check_ownership(&remapper, None, "Deployment").unwrap());
+
+    let child = existing_child(Some(vec![owner("KafkaPartitionRemapper", "remapper-uid")]));
+    assert!(!remapper::check_ownership(&remapper, Some(&child), "Deployment").unwrap());
+}
+
+#[test]
+fn unowned_child_requires_adopt_annotation() {
+    let mut remapper = owned_remapper();
+    let child = existing_child(None);
+
+    let err = remapper::check_ownership(&remapper, Some(&child), "Service").unwrap_err();
+    assert!(err.to_string().contains(ADOPT_ANNOTATION));
+
+    remapper.metadata.annotations = Some(
+        [(ADOPT_ANNOTATION.to_string(), "true".to_string())]
+            .into_iter()
+            .collect(),
+    );
+    assert!(remapper::check_ownership(&remapper, Some(&child), "Service").unwrap());
+}
+
+#[test]
+fn child_controlled_by_another_owner_is_never_adopted() {
+    let mut remapper = owned_remapper();
+    remapper.metadata.annotations = Some(
+        [(ADOPT_ANNOTATION.to_string(), "true".to_string())]
+            .into_iter()
+            .collect(),
+    );
+    let child = existing_child(Some(vec![owner("KafkaPartitionRemapper", "other-uid")]));
+
+    assert!(remapper::check_ownership(&remapper, Some(&child), "Deployment").is_err());
+}
+
+#[test]
+fn cross_namespace_child_is_owned_by_labels() {
+    let mut remapper = owned_remapper();
+    remapper.spec.target_namespace = Some("apps".to_string());
+    let mut child = existing_child(None);
+    child.namespace = Some("apps".to_string());
+    assert!(remapper::check_ownership(&remapper, Some(&child), "Deployment").is_err());
+
+    child.labels = Some(BTreeMap::from([
+        (OWNER_NAME_LABEL.to_string(), "test-remapper".to_string()),
+        (OWNER_NAMESPACE_LABEL.to_string(), "default".to_string()),
+    ]));
+    assert!(remapper::is_owned_by(&remapper, &child));
+    assert!(!remapper::check_ownership(&remapper, Some(&child), "Deployment").unwrap());
+}
+
+#[test]
+fn foreign_child_with_same_name_is_not_owned() {
+    let remapper = owned_remapper();
+    assert!(!remapper::is_owned_by(&remapper, &existing_child(None)));
+    assert!(!remapper::is_owned_by(
+        &remapper,
+        &existing_child(Some(vec![owner("KafkaPartitionRemapper", "other-uid")]))
+    ));
+    assert!(remapper::is_owned_by(
+        &remapper,
+        &existing_child(Some(vec![owner("KafkaPartitionRemapper", "remapper-uid")]))
+    ));
+}
+
+#[test]
+fn child_generated_under_previous_name_is_pruned() {
+    let mut remapper = owned_remapper();
+    let mut child = existing_child(Some(vec![owner("KafkaPartitionRemapper", "remapper-uid")]));
+    child.labels = Some(BTreeMap::from([(
+        "app.kubernetes.io/instance".to_string(),
+        remapper.resource_name(),
+    )]));
+    assert!(!remapper::is_renamed_child(&remapper, &child));
+
+    remapper.spec.name_override = Some("proxy".to_string());
+    assert!(remapper::is_renamed_child(&remapper, &child));
+
+    // Children of other remappers are left alone
+    child.owner_references = Some(vec![owner("KafkaPartitionRemapper", "other-uid")]);
+    assert!(!remapper::is_renamed_child(&remapper, &child));
+}
+
+// ============================================================================
+// Mapping Change Guard Tests
+// ============================================================================
+
+#[test]
+fn mapping_growth_is_not_a_breaking_change() {
+    let previous = valid_mapping_spec();
+    let mut spec = valid_remapper_spec();
+    spec.mapping.virtual_partitions = 2000;
+    spec.mapping.physical_partitions = 200;
+
+    let remapper = create_remapper(spec);
+    assert!(remapper::validate_mapping_change(&remapper, &previous).is_ok());
+}
+
+#[test]
+fn offset_range_change_is_rejected() {
+    let previous = valid_mapping_spec();
+    let mut spec = valid_remapper_spec();
+    spec.mapping.offset_range = 1 << 32;
+
+    let remapper = create_remapper(spec);
+    let err = remapper::validate_mapping_change(&remapper, &previous).unwrap_err();
+    assert!(err.to_string().contains("offsetRange"));
+}
+
+#[test]
+fn topic_virtual_partition_reduction_is_rejected() {
+    let mut previous = valid_mapping_spec();
+    previous.topics = vec![TopicMappingOverride {
+        topic: "orders".to_string(),
+        virtual_partitions: Some(500),
+        physical_partitions: None,
+        offset_range: None,
+    }];
+    let mut spec = valid_remapper_spec();
+    spec.mapping.topics = vec![TopicMappingOverride {
+        topic: "orders".to_string(),
+        virtual_partitions: Some(400),
+        physical_partitions: None,
+        offset_range: None,
+    }];
+
+    let remapper = create_remapper(spec);
+    let err = remapper::validate_mapping_change(&remapper, &previous).unwrap_err();
+    assert!(err.to_string().contains("orders"));
+}
+
+#[test]
+fn breaking_change_annotation_bypasses_guard() {
+    let previous = valid_mapping_spec();
+    let mut spec = valid_remapper_spec();
+    spec.mapping.virtual_partitions = 500;
+
+    let mut remapper = create_remapper(spec);
+    remapper.metadata.annotations = Some(
+        [(
+            ALLOW_BREAKING_CHANGE_ANNOTATION.to_string(),
+            "true".to_string(),
+        )]
+        .into_iter()
+        .collect(),
+    );
+    assert!(remapper::validate_mapping_change(&remapper, &previous).is_ok());
+}
+
+#[test]
+fn remapper_audit_schedule_must_be_a_cron_expression() {
+    let mut spec = valid_remapper_spec();
+    spec.audit = serde_json::from_value(serde_json::json!({})).ok();
+    assert!(remapper::validate(&create_remapper(spec.clone())).is_ok());
+
+    spec.audit.as_mut().unwrap().schedule = "hourly".to_string();
+    let err = remapper::validate(&create_remapper(spec)).unwrap_err();
+    assert!(err.to_string().contains("audit.schedule"));
 }
@@ -0,0 +1,223 @@
+//! Integration tests for KafkaPartitionRemapTask orchestration
+//!
+//! These tests cover the pure parts of a migration: building the new proxy
+//! generation, validating the task and judging consumer group progress.
+
+use k8s_openapi::api::apps::v1::{Deployment, DeploymentSpec, DeploymentStatus};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kafka_partition_remapper_operator::adapters::deployment_builder;
+use kafka_partition_remapper_operator::adapters::kafka_admin::GroupDescription;
+use kafka_partition_remapper_operator::adapters::users;
+use kafka_partition_remapper_operator::crd::{
+    KafkaPartitionRemapTask, KafkaPartitionRemapTaskSpec, KafkaPartitionRemapTaskStatus,
+    KafkaPartitionRemapper, KafkaPartitionRemapperSpec, WorkloadKind,
+};
+use kafka_partition_remapper_operator::reconcilers::remap_task;
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn remapper_spec(value: serde_json::Value) -> KafkaPartitionRemapperSpec {
+    serde_json::from_value(value).expect("valid spec")
+}
+
+fn create_remapper(spec: KafkaPartitionRemapperSpec) -> KafkaPartitionRemapper {
+    KafkaPartitionRemapper {
+        metadata: ObjectMeta {
+            name: Some("orders-proxy".to_string()),
+            namespace: Some("kafka".to_string()),
+            uid: Some("remapper-uid".to_string()),
+            ..Default::default()
+        },
+        spec,
+        status: None,
+    }
+}
+
+fn valid_remapper() -> KafkaPartitionRemapper {
+    create_remapper(remapper_spec(serde_json::json!({
+        "replicas": 2,
+        "workload": "StatefulSet",
+        "listeners": [{ "name": "kafka", "advertisedAddress": "proxy.example.com:9092" }],
+        "kafka": { "bootstrapServers": ["kafka:9092"] },
+        "mapping": { "virtualPartitions": 1000, "physicalPartitions": 100 },
+        "autoscaling": { "enabled": true, "maxReplicas": 5 }
+    })))
+}
+
+fn create_task(virtual_partitions: u32, physical_partitions: u32) -> KafkaPartitionRemapTask {
+    let spec: KafkaPartitionRemapTaskSpec = serde_json::from_value(serde_json::json!({
+        "remapperRef": "orders-proxy",
+        "targetMapping": {
+            "virtualPartitions": virtual_partitions,
+            "physicalPartitions": physical_partitions
+        },
+        "consumerGroups": ["billing"]
+    }))
+    .expect("valid task");
+
+    KafkaPartitionRemapTask {
+        metadata: ObjectMeta {
+            name: Some("grow".to_string()),
+            namespace: Some("kafka".to_string()),
+            uid: Some("task-uid".to_string()),
+            ..Default::default()
+        },
+        spec,
+        status: None,
+    }
+}
+
+fn group(members: &[&str]) -> GroupDescription {
+    GroupDescription {
+        group_id: "billing".to_string(),
+        state: if members.is_empty() {
+            "Empty"
+        } else {
+            "Stable"
+        }
+        .to_string(),
+        member_hosts: members.iter().map(|m| m.to_string()).collect(),
+    }
+}
+
+// ============================================================================
+// Generation Tests
+// ============================================================================
+
+#[test]
+fn generation_runs_target_mapping_as_fixed_deployment() {
+    let remapper = valid_remapper();
+    let task = create_task(2000, 200);
+
+    let generation = remap_task::build_generation(&remapper, &task);
+
+    assert_eq!(
+        generation.metadata.name.as_deref(),
+        Some("orders-proxy-grow")
+    );
+    assert_eq!(generation.metadata.namespace.as_deref(), Some("kafka"));
+    assert_eq!(generation.spec.mapping.virtual_partitions, 2000);
+    assert_eq!(generation.spec.mapping.physical_partitions, 200);
+    assert_eq!(generation.spec.workload, WorkloadKind::Deployment);
+    assert!(generation.spec.autoscaling.is_none());
+    assert!(generation.spec.listeners[0].advertised_address.is_none());
+    assert_eq!(generation.spec.replicas, 2);
+}
+
+#[test]
+fn generation_reads_the_remappers_generated_users() {
+    let remapper = valid_remapper();
+    let generation = remap_task::build_generation(&remapper, &create_task(2000, 200));
+
+    assert_eq!(
+        users::users_secret_name(&generation),
+        users::users_secret_name(&remapper)
+    );
+    assert_eq!(users::users_secret_name(&remapper), "orders-proxy-users");
+}
+
+#[test]
+fn task_validation_accepts_new_mapping() {
+    assert!(remap_task::validate(&valid_remapper(), &create_task(2000, 200)).is_ok());
+}
+
+#[test]
+fn task_validation_rejects_unchanged_or_invalid_mapping() {
+    let remapper = valid_remapper();
+
+    let err = remap_task::validate(&remapper, &create_task(1000, 100)).unwrap_err();
+    assert!(err.to_string().contains("matches the current mapping"));
+
+    // Virtual partitions must be a multiple of physical partitions
+    assert!(remap_task::validate(&remapper, &create_task(1001, 100)).is_err());
+}
+
+#[test]
+fn task_validation_rejects_sharded_remapper() {
+    let remapper = create_remapper(remapper_spec(serde_json::json!({
+        "replicas": 2,
+        "workload": "StatefulSet",
+        "kafka": { "bootstrapServers": ["kafka:9092"] },
+        "mapping": { "virtualPartitions": 1000, "physicalPartitions": 100 },
+        "sharding": { "enabled": true }
+    })));
+
+    let err = remap_task::validate(&remapper, &create_task(2000, 200)).unwrap_err();
+    assert!(err.to_string().contains("sharded"));
+}
+
+// ============================================================================
+// Progress Tests
+// ============================================================================
+
+#[test]
+fn group_is_migrated_once_members_use_the_generation() {
+    let hosts = vec!["10.0.0.5".to_string(), "10.0.0.6".to_string()];
+
+    let moved = remap_task::group_progress(&group(&["10.0.0.5", "10.0.0.6"]), &hosts);
+    assert!(moved.migrated);
+    assert_eq!(moved.remaining_members, 0);
+
+    let partial = remap_task::group_progress(&group(&["10.0.0.5", "10.0.0.9"]), &hosts);
+    assert!(!partial.migrated);
+    assert_eq!(partial.remaining_members, 1);
+
+    let empty = remap_task::group_progress(&group(&[]), &hosts);
+    assert!(empty.migrated);
+    assert_eq!(empty.state, "Empty");
+}
+
+#[test]
+fn deployment_rollout_requires_updated_ready_replicas() {
+    let mut deployment = Deployment {
+        metadata: ObjectMeta {
+            generation: Some(3),
+            ..Default::default()
+        },
+        spec: Some(DeploymentSpec {
+            replicas: Some(2),
+            ..Default::default()
+        }),
+        status: Some(DeploymentStatus {
+            observed_generation: Some(3),
+            replicas: Some(3),
+            updated_replicas: Some(2),
+            ready_replicas: Some(3),
+            ..Default::default()
+        }),
+    };
+    // An old pod is still terminating
+    assert!(!deployment_builder::rolled_out(&deployment));
+
+    deployment.status.as_mut().unwrap().replicas = Some(2);
+    assert!(deployment_builder::rolled_out(&deployment));
+
+    deployment.metadata.generation = Some(4);
+    assert!(!deployment_builder::rolled_out(&deployment));
+}
+
+#[test]
+fn next_status_tracks_start_and_completion_times() {
+    let mut task = create_task(2000, 200);
+
+    let provisioning = remap_task::next_status(&task, "Provisioning", "starting", None);
+    assert_eq!(
+        provisioning.generation_name.as_deref(),
+        Some("orders-proxy-grow")
+    );
+    assert!(provisioning.start_time.is_some());
+    assert!(provisioning.completion_time.is_none());
+
+    task.status = Some(KafkaPartitionRemapTaskStatus {
+        start_time: provisioning.start_time,
+        ..provisioning
+    });
+    let completed = remap_task::next_status(&task, "Completed", "done", None);
+    assert_eq!(
+        completed.start_time,
+        task.status.as_ref().unwrap().start_time
+    );
+    assert!(completed.completion_time.is_some());
+}
@@ -0,0 +1,3047 @@
+//! Integration tests for Kubernetes resource builders
+//!
+//! These tests verify that the adapters render the expected child resources
+//! for a KafkaPartitionRemapper.
+
+use chrono::Utc;
+use k8s_openapi::api::apps::v1::{DeploymentCondition, DeploymentStatus, StatefulSetStatus};
+use k8s_openapi::api::core::v1::{LoadBalancerIngress, LoadBalancerStatus, ServiceStatus};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{FieldsV1, ManagedFieldsEntry, ObjectMeta};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kafka_partition_remapper_operator::adapters::{
+    consistency_audit_builder, deployment_builder, horizontal_pod_autoscaler_builder,
+    keda_scaled_object_builder, pod_disruption_budget_builder, remapper_config, service_builder,
+    sharding, stateful_set_builder, tcp_route_builder, users,
+};
+use kafka_partition_remapper_operator::crd::{
+    reachable_broker_address, AutoscalingSpec, CustomMetricSpec, DeletionPolicy,
+    KafkaPartitionRemapper, KafkaPartitionRemapperSpec, KafkaPartitionRemapperStatus,
+    KafkaRemapperTopicOverride, LogLevel, PodDisruptionBudgetSpec, SecurityProtocol, ServiceType,
+    ShardingSpec, SuspensionStatus, WorkloadKind, OWNER_NAMESPACE_LABEL, OWNER_NAME_LABEL,
+    RESTARTED_AT_ANNOTATION, SERVING_GENERATION_ANNOTATION,
+};
+use kafka_partition_remapper_operator::reconcilers::remapper;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn valid_remapper_spec() -> KafkaPartitionRemapperSpec {
+    serde_json::from_value(serde_json::json!({
+        "replicas": 3,
+        "kafka": { "bootstrapServers": ["kafka:9092"] },
+        "mapping": { "virtualPartitions": 1000, "physicalPartitions": 100 }
+    }))
+    .expect("valid spec")
+}
+
+fn create_remapper(spec: KafkaPartitionRemapperSpec) -> KafkaPartitionRemapper {
+    KafkaPartitionRemapper {
+        metadata: ObjectMeta {
+            name: Some("test-remapper".to_string()),
+            namespace: Some("default".to_string()),
+            uid: Some("1234".to_string()),
+            ..Default::default()
+        },
+        spec,
+        status: None,
+    }
+}
+
+// ============================================================================
+// PodDisruptionBudget Builder Tests
+// ============================================================================
+
+#[test]
+fn pdb_not_built_when_unconfigured_or_disabled() {
+    let remapper = create_remapper(valid_remapper_spec());
+    assert!(pod_disruption_budget_builder::build_pod_disruption_budget(&remapper).is_none());
+
+    let mut spec = valid_remapper_spec();
+    spec.pod_disruption_budget = Some(PodDisruptionBudgetSpec {
+        enabled: false,
+        min_available: None,
+        max_unavailable: None,
+    });
+    let remapper = create_remapper(spec);
+    assert!(pod_disruption_budget_builder::build_pod_disruption_budget(&remapper).is_none());
+}
+
+#[test]
+fn pdb_defaults_to_max_unavailable_one() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_disruption_budget = Some(PodDisruptionBudgetSpec {
+        enabled: true,
+        min_available: None,
+        max_unavailable: None,
+    });
+    let remapper = create_remapper(spec);
+
+    let pdb = pod_disruption_budget_builder::build_pod_disruption_budget(&remapper).unwrap();
+    let pdb_spec = pdb.spec.unwrap();
+    assert_eq!(pdb_spec.max_unavailable, Some(IntOrString::Int(1)));
+    assert_eq!(pdb_spec.min_available, None);
+    assert_eq!(
+        pdb_spec
+            .selector
+            .unwrap()
+            .match_labels
+            .unwrap()
+            .get("app.kubernetes.io/instance"),
+        Some(&"test-remapper".to_string())
+    );
+}
+
+#[test]
+fn pdb_uses_configured_min_available() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_disruption_budget = Some(PodDisruptionBudgetSpec {
+        enabled: true,
+        min_available: Some(IntOrString::String("50%".to_string())),
+        max_unavailable: None,
+    });
+    let remapper = create_remapper(spec);
+
+    let pdb = pod_disruption_budget_builder::build_pod_disruption_budget(&remapper).unwrap();
+    let pdb_spec = pdb.spec.unwrap();
+    assert_eq!(
+        pdb_spec.min_available,
+        Some(IntOrString::String("50%".to_string()))
+    );
+    assert_eq!(pdb_spec.max_unavailable, None);
+}
+
+#[test]
+fn pdb_is_recognised_as_owned_for_deletion() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_disruption_budget = Some(PodDisruptionBudgetSpec {
+        enabled: true,
+        min_available: None,
+        max_unavailable: None,
+    });
+    let mut remapper = create_remapper(spec);
+    let pdb = pod_disruption_budget_builder::build_pod_disruption_budget(&remapper).unwrap();
+    assert!(remapper::is_owned_by(&remapper, &pdb.metadata));
+
+    remapper.spec.target_namespace = Some("apps".to_string());
+    let pdb = pod_disruption_budget_builder::build_pod_disruption_budget(&remapper).unwrap();
+    assert!(remapper::is_owned_by(&remapper, &pdb.metadata));
+}
+
+// ============================================================================
+// HorizontalPodAutoscaler Builder Tests
+// ============================================================================
+
+fn autoscaling_spec() -> AutoscalingSpec {
+    AutoscalingSpec {
+        enabled: true,
+        min_replicas: Some(2),
+        max_replicas: 6,
+        target_cpu_utilization_percentage: None,
+        target_memory_utilization_percentage: None,
+        custom_metrics: vec![],
+        keda: None,
+    }
+}
+
+#[test]
+fn hpa_defaults_to_cpu_target_and_releases_deployment_replicas() {
+    let mut spec = valid_remapper_spec();
+    spec.autoscaling = Some(autoscaling_spec());
+    let remapper = create_remapper(spec);
+
+    let hpa = horizontal_pod_autoscaler_builder::build_horizontal_pod_autoscaler(&remapper)
+        .expect("hpa should be built");
+    let hpa_spec = hpa.spec.unwrap();
+    assert_eq!(hpa_spec.scale_target_ref.kind, "Deployment");
+    assert_eq!(hpa_spec.scale_target_ref.name, "test-remapper");
+    assert_eq!(hpa_spec.min_replicas, Some(2));
+    assert_eq!(hpa_spec.max_replicas, 6);
+
+    let metrics = hpa_spec.metrics.unwrap();
+    assert_eq!(metrics.len(), 1);
+    let cpu = metrics[0].resource.as_ref().unwrap();
+    assert_eq!(cpu.name, "cpu");
+    assert_eq!(cpu.target.average_utilization, Some(80));
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    assert_eq!(deployment.spec.unwrap().replicas, None);
+}
+
+#[test]
+fn hpa_renders_custom_metrics_without_default_cpu() {
+    let mut spec = valid_remapper_spec();
+    let mut autoscaling = autoscaling_spec();
+    autoscaling.custom_metrics = vec![CustomMetricSpec {
+        name: "kafka_proxy_active_connections".to_string(),
+        target_average_value: "500".to_string(),
+    }];
+    spec.autoscaling = Some(autoscaling);
+    let remapper = create_remapper(spec);
+
+    let hpa = horizontal_pod_autoscaler_builder::build_horizontal_pod_autoscaler(&remapper)
+        .expect("hpa should be built");
+    let metrics = hpa.spec.unwrap().metrics.unwrap();
+    assert_eq!(metrics.len(), 1);
+    assert_eq!(metrics[0].type_, "Pods");
+    assert_eq!(
+        metrics[0].pods.as_ref().unwrap().metric.name,
+        "kafka_proxy_active_connections"
+    );
+}
+
+#[test]
+fn hpa_not_built_when_suspended() {
+    let mut spec = valid_remapper_spec();
+    spec.autoscaling = Some(autoscaling_spec());
+    spec.suspend = true;
+    let remapper = create_remapper(spec);
+
+    assert!(
+        horizontal_pod_autoscaler_builder::build_horizontal_pod_autoscaler(&remapper).is_none()
+    );
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    assert_eq!(deployment.spec.unwrap().replicas, Some(0));
+}
+
+#[test]
+fn keda_scaled_object_replaces_hpa_with_prometheus_triggers() {
+    let mut spec = valid_remapper_spec();
+    let mut autoscaling = autoscaling_spec();
+    autoscaling.keda = Some(
+        serde_json::from_value(serde_json::json!({
+            "serverAddress": "http://prometheus.monitoring:9090",
+            "targetConnectionsPerPod": 200,
+            "targetBytesPerSecondPerPod": 10485760,
+            "cooldownPeriodSeconds": 600
+        }))
+        .unwrap(),
+    );
+    spec.autoscaling = Some(autoscaling);
+    let remapper = create_remapper(spec);
+
+    assert!(
+        horizontal_pod_autoscaler_builder::build_horizontal_pod_autoscaler(&remapper).is_none()
+    );
+    let scaled_object =
+        keda_scaled_object_builder::build_scaled_object(&remapper).expect("scaled object");
+    assert_eq!(scaled_object.types.as_ref().unwrap().kind, "ScaledObject");
+
+    let so_spec = &scaled_object.data["spec"];
+    assert_eq!(so_spec["scaleTargetRef"]["kind"], "Deployment");
+    assert_eq!(so_spec["scaleTargetRef"]["name"], "test-remapper");
+    assert_eq!(so_spec["minReplicaCount"], 2);
+    assert_eq!(so_spec["maxReplicaCount"], 6);
+    assert_eq!(so_spec["cooldownPeriod"], 600);
+    assert!(so_spec.get("pollingInterval").is_none());
+
+    let triggers = so_spec["triggers"].as_array().unwrap();
+    assert_eq!(triggers.len(), 2);
+    assert_eq!(triggers[0]["type"], "prometheus");
+    assert_eq!(
+        triggers[0]["metadata"]["serverAddress"],
+        "http://prometheus.monitoring:9090"
+    );
+    assert_eq!(triggers[0]["metadata"]["threshold"], "200");
+    let query = triggers[0]["metadata"]["query"].as_str().unwrap();
+    assert!(query.starts_with("sum(kafka_proxy_active_connections{"));
+    assert!(query.contains("pod=~\"test-remapper-.*\""));
+    let query = triggers[1]["metadata"]["query"].as_str().unwrap();
+    assert!(query.starts_with("sum(rate(kafka_proxy_bytes_total{"));
+    assert_eq!(triggers[1]["metadata"]["threshold"], "10485760");
+}
+
+#[test]
+fn keda_scaled_object_not_built_when_suspended_or_disabled() {
+    let mut spec = valid_remapper_spec();
+    let mut autoscaling = autoscaling_spec();
+    autoscaling.keda = Some(
+        serde_json::from_value(serde_json::json!({
+            "serverAddress": "http://prometheus:9090",
+            "targetConnectionsPerPod": 200
+        }))
+        .unwrap(),
+    );
+    spec.autoscaling = Some(autoscaling);
+    spec.suspend = true;
+    let mut remapper = create_remapper(spec);
+    assert!(keda_scaled_object_builder::build_scaled_object(&remapper).is_none());
+
+    remapper.spec.suspend = false;
+    let keda = remapper.spec.autoscaling.as_mut().unwrap().keda.as_mut();
+    keda.unwrap().enabled = false;
+    assert!(keda_scaled_object_builder::build_scaled_object(&remapper).is_none());
+    assert!(
+        horizontal_pod_autoscaler_builder::build_horizontal_pod_autoscaler(&remapper).is_some()
+    );
+}
+
+#[test]
+fn keda_scaled_object_is_cleaned_up_across_namespaces_and_renames() {
+    let mut spec = valid_remapper_spec();
+    let mut autoscaling = autoscaling_spec();
+    autoscaling.keda = Some(
+        serde_json::from_value(serde_json::json!({
+            "serverAddress": "http://prometheus:9090",
+            "targetConnectionsPerPod": 200
+        }))
+        .unwrap(),
+    );
+    spec.autoscaling = Some(autoscaling);
+    spec.target_namespace = Some("apps".to_string());
+    let mut remapper = create_remapper(spec);
+
+    let scaled_object = keda_scaled_object_builder::build_scaled_object(&remapper).unwrap();
+    assert_eq!(scaled_object.metadata.namespace.as_deref(), Some("apps"));
+    assert!(remapper::is_owned_by(&remapper, &scaled_object.metadata));
+    assert!(!remapper::is_renamed_child(
+        &remapper,
+        &scaled_object.metadata
+    ));
+
+    remapper.spec.name_override = Some("proxy".to_string());
+    assert!(remapper::is_renamed_child(
+        &remapper,
+        &scaled_object.metadata
+    ));
+}
+
+#[test]
+fn suspension_is_recorded_once_and_restored_under_autoscaling() {
+    let mut spec = valid_remapper_spec();
+    spec.autoscaling = Some(autoscaling_spec());
+    spec.suspend = true;
+    let mut remapper = create_remapper(spec);
+    remapper.status = Some(KafkaPartitionRemapperStatus {
+        replicas: Some(7),
+        ..Default::default()
+    });
+
+    let suspended_at = Utc::now() - chrono::Duration::minutes(5);
+    let suspension = remapper::suspension_status(&remapper, Some(7), suspended_at).unwrap();
+    assert_eq!(suspension.previous_replicas, 7);
+    assert_eq!(suspension.resumed_at, None);
+
+    // Later reconciles keep the original record while the workload is at 0
+    remapper.status = Some(KafkaPartitionRemapperStatus {
+        replicas: Some(0),
+        suspension: Some(suspension.clone()),
+        ..Default::default()
+    });
+    assert_eq!(
+        remapper::suspension_status(&remapper, Some(0), Utc::now()).as_ref(),
+        Some(&suspension)
+    );
+    assert_eq!(remapper::replicas_to_restore(&remapper), None);
+    let condition = remapper::suspended_condition(Some(&suspension), Utc::now());
+    assert_eq!(condition.status, "True");
+
+    // Resuming restores the recorded count through the scale subresource,
+    // never through the applied workload
+    remapper.spec.suspend = false;
+    assert_eq!(remapper::replicas_to_restore(&remapper), Some(7));
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    assert_eq!(deployment.spec.unwrap().replicas, None);
+}
+
+#[test]
+fn resume_under_autoscaling_survives_consecutive_reconciles() {
+    let mut spec = valid_remapper_spec();
+    spec.autoscaling = Some(autoscaling_spec());
+    let mut remapper = create_remapper(spec);
+    let suspended_at = Utc::now() - chrono::Duration::minutes(5);
+    remapper.status = Some(KafkaPartitionRemapperStatus {
+        replicas: Some(0),
+        suspension: Some(SuspensionStatus {
+            suspended_at,
+            previous_replicas: 7,
+            resumed_at: None,
+        }),
+        ..Default::default()
+    });
+
+    // First reconcile after resume: the scale is restored and, with the
+    // workload not yet reporting it, the record is kept as resumed
+    assert_eq!(remapper::replicas_to_restore(&remapper), Some(7));
+    let resumed_at = Utc::now();
+    let first = remapper::suspension_status(&remapper, Some(0), resumed_at).unwrap();
+    assert_eq!(first.resumed_at, Some(resumed_at));
+    assert_eq!(first.previous_replicas, 7);
+    let condition = remapper::suspended_condition(Some(&first), resumed_at);
+    assert_eq!(condition.status, "False");
+
+    // Second reconcile: no second restore, and the workload still leaves
+    // replicas to the scale subresource
+    remapper.status.as_mut().unwrap().suspension = Some(first.clone());
+    assert_eq!(remapper::replicas_to_restore(&remapper), None);
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    assert_eq!(deployment.spec.unwrap().replicas, None);
+    assert_eq!(
+        remapper::suspension_status(&remapper, Some(0), Utc::now()),
+        Some(first.clone())
+    );
+
+    // Once the workload reports the restored count the record is dropped
+    assert_eq!(
+        remapper::suspension_status(&remapper, Some(7), Utc::now()),
+        None
+    );
+
+    // Suspending again starts a new record rather than reusing the resumed one
+    remapper.spec.suspend = true;
+    remapper.status.as_mut().unwrap().replicas = Some(4);
+    let again = remapper::suspension_status(&remapper, Some(4), Utc::now()).unwrap();
+    assert_eq!(again.previous_replicas, 4);
+    assert_eq!(again.resumed_at, None);
+}
+
+#[test]
+fn autoscaling_handover_keeps_the_applied_replica_count() {
+    let mut spec = valid_remapper_spec();
+    spec.autoscaling = Some(autoscaling_spec());
+    let mut remapper = create_remapper(spec);
+    let managed = |manager: &str, fields: serde_json::Value| ObjectMeta {
+        managed_fields: Some(vec![ManagedFieldsEntry {
+            manager: Some(manager.to_string()),
+            operation: Some("Apply".to_string()),
+            fields_v1: Some(FieldsV1(fields)),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    let owned = managed(
+        remapper::FIELD_MANAGER,
+        serde_json::json!({ "f:spec": { "f:replicas": {}, "f:template": {} } }),
+    );
+
+    // The operator still owns replicas from before autoscaling, so the
+    // current count is applied rather than dropped back to one
+    assert_eq!(
+        remapper::handover_replicas(&remapper, &owned, Some(3)),
+        Some(3)
+    );
+
+    // Once the HPA has scaled, replicas is no longer the operator's field
+    let released = managed(
+        remapper::FIELD_MANAGER,
+        serde_json::json!({ "f:spec": { "f:template": {} } }),
+    );
+    assert_eq!(
+        remapper::handover_replicas(&remapper, &released, Some(5)),
+        None
+    );
+    let other = managed(
+        "kube-controller-manager",
+        serde_json::json!({ "f:spec": { "f:replicas": {} } }),
+    );
+    assert_eq!(
+        remapper::handover_replicas(&remapper, &other, Some(5)),
+        None
+    );
+
+    // Without autoscaling the spec count is applied anyway
+    remapper.spec.autoscaling = None;
+    assert_eq!(
+        remapper::handover_replicas(&remapper, &owned, Some(5)),
+        None
+    );
+}
+
+#[test]
+fn restarted_at_annotation_is_copied_to_the_pod_template() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    let template_annotations = |remapper: &KafkaPartitionRemapper| {
+        deployment_builder::build_deployment(remapper, "cm", "hash", None)
+            .spec
+            .unwrap()
+            .template
+            .metadata
+            .unwrap()
+            .annotations
+            .unwrap()
+    };
+    assert!(!template_annotations(&remapper).contains_key(RESTARTED_AT_ANNOTATION));
+
+    remapper.metadata.annotations = Some(BTreeMap::from([(
+        RESTARTED_AT_ANNOTATION.to_string(),
+        "2026-01-01T00:00:00Z".to_string(),
+    )]));
+    assert_eq!(
+        template_annotations(&remapper)
+            .get(RESTARTED_AT_ANNOTATION)
+            .map(String::as_str),
+        Some("2026-01-01T00:00:00Z")
+    );
+    // A restart rolls the pods without counting as a spec change
+    assert_eq!(
+        remapper::calculate_config_hash(&remapper),
+        remapper::calculate_config_hash(&create_remapper(valid_remapper_spec()))
+    );
+}
+
+// ============================================================================
+// Scaling Tests
+// ============================================================================
+
+#[test]
+fn label_selector_matches_pod_labels() {
+    let remapper = create_remapper(valid_remapper_spec());
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let pod_labels = deployment
+        .spec
+        .unwrap()
+        .template
+        .metadata
+        .unwrap()
+        .labels
+        .unwrap();
+
+    let selector = deployment_builder::build_label_selector("test-remapper");
+    for pair in selector.split(',') {
+        let (key, value) = pair.split_once('=').unwrap();
+        assert_eq!(pod_labels.get(key).map(String::as_str), Some(value));
+    }
+}
+
+#[test]
+fn config_hash_ignores_replica_changes() {
+    let remapper = create_remapper(valid_remapper_spec());
+    let mut scaled_spec = valid_remapper_spec();
+    scaled_spec.replicas = 7;
+    let scaled = create_remapper(scaled_spec);
+
+    assert_eq!(
+        remapper::calculate_config_hash(&remapper),
+        remapper::calculate_config_hash(&scaled)
+    );
+
+    let mut changed_spec = valid_remapper_spec();
+    changed_spec.logging.level = LogLevel::Debug;
+    let changed = create_remapper(changed_spec);
+    assert_ne!(
+        remapper::calculate_config_hash(&remapper),
+        remapper::calculate_config_hash(&changed)
+    );
+}
+
+#[test]
+fn deletion_policy_defaults_to_delete_and_is_excluded_from_config_hash() {
+    let remapper = create_remapper(valid_remapper_spec());
+    assert_eq!(remapper.spec.deletion_policy, DeletionPolicy::Delete);
+
+    let mut retained_spec = valid_remapper_spec();
+    retained_spec.deletion_policy = DeletionPolicy::Retain;
+    let retained = create_remapper(retained_spec);
+
+    assert_eq!(
+        remapper::calculate_config_hash(&remapper),
+        remapper::calculate_config_hash(&retained)
+    );
+}
+
+#[test]
+fn service_routes_to_serving_generation_during_cutover() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.metadata.annotations = Some(
+        [(
+            SERVING_GENERATION_ANNOTATION.to_string(),
+            "test-remapper-grow".to_string(),
+        )]
+        .into_iter()
+        .collect(),
+    );
+
+    let service = service_builder::build_service(&remapper);
+    let selector = service.spec.unwrap().selector.unwrap();
+    assert_eq!(
+        selector
+            .get("app.kubernetes.io/instance")
+            .map(String::as_str),
+        Some("test-remapper-grow")
+    );
+    assert_eq!(
+        service
+            .metadata
+            .labels
+            .unwrap()
+            .get("app.kubernetes.io/instance")
+            .map(String::as_str),
+        Some("test-remapper")
+    );
+}
+
+#[test]
+fn load_balancer_endpoint_appears_once_address_is_assigned() {
+    let mut spec = valid_remapper_spec();
+    spec.service.type_ = ServiceType::LoadBalancer;
+    let remapper = create_remapper(spec);
+    let mut service = service_builder::build_service(&remapper);
+
+    assert!(service_builder::load_balancer_pending(&service));
+    assert_eq!(
+        service_builder::get_service_endpoint(&service, &remapper.spec).as_deref(),
+        Some("test-remapper.default.svc.cluster.local:9092")
+    );
+
+    service.status = Some(ServiceStatus {
+        load_balancer: Some(LoadBalancerStatus {
+            ingress: Some(vec![LoadBalancerIngress {
+                hostname: Some("lb.example.com".to_string()),
+                ..Default::default()
+            }]),
+        }),
+        ..Default::default()
+    });
+    assert!(!service_builder::load_balancer_pending(&service));
+    assert_eq!(
+        service_builder::get_service_endpoint(&service, &remapper.spec).as_deref(),
+        Some("lb.example.com:9092")
+    );
+
+    // Only LoadBalancer Services wait for an address
+    let cluster_ip = service_builder::build_service(&create_remapper(valid_remapper_spec()));
+    assert!(!service_builder::load_balancer_pending(&cluster_ip));
+}
+
+#[test]
+fn load_balancer_requeue_backs_off_while_pending() {
+    let now = chrono::Utc::now();
+    let max = Duration::from_secs(30);
+
+    assert_eq!(
+        remapper::load_balancer_requeue(now, now, max),
+        Duration::from_secs(5)
+    );
+    assert_eq!(
+        remapper::load_balancer_requeue(now - chrono::Duration::seconds(12), now, max),
+        Duration::from_secs(12)
+    );
+    assert_eq!(
+        remapper::load_balancer_requeue(now - chrono::Duration::minutes(10), now, max),
+        max
+    );
+}
+
+// ============================================================================
+// Secret Checksum Tests
+// ============================================================================
+
+fn secret_with(key: &str, value: &str) -> k8s_openapi::api::core::v1::Secret {
+    let mut data = std::collections::BTreeMap::new();
+    data.insert(
+        key.to_string(),
+        k8s_openapi::ByteString(value.as_bytes().to_vec()),
+    );
+    k8s_openapi::api::core::v1::Secret {
+        data: Some(data),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn referenced_secrets_are_collected_and_deduplicated() {
+    let spec: KafkaPartitionRemapperSpec = serde_json::from_value(serde_json::json!({
+        "listeners": [{
+            "name": "external",
+            "security": {
+                "protocol": "SASL_SSL",
+                "tls": { "certificateSecret": { "name": "proxy-tls" } },
+                "sasl": { "credentialsSecret": { "name": "client-users" } }
+            }
+        }],
+        "kafka": {
+            "bootstrapServers": ["kafka:9093"],
+            "tlsSecret": { "name": "kafka-creds" },
+            "saslSecret": { "name": "kafka-creds", "mechanism": "PLAIN" }
+        },
+        "mapping": { "virtualPartitions": 100, "physicalPartitions": 10 }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        spec.referenced_secrets(),
+        vec!["client-users", "kafka-creds", "proxy-tls"]
+    );
+}
+
+#[test]
+fn secrets_hash_changes_with_secret_content() {
+    let before =
+        remapper::hash_secrets(&[("creds".to_string(), Some(secret_with("password", "a")))]);
+    let after =
+        remapper::hash_secrets(&[("creds".to_string(), Some(secret_with("password", "b")))]);
+    let missing = remapper::hash_secrets(&[("creds".to_string(), None)]);
+
+    assert_ne!(before, after);
+    assert_ne!(before, missing);
+
+    let mut spec = valid_remapper_spec();
+    spec.replicas = 1;
+    let remapper = create_remapper(spec);
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", Some(&before));
+    let annotations = deployment
+        .spec
+        .unwrap()
+        .template
+        .metadata
+        .unwrap()
+        .annotations
+        .unwrap();
+    assert_eq!(annotations.get("checksum/secrets"), Some(&before));
+}
+
+#[test]
+fn rollout_tuning_is_set_on_the_deployment() {
+    let mut spec = valid_remapper_spec();
+    assert!(deployment_builder::build_deployment(
+        &create_remapper(spec.clone()),
+        "cm",
+        "hash",
+        None
+    )
+    .spec
+    .unwrap()
+    .strategy
+    .is_none());
+
+    spec.rollout_strategy = Some(
+        serde_json::from_value(serde_json::json!({
+            "maxSurge": "25%",
+            "maxUnavailable": 0,
+            "progressDeadlineSeconds": 900,
+            "revisionHistoryLimit": 3
+        }))
+        .unwrap(),
+    );
+    let deployment_spec =
+        deployment_builder::build_deployment(&create_remapper(spec), "cm", "hash", None)
+            .spec
+            .unwrap();
+    let rolling_update = deployment_spec.strategy.unwrap().rolling_update.unwrap();
+    assert_eq!(
+        rolling_update.max_surge,
+        Some(IntOrString::String("25%".to_string()))
+    );
+    assert_eq!(rolling_update.max_unavailable, Some(IntOrString::Int(0)));
+    assert_eq!(deployment_spec.progress_deadline_seconds, Some(900));
+    assert_eq!(deployment_spec.revision_history_limit, Some(3));
+}
+
+#[test]
+fn common_labels_and_annotations_reach_every_child() {
+    let mut spec = valid_remapper_spec();
+    spec.common_labels = BTreeMap::from([("cost-center".to_string(), "1234".to_string())]);
+    spec.common_annotations = BTreeMap::from([
+        ("owner".to_string(), "platform".to_string()),
+        ("team".to_string(), "common".to_string()),
+    ]);
+    spec.service.annotations = BTreeMap::from([("team".to_string(), "service".to_string())]);
+    spec.pod_disruption_budget =
+        Some(serde_json::from_value(serde_json::json!({ "enabled": true })).unwrap());
+    let remapper = create_remapper(spec);
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let service = service_builder::build_service(&remapper);
+    let pdb = pod_disruption_budget_builder::build_pod_disruption_budget(&remapper).unwrap();
+    for metadata in [
+        &config_map.metadata,
+        &deployment.metadata,
+        &service.metadata,
+        &pdb.metadata,
+    ] {
+        let labels = metadata.labels.as_ref().unwrap();
+        assert_eq!(labels["cost-center"], "1234");
+        assert_eq!(labels["app.kubernetes.io/instance"], "test-remapper");
+        assert_eq!(metadata.annotations.as_ref().unwrap()["owner"], "platform");
+    }
+    // Resource-specific annotations win over common ones
+    assert_eq!(service.metadata.annotations.unwrap()["team"], "service");
+
+    let spec = deployment.spec.unwrap();
+    let pod_metadata = spec.template.metadata.unwrap();
+    assert_eq!(pod_metadata.labels.unwrap()["cost-center"], "1234");
+    assert_eq!(pod_metadata.annotations.unwrap()["owner"], "platform");
+    // Selectors are immutable, so common labels stay out of them
+    assert!(!spec
+        .selector
+        .match_labels
+        .unwrap()
+        .contains_key("cost-center"));
+}
+
+// ============================================================================
+// StatefulSet Workload Tests
+// ============================================================================
+
+#[test]
+fn stateful_set_uses_headless_service_and_per_pod_config() {
+    let mut spec = valid_remapper_spec();
+    spec.workload = WorkloadKind::StatefulSet;
+    let remapper = create_remapper(spec);
+
+    let stateful_set = stateful_set_builder::build_stateful_set(&remapper, "cm", "hash", None);
+    let sts_spec = stateful_set.spec.unwrap();
+    assert_eq!(sts_spec.service_name, "test-remapper-headless");
+    assert_eq!(sts_spec.replicas, Some(3));
+
+    let container = &sts_spec.template.spec.unwrap().containers[0];
+    assert_eq!(
+        container.args.as_ref().unwrap()[1],
+        "/etc/kafka-proxy/$(POD_NAME).yaml"
+    );
+    let pod_name = container
+        .env
+        .as_ref()
+        .unwrap()
+        .iter()
+        .find(|e| e.name == "POD_NAME")
+        .expect("POD_NAME env var");
+    assert_eq!(
+        pod_name
+            .value_from
+            .as_ref()
+            .and_then(|v| v.field_ref.as_ref())
+            .map(|f| f.field_path.as_str()),
+        Some("metadata.name")
+    );
+
+    let hpa_remapper = {
+        let mut r = remapper.clone();
+        r.spec.autoscaling = Some(autoscaling_spec());
+        r
+    };
+    let hpa = horizontal_pod_autoscaler_builder::build_horizontal_pod_autoscaler(&hpa_remapper)
+        .expect("hpa should be built");
+    assert_eq!(hpa.spec.unwrap().scale_target_ref.kind, "StatefulSet");
+}
+
+#[test]
+fn headless_service_exposes_stable_pod_addresses() {
+    let mut spec = valid_remapper_spec();
+    spec.workload = WorkloadKind::StatefulSet;
+    let remapper = create_remapper(spec);
+
+    let service = service_builder::build_headless_service(&remapper);
+    assert_eq!(
+        service.metadata.name.as_deref(),
+        Some("test-remapper-headless")
+    );
+    let service_spec = service.spec.unwrap();
+    assert_eq!(service_spec.cluster_ip.as_deref(), Some("None"));
+    assert_eq!(service_spec.publish_not_ready_addresses, Some(true));
+
+    assert_eq!(
+        service_builder::pod_dns_address("test-remapper", 1, "default", 9092),
+        "test-remapper-1.test-remapper-headless.default.svc.cluster.local:9092"
+    );
+
+    let deployment = deployment_builder::build_deployment(
+        &create_remapper(valid_remapper_spec()),
+        "cm",
+        "hash",
+        None,
+    );
+    let container = &deployment.spec.unwrap().template.spec.unwrap().containers[0];
+    assert_eq!(
+        container.args.as_ref().unwrap()[1],
+        "/etc/kafka-proxy/config.yaml"
+    );
+}
+
+// ============================================================================
+// Render Tests
+// ============================================================================
+
+#[test]
+fn render_lists_config_map_workload_and_services() {
+    let kinds = |remapper: &KafkaPartitionRemapper| -> Vec<String> {
+        remapper::render_manifests(remapper)
+            .unwrap()
+            .iter()
+            .map(|m| {
+                let value: serde_yaml::Value = serde_yaml::from_str(m).unwrap();
+                value["kind"].as_str().unwrap().to_string()
+            })
+            .collect()
+    };
+
+    let remapper = create_remapper(valid_remapper_spec());
+    assert_eq!(kinds(&remapper), ["ConfigMap", "Deployment", "Service"]);
+
+    let mut spec = valid_remapper_spec();
+    spec.workload = WorkloadKind::StatefulSet;
+    assert_eq!(
+        kinds(&create_remapper(spec)),
+        ["ConfigMap", "Service", "StatefulSet", "Service"]
+    );
+
+    let mut spec = valid_remapper_spec();
+    spec.listeners.clear();
+    assert!(remapper::render_manifests(&create_remapper(spec)).is_err());
+}
+
+// ============================================================================
+// Listener Tests
+// ============================================================================
+
+fn internal_and_external_listeners() -> KafkaPartitionRemapper {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.listeners = serde_json::from_value(serde_json::json!([
+        { "name": "internal", "port": 9092 },
+        {
+            "name": "external",
+            "port": 9094,
+            "advertisedAddress": "kafka.example.com:9094",
+            "security": {
+                "protocol": "SASL_SSL",
+                "tls": { "certificateSecret": { "name": "proxy-tls" } },
+                "sasl": { "credentialsSecret": { "name": "client-users" } }
+            }
+        }
+    ]))
+    .unwrap();
+    remapper
+}
+
+#[test]
+fn every_listener_gets_container_and_service_ports() {
+    let mut remapper = internal_and_external_listeners();
+    remapper.spec.probes = Some(
+        serde_json::from_value(serde_json::json!({ "readiness": { "type": "Tcp" } })).unwrap(),
+    );
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let pod_spec = deployment.spec.unwrap().template.spec.unwrap();
+    let container = &pod_spec.containers[0];
+    let ports: Vec<_> = container
+        .ports
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|p| (p.name.clone().unwrap(), p.container_port))
+        .collect();
+    assert_eq!(
+        ports,
+        vec![
+            ("internal".to_string(), 9092),
+            ("external".to_string(), 9094),
+            ("metrics".to_string(), 9090),
+        ]
+    );
+    assert_eq!(
+        container
+            .readiness_probe
+            .as_ref()
+            .unwrap()
+            .tcp_socket
+            .as_ref()
+            .unwrap()
+            .port,
+        IntOrString::String("internal".to_string())
+    );
+    let mounts: Vec<_> = container
+        .volume_mounts
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|m| m.mount_path.as_str())
+        .collect();
+    assert!(mounts.contains(&"/etc/kafka-proxy/tls/listeners/external"));
+    assert!(mounts.contains(&"/etc/kafka-proxy/sasl/external"));
+
+    let service = service_builder::build_service(&remapper);
+    let service_ports: Vec<_> = service
+        .spec
+        .unwrap()
+        .ports
+        .unwrap()
+        .into_iter()
+        .map(|p| (p.name.unwrap(), p.port))
+        .collect();
+    assert_eq!(
+        service_ports,
+        vec![
+            ("internal".to_string(), 9092),
+            ("external".to_string(), 9094),
+            ("metrics".to_string(), 9090),
+        ]
+    );
+}
+
+#[test]
+fn proxy_config_renders_one_entry_per_listener() {
+    let remapper = internal_and_external_listeners();
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let listeners = config["listeners"].as_sequence().unwrap();
+    assert_eq!(listeners.len(), 2);
+
+    assert_eq!(listeners[0]["address"].as_str(), Some("0.0.0.0:9092"));
+    assert_eq!(
+        listeners[0]["advertised_address"].as_str(),
+        Some("test-remapper.default.svc.cluster.local:9092")
+    );
+    assert_eq!(
+        listeners[0]["security_protocol"].as_str(),
+        Some("PLAINTEXT")
+    );
+
+    assert_eq!(
+        listeners[1]["advertised_address"].as_str(),
+        Some("kafka.example.com:9094")
+    );
+    assert_eq!(listeners[1]["security_protocol"].as_str(), Some("SASL_SSL"));
+    assert_eq!(
+        listeners[1]["tls"]["cert_file"].as_str(),
+        Some("/etc/kafka-proxy/tls/listeners/external/tls.crt")
+    );
+    assert_eq!(
+        listeners[1]["sasl"]["mechanisms"][0].as_str(),
+        Some("PLAIN")
+    );
+}
+
+#[test]
+fn mtls_listener_renders_client_ca_and_projects_secret_keys() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.listeners = serde_json::from_value(serde_json::json!([{
+        "name": "mtls",
+        "port": 9093,
+        "security": {
+            "protocol": "SSL",
+            "tls": {
+                "certificateSecret": { "name": "proxy-tls", "certKey": "server.crt", "keyKey": "server.key" },
+                "clientCaSecret": { "name": "client-ca" },
+                "requireClientCert": true
+            }
+        }
+    }]))
+    .unwrap();
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let tls = &config["listeners"][0]["tls"];
+    assert_eq!(
+        tls["key_file"].as_str(),
+        Some("/etc/kafka-proxy/tls/listeners/mtls/server.key")
+    );
+    assert_eq!(
+        tls["ca_file"].as_str(),
+        Some("/etc/kafka-proxy/tls/listeners/mtls-ca/ca.crt")
+    );
+    assert_eq!(tls["require_client_cert"].as_bool(), Some(true));
+    assert!(config["listeners"][0]["sasl"].is_null());
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let volumes = deployment
+        .spec
+        .unwrap()
+        .template
+        .spec
+        .unwrap()
+        .volumes
+        .unwrap();
+    let tls_volume = volumes
+        .iter()
+        .find(|v| v.name == "listener-mtls-tls")
+        .and_then(|v| v.secret.as_ref())
+        .expect("listener tls volume");
+    let keys: Vec<_> = tls_volume
+        .items
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|i| i.key.as_str())
+        .collect();
+    assert_eq!(keys, vec!["server.crt", "server.key"]);
+    assert!(volumes.iter().any(|v| v.name == "listener-mtls-ca"));
+}
+
+#[test]
+fn external_service_exposes_selected_listeners_only() {
+    let mut remapper = internal_and_external_listeners();
+    assert!(service_builder::build_external_service(&remapper).is_none());
+
+    remapper.spec.external_service = Some(
+        serde_json::from_value(serde_json::json!({
+            "annotations": { "service.beta.kubernetes.io/aws-load-balancer-scheme": "internet-facing" },
+            "listeners": ["external"]
+        }))
+        .unwrap(),
+    );
+    let service = service_builder::build_external_service(&remapper).expect("external service");
+    assert_eq!(
+        service.metadata.name.as_deref(),
+        Some("test-remapper-external")
+    );
+    // Only an external Service carrying the remapper's ownership is removed
+    assert!(remapper::is_owned_by(&remapper, &service.metadata));
+    assert!(service
+        .metadata
+        .annotations
+        .unwrap()
+        .contains_key("service.beta.kubernetes.io/aws-load-balancer-scheme"));
+    let spec = service.spec.unwrap();
+    assert_eq!(spec.type_.as_deref(), Some("LoadBalancer"));
+    let ports = spec.ports.unwrap();
+    assert_eq!(ports.len(), 1);
+    assert_eq!(ports[0].port, 9094);
+
+    // The internal Service keeps its own type
+    let internal = service_builder::build_service(&remapper);
+    assert_eq!(internal.spec.unwrap().type_.as_deref(), Some("ClusterIP"));
+
+    remapper.spec.external_service.as_mut().unwrap().enabled = false;
+    assert!(service_builder::build_external_service(&remapper).is_none());
+}
+
+#[test]
+fn tcp_route_targets_selected_listener_port() {
+    let mut remapper = internal_and_external_listeners();
+    assert!(tcp_route_builder::build_tcp_route(&remapper).is_none());
+
+    remapper.spec.gateway = Some(
+        serde_json::from_value(serde_json::json!({
+            "parentRef": { "name": "edge", "namespace": "gateways", "sectionName": "kafka" },
+            "listener": "external"
+        }))
+        .unwrap(),
+    );
+    let route = tcp_route_builder::build_tcp_route(&remapper).expect("tcp route");
+    assert_eq!(route.types.as_ref().unwrap().kind, "TCPRoute");
+    assert_eq!(route.metadata.name.as_deref(), Some("test-remapper"));
+
+    let parent = &route.data["spec"]["parentRefs"][0];
+    assert_eq!(parent["name"], "edge");
+    assert_eq!(parent["namespace"], "gateways");
+    assert_eq!(parent["sectionName"], "kafka");
+    let backend = &route.data["spec"]["rules"][0]["backendRefs"][0];
+    assert_eq!(backend["name"], "test-remapper");
+    assert_eq!(backend["port"], 9094);
+
+    remapper.spec.gateway.as_mut().unwrap().listener = None;
+    let route = tcp_route_builder::build_tcp_route(&remapper).unwrap();
+    assert_eq!(
+        route.data["spec"]["rules"][0]["backendRefs"][0]["port"],
+        9092
+    );
+}
+
+#[test]
+fn tcp_route_is_cleaned_up_across_namespaces_and_renames() {
+    let mut remapper = internal_and_external_listeners();
+    remapper.spec.gateway = Some(
+        serde_json::from_value(serde_json::json!({ "parentRef": { "name": "edge" } })).unwrap(),
+    );
+    remapper.spec.target_namespace = Some("apps".to_string());
+    let route = tcp_route_builder::build_tcp_route(&remapper).unwrap();
+    assert_eq!(route.metadata.namespace.as_deref(), Some("apps"));
+    assert!(remapper::is_owned_by(&remapper, &route.metadata));
+    assert!(!remapper::is_renamed_child(&remapper, &route.metadata));
+
+    remapper.spec.name_override = Some("proxy".to_string());
+    assert!(remapper::is_renamed_child(&remapper, &route.metadata));
+}
+
+#[test]
+fn external_hostname_annotates_service_and_sets_default_advertised_address() {
+    let mut remapper = internal_and_external_listeners();
+    remapper.spec.service.external_hostname = Some("kafka.example.org".to_string());
+
+    let service = service_builder::build_service(&remapper);
+    assert_eq!(
+        service
+            .metadata
+            .annotations
+            .unwrap()
+            .get(service_builder::EXTERNAL_DNS_HOSTNAME_ANNOTATION)
+            .map(String::as_str),
+        Some("kafka.example.org")
+    );
+
+    // Listeners without an explicit address advertise the hostname
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    assert_eq!(
+        config["listeners"][0]["advertised_address"].as_str(),
+        Some("kafka.example.org:9092")
+    );
+    assert_eq!(
+        config["listeners"][1]["advertised_address"].as_str(),
+        Some("kafka.example.com:9094")
+    );
+}
+
+// ============================================================================
+// Pod Template Tests
+// ============================================================================
+
+#[test]
+fn init_containers_and_sidecars_are_merged_into_the_pod() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_template = Some(
+        serde_json::from_value(serde_json::json!({
+            "initContainers": [{
+                "name": "wait-for-kafka",
+                "image": "busybox:1.36",
+                "command": ["sh", "-c", "until nc -z kafka 9092; do sleep 2; done"]
+            }],
+            "sidecars": [{
+                "name": "log-shipper",
+                "image": "fluent/fluent-bit:3.0",
+                "ports": [{ "containerPort": 2020 }]
+            }]
+        }))
+        .unwrap(),
+    );
+    let remapper = create_remapper(spec);
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let pod_spec = deployment.spec.unwrap().template.spec.unwrap();
+    let init_containers = pod_spec.init_containers.unwrap();
+    assert_eq!(init_containers.len(), 1);
+    assert_eq!(init_containers[0].name, "wait-for-kafka");
+
+    // The proxy stays the first container so probes and ports resolve to it
+    let names: Vec<_> = pod_spec
+        .containers
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        vec![deployment_builder::PROXY_CONTAINER_NAME, "log-shipper"]
+    );
+    assert_eq!(
+        pod_spec.containers[1].ports.as_ref().unwrap()[0].container_port,
+        2020
+    );
+}
+
+#[test]
+fn extra_env_and_volumes_are_added_to_the_proxy() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_template = Some(
+        serde_json::from_value(serde_json::json!({
+            "env": [{ "name": "RUST_BACKTRACE", "value": "1" }],
+            "envFrom": [{ "configMapRef": { "name": "proxy-flags" } }],
+            "extraVolumes": [
+                { "name": "ca-bundle", "configMap": { "name": "corp-ca" } },
+                { "name": "scratch", "emptyDir": { "medium": "Memory" } }
+            ],
+            "extraVolumeMounts": [
+                { "name": "ca-bundle", "mountPath": "/etc/ssl/corp", "readOnly": true },
+                { "name": "scratch", "mountPath": "/scratch" }
+            ]
+        }))
+        .unwrap(),
+    );
+    let remapper = create_remapper(spec);
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let pod_spec = deployment.spec.unwrap().template.spec.unwrap();
+    let proxy = &pod_spec.containers[0];
+    let env = proxy.env.as_ref().unwrap();
+    assert_eq!(env.last().unwrap().name, "RUST_BACKTRACE");
+    assert_eq!(
+        proxy.env_from.as_ref().unwrap()[0]
+            .config_map_ref
+            .as_ref()
+            .unwrap()
+            .name,
+        "proxy-flags"
+    );
+
+    let volumes = pod_spec.volumes.unwrap();
+    assert_eq!(volumes[0].name, "config");
+    assert!(volumes
+        .iter()
+        .any(|v| v.name == "scratch" && v.empty_dir.is_some()));
+    let mounts: Vec<_> = proxy
+        .volume_mounts
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|m| m.mount_path.as_str())
+        .collect();
+    assert!(mounts.contains(&"/etc/ssl/corp"));
+    assert!(mounts.contains(&"/scratch"));
+}
+
+#[test]
+fn pod_template_scheduling_names_are_applied() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_template = Some(
+        serde_json::from_value(serde_json::json!({
+            "priorityClassName": "system-cluster-critical",
+            "runtimeClassName": "gvisor",
+            "schedulerName": "bin-packer"
+        }))
+        .unwrap(),
+    );
+    let remapper = create_remapper(spec);
+
+    let pod_spec = deployment_builder::build_pod_template(&remapper, "cm", "hash", None)
+        .spec
+        .unwrap();
+    assert_eq!(
+        pod_spec.priority_class_name.as_deref(),
+        Some("system-cluster-critical")
+    );
+    assert_eq!(pod_spec.runtime_class_name.as_deref(), Some("gvisor"));
+    assert_eq!(pod_spec.scheduler_name.as_deref(), Some("bin-packer"));
+}
+
+#[test]
+fn probes_default_to_the_health_endpoint() {
+    let remapper = create_remapper(valid_remapper_spec());
+    let pod_spec = deployment_builder::build_pod_template(&remapper, "cm", "hash", None)
+        .spec
+        .unwrap();
+    let proxy = &pod_spec.containers[0];
+    for probe in [&proxy.liveness_probe, &proxy.readiness_probe] {
+        let http_get = probe.as_ref().unwrap().http_get.as_ref().unwrap();
+        assert_eq!(
+            http_get.path.as_deref(),
+            Some(deployment_builder::HEALTH_PATH)
+        );
+        assert_eq!(http_get.port, IntOrString::String("metrics".to_string()));
+    }
+    assert!(proxy.startup_probe.is_none());
+
+    // Without a metrics endpoint there is nothing to query over HTTP
+    let mut no_metrics = create_remapper(valid_remapper_spec());
+    no_metrics.spec.metrics.enabled = false;
+    let pod_spec = deployment_builder::build_pod_template(&no_metrics, "cm", "hash", None)
+        .spec
+        .unwrap();
+    let liveness = pod_spec.containers[0].liveness_probe.clone().unwrap();
+    assert!(liveness.http_get.is_none());
+    assert!(liveness.tcp_socket.is_some());
+}
+
+#[test]
+fn probe_overrides_replace_type_and_timings() {
+    let mut spec = valid_remapper_spec();
+    spec.probes = Some(
+        serde_json::from_value(serde_json::json!({
+            "liveness": { "type": "Tcp", "port": 9092, "periodSeconds": 30 },
+            "readiness": { "path": "/ready", "failureThreshold": 1 },
+            "startup": { "type": "Exec", "command": ["cat", "/tmp/started"] }
+        }))
+        .unwrap(),
+    );
+    let remapper = create_remapper(spec);
+
+    let pod_spec = deployment_builder::build_pod_template(&remapper, "cm", "hash", None)
+        .spec
+        .unwrap();
+    let proxy = &pod_spec.containers[0];
+
+    let liveness = proxy.liveness_probe.as_ref().unwrap();
+    assert_eq!(
+        liveness.tcp_socket.as_ref().unwrap().port,
+        IntOrString::Int(9092)
+    );
+    assert_eq!(liveness.period_seconds, Some(30));
+    assert_eq!(liveness.timeout_seconds, Some(5));
+
+    let readiness = proxy.readiness_probe.as_ref().unwrap();
+    assert_eq!(
+        readiness.http_get.as_ref().unwrap().path.as_deref(),
+        Some("/ready")
+    );
+    assert_eq!(readiness.failure_threshold, Some(1));
+
+    let startup = proxy.startup_probe.as_ref().unwrap();
+    assert_eq!(
+        startup.exec.as_ref().unwrap().command,
+        Some(vec!["cat".to_string(), "/tmp/started".to_string()])
+    );
+    assert_eq!(startup.failure_threshold, Some(30));
+}
+
+#[test]
+fn kafka_readiness_probes_the_ready_endpoint() {
+    let mut spec = valid_remapper_spec();
+    spec.readiness =
+        Some(serde_json::from_value(serde_json::json!({ "requireKafka": true })).unwrap());
+    spec.probes = Some(
+        serde_json::from_value(serde_json::json!({ "readiness": { "periodSeconds": 2 } })).unwrap(),
+    );
+    let remapper = create_remapper(spec);
+
+    let pod_spec = deployment_builder::build_pod_template(&remapper, "cm", "hash", None)
+        .spec
+        .unwrap();
+    let proxy = &pod_spec.containers[0];
+    let readiness = proxy.readiness_probe.as_ref().unwrap();
+    assert_eq!(
+        readiness.http_get.as_ref().unwrap().path.as_deref(),
+        Some(deployment_builder::READY_PATH)
+    );
+    assert_eq!(readiness.period_seconds, Some(2));
+
+    // Liveness must not restart proxies during a broker outage
+    assert_eq!(
+        proxy
+            .liveness_probe
+            .as_ref()
+            .unwrap()
+            .http_get
+            .as_ref()
+            .unwrap()
+            .path
+            .as_deref(),
+        Some(deployment_builder::HEALTH_PATH)
+    );
+}
+
+#[test]
+fn pods_meet_the_restricted_pod_security_standard_by_default() {
+    let remapper = create_remapper(valid_remapper_spec());
+    let pod_spec = deployment_builder::build_pod_template(&remapper, "cm", "hash", None)
+        .spec
+        .unwrap();
+
+    let pod_context = pod_spec.security_context.as_ref().unwrap();
+    assert_eq!(pod_context.run_as_non_root, Some(true));
+    assert_eq!(
+        pod_context.seccomp_profile.as_ref().unwrap().type_,
+        "RuntimeDefault"
+    );
+
+    let proxy = &pod_spec.containers[0];
+    let context = proxy.security_context.as_ref().unwrap();
+    assert_eq!(context.allow_privilege_escalation, Some(false));
+    assert_eq!(context.read_only_root_filesystem, Some(true));
+    assert_eq!(
+        context.capabilities.as_ref().unwrap().drop,
+        Some(vec!["ALL".to_string()])
+    );
+
+    // The read-only root filesystem still gets a writable /tmp
+    let tmp = proxy
+        .volume_mounts
+        .as_ref()
+        .unwrap()
+        .iter()
+        .find(|m| m.mount_path == "/tmp")
+        .unwrap();
+    let volume = pod_spec
+        .volumes
+        .as_ref()
+        .unwrap()
+        .iter()
+        .find(|v| v.name == tmp.name)
+        .unwrap();
+    assert!(volume.empty_dir.is_some());
+}
+
+#[test]
+fn pod_template_security_contexts_replace_the_defaults() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_template = Some(
+        serde_json::from_value(serde_json::json!({
+            "securityContext": { "runAsUser": 1000, "fsGroup": 2000 },
+            "containerSecurityContext": { "readOnlyRootFilesystem": false }
+        }))
+        .unwrap(),
+    );
+    let remapper = create_remapper(spec);
+
+    let pod_spec = deployment_builder::build_pod_template(&remapper, "cm", "hash", None)
+        .spec
+        .unwrap();
+    let pod_context = pod_spec.security_context.unwrap();
+    assert_eq!(pod_context.run_as_user, Some(1000));
+    assert_eq!(pod_context.fs_group, Some(2000));
+    assert_eq!(
+        pod_spec.containers[0]
+            .security_context
+            .as_ref()
+            .unwrap()
+            .read_only_root_filesystem,
+        Some(false)
+    );
+}
+
+#[test]
+fn host_network_pods_use_host_dns_and_distinct_nodes() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_template = Some(
+        serde_json::from_value(serde_json::json!({
+            "hostNetwork": true,
+            "dnsConfig": { "options": [{ "name": "ndots", "value": "2" }] }
+        }))
+        .unwrap(),
+    );
+    let remapper = create_remapper(spec);
+
+    let pod_spec = deployment_builder::build_pod_template(&remapper, "cm", "hash", None)
+        .spec
+        .unwrap();
+    assert_eq!(pod_spec.host_network, Some(true));
+    assert_eq!(
+        pod_spec.dns_policy.as_deref(),
+        Some("ClusterFirstWithHostNet")
+    );
+    assert_eq!(
+        pod_spec.dns_config.unwrap().options.unwrap()[0]
+            .name
+            .as_deref(),
+        Some("ndots")
+    );
+
+    let required = pod_spec
+        .affinity
+        .unwrap()
+        .pod_anti_affinity
+        .unwrap()
+        .required_during_scheduling_ignored_during_execution
+        .unwrap();
+    assert_eq!(
+        required[0].topology_key,
+        deployment_builder::HOSTNAME_TOPOLOGY_KEY
+    );
+}
+
+#[test]
+fn children_use_the_fullname_override() {
+    let mut spec = valid_remapper_spec();
+    spec.fullname_override = Some("orders-proxy".to_string());
+    let remapper = create_remapper(spec);
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    assert_eq!(deployment.metadata.name.as_deref(), Some("orders-proxy"));
+    let service = service_builder::build_service(&remapper);
+    assert_eq!(service.metadata.name.as_deref(), Some("orders-proxy"));
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    assert_eq!(
+        config_map.metadata.name.as_deref(),
+        Some("orders-proxy-config")
+    );
+}
+
+#[test]
+fn long_remapper_names_produce_valid_child_names_and_labels() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.metadata.name = Some("x".repeat(63));
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let name = deployment.metadata.name.unwrap();
+    assert_eq!(name, remapper.resource_name());
+    assert!(name.len() <= 63);
+    let labels = deployment
+        .spec
+        .unwrap()
+        .template
+        .metadata
+        .unwrap()
+        .labels
+        .unwrap();
+    assert!(labels.values().all(|v| v.len() <= 63));
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    assert!(config_map.metadata.name.unwrap().len() <= 63);
+    assert_eq!(
+        users::users_secret_name(&remapper),
+        format!("{}-users", name)
+    );
+}
+
+#[test]
+fn children_in_another_namespace_use_owner_labels() {
+    let mut spec = valid_remapper_spec();
+    spec.target_namespace = Some("apps".to_string());
+    let remapper = create_remapper(spec);
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    assert_eq!(deployment.metadata.namespace.as_deref(), Some("apps"));
+    assert!(deployment.metadata.owner_references.is_none());
+    let labels = deployment.metadata.labels.unwrap();
+    assert_eq!(labels[OWNER_NAME_LABEL], "test-remapper");
+    assert_eq!(labels[OWNER_NAMESPACE_LABEL], "default");
+
+    let service = service_builder::build_service(&remapper);
+    assert_eq!(service.metadata.namespace.as_deref(), Some("apps"));
+    assert!(service.metadata.owner_references.is_none());
+}
+
+#[test]
+fn target_namespace_matching_the_remapper_keeps_owner_references() {
+    let mut spec = valid_remapper_spec();
+    spec.target_namespace = Some("default".to_string());
+    let remapper = create_remapper(spec);
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    assert_eq!(deployment.metadata.owner_references.unwrap().len(), 1);
+    assert!(!deployment
+        .metadata
+        .labels
+        .unwrap()
+        .contains_key(OWNER_NAME_LABEL));
+}
+
+#[test]
+fn service_renders_dual_stack_and_load_balancer_options() {
+    let mut spec = valid_remapper_spec();
+    spec.workload = WorkloadKind::StatefulSet;
+    spec.service = serde_json::from_value(serde_json::json!({
+        "type": "LoadBalancer",
+        "ipFamilies": ["IPv6", "IPv4"],
+        "ipFamilyPolicy": "RequireDualStack",
+        "internalTrafficPolicy": "Local",
+        "loadBalancerClass": "service.k8s.aws/nlb",
+        "loadBalancerSourceRanges": ["10.0.0.0/8"],
+        "allocateLoadBalancerNodePorts": false
+    }))
+    .unwrap();
+    let remapper = create_remapper(spec);
+
+    let service_spec = service_builder::build_service(&remapper).spec.unwrap();
+    assert_eq!(
+        service_spec.ip_families,
+        Some(vec!["IPv6".to_string(), "IPv4".to_string()])
+    );
+    assert_eq!(
+        service_spec.ip_family_policy.as_deref(),
+        Some("RequireDualStack")
+    );
+    assert_eq!(
+        service_spec.internal_traffic_policy.as_deref(),
+        Some("Local")
+    );
+    assert_eq!(
+        service_spec.load_balancer_class.as_deref(),
+        Some("service.k8s.aws/nlb")
+    );
+    assert_eq!(
+        service_spec.load_balancer_source_ranges,
+        Some(vec!["10.0.0.0/8".to_string()])
+    );
+    assert_eq!(service_spec.allocate_load_balancer_node_ports, Some(false));
+
+    let headless = service_builder::build_headless_service(&remapper)
+        .spec
+        .unwrap();
+    assert_eq!(headless.ip_families, service_spec.ip_families);
+    assert!(headless.load_balancer_class.is_none());
+}
+
+#[test]
+fn service_pins_node_ports_and_session_affinity() {
+    let mut spec = valid_remapper_spec();
+    spec.service = serde_json::from_value(serde_json::json!({
+        "type": "NodePort",
+        "nodePorts": { "kafka": 30092 },
+        "sessionAffinity": "ClientIP",
+        "sessionAffinityTimeoutSeconds": 600
+    }))
+    .unwrap();
+    let remapper = create_remapper(spec);
+
+    let service_spec = service_builder::build_service(&remapper).spec.unwrap();
+    let ports = service_spec.ports.unwrap();
+    let node_port = |name: &str| {
+        ports
+            .iter()
+            .find(|p| p.name.as_deref() == Some(name))
+            .unwrap()
+            .node_port
+    };
+    assert_eq!(node_port("kafka"), Some(30092));
+    assert_eq!(node_port("metrics"), None);
+    assert_eq!(service_spec.session_affinity.as_deref(), Some("ClientIP"));
+    assert_eq!(
+        service_spec
+            .session_affinity_config
+            .unwrap()
+            .client_ip
+            .unwrap()
+            .timeout_seconds,
+        Some(600)
+    );
+}
+
+fn port_names(service: &k8s_openapi::api::core::v1::Service) -> Vec<String> {
+    service
+        .spec
+        .as_ref()
+        .and_then(|s| s.ports.as_ref())
+        .into_iter()
+        .flatten()
+        .filter_map(|p| p.name.clone())
+        .collect()
+}
+
+#[test]
+fn metrics_port_stays_on_client_service_by_default() {
+    let remapper = create_remapper(valid_remapper_spec());
+
+    let service = service_builder::build_service(&remapper);
+    assert!(port_names(&service).contains(&"metrics".to_string()));
+    assert!(service_builder::build_metrics_service(&remapper).is_none());
+}
+
+#[test]
+fn dedicated_metrics_service_takes_the_metrics_port() {
+    let mut spec = valid_remapper_spec();
+    spec.service.type_ = ServiceType::LoadBalancer;
+    spec.metrics = serde_json::from_value(serde_json::json!({
+        "service": { "type": "Headless", "annotations": { "prometheus.io/scrape": "true" } }
+    }))
+    .unwrap();
+    let remapper = create_remapper(spec);
+
+    let service = service_builder::build_service(&remapper);
+    assert_eq!(port_names(&service), vec!["kafka".to_string()]);
+
+    let metrics = service_builder::build_metrics_service(&remapper).unwrap();
+    assert_eq!(
+        metrics.metadata.name.as_deref(),
+        Some("test-remapper-metrics")
+    );
+    // Only a metrics Service carrying the remapper's ownership is removed
+    assert!(remapper::is_owned_by(&remapper, &metrics.metadata));
+    assert_eq!(
+        metrics.metadata.annotations.as_ref().unwrap()["prometheus.io/scrape"],
+        "true"
+    );
+    assert_eq!(port_names(&metrics), vec!["metrics".to_string()]);
+    let metrics_spec = metrics.spec.unwrap();
+    assert_eq!(metrics_spec.type_.as_deref(), Some("ClusterIP"));
+    assert_eq!(metrics_spec.cluster_ip.as_deref(), Some("None"));
+}
+
+#[test]
+fn disabled_metrics_service_hides_the_metrics_port() {
+    let mut spec = valid_remapper_spec();
+    spec.metrics = serde_json::from_value(serde_json::json!({
+        "service": { "enabled": false }
+    }))
+    .unwrap();
+    let remapper = create_remapper(spec);
+
+    let service = service_builder::build_service(&remapper);
+    assert!(!port_names(&service).contains(&"metrics".to_string()));
+    assert!(service_builder::build_metrics_service(&remapper).is_none());
+}
+
+#[test]
+fn disabled_metrics_remove_every_metrics_port() {
+    let mut spec = valid_remapper_spec();
+    spec.metrics.enabled = false;
+    let remapper = create_remapper(spec);
+
+    let service = service_builder::build_service(&remapper);
+    assert_eq!(port_names(&service), vec!["kafka".to_string()]);
+
+    let pod_spec = deployment_builder::build_pod_template(&remapper, "cm", "hash", None)
+        .spec
+        .unwrap();
+    let proxy = &pod_spec.containers[0];
+    let container_ports: Vec<_> = proxy
+        .ports
+        .iter()
+        .flatten()
+        .filter_map(|p| p.name.as_deref())
+        .collect();
+    assert_eq!(container_ports, vec!["kafka"]);
+    for probe in [&proxy.liveness_probe, &proxy.readiness_probe] {
+        let probe = probe.as_ref().unwrap();
+        assert!(probe.http_get.is_none());
+        assert!(probe.tcp_socket.is_some());
+    }
+}
+
+#[test]
+fn replicas_are_spread_across_nodes_and_zones_by_default() {
+    let remapper = create_remapper(valid_remapper_spec());
+    let pod_spec = deployment_builder::build_pod_template(&remapper, "cm", "hash", None)
+        .spec
+        .unwrap();
+    let terms = pod_spec
+        .affinity
+        .unwrap()
+        .pod_anti_affinity
+        .unwrap()
+        .preferred_during_scheduling_ignored_during_execution
+        .unwrap();
+    let keys: Vec<_> = terms
+        .iter()
+        .map(|t| t.pod_affinity_term.topology_key.as_str())
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            deployment_builder::HOSTNAME_TOPOLOGY_KEY,
+            deployment_builder::ZONE_TOPOLOGY_KEY
+        ]
+    );
+    assert_eq!(
+        terms[0]
+            .pod_affinity_term
+            .label_selector
+            .as_ref()
+            .unwrap()
+            .match_labels,
+        Some(deployment_builder::build_labels("test-remapper"))
+    );
+
+    // A single replica has nothing to spread
+    let mut single = create_remapper(valid_remapper_spec());
+    single.spec.replicas = 1;
+    let pod_spec = deployment_builder::build_pod_template(&single, "cm", "hash", None)
+        .spec
+        .unwrap();
+    assert!(pod_spec.affinity.is_none());
+}
+
+#[test]
+fn pod_template_affinity_and_spread_constraints_are_applied() {
+    let mut spec = valid_remapper_spec();
+    spec.pod_template = Some(
+        serde_json::from_value(serde_json::json!({
+            "affinity": {
+                "nodeAffinity": {
+                    "requiredDuringSchedulingIgnoredDuringExecution": {
+                        "nodeSelectorTerms": [{
+                            "matchExpressions": [{
+                                "key": "pool", "operator": "In", "values": ["kafka"]
+                            }]
+                        }]
+                    }
+                }
+            },
+            "topologySpreadConstraints": [{
+                "maxSkew": 1,
+                "topologyKey": "topology.kubernetes.io/zone",
+                "whenUnsatisfiable": "DoNotSchedule"
+            }]
+        }))
+        .unwrap(),
+    );
+    let remapper = create_remapper(spec);
+
+    let pod_spec = deployment_builder::build_pod_template(&remapper, "cm", "hash", None)
+        .spec
+        .unwrap();
+    let affinity = pod_spec.affinity.unwrap();
+    assert!(affinity.node_affinity.is_some());
+    assert!(affinity.pod_anti_affinity.is_none());
+
+    let constraints = pod_spec.topology_spread_constraints.unwrap();
+    assert_eq!(constraints[0].max_skew, 1);
+    assert_eq!(
+        constraints[0].label_selector.as_ref().unwrap().match_labels,
+        Some(deployment_builder::build_labels("test-remapper"))
+    );
+}
+
+// ============================================================================
+// Secrets Store CSI Tests
+// ============================================================================
+
+#[test]
+fn secret_provider_classes_are_mounted_through_the_csi_driver() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.kafka.security_protocol = SecurityProtocol::SaslSsl;
+    remapper.spec.kafka.tls_secret = Some(
+        serde_json::from_value(serde_json::json!({ "secretProviderClass": "vault-kafka-tls" }))
+            .unwrap(),
+    );
+    remapper.spec.kafka.sasl_secret = Some(
+        serde_json::from_value(serde_json::json!({
+            "secretProviderClass": "vault-kafka-sasl",
+            "mechanism": "SCRAM-SHA-512"
+        }))
+        .unwrap(),
+    );
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let pod_spec = deployment.spec.unwrap().template.spec.unwrap();
+    let volumes = pod_spec.volumes.unwrap();
+    for (volume_name, provider_class) in [
+        ("kafka-tls", "vault-kafka-tls"),
+        ("kafka-sasl", "vault-kafka-sasl"),
+    ] {
+        let volume = volumes.iter().find(|v| v.name == volume_name).unwrap();
+        assert!(volume.secret.is_none());
+        let csi = volume.csi.as_ref().unwrap();
+        assert_eq!(csi.driver, deployment_builder::SECRETS_STORE_CSI_DRIVER);
+        assert_eq!(
+            csi.volume_attributes.as_ref().unwrap()["secretProviderClass"],
+            provider_class
+        );
+    }
+    // Credentials are read from files rather than Secret-backed env vars
+    assert!(pod_spec.containers[0].env.is_none());
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let sasl = &config["kafka"]["sasl"];
+    assert_eq!(sasl["mechanism"].as_str(), Some("SCRAM-SHA-512"));
+    assert_eq!(
+        sasl["password_file"].as_str(),
+        Some("/etc/kafka-proxy/broker-sasl/password")
+    );
+}
+
+// ============================================================================
+// Broker Address Rewriting Tests
+// ============================================================================
+
+#[test]
+fn broker_address_map_and_bootstrap_host_are_rendered_into_config() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.kafka = serde_json::from_value(serde_json::json!({
+        "bootstrapServers": ["lb.example.com:9092"],
+        "brokerAddressMap": { "broker-0.internal:9092": "lb.example.com:30000" },
+        "brokerAddressMode": "BootstrapHost"
+    }))
+    .unwrap();
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let kafka = &config["kafka"];
+    assert_eq!(
+        kafka["broker_address_map"]["broker-0.internal:9092"].as_str(),
+        Some("lb.example.com:30000")
+    );
+    assert_eq!(
+        kafka["broker_address_host"].as_str(),
+        Some("lb.example.com")
+    );
+}
+
+#[test]
+fn broker_address_rewriting_is_omitted_by_default() {
+    let remapper = create_remapper(valid_remapper_spec());
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    assert!(config["kafka"].get("broker_address_map").is_none());
+    assert!(config["kafka"].get("broker_address_host").is_none());
+}
+
+#[test]
+fn reachable_broker_address_prefers_map_then_bootstrap_host() {
+    let map = BTreeMap::from([(
+        "broker-0.internal:9092".to_string(),
+        "lb.example.com:30000".to_string(),
+    )]);
+    assert_eq!(
+        reachable_broker_address(&map, Some("lb.example.com"), "broker-0.internal:9092"),
+        "lb.example.com:30000"
+    );
+    assert_eq!(
+        reachable_broker_address(&map, Some("lb.example.com"), "broker-1.internal:9093"),
+        "lb.example.com:9093"
+    );
+    assert_eq!(
+        reachable_broker_address(&map, None, "broker-1.internal:9093"),
+        "broker-1.internal:9093"
+    );
+}
+
+// ============================================================================
+// Failover Cluster Tests
+// ============================================================================
+
+fn remapper_with_failover_clusters() -> KafkaPartitionRemapper {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.kafka.failover_clusters = serde_json::from_value(serde_json::json!([
+        {
+            "name": "dr-east",
+            "bootstrapServers": ["kafka-east:9093"],
+            "securityProtocol": "SASL_SSL",
+            "tlsSecret": { "name": "east-tls", "caKey": "ca.crt" },
+            "saslSecret": { "name": "east-sasl", "mechanism": "SCRAM-SHA-512" }
+        },
+        {
+            "name": "dr-west",
+            "bootstrapServers": ["kafka-west:9092"],
+            "securityProtocol": "SASL_PLAINTEXT",
+            "saslSecret": { "secretProviderClass": "west-store", "mechanism": "PLAIN" }
+        }
+    ]))
+    .unwrap();
+    remapper
+}
+
+#[test]
+fn failover_clusters_are_rendered_in_priority_order() {
+    let remapper = remapper_with_failover_clusters();
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let clusters = config["kafka"]["failover_clusters"].as_sequence().unwrap();
+    assert_eq!(clusters.len(), 2);
+
+    let east = &clusters[0];
+    assert_eq!(east["name"].as_str(), Some("dr-east"));
+    assert_eq!(
+        east["bootstrap_servers"][0].as_str(),
+        Some("kafka-east:9093")
+    );
+    assert_eq!(east["security_protocol"].as_str(), Some("SASL_SSL"));
+    assert_eq!(
+        east["tls"]["ca_file"].as_str(),
+        Some("/etc/kafka-proxy/upstream/dr-east/tls/ca.crt")
+    );
+    assert_eq!(east["sasl"]["mechanism"].as_str(), Some("SCRAM-SHA-512"));
+    assert_eq!(
+        east["sasl"]["username_env"].as_str(),
+        Some("KAFKA_UPSTREAM_DR_EAST_USERNAME")
+    );
+
+    let west = &clusters[1];
+    assert_eq!(west["name"].as_str(), Some("dr-west"));
+    assert!(west.get("tls").is_none());
+    assert_eq!(
+        west["sasl"]["password_file"].as_str(),
+        Some("/etc/kafka-proxy/upstream/dr-west/sasl/password")
+    );
+}
+
+#[test]
+fn failover_cluster_credentials_are_mounted_and_referenced() {
+    let remapper = remapper_with_failover_clusters();
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let pod_spec = deployment.spec.unwrap().template.spec.unwrap();
+    let volumes = pod_spec.volumes.unwrap();
+    let east_tls = volumes
+        .iter()
+        .find(|v| v.name == "upstream-dr-east-tls")
+        .unwrap();
+    assert_eq!(
+        east_tls.secret.as_ref().unwrap().secret_name.as_deref(),
+        Some("east-tls")
+    );
+    assert!(volumes.iter().any(|v| v.name == "upstream-dr-west-sasl"));
+    assert!(!volumes.iter().any(|v| v.name == "upstream-dr-east-sasl"));
+
+    let env = pod_spec.containers[0].env.as_ref().unwrap();
+    let username = env
+        .iter()
+        .find(|e| e.name == "KAFKA_UPSTREAM_DR_EAST_USERNAME")
+        .unwrap();
+    let secret_ref = username
+        .value_from
+        .as_ref()
+        .unwrap()
+        .secret_key_ref
+        .as_ref()
+        .unwrap();
+    assert_eq!(secret_ref.name, "east-sasl");
+
+    let secrets = remapper.spec.referenced_secrets();
+    assert!(secrets.contains(&"east-tls".to_string()));
+    assert!(secrets.contains(&"east-sasl".to_string()));
+    assert!(!secrets.contains(&"west-store".to_string()));
+}
+
+// ============================================================================
+// Topic Routing Tests
+// ============================================================================
+
+#[test]
+fn topic_routes_and_their_clusters_are_rendered_into_config() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.routing = Some(
+        serde_json::from_value(serde_json::json!({
+            "clusters": [{
+                "name": "legacy-billing",
+                "bootstrapServers": ["billing-kafka:9093"],
+                "securityProtocol": "SSL",
+                "tlsSecret": { "name": "billing-tls" }
+            }],
+            "routes": [
+                { "prefix": "billing.", "cluster": "legacy-billing" },
+                { "pattern": "invoices-[0-9]+", "cluster": "legacy-billing" }
+            ]
+        }))
+        .unwrap(),
+    );
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let routing = &config["routing"];
+    let cluster = &routing["clusters"][0];
+    assert_eq!(cluster["name"].as_str(), Some("legacy-billing"));
+    assert_eq!(
+        cluster["tls"]["ca_file"].as_str(),
+        Some("/etc/kafka-proxy/upstream/legacy-billing/tls/ca.crt")
+    );
+    let routes = routing["routes"].as_sequence().unwrap();
+    assert_eq!(routes[0]["prefix"].as_str(), Some("billing."));
+    assert_eq!(routes[0]["cluster"].as_str(), Some("legacy-billing"));
+    assert_eq!(routes[1]["pattern"].as_str(), Some("^(?:invoices-[0-9]+)$"));
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let volumes = deployment
+        .spec
+        .unwrap()
+        .template
+        .spec
+        .unwrap()
+        .volumes
+        .unwrap();
+    assert!(volumes
+        .iter()
+        .any(|v| v.name == "upstream-legacy-billing-tls"));
+    assert!(remapper
+        .spec
+        .referenced_secrets()
+        .contains(&"billing-tls".to_string()));
+}
+
+// ============================================================================
+// Topic Access Tests
+// ============================================================================
+
+#[test]
+fn topic_access_lists_are_rendered_into_config() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.access = Some(
+        serde_json::from_value(serde_json::json!({
+            "topicAllowList": ["orders", "payments-.*"],
+            "topicDenyList": ["payments-internal"]
+        }))
+        .unwrap(),
+    );
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let access = &config["access"];
+    assert_eq!(access["topic_allow_list"][0].as_str(), Some("orders"));
+    assert_eq!(access["topic_allow_list"][1].as_str(), Some("payments-.*"));
+    assert_eq!(
+        access["topic_deny_list"][0].as_str(),
+        Some("payments-internal")
+    );
+}
+
+// ============================================================================
+// Quota Tests
+// ============================================================================
+
+#[test]
+fn quotas_are_rendered_into_config() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.quotas = Some(
+        serde_json::from_value(serde_json::json!({
+            "default": { "ingressBytesPerSecond": 1048576, "egressBytesPerSecond": 2097152 },
+            "clients": [{ "user": "reporting", "connectionsPerSecond": 5 }]
+        }))
+        .unwrap(),
+    );
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let quotas = &config["quotas"];
+    assert_eq!(
+        quotas["default"]["ingress_bytes_per_second"].as_u64(),
+        Some(1048576)
+    );
+    assert_eq!(
+        quotas["default"]["egress_bytes_per_second"].as_u64(),
+        Some(2097152)
+    );
+    assert!(quotas["default"].get("connections_per_second").is_none());
+    let client = &quotas["clients"][0];
+    assert_eq!(client["user"].as_str(), Some("reporting"));
+    assert_eq!(client["connections_per_second"].as_u64(), Some(5));
+    assert!(client.get("client_id").is_none());
+}
+
+// ============================================================================
+// Maintenance Mode Tests
+// ============================================================================
+
+#[test]
+fn maintenance_mode_is_rendered_into_config() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    assert!(config.get("maintenance").is_none());
+
+    for (mode, rendered) in [("ReadOnly", "read_only"), ("Blocked", "blocked")] {
+        remapper.spec.maintenance_mode =
+            Some(serde_json::from_value(serde_json::json!(mode)).unwrap());
+        let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+        let config: serde_yaml::Value =
+            serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+        assert_eq!(config["maintenance"]["mode"].as_str(), Some(rendered));
+    }
+}
+
+// ============================================================================
+// Consumer Group Tests
+// ============================================================================
+
+#[test]
+fn consumer_group_settings_are_rendered_into_config() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.consumer_groups = Some(
+        serde_json::from_value(serde_json::json!({
+            "groupIdPrefix": "remapped.",
+            "passthroughProtocolTypes": ["connect"],
+            "maxSessionTimeoutMs": 45000
+        }))
+        .unwrap(),
+    );
+    assert_eq!(remapper.spec.group_id_prefix(), "remapped.");
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let groups = &config["consumer_groups"];
+    assert_eq!(groups["group_id_prefix"].as_str(), Some("remapped."));
+    assert_eq!(
+        groups["passthrough_protocol_types"][0].as_str(),
+        Some("connect")
+    );
+    assert_eq!(groups["max_session_timeout_ms"].as_u64(), Some(45000));
+    assert!(groups.get("min_session_timeout_ms").is_none());
+}
+
+// ============================================================================
+// API Version Tests
+// ============================================================================
+
+#[test]
+fn api_version_ceilings_are_rendered_into_config() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.kafka.max_api_version = Some(12);
+    remapper.spec.kafka.api_versions = BTreeMap::from([("Fetch".to_string(), 11)]);
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    assert_eq!(config["kafka"]["max_api_version"].as_i64(), Some(12));
+    assert_eq!(config["kafka"]["api_versions"]["Fetch"].as_i64(), Some(11));
+}
+
+// ============================================================================
+// Tuning Tests
+// ============================================================================
+
+#[test]
+fn tuning_settings_are_passed_through_to_config() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.tuning = Some(
+        serde_json::from_value(serde_json::json!({
+            "workerThreads": 8,
+            "socketSendBufferBytes": 1048576,
+            "tcpKeepaliveSecs": 60
+        }))
+        .unwrap(),
+    );
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let tuning = config["tuning"].as_mapping().unwrap();
+    assert_eq!(tuning.len(), 3);
+    assert_eq!(config["tuning"]["worker_threads"].as_u64(), Some(8));
+    assert_eq!(
+        config["tuning"]["socket_send_buffer_bytes"].as_u64(),
+        Some(1048576)
+    );
+    assert_eq!(config["tuning"]["tcp_keepalive_secs"].as_u64(), Some(60));
+}
+
+// ============================================================================
+// Connection Limit Tests
+// ============================================================================
+
+fn rendered_max_connections(remapper: &KafkaPartitionRemapper) -> Vec<u64> {
+    let config_map = remapper::build_config_map(remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    config["listeners"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .map(|l| l["max_connections"].as_u64().unwrap())
+        .collect()
+}
+
+#[test]
+fn max_connections_defaults_without_memory_limit() {
+    let remapper = create_remapper(valid_remapper_spec());
+    assert_eq!(
+        rendered_max_connections(&remapper),
+        vec![u64::from(deployment_builder::DEFAULT_MAX_CONNECTIONS)]
+    );
+}
+
+#[test]
+fn max_connections_is_derived_from_memory_limit() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.pod_template = Some(
+        serde_json::from_value(serde_json::json!({
+            "resources": { "limits": { "memory": "512Mi" } }
+        }))
+        .unwrap(),
+    );
+    // Three quarters of 512Mi at 1Mi per connection
+    assert_eq!(rendered_max_connections(&remapper), vec![384]);
+
+    remapper.spec.tuning = Some(
+        serde_json::from_value(serde_json::json!({ "connectionMemoryBytes": 262144 })).unwrap(),
+    );
+    assert_eq!(rendered_max_connections(&remapper), vec![1536]);
+
+    // Explicit ceilings are kept and taken out of the shared budget
+    remapper.spec.tuning = None;
+    remapper.spec.listeners = serde_json::from_value(serde_json::json!([
+        { "name": "plain", "port": 9092, "maxConnections": 100 },
+        { "name": "tls", "port": 9093 },
+        { "name": "sasl", "port": 9094 }
+    ]))
+    .unwrap();
+    assert_eq!(rendered_max_connections(&remapper), vec![100, 142, 142]);
+}
+
+#[test]
+fn memory_quantities_are_parsed() {
+    assert_eq!(
+        deployment_builder::parse_memory_quantity("512Mi"),
+        Some(512 << 20)
+    );
+    assert_eq!(
+        deployment_builder::parse_memory_quantity("1.5Gi"),
+        Some(3 << 29)
+    );
+    assert_eq!(
+        deployment_builder::parse_memory_quantity("1G"),
+        Some(1_000_000_000)
+    );
+    assert_eq!(
+        deployment_builder::parse_memory_quantity("4096"),
+        Some(4096)
+    );
+    assert_eq!(deployment_builder::parse_memory_quantity("512MB"), None);
+}
+
+#[test]
+fn kubernetes_quantities_are_parsed() {
+    for (value, expected) in [
+        ("500m", 0.5),
+        ("2", 2.0),
+        ("1.5", 1.5),
+        (".5", 0.5),
+        ("1e3", 1000.0),
+        ("1E-3", 0.001),
+        ("+2k", 2000.0),
+        ("-1Ki", -1024.0),
+        ("128Mi", 134_217_728.0),
+    ] {
+        assert_eq!(
+            deployment_builder::parse_quantity(value),
+            Some(expected),
+            "{}",
+            value
+        );
+    }
+    for value in ["100mi", "2Gb", "", ".", "1.2.3", "1e", "Mi", "1 Gi", "1Ki2"] {
+        assert_eq!(deployment_builder::parse_quantity(value), None, "{}", value);
+    }
+}
+
+// ============================================================================
+// Audit Logging Tests
+// ============================================================================
+
+#[test]
+fn audit_logging_is_rendered_into_config() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.audit_logging = Some(
+        serde_json::from_value(serde_json::json!({
+            "sampleRate": 0.5,
+            "includePrincipal": false,
+            "destinations": [
+                { "type": "Stdout" },
+                { "type": "File" },
+                { "type": "Syslog", "address": "syslog.logging:514" }
+            ]
+        }))
+        .unwrap(),
+    );
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let audit = &config["audit"];
+    assert_eq!(audit["sample_rate"].as_f64(), Some(0.5));
+    assert_eq!(audit["include_client_id"].as_bool(), Some(true));
+    assert_eq!(audit["include_principal"].as_bool(), Some(false));
+    let destinations = audit["destinations"].as_sequence().unwrap();
+    assert_eq!(destinations[0]["type"].as_str(), Some("stdout"));
+    assert_eq!(
+        destinations[1]["path"].as_str(),
+        Some("/var/log/kafka-proxy/audit.log")
+    );
+    assert_eq!(
+        destinations[2]["address"].as_str(),
+        Some("syslog.logging:514")
+    );
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let pod_spec = deployment.spec.unwrap().template.spec.unwrap();
+    let volumes = pod_spec.volumes.unwrap();
+    assert!(volumes
+        .iter()
+        .any(|v| v.name == "audit-log" && v.empty_dir.is_some()));
+    let mounts = pod_spec.containers[0].volume_mounts.as_ref().unwrap();
+    let mount = mounts.iter().find(|m| m.name == "audit-log").unwrap();
+    assert_eq!(mount.mount_path, deployment_builder::AUDIT_LOG_DIR);
+}
+
+#[test]
+fn disabled_audit_logging_renders_nothing() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.audit_logging = Some(
+        serde_json::from_value(serde_json::json!({
+            "enabled": false,
+            "destinations": [{ "type": "File" }]
+        }))
+        .unwrap(),
+    );
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    assert!(config.get("audit").is_none());
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let volumes = deployment
+        .spec
+        .unwrap()
+        .template
+        .spec
+        .unwrap()
+        .volumes
+        .unwrap();
+    assert!(!volumes.iter().any(|v| v.name == "audit-log"));
+}
+
+// ============================================================================
+// Topic Rename Tests
+// ============================================================================
+
+#[test]
+fn topic_renames_are_rendered_in_order() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.mapping.topic_renames = serde_json::from_value(serde_json::json!([
+        { "prefix": "legacy.", "replacement": "prod." },
+        { "pattern": "old-(.*)", "replacement": "new-$1" }
+    ]))
+    .unwrap();
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let renames = config["mapping"]["topic_renames"].as_sequence().unwrap();
+    assert_eq!(renames.len(), 2);
+    assert_eq!(renames[0]["prefix"].as_str(), Some("legacy."));
+    assert_eq!(renames[0]["replacement"].as_str(), Some("prod."));
+    assert_eq!(renames[1]["pattern"].as_str(), Some("^(?:old-(.*))$"));
+    assert_eq!(renames[1]["replacement"].as_str(), Some("new-$1"));
+}
+
+// ============================================================================
+// Header Injection Tests
+// ============================================================================
+
+#[test]
+fn header_injection_is_rendered_into_mapping_config() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.mapping.header_injection = Some(
+        serde_json::from_value(serde_json::json!({
+            "virtualPartitionHeader": "x-virtual-partition",
+            "headers": { "x-env": "prod" },
+            "overwrite": true
+        }))
+        .unwrap(),
+    );
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let injection = &config["mapping"]["header_injection"];
+    assert_eq!(
+        injection["virtual_partition_header"].as_str(),
+        Some("x-virtual-partition")
+    );
+    assert_eq!(injection["headers"]["x-env"].as_str(), Some("prod"));
+    assert_eq!(injection["overwrite"].as_bool(), Some(true));
+}
+
+// ============================================================================
+// Schema Registry Tests
+// ============================================================================
+
+#[test]
+fn schema_registry_is_rendered_and_mounted() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.schema_registry = Some(
+        serde_json::from_value(serde_json::json!({
+            "url": "https://registry.internal:8081",
+            "advertisedUrl": "http://my-remapper:8081",
+            "tlsSecret": { "name": "registry-tls" },
+            "authSecret": { "name": "registry-auth" },
+            "forwardPort": 8081
+        }))
+        .unwrap(),
+    );
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let registry = &config["schema_registry"];
+    assert_eq!(
+        registry["url"].as_str(),
+        Some("https://registry.internal:8081")
+    );
+    assert_eq!(
+        registry["advertised_url"].as_str(),
+        Some("http://my-remapper:8081")
+    );
+    assert_eq!(
+        registry["tls"]["ca_file"].as_str(),
+        Some("/etc/kafka-proxy/tls/schema-registry/ca.crt")
+    );
+    assert_eq!(
+        registry["basic_auth"]["password_env"].as_str(),
+        Some("SCHEMA_REGISTRY_PASSWORD")
+    );
+    assert_eq!(registry["listen_address"].as_str(), Some("0.0.0.0:8081"));
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let pod_spec = deployment.spec.unwrap().template.spec.unwrap();
+    let proxy = &pod_spec.containers[0];
+    assert!(proxy
+        .ports
+        .as_ref()
+        .unwrap()
+        .iter()
+        .any(|p| p.name.as_deref() == Some("schema-registry") && p.container_port == 8081));
+    let env = proxy.env.as_ref().unwrap();
+    let username = env
+        .iter()
+        .find(|e| e.name == "SCHEMA_REGISTRY_USERNAME")
+        .unwrap();
+    let selector = username
+        .value_from
+        .as_ref()
+        .unwrap()
+        .secret_key_ref
+        .as_ref()
+        .unwrap();
+    assert_eq!(selector.name, "registry-auth");
+    assert_eq!(selector.key, "username");
+    assert!(proxy
+        .volume_mounts
+        .as_ref()
+        .unwrap()
+        .iter()
+        .any(|m| m.name == "schema-registry-tls"));
+
+    let service = service_builder::build_service(&remapper);
+    assert!(service
+        .spec
+        .unwrap()
+        .ports
+        .unwrap()
+        .iter()
+        .any(|p| p.name.as_deref() == Some("schema-registry") && p.port == 8081));
+
+    let secrets = remapper.spec.referenced_secrets();
+    assert!(secrets.contains(&"registry-tls".to_string()));
+    assert!(secrets.contains(&"registry-auth".to_string()));
+}
+
+// ============================================================================
+// Kerberos Tests
+// ============================================================================
+
+#[test]
+fn kerberos_renders_gssapi_config_and_mounts_keytab_and_krb5_conf() {
+    let mut remapper = create_remapper(valid_remapper_spec());
+    remapper.spec.kafka.security_protocol = SecurityProtocol::SaslPlaintext;
+    remapper.spec.kafka.kerberos = Some(
+        serde_json::from_value(serde_json::json!({
+            "principal": "kafka-proxy@EXAMPLE.COM",
+            "keytabSecret": { "name": "proxy-keytab", "key": "proxy.keytab" },
+            "krb5ConfigMap": { "name": "krb5" }
+        }))
+        .unwrap(),
+    );
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    let sasl = &config["kafka"]["sasl"];
+    assert_eq!(sasl["mechanism"].as_str(), Some("GSSAPI"));
+    assert_eq!(
+        sasl["kerberos"]["principal"].as_str(),
+        Some("kafka-proxy@EXAMPLE.COM")
+    );
+    assert_eq!(sasl["kerberos"]["service_name"].as_str(), Some("kafka"));
+    assert_eq!(
+        sasl["kerberos"]["keytab_file"].as_str(),
+        Some("/etc/kafka-proxy/kerberos/keytab/proxy.keytab")
+    );
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let pod_spec = deployment.spec.unwrap().template.spec.unwrap();
+    let volumes = pod_spec.volumes.unwrap();
+    let keytab = volumes.iter().find(|v| v.name == "kafka-keytab").unwrap();
+    assert_eq!(
+        keytab.secret.as_ref().unwrap().secret_name.as_deref(),
+        Some("proxy-keytab")
+    );
+    let krb5 = volumes.iter().find(|v| v.name == "krb5-config").unwrap();
+    assert_eq!(krb5.config_map.as_ref().unwrap().name, "krb5");
+
+    let env = pod_spec.containers[0].env.as_ref().unwrap();
+    let krb5_config = env.iter().find(|e| e.name == "KRB5_CONFIG").unwrap();
+    assert_eq!(
+        krb5_config.value.as_deref(),
+        Some("/etc/kafka-proxy/kerberos/krb5/krb5.conf")
+    );
+}
+
+// ============================================================================
+// Generated User Tests
+// ============================================================================
+
+fn remapper_with_users() -> KafkaPartitionRemapper {
+    let mut remapper = internal_and_external_listeners();
+    remapper.spec.users = serde_json::from_value(serde_json::json!([
+        { "name": "orders-app" },
+        { "name": "billing", "mechanism": "SCRAM-SHA-256" }
+    ]))
+    .unwrap();
+    remapper
+}
+
+#[test]
+fn generated_users_are_mounted_and_rendered_for_sasl_listeners() {
+    let remapper = remapper_with_users();
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let pod_spec = deployment.spec.unwrap().template.spec.unwrap();
+    let volume = pod_spec
+        .volumes
+        .unwrap()
+        .into_iter()
+        .find(|v| v.name == "generated-users")
+        .expect("generated users volume");
+    assert_eq!(
+        volume.secret.unwrap().secret_name.as_deref(),
+        Some("test-remapper-users")
+    );
+    assert!(pod_spec.containers[0]
+        .volume_mounts
+        .as_ref()
+        .unwrap()
+        .iter()
+        .any(|m| m.mount_path == users::GENERATED_USERS_DIR));
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    assert!(config["listeners"][0]["sasl"].is_null());
+    assert_eq!(
+        config["listeners"][1]["sasl"]["generated_credentials_dir"].as_str(),
+        Some(users::GENERATED_USERS_DIR)
+    );
+}
+
+#[test]
+fn user_secrets_carry_credentials_for_clients_and_proxy() {
+    let remapper = remapper_with_users();
+    let user = &remapper.spec.users[1];
+    let credentials = users::UserCredentials::generate(Utc::now()).unwrap();
+    let password = credentials.password.clone();
+    assert_eq!(password.len(), 48);
+    assert_ne!(password, users::generate_password().unwrap());
+
+    let secret = users::build_user_secret(&remapper, user, &credentials);
+    assert_eq!(
+        secret.metadata.name.as_deref(),
+        Some("test-remapper-user-billing")
+    );
+    assert_eq!(
+        secret.metadata.labels.as_ref().unwrap()[users::USER_LABEL],
+        "billing"
+    );
+    assert_eq!(
+        secret.metadata.owner_references.as_ref().unwrap()[0].uid,
+        "1234"
+    );
+    let data = secret.data.as_ref().unwrap();
+    assert_eq!(data["mechanism"].0, b"SCRAM-SHA-256");
+    assert!(String::from_utf8(data["sasl.jaas.config"].0.clone())
+        .unwrap()
+        .contains(&format!("username=\"billing\" password=\"{}\"", password)));
+    assert!(!data.contains_key("previous-password"));
+    assert_eq!(
+        users::UserCredentials::from_secret(&secret),
+        Some(credentials)
+    );
+
+    let passwords = [("billing".to_string(), password.clone())].into();
+    let users_secret =
+        users::build_users_secret(&remapper, &users::users_secret_name(&remapper), &passwords);
+    assert_eq!(
+        users_secret.metadata.name.as_deref(),
+        Some("test-remapper-users")
+    );
+    assert_eq!(users_secret.data.unwrap()["billing"].0, password.as_bytes());
+}
+
+#[test]
+fn rotating_users_mount_and_render_previous_passwords() {
+    let mut remapper = remapper_with_users();
+    assert!(!remapper::build_config_map(&remapper, "default")
+        .unwrap()
+        .data
+        .unwrap()["config.yaml"]
+        .contains("previous_credentials_dir"));
+
+    remapper.spec.users[0].rotation =
+        Some(serde_json::from_value(serde_json::json!({ "intervalDays": 30 })).unwrap());
+
+    let deployment = deployment_builder::build_deployment(&remapper, "cm", "hash", None);
+    let volume = deployment
+        .spec
+        .unwrap()
+        .template
+        .spec
+        .unwrap()
+        .volumes
+        .unwrap()
+        .into_iter()
+        .find(|v| v.name == "generated-users-previous")
+        .expect("previous passwords volume");
+    assert_eq!(
+        volume.secret.unwrap().secret_name,
+        Some(users::previous_users_secret_name(&remapper))
+    );
+
+    let config_map = remapper::build_config_map(&remapper, "default").unwrap();
+    let config: serde_yaml::Value =
+        serde_yaml::from_str(&config_map.data.unwrap()["config.yaml"]).unwrap();
+    assert_eq!(
+        config["listeners"][1]["sasl"]["previous_credentials_dir"].as_str(),
+        Some(users::PREVIOUS_USERS_DIR)
+    );
+}
+
+// ============================================================================
+// Rollout Progress Tests
+// ============================================================================
+
+fn progressing(reason: &str) -> DeploymentCondition {
+    DeploymentCondition {
+        type_: "Progressing".to_string(),
+        status: "True".to_string(),
+        reason: Some(reason.to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn deployment_rollout_in_progress_follows_progressing_condition() {
+    let mut deployment = deployment_builder::build_deployment(
+        &create_remapper(valid_remapper_spec()),
+        "cm",
+        "hash",
+        None,
+    );
+    deployment.metadata.generation = Some(2);
+    deployment.status = Some(DeploymentStatus {
+        observed_generation: Some(2),
+        conditions: Some(vec![progressing("ReplicaSetUpdated")]),
+        ..Default::default()
+    });
+    assert!(deployment_builder::rollout_in_progress(&deployment));
+
+    deployment.status.as_mut().unwrap().conditions =
+        Some(vec![progressing("NewReplicaSetAvailable")]);
+    assert!(!deployment_builder::rollout_in_progress(&deployment));
+
+    deployment.metadata.generation = Some(3);
+    assert!(deployment_builder::rollout_in_progress(&deployment));
+}
+
+#[test]
+fn stateful_set_rollout_in_progress_until_revisions_match() {
+    let mut spec = valid_remapper_spec();
+    spec.workload = WorkloadKind::StatefulSet;
+    let mut stateful_set =
+        stateful_set_builder::build_stateful_set(&create_remapper(spec), "cm", "hash", None);
+    stateful_set.status = Some(StatefulSetStatus {
+        current_revision: Some("rev-1".to_string()),
+        update_revision: Some("rev-2".to_string()),
+        ..Default::default()
+    });
+    assert!(stateful_set_builder::rollout_in_progress(&stateful_set));
+
+    stateful_set.status.as_mut().unwrap().current_revision = Some("rev-2".to_string());
+    assert!(!stateful_set_builder::rollout_in_progress(&stateful_set));
+}
+
+// ============================================================================
+// Sharding Tests
+// ============================================================================
+
+#[test]
+fn shard_ranges_are_disjoint_and_cover_all_partitions() {
+    let ranges = sharding::shard_ranges(10, 3);
+    let bounds: Vec<_> = ranges.iter().map(|r| (r.start, r.end)).collect();
+    assert_eq!(bounds, vec![(0, 4), (4, 7), (7, 10)]);
+
+    assert!(sharding::shard_ranges(10, 0).is_empty());
+}
+
+#[test]
+fn shard_config_and_service_target_a_single_pod() {
+    let mut spec = valid_remapper_spec();
+    spec.workload = WorkloadKind::StatefulSet;
+    spec.sharding = Some(ShardingSpec { enabled: true });
+    let remapper = create_remapper(spec);
+
+    let ranges = sharding::shard_ranges_for(&remapper.spec);
+    assert_eq!(ranges.len(), 3);
+
+    let config = remapper_config::build_shard_proxy_config(
+        &remapper.spec,
+        &["test-remapper-shard-1.default.svc.cluster.local:9092".to_string()],
+        &ranges[1],
+    )
+    .unwrap();
+    let config: serde_yaml::Value = serde_yaml::from_str(&config).unwrap();
+    let range = &config["mapping"]["partition_range"];
+    assert_eq!(range["shard"].as_i64(), Some(1));
+    assert_eq!(range["start"].as_u64(), Some(334));
+    assert_eq!(range["end"].as_u64(), Some(667));
+
+    let service = service_builder::build_shard_service(&remapper, 1);
+    assert_eq!(
+        service.metadata.name.as_deref(),
+        Some("test-remapper-shard-1")
+    );
+    let selector = service.spec.unwrap().selector.unwrap();
+    assert_eq!(
+        selector
+            .get("statefulset.kubernetes.io/pod-name")
+            .map(String::as_str),
+        Some("test-remapper-1")
+    );
+}
+
+#[test]
+fn config_hash_tracks_replicas_when_sharded() {
+    let mut spec = valid_remapper_spec();
+    spec.workload = WorkloadKind::StatefulSet;
+    spec.sharding = Some(ShardingSpec { enabled: true });
+    let three = create_remapper(spec.clone());
+    spec.replicas = 4;
+    let four = create_remapper(spec);
+
+    assert_ne!(
+        remapper::calculate_config_hash(&three),
+        remapper::calculate_config_hash(&four)
+    );
+}
+
+// ============================================================================
+// Topic Override Aggregation Tests
+// ============================================================================
+
+fn topic_override(name: &str, remapper_ref: &str, topic: &str) -> KafkaRemapperTopicOverride {
+    let mut child = KafkaRemapperTopicOverride::new(
+        name,
+        serde_json::from_value(serde_json::json!({
+            "remapperRef": remapper_ref,
+            "topic": topic,
+            "physicalPartitions": 20
+        }))
+        .unwrap(),
+    );
+    child.metadata.namespace = Some("default".to_string());
+    child
+}
+
+#[test]
+fn topic_overrides_are_merged_after_inline_entries() {
+    let mut spec = valid_remapper_spec();
+    spec.mapping.topics = serde_json::from_value(serde_json::json!([
+        { "topic": "orders", "physicalPartitions": 10 }
+    ]))
+    .unwrap();
+    let mut remapper = create_remapper(spec);
+
+    remapper::merge_topic_overrides(
+        &mut remapper,
+        vec![
+            topic_override("z-payments", "test-remapper", "payments"),
+            topic_override("a-clicks", "test-remapper", "clicks"),
+            topic_override("orders-dup", "test-remapper", "orders"),
+            topic_override("other", "another-remapper", "events"),
+        ],
+    );
+
+    let topics: Vec<_> = remapper
+        .spec
+        .mapping
+        .topics
+        .iter()
+        .map(|t| (t.topic.as_str(), t.physical_partitions))
+        .collect();
+    assert_eq!(
+        topics,
+        vec![
+            ("orders", Some(10)),
+            ("clicks", Some(20)),
+            ("payments", Some(20))
+        ]
+    );
+}
+
+// ============================================================================
+// Consistency Audit Builder Tests
+// ============================================================================
+
+fn audited_spec() -> KafkaPartitionRemapperSpec {
+    let mut spec = valid_remapper_spec();
+    spec.kafka.security_protocol = SecurityProtocol::SaslSsl;
+    spec.kafka.sasl_secret = Some(
+        serde_json::from_value(serde_json::json!({ "name": "kafka-sasl", "mechanism": "PLAIN" }))
+            .unwrap(),
+    );
+    spec.audit = serde_json::from_value(serde_json::json!({
+        "schedule": "*/15 * * * *",
+        "topics": ["orders"],
+        "image": "operator:1.0"
+    }))
+    .unwrap();
+    spec
+}
+
+#[test]
+fn audit_cron_job_runs_operator_image_with_target() {
+    let remapper = create_remapper(valid_remapper_spec());
+    assert!(consistency_audit_builder::build_audit_cron_job(&remapper)
+        .unwrap()
+        .is_none());
+
+    let remapper = create_remapper(audited_spec());
+    let cron_job = consistency_audit_builder::build_audit_cron_job(&remapper)
+        .unwrap()
+        .expect("cron job");
+    assert_eq!(
+        cron_job.metadata.name.as_deref(),
+        Some("test-remapper-audit")
+    );
+    let spec = cron_job.spec.unwrap();
+    assert_eq!(spec.schedule, "*/15 * * * *");
+    assert_eq!(spec.concurrency_policy.as_deref(), Some("Forbid"));
+
+    let pod = spec.job_template.spec.unwrap().template;
+    let pod_labels = pod.metadata.unwrap().labels.unwrap();
+    assert_ne!(
+        pod_labels.get("app.kubernetes.io/name"),
+        deployment_builder::build_labels("test-remapper").get("app.kubernetes.io/name")
+    );
+    let pod_spec = pod.spec.unwrap();
+    assert_eq!(
+        pod_spec.service_account_name.as_deref(),
+        Some("test-remapper-audit")
+    );
+    let container = &pod_spec.containers[0];
+    assert_eq!(container.image.as_deref(), Some("operator:1.0"));
+    assert_eq!(container.args, Some(vec!["--audit".to_string()]));
+
+    let target: consistency_audit_builder::AuditTarget =
+        serde_json::from_str(container.env.as_ref().unwrap()[0].value.as_deref().unwrap()).unwrap();
+    assert_eq!(target.name, "test-remapper");
+    assert_eq!(target.topics, vec!["orders"]);
+    assert_eq!(target.mapping.physical_partitions, 100);
+}
+
+#[test]
+fn audit_role_is_limited_to_credentials_and_status() {
+    let remapper = create_remapper(audited_spec());
+    let roles = consistency_audit_builder::build_audit_roles(&remapper);
+    assert_eq!(roles.len(), 1);
+    let rules = roles[0].rules.as_ref().unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(
+        rules[0].resource_names,
+        Some(vec!["kafka-sasl".to_string()])
+    );
+    assert_eq!(rules[0].verbs, vec!["get"]);
+    assert_eq!(
+        rules[1].resources,
+        Some(vec!["kafkapartitionremappers/status".to_string()])
+    );
+    assert_eq!(
+        rules[1].resource_names,
+        Some(vec!["test-remapper".to_string()])
+    );
+}
+
+#[test]
+fn cross_namespace_audit_gets_a_role_in_each_namespace() {
+    let mut spec = audited_spec();
+    spec.target_namespace = Some("apps".to_string());
+    let remapper = create_remapper(spec);
+
+    let bindings = consistency_audit_builder::build_audit_role_bindings(&remapper);
+    let namespaces: Vec<_> = bindings
+        .iter()
+        .map(|b| b.metadata.namespace.as_deref().unwrap())
+        .collect();
+    assert_eq!(namespaces, vec!["default", "apps"]);
+    // The status Role sits with the remapper and can be garbage collected
+    assert!(bindings[0].metadata.owner_references.is_some());
+    for binding in &bindings {
+        let subject = &binding.subjects.as_ref().unwrap()[0];
+        assert_eq!(subject.namespace.as_deref(), Some("apps"));
+    }
+
+    // Every audit object is recognised as the remapper's when it is removed
+    let roles = consistency_audit_builder::build_audit_roles(&remapper);
+    assert_eq!(roles.len(), 2);
+    let service_account = consistency_audit_builder::build_audit_service_account(&remapper);
+    let cron_job = consistency_audit_builder::build_audit_cron_job(&remapper)
+        .unwrap()
+        .unwrap();
+    assert!(bindings
+        .iter()
+        .map(|b| &b.metadata)
+        .chain(roles.iter().map(|r| &r.metadata))
+        .chain([&service_account.metadata, &cron_job.metadata])
+        .all(|meta| remapper::is_owned_by(&remapper, meta)));
+}
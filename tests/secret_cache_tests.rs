@@ -0,0 +1,53 @@
+//! Integration tests for the TTL-bounded secret cache
+
+use k8s_openapi::api::core::v1::Secret;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kafka_partition_remapper_operator::adapters::secrets::SecretCache;
+use std::time::Duration;
+
+fn secret(name: &str, resource_version: &str) -> Secret {
+    Secret {
+        metadata: ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: Some("kafka".to_string()),
+            resource_version: Some(resource_version.to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn fresh_entries_are_served() {
+    let cache = SecretCache::new(Duration::from_secs(60));
+    assert!(cache.get("kafka", "tls").is_none());
+
+    cache.insert("kafka", &secret("tls", "1"));
+    assert_eq!(
+        cache.get("kafka", "tls").unwrap().metadata.resource_version,
+        Some("1".to_string())
+    );
+    assert!(cache.get("other", "tls").is_none());
+
+    cache.invalidate("kafka", "tls");
+    assert!(cache.get("kafka", "tls").is_none());
+}
+
+#[test]
+fn expired_entries_are_dropped() {
+    let cache = SecretCache::new(Duration::ZERO);
+    cache.insert("kafka", &secret("tls", "1"));
+    assert!(cache.get("kafka", "tls").is_none());
+}
+
+#[test]
+fn newer_resource_versions_evict_entries() {
+    let cache = SecretCache::new(Duration::from_secs(60));
+    cache.insert("kafka", &secret("sasl", "1"));
+
+    cache.observe(&secret("sasl", "1"));
+    assert!(cache.get("kafka", "sasl").is_some());
+
+    cache.observe(&secret("sasl", "2"));
+    assert!(cache.get("kafka", "sasl").is_none());
+}
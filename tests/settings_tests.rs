@@ -0,0 +1,108 @@
+//! Integration tests for operator settings parsing
+
+use kafka_partition_remapper_operator::settings::OperatorSettings;
+use std::time::Duration;
+
+#[test]
+fn empty_settings_use_defaults() {
+    let settings = OperatorSettings::from_yaml("").unwrap();
+    assert_eq!(settings, OperatorSettings::default());
+    assert_eq!(
+        settings.default_image,
+        "ghcr.io/osodevops/kafka-partition-remapper"
+    );
+    assert_eq!(
+        settings
+            .default_resources
+            .requests
+            .get("cpu")
+            .map(String::as_str),
+        Some("100m")
+    );
+}
+
+#[test]
+fn partial_settings_keep_remaining_defaults() {
+    let yaml = r#"
+defaultImageTag: "1.2.3"
+defaultResources:
+  limits:
+    memory: 256Mi
+requeue:
+  kafkaUnreachableSecs: 5
+"#;
+    let settings = OperatorSettings::from_yaml(yaml).unwrap();
+
+    assert_eq!(settings.default_image_tag, "1.2.3");
+    assert_eq!(
+        settings.default_image,
+        OperatorSettings::default().default_image
+    );
+    assert_eq!(
+        settings
+            .default_resources
+            .limits
+            .get("memory")
+            .map(String::as_str),
+        Some("256Mi")
+    );
+    assert_eq!(
+        settings
+            .default_resources
+            .requests
+            .get("memory")
+            .map(String::as_str),
+        Some("128Mi")
+    );
+    assert_eq!(settings.requeue.kafka_unreachable(), Duration::from_secs(5));
+    assert_eq!(settings.requeue.progressing(), Duration::from_secs(30));
+    assert_eq!(settings.requeue.invalid_spec(), Duration::from_secs(300));
+}
+
+#[test]
+fn invalid_settings_are_rejected() {
+    assert!(OperatorSettings::from_yaml("requeue: [1, 2]").is_err());
+}
+
+#[test]
+fn watch_namespaces_filter_remappers() {
+    let all = OperatorSettings::default();
+    assert!(all.watches_namespace("anything"));
+
+    let settings = OperatorSettings::from_yaml("watchNamespaces: [kafka, streaming]").unwrap();
+    assert!(settings.watches_namespace("kafka"));
+    assert!(settings.watches_namespace("streaming"));
+    assert!(!settings.watches_namespace("default"));
+}
+
+#[test]
+fn force_apply_is_opt_in() {
+    assert!(!OperatorSettings::default().force_apply);
+    let settings = OperatorSettings::from_yaml("forceApply: true").unwrap();
+    assert!(settings.force_apply);
+}
+
+#[test]
+fn error_backoff_doubles_up_to_the_cap_with_jitter() {
+    let requeue = OperatorSettings::from_yaml("requeue: { maxBackoffSecs: 100, retryBudget: 3 }")
+        .unwrap()
+        .requeue;
+    let base = Duration::from_secs(10);
+
+    assert_eq!(requeue.backoff(base, 1, 0.0), Duration::from_secs(5));
+    assert_eq!(requeue.backoff(base, 1, 1.0), Duration::from_secs(10));
+    assert_eq!(requeue.backoff(base, 3, 0.5), Duration::from_secs(30));
+    assert_eq!(requeue.backoff(base, 30, 1.0), Duration::from_secs(100));
+    assert_eq!(
+        requeue.backoff(base, u32::MAX, 0.0),
+        Duration::from_secs(50)
+    );
+    // A base above the cap is never shortened
+    assert_eq!(
+        requeue.backoff(Duration::from_secs(300), 5, 1.0),
+        Duration::from_secs(300)
+    );
+
+    assert!(!requeue.budget_exhausted(3));
+    assert!(requeue.budget_exhausted(4));
+}
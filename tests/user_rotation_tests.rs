@@ -0,0 +1,117 @@
+//! Integration tests for generated user password rotation
+//!
+//! These tests verify how credentials advance through a rotation schedule
+//! and its overlap window.
+
+use chrono::{Duration as ChronoDuration, TimeZone, Utc};
+use kafka_partition_remapper_operator::adapters::users::{self, UserCredentials};
+use kafka_partition_remapper_operator::crd::{CredentialRotationSpec, UserStatus};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn rotation(interval_days: u32, overlap_hours: u32) -> CredentialRotationSpec {
+    CredentialRotationSpec {
+        interval_days,
+        overlap_hours,
+    }
+}
+
+fn issued_days_ago(days: i64) -> UserCredentials {
+    UserCredentials {
+        password: "current".to_string(),
+        rotated_at: Utc::now() - ChronoDuration::days(days),
+        previous_password: None,
+        previous_expires_at: None,
+    }
+}
+
+// ============================================================================
+// Rotation Tests
+// ============================================================================
+
+#[test]
+fn new_users_get_generated_credentials() {
+    let now = Utc::now();
+    let (credentials, rotated) = users::next_credentials(None, None, now).unwrap();
+
+    assert!(!rotated);
+    assert_eq!(credentials.password.len(), 48);
+    assert_eq!(credentials.rotated_at, now);
+    assert!(credentials.previous_password.is_none());
+}
+
+#[test]
+fn password_is_kept_until_the_interval_passes() {
+    let existing = issued_days_ago(10);
+    let (credentials, rotated) =
+        users::next_credentials(Some(existing.clone()), Some(&rotation(30, 24)), Utc::now())
+            .unwrap();
+
+    assert!(!rotated);
+    assert_eq!(credentials, existing);
+}
+
+#[test]
+fn rotation_keeps_the_old_password_for_the_overlap() {
+    let now = Utc::now();
+    let (credentials, rotated) =
+        users::next_credentials(Some(issued_days_ago(31)), Some(&rotation(30, 12)), now).unwrap();
+
+    assert!(rotated);
+    assert_ne!(credentials.password, "current");
+    assert_eq!(credentials.rotated_at, now);
+    assert_eq!(credentials.previous_password.as_deref(), Some("current"));
+    assert_eq!(
+        credentials.previous_expires_at,
+        Some(now + ChronoDuration::hours(12))
+    );
+
+    // Without an overlap the old password is retired straight away
+    let (credentials, _) =
+        users::next_credentials(Some(issued_days_ago(31)), Some(&rotation(30, 0)), now).unwrap();
+    assert!(credentials.previous_password.is_none());
+}
+
+#[test]
+fn previous_password_is_retired_after_the_overlap() {
+    let now = Utc::now();
+    let mut existing = issued_days_ago(1);
+    existing.previous_password = Some("old".to_string());
+    existing.previous_expires_at = Some(now + ChronoDuration::hours(1));
+
+    let (kept, _) =
+        users::next_credentials(Some(existing.clone()), Some(&rotation(30, 24)), now).unwrap();
+    assert_eq!(kept.previous_password.as_deref(), Some("old"));
+
+    let later = now + ChronoDuration::hours(2);
+    let (retired, rotated) =
+        users::next_credentials(Some(existing.clone()), Some(&rotation(30, 24)), later).unwrap();
+    assert!(!rotated);
+    assert_eq!(retired.password, "current");
+    assert!(retired.previous_password.is_none());
+    assert!(retired.previous_expires_at.is_none());
+
+    // Removing the schedule retires the previous password too
+    let (unscheduled, _) = users::next_credentials(Some(existing), None, now).unwrap();
+    assert!(unscheduled.previous_password.is_none());
+}
+
+#[test]
+fn recheck_follows_the_earliest_rotation_or_retirement() {
+    let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let status = |next_rotation_days: Option<i64>, expires_hours: Option<i64>| UserStatus {
+        name: "orders-app".to_string(),
+        secret_name: "proxy-user-orders-app".to_string(),
+        rotated_at: now,
+        next_rotation_at: next_rotation_days.map(|d| now + ChronoDuration::days(d)),
+        previous_password_expires_at: expires_hours.map(|h| now + ChronoDuration::hours(h)),
+    };
+
+    assert_eq!(users::recheck_after(&[status(None, None)], now), None);
+    assert_eq!(
+        users::recheck_after(&[status(Some(30), None), status(Some(10), Some(6))], now),
+        Some(std::time::Duration::from_secs(6 * 3600))
+    );
+}
@@ -0,0 +1,34 @@
+//! Integration tests for controller watch scoping
+
+use kafka_partition_remapper_operator::controllers::WatchScope;
+
+#[test]
+fn empty_scope_is_cluster_wide() {
+    let scope = WatchScope::new("", None);
+    assert!(scope.is_cluster_wide());
+    assert_eq!(scope.label_selector, None);
+}
+
+#[test]
+fn namespaces_are_split_and_trimmed() {
+    let scope = WatchScope::new(" team-a, team-b ,,", Some(""));
+    assert!(!scope.is_cluster_wide());
+    assert_eq!(scope.namespaces, vec!["team-a", "team-b"]);
+    assert_eq!(scope.label_selector, None);
+}
+
+#[test]
+fn label_selector_is_kept() {
+    let scope = WatchScope::new("", Some("team=payments"));
+    assert_eq!(scope.label_selector.as_deref(), Some("team=payments"));
+}
+
+#[test]
+fn one_controller_runs_per_watched_namespace() {
+    assert_eq!(WatchScope::new("", None).controller_count(), 1);
+    assert_eq!(WatchScope::new("team-a", None).controller_count(), 1);
+    assert_eq!(
+        WatchScope::new("team-a,team-b,team-c", None).controller_count(),
+        3
+    );
+}
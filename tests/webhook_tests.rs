@@ -0,0 +1,277 @@
+//! Integration tests for the admission webhooks
+
+use kafka_partition_remapper_operator::crd::KafkaPartitionRemapper;
+use kafka_partition_remapper_operator::webhooks::{configuration, mutating, validating};
+use kube::core::admission::{AdmissionRequest, AdmissionReview};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn admission_request(
+    operation: &str,
+    object: Option<serde_json::Value>,
+) -> AdmissionRequest<KafkaPartitionRemapper> {
+    admission_update_request(operation, object, None)
+}
+
+fn admission_update_request(
+    operation: &str,
+    object: Option<serde_json::Value>,
+    old_object: Option<serde_json::Value>,
+) -> AdmissionRequest<KafkaPartitionRemapper> {
+    let review: AdmissionReview<KafkaPartitionRemapper> =
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "admission.k8s.io/v1",
+            "kind": "AdmissionReview",
+            "request": {
+                "uid": "705ab4f5-6393-11e8-b7cc-42010a800002",
+                "kind": {"group": "kafka.oso.sh", "version": "v1alpha1", "kind": "KafkaPartitionRemapper"},
+                "resource": {"group": "kafka.oso.sh", "version": "v1alpha1", "resource": "kafkapartitionremappers"},
+                "name": "test-remapper",
+                "namespace": "default",
+                "operation": operation,
+                "userInfo": {"username": "admin"},
+                "object": object,
+                "oldObject": old_object,
+                "dryRun": false
+            }
+        }))
+        .expect("valid admission review");
+    review.try_into().expect("review contains a request")
+}
+
+fn remapper_object(virtual_partitions: u32, physical_partitions: u32) -> serde_json::Value {
+    serde_json::json!({
+        "apiVersion": "kafka.oso.sh/v1alpha1",
+        "kind": "KafkaPartitionRemapper",
+        "metadata": {"name": "test-remapper", "namespace": "default"},
+        "spec": {
+            "kafka": {"bootstrapServers": ["kafka:9092"]},
+            "mapping": {
+                "virtualPartitions": virtual_partitions,
+                "physicalPartitions": physical_partitions
+            }
+        }
+    })
+}
+
+// ============================================================================
+// Validating Webhook Tests
+// ============================================================================
+
+#[test]
+fn validating_webhook_allows_valid_spec() {
+    let req = admission_request("CREATE", Some(remapper_object(1000, 100)));
+    let response = validating::validate(&req);
+
+    assert!(response.allowed);
+    assert_eq!(response.uid, "705ab4f5-6393-11e8-b7cc-42010a800002");
+}
+
+#[test]
+fn validating_webhook_denies_invalid_spec() {
+    let req = admission_request("UPDATE", Some(remapper_object(1001, 100)));
+    let response = validating::validate(&req);
+
+    assert!(!response.allowed);
+    assert!(response.result.message.contains("divisible"));
+}
+
+#[test]
+fn validating_webhook_allows_delete_without_object() {
+    let req = admission_request("DELETE", None);
+    assert!(validating::validate(&req).allowed);
+}
+
+#[test]
+fn validating_webhook_denies_breaking_mapping_change() {
+    let old = remapper_object(1000, 100);
+    let req = admission_update_request("UPDATE", Some(remapper_object(500, 100)), Some(old));
+    let response = validating::validate(&req);
+
+    assert!(!response.allowed);
+    assert!(response.result.message.contains("virtualPartitions"));
+}
+
+#[test]
+fn validating_webhook_allows_breaking_change_with_annotation() {
+    let old = remapper_object(1000, 100);
+    let mut new = remapper_object(500, 100);
+    new["metadata"]["annotations"] =
+        serde_json::json!({"kafka.oso.sh/allow-breaking-change": "true"});
+
+    let req = admission_update_request("UPDATE", Some(new), Some(old));
+    assert!(validating::validate(&req).allowed);
+}
+
+#[test]
+fn validating_webhook_denies_name_override_change() {
+    let mut old = remapper_object(1000, 100);
+    old["spec"]["nameOverride"] = serde_json::json!("proxy");
+    let mut new = remapper_object(1000, 100);
+    new["spec"]["nameOverride"] = serde_json::json!("remapper");
+
+    let req = admission_update_request("UPDATE", Some(new), Some(old));
+    let response = validating::validate(&req);
+
+    assert!(!response.allowed);
+    assert!(response
+        .result
+        .message
+        .contains("nameOverride cannot be changed"));
+}
+
+#[test]
+fn webhook_configuration_targets_operator_service() {
+    let config =
+        configuration::build_validating_webhook_configuration("operator-webhook", "ops", 443);
+    let webhook = &config.webhooks.unwrap()[0];
+    let service = webhook.client_config.service.as_ref().unwrap();
+
+    assert_eq!(service.name, "operator-webhook");
+    assert_eq!(service.namespace, "ops");
+    assert_eq!(service.path.as_deref(), Some(validating::VALIDATE_PATH));
+    assert_eq!(
+        webhook.rules.as_ref().unwrap()[0].resources,
+        Some(vec!["kafkapartitionremappers".to_string()])
+    );
+}
+
+// ============================================================================
+// Mutating Webhook Tests
+// ============================================================================
+
+fn decode_patch(response: &kube::core::admission::AdmissionResponse) -> json_patch::Patch {
+    serde_json::from_slice(response.patch.as_ref().expect("patch expected")).unwrap()
+}
+
+fn patched(object: serde_json::Value, patch: &json_patch::Patch) -> KafkaPartitionRemapper {
+    let mut object = object;
+    json_patch::patch(&mut object, patch).unwrap();
+    serde_json::from_value(object).unwrap()
+}
+
+#[test]
+fn mutating_webhook_fills_defaults_and_normalizes_casing() {
+    let mut object = remapper_object(1000, 100);
+    object["spec"]["kafka"]["securityProtocol"] = serde_json::json!("sasl_plaintext");
+    object["spec"]["kafka"]["saslSecret"] =
+        serde_json::json!({"name": "creds", "mechanism": "scram-sha-512"});
+    object["spec"]["logging"] = serde_json::json!({"level": "DEBUG"});
+
+    let req = admission_request("CREATE", Some(object.clone()));
+    let response = mutating::mutate(&req);
+    assert!(response.allowed);
+
+    // The schema only lists canonical spellings, so the patch must carry them
+    let mut patched_object = object.clone();
+    json_patch::patch(&mut patched_object, &decode_patch(&response)).unwrap();
+    assert_eq!(
+        patched_object["spec"]["kafka"]["securityProtocol"],
+        "SASL_PLAINTEXT"
+    );
+    assert_eq!(
+        patched_object["spec"]["kafka"]["saslSecret"]["mechanism"],
+        "SCRAM-SHA-512"
+    );
+    assert_eq!(patched_object["spec"]["logging"]["level"], "debug");
+
+    let remapper = patched(object, &decode_patch(&response));
+    assert_eq!(
+        remapper.spec.listeners[0].advertised_address.as_deref(),
+        Some("test-remapper.default.svc.cluster.local:9092")
+    );
+    let requests = remapper
+        .spec
+        .pod_template
+        .unwrap()
+        .resources
+        .unwrap()
+        .requests;
+    assert_eq!(requests.get("cpu").map(String::as_str), Some("100m"));
+    assert_eq!(requests.get("memory").map(String::as_str), Some("128Mi"));
+}
+
+#[test]
+fn mutating_webhook_keeps_user_values() {
+    let mut object = remapper_object(1000, 100);
+    object["spec"]["listeners"] =
+        serde_json::json!([{ "name": "kafka", "advertisedAddress": "kafka.example.com:9092" }]);
+    object["spec"]["podTemplate"] = serde_json::json!({"resources": {"requests": {"cpu": "1"}}});
+
+    let req = admission_request("CREATE", Some(object.clone()));
+    let remapper = patched(object, &decode_patch(&mutating::mutate(&req)));
+
+    assert_eq!(
+        remapper.spec.listeners[0].advertised_address.as_deref(),
+        Some("kafka.example.com:9092")
+    );
+    let requests = remapper
+        .spec
+        .pod_template
+        .unwrap()
+        .resources
+        .unwrap()
+        .requests;
+    assert_eq!(requests.get("cpu").map(String::as_str), Some("1"));
+    assert_eq!(requests.get("memory").map(String::as_str), Some("128Mi"));
+}
+
+#[test]
+fn mutating_webhook_skips_default_requests_above_limits() {
+    let mut object = remapper_object(1000, 100);
+    object["spec"]["podTemplate"] =
+        serde_json::json!({"resources": {"limits": {"memory": "64Mi"}}});
+
+    let req = admission_request("CREATE", Some(object.clone()));
+    let remapper = patched(object, &decode_patch(&mutating::mutate(&req)));
+
+    let requests = remapper
+        .spec
+        .pod_template
+        .unwrap()
+        .resources
+        .unwrap()
+        .requests;
+    assert_eq!(requests.get("cpu").map(String::as_str), Some("100m"));
+    assert_eq!(requests.get("memory"), None);
+}
+
+#[test]
+fn mutating_webhook_rederives_defaulted_address_on_port_change() {
+    let mut old = remapper_object(1000, 100);
+    old["spec"]["listeners"] = serde_json::json!([{
+        "name": "kafka",
+        "advertisedAddress": "test-remapper.default.svc.cluster.local:9092"
+    }]);
+    let mut new = old.clone();
+    new["spec"]["listeners"][0]["port"] = serde_json::json!(19092);
+
+    let req = admission_update_request("UPDATE", Some(new.clone()), Some(old));
+    let remapper = patched(new, &decode_patch(&mutating::mutate(&req)));
+
+    assert_eq!(
+        remapper.spec.listeners[0].advertised_address.as_deref(),
+        Some("test-remapper.default.svc.cluster.local:19092")
+    );
+}
+
+#[test]
+fn mutating_webhook_defaults_address_to_external_hostname() {
+    let mut old = remapper_object(1000, 100);
+    old["spec"]["listeners"] = serde_json::json!([{
+        "name": "kafka",
+        "advertisedAddress": "test-remapper.default.svc.cluster.local:9092"
+    }]);
+    let mut new = old.clone();
+    new["spec"]["service"] = serde_json::json!({ "externalHostname": "kafka.example.com" });
+
+    let req = admission_update_request("UPDATE", Some(new.clone()), Some(old));
+    let remapper = patched(new, &decode_patch(&mutating::mutate(&req)));
+
+    assert_eq!(
+        remapper.spec.listeners[0].advertised_address.as_deref(),
+        Some("kafka.example.com:9092")
+    );
+}